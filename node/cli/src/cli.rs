@@ -1,3 +1,9 @@
+use std::str::FromStr;
+
+use ddc_primitives::{ClusterId, NodePubKey, OcwSettings};
+use sp_core::crypto::AccountId32;
+use url::Url;
+
 #[allow(missing_docs)]
 #[derive(Debug, clap::Parser)]
 pub struct Cli {
@@ -27,6 +33,106 @@ pub struct RunCmd {
 	/// telemetry, if telemetry is enabled.
 	#[arg(long)]
 	pub no_hardware_benchmarks: bool,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub ddc_ocw: DdcOcwParams,
+}
+
+/// Settings of the DDC verification offchain worker.
+///
+/// They are written to the offchain local storage at startup, the offchain worker falls back to
+/// its defaults for the settings which are not provided.
+#[derive(Debug, Clone, clap::Args)]
+pub struct DdcOcwParams {
+	/// Timeout of a request to a DAC aggregator, in milliseconds.
+	#[arg(long = "ddc-ocw-response-timeout", value_name = "MS")]
+	pub response_timeout: Option<u64>,
+
+	/// Number of attempts of a request to a DAC aggregator, the first one included.
+	#[arg(
+		long = "ddc-ocw-retries",
+		value_name = "COUNT",
+		value_parser = clap::value_parser!(u32).range(1..)
+	)]
+	pub retries: Option<u32>,
+
+	/// Page size used to fetch buckets aggregates from DAC aggregators.
+	#[arg(
+		long = "ddc-ocw-buckets-batch-size",
+		value_name = "SIZE",
+		value_parser = clap::value_parser!(u32).range(1..)
+	)]
+	pub buckets_batch_size: Option<u32>,
+
+	/// Page size used to fetch nodes aggregates from DAC aggregators.
+	#[arg(
+		long = "ddc-ocw-nodes-batch-size",
+		value_name = "SIZE",
+		value_parser = clap::value_parser!(u32).range(1..)
+	)]
+	pub nodes_batch_size: Option<u32>,
+
+	/// Cluster to validate, may be repeated.
+	///
+	/// All activated clusters are validated if no cluster is specified.
+	#[arg(long = "ddc-ocw-cluster", value_name = "CLUSTER_ID", value_parser = parse_cluster_id)]
+	pub clusters: Vec<ClusterId>,
+
+	/// Cluster to never validate, may be repeated.
+	#[arg(
+		long = "ddc-ocw-exclude-cluster",
+		value_name = "CLUSTER_ID",
+		value_parser = parse_cluster_id
+	)]
+	pub excluded_clusters: Vec<ClusterId>,
+
+	/// Base URL of a DAC node to use instead of its registered host and port, may be repeated.
+	///
+	/// Example: `--ddc-ocw-endpoint 0x9ef9..6d3c=http://dac-1.internal:8080`.
+	#[arg(
+		long = "ddc-ocw-endpoint",
+		value_name = "NODE_KEY=URL",
+		value_parser = parse_endpoint_override
+	)]
+	pub endpoint_overrides: Vec<(NodePubKey, Url)>,
+}
+
+impl DdcOcwParams {
+	/// Offchain worker settings to be stored in the offchain local storage.
+	pub fn settings(&self) -> OcwSettings {
+		OcwSettings {
+			response_timeout_ms: self.response_timeout,
+			retries: self.retries,
+			buckets_aggregates_batch_size: self.buckets_batch_size,
+			nodes_aggregates_batch_size: self.nodes_batch_size,
+			clusters: self.clusters.clone(),
+			excluded_clusters: self.excluded_clusters.clone(),
+			endpoint_overrides: self
+				.endpoint_overrides
+				.iter()
+				.map(|(node_pub_key, url)| {
+					(node_pub_key.clone(), url.as_str().trim_end_matches('/').as_bytes().to_vec())
+				})
+				.collect(),
+		}
+	}
+}
+
+pub(crate) fn parse_cluster_id(s: &str) -> Result<ClusterId, String> {
+	ClusterId::from_str(s.trim_start_matches("0x")).map_err(|e| format!("{:?}", e))
+}
+
+fn parse_endpoint_override(s: &str) -> Result<(NodePubKey, Url), String> {
+	let (node_key, url) = s.split_once('=').ok_or_else(|| "expected `NODE_KEY=URL`".to_string())?;
+
+	let node_key = AccountId32::from_str(node_key).map_err(|e| format!("{:?}", e))?;
+	let url = Url::parse(url).map_err(|e| e.to_string())?;
+	if !matches!(url.scheme(), "http" | "https") {
+		return Err(format!("unsupported URL scheme `{}`", url.scheme()));
+	}
+
+	Ok((NodePubKey::StoragePubKey(node_key), url))
 }
 
 #[allow(missing_docs, clippy::large_enum_variant)]
@@ -71,4 +177,7 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Replay the DDC validation of a cluster era without submitting transactions.
+	DdcValidate(crate::ddc_validate::DdcValidateCmd),
 }
@@ -195,12 +195,23 @@ pub fn run() -> sc_cli::Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|config| cmd.run::<cere_service::Block>(&config))
 		},
+		Some(Subcommand::DdcValidate(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.sync_run(|config| {
+				let (client, _, _, _) = cere_service::new_chain_ops(&config)?;
+				cmd.run(client)
+			})
+		},
 		None => {
 			let runner = cli.create_runner(&cli.run.base)?;
 			runner.run_node_until_exit(|config| async move {
-				cere_service::build_full(config, cli.run.no_hardware_benchmarks)
-					.map(|full| full.task_manager)
-					.map_err(Error::Service)
+				cere_service::build_full(
+					config,
+					cli.run.no_hardware_benchmarks,
+					cli.run.ddc_ocw.settings(),
+				)
+				.map(|full| full.task_manager)
+				.map_err(Error::Service)
 			})
 		},
 	}
@@ -0,0 +1,135 @@
+use std::{
+	fs::File,
+	io::{BufReader, BufWriter},
+	path::PathBuf,
+	sync::Arc,
+};
+
+use cere_service::{
+	ddc_validate::{dry_run_ddc_era, DdcDryRun, HttpExchange, ValidationDryRun},
+	Block, Client,
+};
+use ddc_primitives::{ClusterId, DdcEra};
+use sc_cli::{BlockNumberOrHash, CliConfiguration, ImportParams, SharedParams};
+
+use crate::cli::{parse_cluster_id, DdcOcwParams};
+
+/// The `ddc-validate` command used to replay the DDC validation of a cluster era.
+///
+/// The validation is executed against the state of a block and the responses of DAC aggregators,
+/// nothing is stored or submitted. The exchanges with aggregators can be recorded and replayed
+/// later, e.g. to reproduce the roots computed by another validator.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct DdcValidateCmd {
+	/// Cluster to validate.
+	#[arg(long, value_name = "CLUSTER_ID", value_parser = parse_cluster_id)]
+	pub cluster_id: ClusterId,
+
+	/// Era to validate.
+	#[arg(long, value_name = "ERA")]
+	pub era: DdcEra,
+
+	/// Block to replay the validation at, the best block by default.
+	#[arg(long, value_name = "HASH or NUMBER")]
+	pub at: Option<BlockNumberOrHash>,
+
+	/// Save the exchanges with DAC aggregators to a file.
+	#[arg(long, value_name = "FILE", conflicts_with = "replay")]
+	pub record: Option<PathBuf>,
+
+	/// Replay the exchanges with DAC aggregators saved with `--record` instead of sending
+	/// requests.
+	#[arg(long, value_name = "FILE")]
+	pub replay: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub ddc_ocw: DdcOcwParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+}
+
+impl DdcValidateCmd {
+	/// Run the command.
+	pub fn run(&self, client: Arc<Client>) -> sc_cli::Result<()> {
+		let replay = match &self.replay {
+			Some(path) => {
+				let exchanges: Vec<HttpExchange> =
+					serde_json::from_reader(BufReader::new(File::open(path)?))
+						.map_err(|e| format!("Invalid recorded exchanges: {}", e))?;
+				Some(exchanges)
+			},
+			None => None,
+		};
+
+		let output = dry_run_ddc_era(
+			&client,
+			DdcDryRun {
+				cluster_id: self.cluster_id,
+				era_id: self.era,
+				at: self.at.as_ref().map(|at| at.parse::<Block>()).transpose()?,
+				ocw_settings: self.ddc_ocw.settings(),
+				replay,
+			},
+		)?;
+
+		if let Some(path) = &self.record {
+			serde_json::to_writer_pretty(BufWriter::new(File::create(path)?), &output.exchanges)
+				.map_err(|e| format!("Failed to record exchanges: {}", e))?;
+		}
+
+		match output.result {
+			Ok(dry_run) => {
+				print_dry_run(&dry_run);
+				Ok(())
+			},
+			Err(errors) =>
+				Err(format!("Validation of era {} failed: {:?}", self.era, errors).into()),
+		}
+	}
+}
+
+fn print_dry_run(dry_run: &ValidationDryRun) {
+	println!("Era {} [{}, {}]", dry_run.era.id, dry_run.era.start, dry_run.era.end);
+
+	for (kind, groups) in [("Buckets", &dry_run.buckets_groups), ("Nodes", &dry_run.nodes_groups)] {
+		println!("{} consistency groups:", kind);
+		for (group, aggregates) in [
+			("consensus", &groups.consensus),
+			("quorum", &groups.quorum),
+			("others", &groups.others),
+		] {
+			println!("  {} ({}):", group, aggregates.len());
+			for (key, hash, count) in aggregates {
+				println!("    {:?} {:?} reported by {} aggregators", key, hash, count);
+			}
+		}
+	}
+
+	println!("Payers delta root: {:?}", dry_run.payers_delta_root);
+	println!("Payers delta batch roots: {:?}", dry_run.payers_delta_batch_roots);
+	println!("Payees delta root: {:?}", dry_run.payees_delta_root);
+	println!("Payees delta batch roots: {:?}", dry_run.payees_delta_batch_roots);
+	println!("Payers root: {:?}", dry_run.payers_root);
+	println!("Payers batch roots: {:?}", dry_run.payers_batch_roots);
+	println!("Payees root: {:?}", dry_run.payees_root);
+	println!("Payees batch roots: {:?}", dry_run.payees_batch_roots);
+	println!("Cluster usage: {:?}", dry_run.cluster_usage);
+	println!("Fingerprint: {:?}", dry_run.fingerprint);
+}
+
+impl CliConfiguration for DdcValidateCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+}
@@ -6,10 +6,14 @@
 mod cli;
 #[cfg(feature = "cli")]
 mod command;
+#[cfg(feature = "cli")]
+mod ddc_validate;
 
 #[cfg(feature = "cli")]
 pub use cli::*;
 #[cfg(feature = "cli")]
 pub use command::*;
 #[cfg(feature = "cli")]
+pub use ddc_validate::*;
+#[cfg(feature = "cli")]
 pub use sc_cli::{Error, Result};
@@ -483,6 +483,7 @@ macro_rules! signed_payload {
 			frame_system::CheckNonce::<runtime::Runtime>::from($nonce),
 			frame_system::CheckWeight::<runtime::Runtime>::new(),
 			pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from($tip),
+			pallet_ddc_verification::CheckPayoutStep::<runtime::Runtime>::new(),
 		);
 
 		let $raw_payload = runtime::SignedPayload::from_raw(
@@ -497,6 +498,7 @@ macro_rules! signed_payload {
 				(),
 				(),
 				(),
+				(),
 			),
 		);
 	};
@@ -514,6 +516,7 @@ pub trait RuntimeApiCollection:
 	+ sp_offchain::OffchainWorkerApi<Block>
 	+ sp_session::SessionKeys<Block>
 	+ sp_authority_discovery::AuthorityDiscoveryApi<Block>
+	+ pallet_ddc_verification::runtime_api::DdcVerificationApi<Block, AccountId>
 {
 }
 
@@ -529,6 +532,7 @@ impl<Api> RuntimeApiCollection for Api where
 		+ sp_offchain::OffchainWorkerApi<Block>
 		+ sp_session::SessionKeys<Block>
 		+ sp_authority_discovery::AuthorityDiscoveryApi<Block>
+		+ pallet_ddc_verification::runtime_api::DdcVerificationApi<Block, AccountId>
 {
 }
 
@@ -0,0 +1,113 @@
+//! RPC methods of the DDC verification offchain worker.
+
+use std::sync::Arc;
+
+use ddc_primitives::DAC_VERIFICATION_KEY_TYPE;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use node_primitives::{AccountId, Block, Hash};
+use pallet_ddc_verification::runtime_api::DdcVerificationApi as DdcVerificationRuntimeApi;
+use sc_rpc_api::DenyUnsafe;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_keystore::KeystorePtr;
+
+const RUNTIME_ERROR: i32 = 1;
+
+/// Status of a DAC verification key of the node keystore.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationKeyStatus {
+	/// The verification key.
+	pub key: AccountId,
+	/// The key belongs to a validator of the current session.
+	pub in_validator_set: bool,
+	/// Stash the key is bound to.
+	pub stash: Option<AccountId>,
+	/// The key is the latest key bound to its stash.
+	pub is_latest: bool,
+}
+
+/// Status of the DAC verification keys of the node keystore.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcwKeyStatus {
+	/// Verification keys found in the keystore.
+	pub keys: Vec<VerificationKeyStatus>,
+	/// The offchain worker has exactly one key of a current validator bound to its stash, i.e.
+	/// it is able to validate DDC eras.
+	pub ready: bool,
+}
+
+/// DDC verification RPC methods.
+#[rpc(server)]
+pub trait DdcVerificationApi {
+	/// Status of the DAC verification keys of the node keystore at the given block, the best
+	/// block by default.
+	#[method(name = "ddcVerification_keyStatus")]
+	fn key_status(&self, at: Option<Hash>) -> RpcResult<OcwKeyStatus>;
+}
+
+/// Implementation of the DDC verification RPC methods.
+pub struct DdcVerification<C> {
+	client: Arc<C>,
+	keystore: KeystorePtr,
+	deny_unsafe: DenyUnsafe,
+}
+
+impl<C> DdcVerification<C> {
+	/// Create new `DdcVerification` with the given reference to the client and keystore.
+	pub fn new(client: Arc<C>, keystore: KeystorePtr, deny_unsafe: DenyUnsafe) -> Self {
+		Self { client, keystore, deny_unsafe }
+	}
+}
+
+impl<C> DdcVerificationApiServer for DdcVerification<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: DdcVerificationRuntimeApi<Block, AccountId>,
+{
+	fn key_status(&self, at: Option<Hash>) -> RpcResult<OcwKeyStatus> {
+		// the keys of the node keystore are not exposed to the public
+		self.deny_unsafe.check_if_safe().map_err(|e| {
+			CallError::Custom(ErrorObject::owned(RUNTIME_ERROR, e.to_string(), None::<()>))
+		})?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let keys = self
+			.keystore
+			.sr25519_public_keys(DAC_VERIFICATION_KEY_TYPE)
+			.into_iter()
+			.map(|public| {
+				let key = AccountId::from(public);
+				let status = api.verification_key_status(at, key.clone()).map_err(|e| {
+					CallError::Custom(ErrorObject::owned(
+						RUNTIME_ERROR,
+						"Unable to query the verification key status.",
+						Some(e.to_string()),
+					))
+				})?;
+
+				Ok(VerificationKeyStatus {
+					key,
+					in_validator_set: status.in_validator_set,
+					stash: status.stash,
+					is_latest: status.is_latest,
+				})
+			})
+			.collect::<Result<Vec<_>, JsonRpseeError>>()?;
+
+		let ready = keys
+			.iter()
+			.filter(|status| status.in_validator_set && status.stash.is_some() && status.is_latest)
+			.count() == 1;
+
+		Ok(OcwKeyStatus { keys, ready })
+	}
+}
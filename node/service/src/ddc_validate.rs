@@ -0,0 +1,377 @@
+//! Dry run of the DDC validation of a cluster era.
+//!
+//! The validation implemented by `pallet-ddc-verification` is executed by a runtime call against
+//! the state of a chosen block. The offchain extensions of the call are provided by the node: the
+//! local storage is kept in memory and the requests to DAC aggregators are either sent or
+//! replayed from previously captured exchanges, so that nothing is persisted or submitted.
+
+use std::{
+	collections::{BTreeMap, VecDeque},
+	sync::{Arc, Mutex},
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use codec::Encode;
+use ddc_primitives::{ClusterId, DdcEra, OcwSettings, OCW_SETTINGS_KEY};
+use pallet_ddc_verification::runtime_api::DdcVerificationApi;
+pub use pallet_ddc_verification::{OCWError, ValidationDryRun};
+use sc_service::error::Error as ServiceError;
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, CallContext, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+	offchain::{
+		storage::InMemOffchainStorage, Externalities, HttpError, HttpRequestId, HttpRequestStatus,
+		OffchainDbExt, OffchainStorage, OffchainWorkerExt, OpaqueNetworkState, Timestamp,
+		STORAGE_PREFIX,
+	},
+	Bytes, OpaquePeerId,
+};
+use sp_runtime::{generic::BlockId, traits::BlakeTwo256};
+
+use crate::{AbstractClient, Block, Client, ClientHandle, ExecuteWithClient, RuntimeApiCollection};
+
+/// HTTP request to a DAC aggregator and its response, captured during a dry run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpExchange {
+	pub method: String,
+	pub uri: String,
+	pub status: u16,
+	pub headers: Vec<(String, String)>,
+	pub body: Bytes,
+}
+
+/// Parameters of a dry run of the DDC validation.
+pub struct DdcDryRun {
+	pub cluster_id: ClusterId,
+	pub era_id: DdcEra,
+	/// Block to replay the validation at, the best block if not provided.
+	pub at: Option<BlockId<Block>>,
+	/// Offchain worker settings, e.g. overrides of the aggregators endpoints.
+	pub ocw_settings: OcwSettings,
+	/// Exchanges to replay instead of sending requests to DAC aggregators.
+	pub replay: Option<Vec<HttpExchange>>,
+}
+
+/// Result of a dry run of the DDC validation.
+pub struct DdcDryRunOutput {
+	pub result: Result<ValidationDryRun, Vec<OCWError>>,
+	/// Exchanges with DAC aggregators, in the order of the requests.
+	pub exchanges: Vec<HttpExchange>,
+}
+
+impl ExecuteWithClient for DdcDryRun {
+	type Output = Result<DdcDryRunOutput, ServiceError>;
+
+	fn execute_with_client<Client, Api, Backend>(self, client: Arc<Client>) -> Self::Output
+	where
+		Backend: sc_client_api::Backend<Block>,
+		Backend::State: sp_api::StateBackend<BlakeTwo256>,
+		Api: RuntimeApiCollection,
+		Client: AbstractClient<Block, Backend, Api = Api> + 'static,
+	{
+		let at = match self.at {
+			None => client.info().best_hash,
+			Some(BlockId::Hash(hash)) => hash,
+			Some(BlockId::Number(number)) => client
+				.hash(number)?
+				.ok_or_else(|| ServiceError::Other(format!("Block #{} is not found", number)))?,
+		};
+
+		let mut offchain_storage = InMemOffchainStorage::default();
+		offchain_storage.set(STORAGE_PREFIX, OCW_SETTINGS_KEY, &self.ocw_settings.encode());
+
+		let http = match self.replay {
+			Some(exchanges) => Http::replay(exchanges),
+			None => Http::live()?,
+		};
+		let offchain = DryRunOffchainExt(Arc::new(Mutex::new(http)));
+
+		let mut api = client.runtime_api();
+		api.set_call_context(CallContext::Offchain);
+		api.register_extension(OffchainWorkerExt::new(offchain.clone()));
+		api.register_extension(OffchainDbExt::new(sc_offchain::OffchainDb::new(offchain_storage)));
+
+		let result = api
+			.dry_run_era(at, self.cluster_id, self.era_id)
+			.map_err(sp_blockchain::Error::from)?;
+		let exchanges = offchain.0.lock().expect("http state is not poisoned; qed").take_recorded();
+
+		Ok(DdcDryRunOutput { result, exchanges })
+	}
+}
+
+/// Replay the DDC validation of a cluster era without submitting transactions.
+pub fn dry_run_ddc_era(
+	client: &Client,
+	dry_run: DdcDryRun,
+) -> Result<DdcDryRunOutput, ServiceError> {
+	client.execute_with(dry_run)
+}
+
+enum Transport {
+	Live { runtime: tokio::runtime::Runtime, client: hyper::Client<hyper::client::HttpConnector> },
+	Replay(BTreeMap<(String, String), VecDeque<HttpExchange>>),
+}
+
+enum Request {
+	Pending { method: String, uri: String, headers: Vec<(String, String)>, body: Vec<u8> },
+	Answered { exchange: HttpExchange, read: usize },
+	Failed(HttpRequestStatus),
+}
+
+struct Http {
+	transport: Transport,
+	next_id: u16,
+	requests: BTreeMap<u16, Request>,
+	recorded: Vec<HttpExchange>,
+}
+
+impl Http {
+	fn live() -> Result<Self, ServiceError> {
+		let runtime = tokio::runtime::Builder::new_current_thread()
+			.enable_all()
+			.build()
+			.map_err(|e| ServiceError::Other(e.to_string()))?;
+
+		Ok(Self::new(Transport::Live { runtime, client: hyper::Client::new() }))
+	}
+
+	fn replay(exchanges: Vec<HttpExchange>) -> Self {
+		let mut responses: BTreeMap<_, VecDeque<_>> = BTreeMap::new();
+		for exchange in exchanges {
+			responses
+				.entry((exchange.method.clone(), exchange.uri.clone()))
+				.or_default()
+				.push_back(exchange);
+		}
+
+		Self::new(Transport::Replay(responses))
+	}
+
+	fn new(transport: Transport) -> Self {
+		Self { transport, next_id: 0, requests: BTreeMap::new(), recorded: Vec::new() }
+	}
+
+	fn take_recorded(&mut self) -> Vec<HttpExchange> {
+		std::mem::take(&mut self.recorded)
+	}
+
+	fn send(
+		&mut self,
+		method: String,
+		uri: String,
+		headers: Vec<(String, String)>,
+		body: Vec<u8>,
+		deadline: Option<Timestamp>,
+	) -> Result<HttpExchange, HttpRequestStatus> {
+		match &mut self.transport {
+			Transport::Live { runtime, client } => {
+				let mut request = hyper::Request::builder().method(method.as_str()).uri(&uri);
+				for (name, value) in headers {
+					request = request.header(name, value);
+				}
+				let request = request
+					.body(hyper::Body::from(body))
+					.map_err(|_| HttpRequestStatus::Invalid)?;
+
+				let response = async {
+					let response = client.request(request).await?;
+					let status = response.status().as_u16();
+					let headers = response
+						.headers()
+						.iter()
+						.map(|(name, value)| {
+							(name.to_string(), String::from_utf8_lossy(value.as_bytes()).into())
+						})
+						.collect();
+					let body = hyper::body::to_bytes(response.into_body()).await?;
+					Ok::<_, hyper::Error>((status, headers, body.to_vec()))
+				};
+
+				let (status, headers, body) = runtime
+					.block_on(async {
+						match deadline {
+							Some(deadline) => {
+								let timeout = Duration::from_millis(deadline.diff(&now()).millis());
+								tokio::time::timeout(timeout, response)
+									.await
+									.map_err(|_| HttpRequestStatus::DeadlineReached)
+							},
+							None => Ok(response.await),
+						}
+					})?
+					.map_err(|_| HttpRequestStatus::IoError)?;
+
+				Ok(HttpExchange { method, uri, status, headers, body: body.into() })
+			},
+			Transport::Replay(responses) => {
+				let exchanges =
+					responses.get_mut(&(method, uri)).ok_or(HttpRequestStatus::IoError)?;
+
+				// the last response is replayed for all the further requests
+				match exchanges.len() {
+					0 => Err(HttpRequestStatus::IoError),
+					1 => Ok(exchanges[0].clone()),
+					_ => exchanges.pop_front().ok_or(HttpRequestStatus::IoError),
+				}
+			},
+		}
+	}
+
+	fn wait(&mut self, id: HttpRequestId, deadline: Option<Timestamp>) -> HttpRequestStatus {
+		let Some(request) = self.requests.remove(&id.0) else { return HttpRequestStatus::Invalid };
+
+		let (status, request) = match request {
+			Request::Pending { method, uri, headers, body } =>
+				match self.send(method, uri, headers, body, deadline) {
+					Ok(exchange) => {
+						self.recorded.push(exchange.clone());
+						(
+							HttpRequestStatus::Finished(exchange.status),
+							Request::Answered { exchange, read: 0 },
+						)
+					},
+					Err(status) => (status, Request::Failed(status)),
+				},
+			Request::Answered { exchange, read } =>
+				(HttpRequestStatus::Finished(exchange.status), Request::Answered { exchange, read }),
+			Request::Failed(status) => (status, Request::Failed(status)),
+		};
+
+		self.requests.insert(id.0, request);
+		status
+	}
+}
+
+/// Offchain worker externalities of a dry run.
+#[derive(Clone)]
+struct DryRunOffchainExt(Arc<Mutex<Http>>);
+
+impl DryRunOffchainExt {
+	fn http(&self) -> std::sync::MutexGuard<'_, Http> {
+		self.0.lock().expect("http state is not poisoned; qed")
+	}
+}
+
+fn now() -> Timestamp {
+	let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+	Timestamp::from_unix_millis(now.as_millis() as u64)
+}
+
+impl Externalities for DryRunOffchainExt {
+	fn is_validator(&self) -> bool {
+		true
+	}
+
+	fn network_state(&self) -> Result<OpaqueNetworkState, ()> {
+		Err(())
+	}
+
+	fn timestamp(&mut self) -> Timestamp {
+		now()
+	}
+
+	fn sleep_until(&mut self, deadline: Timestamp) {
+		std::thread::sleep(Duration::from_millis(deadline.diff(&now()).millis()));
+	}
+
+	fn random_seed(&mut self) -> [u8; 32] {
+		rand::random()
+	}
+
+	fn http_request_start(
+		&mut self,
+		method: &str,
+		uri: &str,
+		_meta: &[u8],
+	) -> Result<HttpRequestId, ()> {
+		let mut http = self.http();
+		let id = http.next_id;
+		http.next_id = id.checked_add(1).ok_or(())?;
+		http.requests.insert(
+			id,
+			Request::Pending {
+				method: method.into(),
+				uri: uri.into(),
+				headers: Vec::new(),
+				body: Vec::new(),
+			},
+		);
+
+		Ok(HttpRequestId(id))
+	}
+
+	fn http_request_add_header(
+		&mut self,
+		request_id: HttpRequestId,
+		name: &str,
+		value: &str,
+	) -> Result<(), ()> {
+		match self.http().requests.get_mut(&request_id.0) {
+			Some(Request::Pending { headers, .. }) => {
+				headers.push((name.into(), value.into()));
+				Ok(())
+			},
+			_ => Err(()),
+		}
+	}
+
+	fn http_request_write_body(
+		&mut self,
+		request_id: HttpRequestId,
+		chunk: &[u8],
+		_deadline: Option<Timestamp>,
+	) -> Result<(), HttpError> {
+		match self.http().requests.get_mut(&request_id.0) {
+			Some(Request::Pending { body, .. }) => {
+				body.extend_from_slice(chunk);
+				Ok(())
+			},
+			_ => Err(HttpError::Invalid),
+		}
+	}
+
+	fn http_response_wait(
+		&mut self,
+		ids: &[HttpRequestId],
+		deadline: Option<Timestamp>,
+	) -> Vec<HttpRequestStatus> {
+		let mut http = self.http();
+		ids.iter().map(|id| http.wait(*id, deadline)).collect()
+	}
+
+	fn http_response_headers(&mut self, request_id: HttpRequestId) -> Vec<(Vec<u8>, Vec<u8>)> {
+		match self.http().requests.get(&request_id.0) {
+			Some(Request::Answered { exchange, .. }) => exchange
+				.headers
+				.iter()
+				.map(|(name, value)| (name.as_bytes().to_vec(), value.as_bytes().to_vec()))
+				.collect(),
+			_ => Vec::new(),
+		}
+	}
+
+	fn http_response_read_body(
+		&mut self,
+		request_id: HttpRequestId,
+		buffer: &mut [u8],
+		_deadline: Option<Timestamp>,
+	) -> Result<usize, HttpError> {
+		match self.http().requests.get_mut(&request_id.0) {
+			Some(Request::Answered { exchange, read }) => {
+				let remaining = &exchange.body.0[*read..];
+				let len = remaining.len().min(buffer.len());
+				buffer[..len].copy_from_slice(&remaining[..len]);
+				*read += len;
+				Ok(len)
+			},
+			Some(Request::Failed(HttpRequestStatus::DeadlineReached)) =>
+				Err(HttpError::DeadlineReached),
+			Some(Request::Failed(_)) => Err(HttpError::IoError),
+			_ => Err(HttpError::Invalid),
+		}
+	}
+
+	fn set_authorized_nodes(&mut self, _nodes: Vec<OpaquePeerId>, _authorized_only: bool) {}
+}
@@ -20,6 +20,8 @@ use sc_service::{
 use sc_telemetry::{Telemetry, TelemetryWorker};
 use sp_runtime::traits::{BlakeTwo256, Block as BlockT};
 pub mod chain_spec;
+pub mod ddc_validate;
+mod ocw_metrics;
 #[cfg(feature = "cere-dev-native")]
 pub use cere_client::CereDevExecutorDispatch;
 #[cfg(feature = "cere-native")]
@@ -29,6 +31,8 @@ pub use cere_client::{
 	RuntimeApiCollection,
 };
 pub use chain_spec::{CereChainSpec, CereDevChainSpec};
+use codec::Encode;
+use ddc_primitives::{OcwSettings, OCW_SETTINGS_KEY};
 pub use node_primitives::{Block, BlockNumber};
 use sc_executor::{
 	HeapAllocStrategy, NativeElseWasmExecutor, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY,
@@ -36,6 +40,7 @@ use sc_executor::{
 pub use sc_service::ChainSpec;
 use sc_transaction_pool_api::OffchainTransactionPoolFactory;
 pub use sp_api::ConstructRuntimeApi;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
 
 /// The minimum period of blocks on which justifications will be
 /// imported and generated.
@@ -278,12 +283,14 @@ where
 pub fn build_full(
 	config: Configuration,
 	disable_hardware_benchmarks: bool,
+	ocw_settings: OcwSettings,
 ) -> Result<NewFull<Client>, ServiceError> {
 	#[cfg(feature = "cere-dev-native")]
 	if config.chain_spec.is_cere_dev() {
 		return new_full::<cere_dev_runtime::RuntimeApi, CereDevExecutorDispatch>(
 			config,
 			disable_hardware_benchmarks,
+			ocw_settings,
 			|_, _| (),
 		)
 		.map(|full| full.with_client(Client::CereDev));
@@ -294,6 +301,7 @@ pub fn build_full(
 		new_full::<cere_runtime::RuntimeApi, CereExecutorDispatch>(
 			config,
 			disable_hardware_benchmarks,
+			ocw_settings,
 			|_, _| (),
 		)
 		.map(|full| full.with_client(Client::Cere))
@@ -327,6 +335,7 @@ impl<C> NewFull<C> {
 pub fn new_full<RuntimeApi, ExecutorDispatch>(
 	config: Configuration,
 	disable_hardware_benchmarks: bool,
+	ocw_settings: OcwSettings,
 	with_startup_data: impl FnOnce(
 		&sc_consensus_babe::BabeBlockImport<
 			Block,
@@ -401,6 +410,10 @@ where
 		})?;
 
 	if config.offchain_worker.enabled {
+		if let Some(mut offchain_storage) = backend.offchain_storage() {
+			offchain_storage.set(STORAGE_PREFIX, OCW_SETTINGS_KEY, &ocw_settings.encode());
+		}
+
 		task_manager.spawn_handle().spawn(
 			"offchain-workers-runner",
 			"offchain-worker",
@@ -429,6 +442,14 @@ where
 	let enable_grandpa = !config.disable_grandpa;
 	let prometheus_registry = config.prometheus_registry().cloned();
 
+	if config.offchain_worker.enabled && role.is_authority() {
+		if let (Some(registry), Some(offchain_storage)) =
+			(prometheus_registry.as_ref(), backend.offchain_storage())
+		{
+			ocw_metrics::spawn(&task_manager, client.clone(), offchain_storage, registry)?;
+		}
+	}
+
 	let rpc_handlers = sc_service::spawn_tasks(sc_service::SpawnTasksParams {
 		config,
 		backend: backend.clone(),
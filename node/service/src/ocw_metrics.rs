@@ -0,0 +1,228 @@
+//! Prometheus metrics of the DDC verification offchain worker.
+//!
+//! The offchain worker accumulates its metrics in the offchain local storage, they are exported
+//! to the node Prometheus registry after each imported block.
+
+use std::sync::Arc;
+
+use codec::Decode;
+use ddc_primitives::{OcwMetrics, OCW_METRICS_KEY};
+use futures::StreamExt;
+use node_primitives::Block;
+use sc_client_api::BlockchainEvents;
+use sc_service::TaskManager;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+use substrate_prometheus_endpoint::{
+	register, CounterVec, GaugeVec, Opts, PrometheusError, Registry, U64,
+};
+
+struct Metrics {
+	runs: CounterVec<U64>,
+	phase_runs: CounterVec<U64>,
+	phase_duration: CounterVec<U64>,
+	phase_last_duration: GaugeVec<U64>,
+	aggregator_requests: CounterVec<U64>,
+	aggregator_request_errors: CounterVec<U64>,
+	aggregator_request_duration: CounterVec<U64>,
+	consistency_groups: GaugeVec<U64>,
+	challenges: CounterVec<U64>,
+	challenges_failed: CounterVec<U64>,
+	submitted_errors: CounterVec<U64>,
+}
+
+impl Metrics {
+	fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			runs: register(
+				CounterVec::new(
+					Opts::new("ddc_ocw_runs_total", "Number of offchain worker runs per cluster"),
+					&["cluster"],
+				)?,
+				registry,
+			)?,
+			phase_runs: register(
+				CounterVec::new(
+					Opts::new("ddc_ocw_phase_runs_total", "Number of offchain worker phase runs"),
+					&["cluster", "phase"],
+				)?,
+				registry,
+			)?,
+			phase_duration: register(
+				CounterVec::new(
+					Opts::new(
+						"ddc_ocw_phase_duration_ms_total",
+						"Total duration of offchain worker phases in milliseconds",
+					),
+					&["cluster", "phase"],
+				)?,
+				registry,
+			)?,
+			phase_last_duration: register(
+				GaugeVec::new(
+					Opts::new(
+						"ddc_ocw_phase_last_duration_ms",
+						"Duration of the last offchain worker phase run in milliseconds",
+					),
+					&["cluster", "phase"],
+				)?,
+				registry,
+			)?,
+			aggregator_requests: register(
+				CounterVec::new(
+					Opts::new(
+						"ddc_ocw_aggregator_requests_total",
+						"Number of requests to DAC aggregators",
+					),
+					&["node"],
+				)?,
+				registry,
+			)?,
+			aggregator_request_errors: register(
+				CounterVec::new(
+					Opts::new(
+						"ddc_ocw_aggregator_request_errors_total",
+						"Number of failed requests to DAC aggregators",
+					),
+					&["node"],
+				)?,
+				registry,
+			)?,
+			aggregator_request_duration: register(
+				CounterVec::new(
+					Opts::new(
+						"ddc_ocw_aggregator_request_duration_ms_total",
+						"Total latency of requests to DAC aggregators in milliseconds",
+					),
+					&["node"],
+				)?,
+				registry,
+			)?,
+			consistency_groups: register(
+				GaugeVec::new(
+					Opts::new(
+						"ddc_ocw_consistency_group_size",
+						"Number of aggregates in the last consistency groups",
+					),
+					&["cluster", "kind", "group"],
+				)?,
+				registry,
+			)?,
+			challenges: register(
+				CounterVec::new(
+					Opts::new("ddc_ocw_challenges_total", "Number of challenged aggregates"),
+					&["cluster"],
+				)?,
+				registry,
+			)?,
+			challenges_failed: register(
+				CounterVec::new(
+					Opts::new(
+						"ddc_ocw_challenges_failed_total",
+						"Number of challenged aggregates which failed the challenge",
+					),
+					&["cluster"],
+				)?,
+				registry,
+			)?,
+			submitted_errors: register(
+				CounterVec::new(
+					Opts::new(
+						"ddc_ocw_submitted_errors_total",
+						"Number of errors reported on-chain per error category",
+					),
+					&["category"],
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	fn update(&self, metrics: &OcwMetrics) {
+		for (cluster_id, runs) in &metrics.runs {
+			set_counter(&self.runs, &[&format!("{:?}", cluster_id)], *runs);
+		}
+
+		for ((cluster_id, phase), phase_metrics) in &metrics.phases {
+			let cluster_id = format!("{:?}", cluster_id);
+			let phase = String::from_utf8_lossy(phase);
+			let labels = [cluster_id.as_str(), &*phase];
+			set_counter(&self.phase_runs, &labels, phase_metrics.runs);
+			set_counter(&self.phase_duration, &labels, phase_metrics.total_duration_ms);
+			self.phase_last_duration
+				.with_label_values(&labels)
+				.set(phase_metrics.last_duration_ms);
+		}
+
+		for (node_pub_key, requests) in &metrics.aggregator_requests {
+			let node = format!("0x{}", node_pub_key.get_hex());
+			let labels = [node.as_str()];
+			set_counter(&self.aggregator_requests, &labels, requests.requests);
+			set_counter(&self.aggregator_request_errors, &labels, requests.errors);
+			set_counter(&self.aggregator_request_duration, &labels, requests.total_latency_ms);
+		}
+
+		for ((cluster_id, kind), groups) in &metrics.consistency_groups {
+			let cluster_id = format!("{:?}", cluster_id);
+			let kind = String::from_utf8_lossy(kind);
+			for (group, size) in [
+				("consensus", groups.consensus),
+				("quorum", groups.quorum),
+				("others", groups.others),
+			] {
+				self.consistency_groups
+					.with_label_values(&[&cluster_id, &kind, group])
+					.set(size.into());
+			}
+		}
+
+		for (cluster_id, challenges) in &metrics.challenges {
+			let cluster_id = format!("{:?}", cluster_id);
+			let labels = [cluster_id.as_str()];
+			set_counter(&self.challenges, &labels, challenges.runs);
+			set_counter(&self.challenges_failed, &labels, challenges.failures);
+		}
+
+		for (category, errors) in &metrics.submitted_errors {
+			set_counter(&self.submitted_errors, &[&String::from_utf8_lossy(category)], *errors);
+		}
+	}
+}
+
+/// Brings a counter up to the total accumulated by the offchain worker. The totals only go down
+/// when the offchain storage is reset, the counter is left as is then.
+fn set_counter(counter: &CounterVec<U64>, labels: &[&str], total: u64) {
+	let counter = counter.with_label_values(labels);
+	let current = counter.get();
+	if total > current {
+		counter.inc_by(total - current);
+	}
+}
+
+/// Spawn the task exporting the offchain worker metrics to the Prometheus `registry`.
+pub(crate) fn spawn<C, S>(
+	task_manager: &TaskManager,
+	client: Arc<C>,
+	offchain_storage: S,
+	registry: &Registry,
+) -> Result<(), PrometheusError>
+where
+	C: BlockchainEvents<Block> + Send + Sync + 'static,
+	S: OffchainStorage + 'static,
+{
+	let metrics = Metrics::register(registry)?;
+
+	task_manager.spawn_handle().spawn("ddc-ocw-metrics", None, async move {
+		let mut imported_blocks = client.import_notification_stream();
+		while imported_blocks.next().await.is_some() {
+			let ocw_metrics = offchain_storage
+				.get(STORAGE_PREFIX, OCW_METRICS_KEY)
+				.and_then(|encoded| OcwMetrics::decode(&mut &encoded[..]).ok());
+
+			if let Some(ocw_metrics) = ocw_metrics {
+				metrics.update(&ocw_metrics);
+			}
+		}
+	});
+
+	Ok(())
+}
@@ -3,12 +3,12 @@
 
 use ddc_primitives::{
 	ClusterBondingParams, ClusterId, ClusterNodeKind, ClusterParams, ClusterProtocolParams,
-	NodeParams, StorageNodeMode, StorageNodeParams, StorageNodePubKey,
+	CustomerPricing, NodeParams, StorageNodeMode, StorageNodeParams, StorageNodePubKey,
 };
 use frame_benchmarking::{account, benchmarks};
 use frame_system::RawOrigin;
 use pallet_referenda::Pallet as Referenda;
-use sp_runtime::{Perquintill, SaturatedConversion};
+use sp_runtime::{Percent, Perquintill, SaturatedConversion};
 use sp_std::prelude::*;
 
 use super::*;
@@ -49,10 +49,12 @@ pub fn create_cluster_with_nodes<T: Config>(
 		storage_bond_size: bond_size,
 		storage_chill_delay: BlockNumberFor::<T>::from(20_u32),
 		storage_unbonding_delay: BlockNumberFor::<T>::from(20_u32),
+		node_removal_notice_period: BlockNumberFor::<T>::from(20_u32),
 		unit_per_mb_stored: 97656,
 		unit_per_mb_streamed: 48828,
 		unit_per_put_request: 10,
 		unit_per_get_request: 5,
+		pricing_tiers: Default::default(),
 	};
 
 	let cluster_params = ClusterParams {
@@ -114,6 +116,33 @@ pub fn create_cluster_with_nodes<T: Config>(
 	}
 }
 
+/// Fill the cluster with ongoing proposals that are checked on a new proposal submission.
+fn create_proposals<T: Config>(cluster_id: ClusterId, author: T::AccountId, count: u32) {
+	let start = frame_system::Pallet::<T>::block_number();
+	let end = start + T::ClusterProposalDuration::get();
+	for i in 0..count {
+		let call = T::ClusterProposalCall::from(Call::<T>::spend_cluster_reserve {
+			cluster_id,
+			beneficiary: author.clone(),
+			amount: (i as u128 + 1).saturated_into::<BalanceOf<T>>(),
+		});
+		let proposal =
+			Proposal { author: author.clone(), kind: ProposalKind::SpendClusterReserve, call };
+		let votes = Votes {
+			seats: 1,
+			threshold: 1,
+			ayes: vec![],
+			nays: vec![],
+			start,
+			end,
+			electorate: vec![],
+		};
+		ClusterProposal::<T>::insert(cluster_id, i, proposal);
+		ClusterProposalVoting::<T>::insert(cluster_id, i, votes);
+	}
+	ClusterProposalCount::<T>::insert(cluster_id, count);
+}
+
 fn next_block<T: Config>() {
 	frame_system::Pallet::<T>::set_block_number(
 		frame_system::Pallet::<T>::block_number() + BlockNumberFor::<T>::from(1_u32),
@@ -137,45 +166,51 @@ fn assert_has_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 benchmarks! {
 
 	propose_activate_cluster_protocol {
+		let p in 0 .. T::MaxClusterProposals::get() - 1;
+		let m in 4 .. T::MaxClusterNodes::get(); // nodes range
 
 		let cluster_id = ClusterId::from([1; 20]);
 		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
 		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
 
 		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
-		for i in 0 .. 3 {
+		for i in 0 .. m {
 			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
 			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
 			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
 		}
 
 		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes.clone(), false);
+		create_proposals::<T>(cluster_id, cluster_manager_id.clone(), p);
 
 	}: propose_activate_cluster_protocol(RawOrigin::Signed(cluster_manager_id), cluster_id, ClusterProtocolParams::default())
 	verify {
-		assert!(ClusterProposal::<T>::contains_key(cluster_id));
-		assert!(ClusterProposalVoting::<T>::contains_key(cluster_id));
+		assert!(ClusterProposal::<T>::contains_key(cluster_id, p));
+		assert!(ClusterProposalVoting::<T>::contains_key(cluster_id, p));
 	}
 
 	propose_update_cluster_protocol {
+		let p in 0 .. T::MaxClusterProposals::get() - 1;
+		let m in 4 .. T::MaxClusterNodes::get(); // nodes range
 
 		let cluster_id = ClusterId::from([1; 20]);
 		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
 		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
 
 		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
-		for i in 0 .. 3 {
+		for i in 0 .. m {
 			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
 			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
 			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
 		}
 
 		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes, true);
+		create_proposals::<T>(cluster_id, cluster_manager_id.clone(), p);
 
 	}: propose_update_cluster_protocol(RawOrigin::Signed(cluster_manager_id), cluster_id, ClusterProtocolParams::default(), ClusterMember::ClusterManager)
 	verify {
-		assert!(ClusterProposal::<T>::contains_key(cluster_id));
-		assert!(ClusterProposalVoting::<T>::contains_key(cluster_id));
+		assert!(ClusterProposal::<T>::contains_key(cluster_id, p));
+		assert!(ClusterProposalVoting::<T>::contains_key(cluster_id, p));
 	}
 
 	vote_proposal {
@@ -200,14 +235,14 @@ benchmarks! {
 			.map(|(key, provider)| (key.clone(), provider.clone()))
 			.unwrap();
 
-	}: vote_proposal(RawOrigin::Signed(node_provider_1.clone()), cluster_id, true, ClusterMember::NodeProvider(node_pub_key_1.clone()))
+	}: vote_proposal(RawOrigin::Signed(node_provider_1.clone()), cluster_id, 0, true, ClusterMember::NodeProvider(node_pub_key_1.clone()))
 	verify {
-		let votes = ClusterProposalVoting::<T>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<T>::get(cluster_id, 0).unwrap();
 		assert_eq!(votes.ayes, vec![node_provider_1.clone()]);
 	}
 
 	close_early_approved {
-		let m in 4 .. 64; // nodes range
+		let m in 4 .. T::MaxClusterNodes::get(); // nodes range
 
 		let cluster_id = ClusterId::from([1; 20]);
 		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
@@ -232,6 +267,7 @@ benchmarks! {
 			DdcClustersGov::<T>::vote_proposal(
 				RawOrigin::Signed(node_provider.clone()).into(),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::NodeProvider(node_pub_key.clone()),
 			)?;
@@ -240,21 +276,22 @@ benchmarks! {
 		DdcClustersGov::<T>::vote_proposal(
 			RawOrigin::Signed(cluster_manager_id.clone()).into(),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		)?;
 
-	}: close_proposal(RawOrigin::Signed(cluster_manager_id.clone()), cluster_id, ClusterMember::ClusterManager)
+	}: close_proposal(RawOrigin::Signed(cluster_manager_id.clone()), cluster_id, 0, ClusterMember::ClusterManager)
 	verify {
 		let cluster_id = ClusterId::from([1; 20]);
-		assert!(!ClusterProposal::<T>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<T>::contains_key(cluster_id));
-		assert_has_event::<T>(Event::Approved { cluster_id }.into());
-		assert_last_event::<T>(Event::Removed { cluster_id }.into());
+		assert!(!ClusterProposal::<T>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<T>::contains_key(cluster_id, 0));
+		assert_has_event::<T>(Event::Approved { cluster_id, proposal_index: 0 }.into());
+		assert_last_event::<T>(Event::Removed { cluster_id, proposal_index: 0 }.into());
 	}
 
 	close_approved {
-		let m in 4 .. 64; // nodes range
+		let m in 4 .. T::MaxClusterNodes::get(); // nodes range
 
 		let cluster_id = ClusterId::from([1; 20]);
 		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
@@ -288,6 +325,7 @@ benchmarks! {
 			DdcClustersGov::<T>::vote_proposal(
 				RawOrigin::Signed(cluster_manager_id.clone()).into(),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::ClusterManager,
 			)?;
@@ -297,6 +335,7 @@ benchmarks! {
 			DdcClustersGov::<T>::vote_proposal(
 				RawOrigin::Signed(cluster_manager_id.clone()).into(),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::ClusterManager,
 			)?;
@@ -306,26 +345,27 @@ benchmarks! {
 				DdcClustersGov::<T>::vote_proposal(
 					RawOrigin::Signed(node_provider.clone()).into(),
 					cluster_id,
+					0,
 					true,
 					ClusterMember::NodeProvider(node_pub_key.clone()),
 				)?;
 			}
 		}
 
-		let votes = ClusterProposalVoting::<T>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<T>::get(cluster_id, 0).unwrap();
 		fast_forward_to::<T>(votes.end + BlockNumberFor::<T>::from(1_u32));
 
-	}: close_proposal(RawOrigin::Signed(cluster_manager_id.clone()), cluster_id, ClusterMember::ClusterManager)
+	}: close_proposal(RawOrigin::Signed(cluster_manager_id.clone()), cluster_id, 0, ClusterMember::ClusterManager)
 	verify {
 		let cluster_id = ClusterId::from([1; 20]);
-		assert!(!ClusterProposal::<T>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<T>::contains_key(cluster_id));
-		assert_has_event::<T>(Event::Approved { cluster_id }.into());
-		assert_last_event::<T>(Event::Removed { cluster_id }.into());
+		assert!(!ClusterProposal::<T>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<T>::contains_key(cluster_id, 0));
+		assert_has_event::<T>(Event::Approved { cluster_id, proposal_index: 0 }.into());
+		assert_last_event::<T>(Event::Removed { cluster_id, proposal_index: 0 }.into());
 	}
 
 	close_early_disapproved {
-		let m in 4 .. 64; // nodes range
+		let m in 4 .. T::MaxClusterNodes::get(); // nodes range
 
 		let cluster_id = ClusterId::from([1; 20]);
 		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
@@ -349,6 +389,7 @@ benchmarks! {
 			DdcClustersGov::<T>::vote_proposal(
 				RawOrigin::Signed(node_provider.clone()).into(),
 				cluster_id,
+				0,
 				false,
 				ClusterMember::NodeProvider(node_pub_key.clone()),
 			)?;
@@ -357,21 +398,22 @@ benchmarks! {
 		DdcClustersGov::<T>::vote_proposal(
 			RawOrigin::Signed(cluster_manager_id.clone()).into(),
 			cluster_id,
+			0,
 			false,
 			ClusterMember::ClusterManager,
 		)?;
 
-	}: close_proposal(RawOrigin::Signed(cluster_manager_id.clone()), cluster_id, ClusterMember::ClusterManager)
+	}: close_proposal(RawOrigin::Signed(cluster_manager_id.clone()), cluster_id, 0, ClusterMember::ClusterManager)
 	verify {
 		let cluster_id = ClusterId::from([1; 20]);
-		assert!(!ClusterProposal::<T>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<T>::contains_key(cluster_id));
-		assert_has_event::<T>(Event::Disapproved { cluster_id }.into());
-		assert_last_event::<T>(Event::Removed { cluster_id }.into());
+		assert!(!ClusterProposal::<T>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<T>::contains_key(cluster_id, 0));
+		assert_has_event::<T>(Event::Disapproved { cluster_id, proposal_index: 0 }.into());
+		assert_last_event::<T>(Event::Removed { cluster_id, proposal_index: 0 }.into());
 	}
 
 	close_disapproved {
-		let m in 4 .. 64; // nodes range
+		let m in 4 .. T::MaxClusterNodes::get(); // nodes range
 
 		let cluster_id = ClusterId::from([1; 20]);
 		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
@@ -390,15 +432,15 @@ benchmarks! {
 
 		DdcClustersGov::<T>::propose_activate_cluster_protocol(RawOrigin::Signed(cluster_manager_id.clone()).into(), cluster_id, ClusterProtocolParams::default())?;
 
-		let votes = ClusterProposalVoting::<T>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<T>::get(cluster_id, 0).unwrap();
 		fast_forward_to::<T>(votes.end + BlockNumberFor::<T>::from(1_u32));
 
-	}: close_proposal(RawOrigin::Signed(cluster_manager_id.clone()), cluster_id, ClusterMember::ClusterManager)
+	}: close_proposal(RawOrigin::Signed(cluster_manager_id.clone()), cluster_id, 0, ClusterMember::ClusterManager)
 	verify {
 		let cluster_id = ClusterId::from([1; 20]);
-		assert!(!ClusterProposal::<T>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<T>::contains_key(cluster_id));
-		assert_has_event::<T>(Event::Disapproved { cluster_id }.into());
+		assert!(!ClusterProposal::<T>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<T>::contains_key(cluster_id, 0));
+		assert_has_event::<T>(Event::Disapproved { cluster_id, proposal_index: 0 }.into());
 	}
 
 	retract_proposal {
@@ -418,15 +460,15 @@ benchmarks! {
 
 		DdcClustersGov::<T>::propose_activate_cluster_protocol(RawOrigin::Signed(cluster_manager_id.clone()).into(), cluster_id, ClusterProtocolParams::default())?;
 
-		assert!(ClusterProposal::<T>::contains_key(cluster_id));
-		assert!(ClusterProposalVoting::<T>::contains_key(cluster_id));
+		assert!(ClusterProposal::<T>::contains_key(cluster_id, 0));
+		assert!(ClusterProposalVoting::<T>::contains_key(cluster_id, 0));
 
-	}: retract_proposal(RawOrigin::Signed(cluster_manager_id.clone()), cluster_id)
+	}: retract_proposal(RawOrigin::Signed(cluster_manager_id.clone()), cluster_id, 0)
 	verify {
 		let cluster_id = ClusterId::from([1; 20]);
-		assert!(!ClusterProposal::<T>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<T>::contains_key(cluster_id));
-		assert_last_event::<T>(Event::Removed { cluster_id }.into());
+		assert!(!ClusterProposal::<T>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<T>::contains_key(cluster_id, 0));
+		assert_last_event::<T>(Event::Removed { cluster_id, proposal_index: 0 }.into());
 	}
 
 	refund_submission_deposit {
@@ -452,6 +494,7 @@ benchmarks! {
 			DdcClustersGov::<T>::vote_proposal(
 				RawOrigin::Signed(node_provider.clone()).into(),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::NodeProvider(node_pub_key.clone()),
 			)?;
@@ -460,11 +503,12 @@ benchmarks! {
 		DdcClustersGov::<T>::vote_proposal(
 			RawOrigin::Signed(cluster_manager_id.clone()).into(),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		)?;
 
-		DdcClustersGov::<T>::close_proposal(RawOrigin::Signed(cluster_manager_id.clone()).into(), cluster_id, ClusterMember::ClusterManager).expect("Could not close proposal");
+		DdcClustersGov::<T>::close_proposal(RawOrigin::Signed(cluster_manager_id.clone()).into(), cluster_id, 0, ClusterMember::ClusterManager).expect("Could not close proposal");
 
 		let referenda_index = pallet_referenda::ReferendumCount::<T>::get() - 1;
 
@@ -522,10 +566,12 @@ benchmarks! {
 		storage_bond_size: 10000_u128.saturated_into::<BalanceOf<T>>(),
 		storage_chill_delay: BlockNumberFor::<T>::from(20_u32),
 		storage_unbonding_delay: BlockNumberFor::<T>::from(20_u32),
+		node_removal_notice_period: BlockNumberFor::<T>::from(20_u32),
 		unit_per_mb_stored: 97656,
 		unit_per_mb_streamed: 48828,
 		unit_per_put_request: 10,
 		unit_per_get_request: 5,
+		pricing_tiers: Default::default(),
 	})
 	verify {
 		let cluster_id = ClusterId::from([1; 20]);
@@ -538,4 +584,343 @@ benchmarks! {
 		assert_eq!(bonding_params, updated_bonding);
 	}
 
+	propose_transfer_cluster_manager {
+		let p in 0 .. T::MaxClusterProposals::get() - 1;
+		let m in 4 .. T::MaxClusterNodes::get(); // nodes range
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+		let new_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 1, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. m {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes, true);
+		create_proposals::<T>(cluster_id, cluster_manager_id.clone(), p);
+
+	}: propose_transfer_cluster_manager(RawOrigin::Signed(cluster_manager_id), cluster_id, new_manager_id, ClusterMember::ClusterManager)
+	verify {
+		assert!(ClusterProposal::<T>::contains_key(cluster_id, p));
+		assert!(ClusterProposalVoting::<T>::contains_key(cluster_id, p));
+	}
+
+	transfer_cluster_manager {
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+		let new_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 1, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. 3 {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes, true);
+		next_block::<T>();
+
+	}: transfer_cluster_manager(RawOrigin::Root, cluster_id, new_manager_id)
+	verify {
+		// the proposed manager takes over the cluster once it accepts the transfer
+		let manager_id = T::ClusterManager::get_manager_account_id(&cluster_id).unwrap();
+		assert_eq!(manager_id, cluster_manager_id);
+	}
+
+	propose_remove_cluster_node {
+		let p in 0 .. T::MaxClusterProposals::get() - 1;
+		let m in 4 .. T::MaxClusterNodes::get(); // nodes range
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. m {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes.clone(), true);
+		create_proposals::<T>(cluster_id, cluster_manager_id.clone(), p);
+
+		let (node_pub_key, _) = cluster_nodes.first().cloned().unwrap();
+
+	}: propose_remove_cluster_node(RawOrigin::Signed(cluster_manager_id), cluster_id, node_pub_key, ClusterMember::ClusterManager)
+	verify {
+		assert!(ClusterProposal::<T>::contains_key(cluster_id, p));
+		assert!(ClusterProposalVoting::<T>::contains_key(cluster_id, p));
+	}
+
+	remove_cluster_node {
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. 3 {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes.clone(), true);
+		next_block::<T>();
+
+		let (node_pub_key, _) = cluster_nodes.first().cloned().unwrap();
+
+	}: remove_cluster_node(RawOrigin::Root, cluster_id, node_pub_key.clone())
+	verify {
+		// the node leaves the cluster once the notice period of the cluster ends
+		assert!(T::ClusterManager::contains_node(&cluster_id, &node_pub_key, None));
+	}
+
+	propose_update_cluster_params {
+		let p in 0 .. T::MaxClusterProposals::get() - 1;
+		let m in 4 .. T::MaxClusterNodes::get(); // nodes range
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. m {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes.clone(), true);
+		create_proposals::<T>(cluster_id, cluster_manager_id.clone(), p);
+		let cluster_params = ClusterParams {
+			node_provider_auth_contract: None,
+			erasure_coding_required: 4,
+			erasure_coding_total: 6,
+			replication_total: 3,
+		};
+
+	}: propose_update_cluster_params(RawOrigin::Signed(cluster_manager_id), cluster_id, cluster_params, ClusterMember::ClusterManager)
+	verify {
+		assert!(ClusterProposal::<T>::contains_key(cluster_id, p));
+		assert!(ClusterProposalVoting::<T>::contains_key(cluster_id, p));
+	}
+
+	update_cluster_params {
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. 3 {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes.clone(), true);
+		next_block::<T>();
+
+	}: update_cluster_params(RawOrigin::Root, cluster_id, ClusterParams {
+		node_provider_auth_contract: None,
+		erasure_coding_required: 4,
+		erasure_coding_total: 6,
+		replication_total: 3,
+	})
+
+	propose_spend_cluster_reserve {
+		let p in 0 .. T::MaxClusterProposals::get() - 1;
+		let m in 4 .. T::MaxClusterNodes::get(); // nodes range
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. m {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes.clone(), true);
+		create_proposals::<T>(cluster_id, cluster_manager_id.clone(), p);
+
+		let amount = <T as pallet::Config>::Currency::minimum_balance() * 10_u32.saturated_into::<BalanceOf<T>>();
+
+	}: propose_spend_cluster_reserve(RawOrigin::Signed(cluster_manager_id.clone()), cluster_id, cluster_manager_id.clone(), amount, ClusterMember::ClusterManager)
+	verify {
+		assert!(ClusterProposal::<T>::contains_key(cluster_id, p));
+		assert!(ClusterProposalVoting::<T>::contains_key(cluster_id, p));
+	}
+
+	spend_cluster_reserve {
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. 3 {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes.clone(), true);
+		let _ = fund_user::<T>(DdcClustersGov::<T>::cluster_reserve_account_id(&cluster_id), 1000);
+		next_block::<T>();
+
+		let beneficiary = create_funded_user_with_balance::<T>("beneficiary", 0, 5);
+		let amount = <T as pallet::Config>::Currency::minimum_balance() * 10_u32.saturated_into::<BalanceOf<T>>();
+
+	}: spend_cluster_reserve(RawOrigin::Root, cluster_id, beneficiary.clone(), amount)
+	verify {
+		assert_last_event::<T>(Event::ClusterReserveSpent { cluster_id, beneficiary, amount }.into());
+	}
+
+	delegate_vote {
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. 3 {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes.clone(), true);
+
+		let (node_pub_key, node_provider) = cluster_nodes.first().unwrap().clone();
+		let (delegate_node_pub_key, _) = cluster_nodes.last().unwrap().clone();
+		let delegate = ClusterMember::NodeProvider(delegate_node_pub_key);
+
+	}: delegate_vote(RawOrigin::Signed(node_provider), cluster_id, node_pub_key.clone(), delegate.clone())
+	verify {
+		assert_eq!(VoteDelegations::<T>::get(cluster_id, &node_pub_key), Some(delegate));
+	}
+
+	remove_vote_delegation {
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. 3 {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes.clone(), true);
+
+		let (node_pub_key, node_provider) = cluster_nodes.first().unwrap().clone();
+		VoteDelegations::<T>::insert(cluster_id, &node_pub_key, ClusterMember::ClusterManager);
+
+	}: remove_vote_delegation(RawOrigin::Signed(node_provider), cluster_id, node_pub_key.clone())
+	verify {
+		assert!(!VoteDelegations::<T>::contains_key(cluster_id, &node_pub_key));
+	}
+
+	propose_set_pricing_override {
+		let p in 0 .. T::MaxClusterProposals::get() - 1;
+		let m in 4 .. T::MaxClusterNodes::get(); // nodes range
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+		let customer_id = create_funded_user_with_balance::<T>("customer", 0, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. m {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes.clone(), true);
+		create_proposals::<T>(cluster_id, cluster_manager_id.clone(), p);
+		let pricing_override = PricingOverride {
+			pricing: CustomerPricing::Discount(Percent::from_percent(10)),
+			valid_from: 0,
+			valid_till: i64::MAX,
+		};
+
+	}: propose_set_pricing_override(RawOrigin::Signed(cluster_manager_id), cluster_id, PricingOverrideTarget::Customer(customer_id), Some(pricing_override), ClusterMember::ClusterManager)
+	verify {
+		assert!(ClusterProposal::<T>::contains_key(cluster_id, p));
+		assert!(ClusterProposalVoting::<T>::contains_key(cluster_id, p));
+	}
+
+	set_pricing_override {
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+		let customer_id = create_funded_user_with_balance::<T>("customer", 0, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. 3 {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes.clone(), true);
+		next_block::<T>();
+
+	}: set_pricing_override(RawOrigin::Root, cluster_id, PricingOverrideTarget::Customer(customer_id), Some(PricingOverride {
+		pricing: CustomerPricing::Discount(Percent::from_percent(10)),
+		valid_from: 0,
+		valid_till: i64::MAX,
+	}))
+
+	propose_cancel_cluster_manager_transfer {
+		let p in 0 .. T::MaxClusterProposals::get() - 1;
+		let m in 4 .. T::MaxClusterNodes::get(); // nodes range
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. m {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes, true);
+		create_proposals::<T>(cluster_id, cluster_manager_id.clone(), p);
+
+	}: propose_cancel_cluster_manager_transfer(RawOrigin::Signed(cluster_manager_id), cluster_id, ClusterMember::ClusterManager)
+	verify {
+		assert!(ClusterProposal::<T>::contains_key(cluster_id, p));
+		assert!(ClusterProposalVoting::<T>::contains_key(cluster_id, p));
+	}
+
+	cancel_cluster_manager_transfer {
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 0, 5);
+		let cluster_reserve_id = create_funded_user_with_balance::<T>("cluster-stash", 0, 5);
+		let new_manager_id = create_funded_user_with_balance::<T>("cluster-controller", 1, 5);
+
+		let mut cluster_nodes: Vec<(NodePubKey, T::AccountId)> = Vec::new();
+		for i in 0 .. 3 {
+			let node_provider = create_funded_user_with_balance::<T>("node-provider", i, 5);
+			let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([i as u8; 32]));
+			cluster_nodes.push((node_pub_key.clone(), node_provider.clone()));
+		}
+
+		create_cluster_with_nodes::<T>(cluster_id, cluster_manager_id.clone(), cluster_reserve_id.clone(), cluster_nodes, true);
+		T::ClusterManager::propose_manager_transfer(&cluster_id, &new_manager_id)?;
+		next_block::<T>();
+
+	}: cancel_cluster_manager_transfer(RawOrigin::Root, cluster_id)
+
 }
@@ -19,12 +19,17 @@ use codec::{Decode, Encode};
 use ddc_primitives::traits::staking::StakerCreator;
 use ddc_primitives::{
 	traits::{
-		cluster::{ClusterCreator, ClusterManager, ClusterProtocol, ClusterQuery},
-		cluster_gov::{DefaultVote, MemberCount, SeatsConsensus},
+		cluster::{
+			ClusterCreator, ClusterDecommissionHandler, ClusterManager, ClusterProtocol,
+			ClusterQuery,
+		},
+		cluster_gov::{DefaultVote, MemberCount, SeatsConsensus, VoteWeight},
 		node::NodeManager,
 		pallet::GetDdcOrigin,
+		staking::StakingVisitor,
 	},
-	ClusterId, ClusterNodeStatus, ClusterProtocolParams, ClusterStatus, NodePubKey,
+	ClusterId, ClusterNodeStatus, ClusterParams, ClusterProtocolParams, ClusterStatus, NodePubKey,
+	PricingOverride, PricingOverrideTarget,
 };
 use frame_support::{
 	dispatch::{GetDispatchInfo, Pays},
@@ -40,9 +45,10 @@ pub use frame_system::Config as SysConfig;
 pub use pallet::*;
 use pallet_referenda::ReferendumIndex;
 use scale_info::TypeInfo;
+use sp_io::hashing::blake2_128;
 use sp_runtime::{
 	traits::{AccountIdConversion, Dispatchable},
-	DispatchError, DispatchResult, RuntimeDebug, SaturatedConversion,
+	ArithmeticError, DispatchError, DispatchResult, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::prelude::*;
 
@@ -53,6 +59,7 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -62,6 +69,9 @@ pub type BalanceOf<T> =
 
 pub type ReferendaCall<T> = pallet_referenda::Call<T>;
 
+/// Index of a proposal within the proposals of a cluster.
+pub type ProposalIndex = u32;
+
 /// Info for keeping track of a proposal being voted on.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct Votes<AccountId, BlockNumber> {
@@ -77,9 +87,23 @@ pub struct Votes<AccountId, BlockNumber> {
 	start: BlockNumber,
 	/// The hard end time of this vote.
 	end: BlockNumber,
+	/// The members counted in the tally with their voting power at the moment the proposal was
+	/// initiated.
+	electorate: Vec<Elector>,
+}
+
+/// Voting power of a cluster member on a proposal, snapshotted when the proposal is initiated.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct Elector {
+	/// The member the voting power belongs to.
+	member: ClusterMember,
+	/// The member casting the ballot, the member itself or the member its vote is delegated to.
+	voter: ClusterMember,
+	/// The voting power of the member.
+	weight: VoteWeight,
 }
 
-#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq)]
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
 pub enum ClusterMember {
 	ClusterManager,
 	NodeProvider(NodePubKey),
@@ -96,6 +120,12 @@ pub struct Proposal<AccountId, Call> {
 pub enum ProposalKind {
 	ActivateClusterProtocol,
 	UpdateClusterProtocol,
+	TransferClusterManager,
+	RemoveClusterNode,
+	UpdateClusterParams,
+	SpendClusterReserve,
+	SetPricingOverride,
+	CancelClusterManagerTransfer,
 }
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -104,6 +134,43 @@ pub struct SubmissionDeposit<AccountId> {
 	amount: u128,
 }
 
+/// Voting power of the cluster members on a proposal at the moment of its closing, counted for
+/// the electorate snapshotted when the proposal was initiated.
+#[derive(PartialEq, Eq, Clone, Default, RuntimeDebug)]
+struct Tally {
+	/// The total voting power of the members that can vote on the proposal.
+	seats: VoteWeight,
+	/// The voting power that is needed to pass the proposal.
+	threshold: VoteWeight,
+	/// The voting power of the members that approved the proposal.
+	ayes: VoteWeight,
+	/// The voting power of the members that rejected the proposal.
+	nays: VoteWeight,
+	/// The number of the members counted in the tally.
+	members: MemberCount,
+}
+
+impl Tally {
+	/// Counts the voting power of a member for the ballot of the voter, the voter is the member
+	/// itself or the member the vote is delegated to.
+	fn count<AccountId: PartialEq, BlockNumber>(
+		&mut self,
+		voting: &Votes<AccountId, BlockNumber>,
+		voter: Option<AccountId>,
+		weight: VoteWeight,
+	) {
+		self.seats = self.seats.saturating_add(weight);
+		self.members = self.members.saturating_add(1);
+		if let Some(voter) = voter {
+			if voting.ayes.contains(&voter) {
+				self.ayes = self.ayes.saturating_add(weight);
+			} else if voting.nays.contains(&voter) {
+				self.nays = self.nays.saturating_add(weight);
+			}
+		}
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::PalletId;
@@ -112,7 +179,7 @@ pub mod pallet {
 
 	/// The current storage version.
 	const STORAGE_VERSION: frame_support::traits::StorageVersion =
-		frame_support::traits::StorageVersion::new(0);
+		frame_support::traits::StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -143,16 +210,29 @@ pub mod pallet {
 		type DefaultVote: DefaultVote;
 		type MinValidatedNodesCount: Get<u16>;
 		type ReferendumEnactmentDuration: Get<BlockNumberFor<Self>>;
+		/// The maximum number of proposals ongoing in a cluster at the same time.
+		#[pallet::constant]
+		type MaxClusterProposals: Get<u32>;
+		/// The maximum number of nodes in a cluster its proposals are initiated and closed for,
+		/// the weights of the proposal calls are charged for this number of nodes.
+		#[pallet::constant]
+		type MaxClusterNodes: Get<u32>;
+		/// Whether the votes of the members are weighted by their bonded stake instead of
+		/// counting one vote per seat.
+		type StakeWeightedVoting: Get<bool>;
+		type StakingVisitor: StakingVisitor<Self>;
 		#[cfg(feature = "runtime-benchmarks")]
 		type StakerCreator: StakerCreator<Self, BalanceOf<Self>>;
 	}
 
 	#[pallet::storage]
 	#[pallet::getter(fn proposal_of)]
-	pub type ClusterProposal<T: Config> = StorageMap<
+	pub type ClusterProposal<T: Config> = StorageDoubleMap<
 		_,
 		Identity,
 		ClusterId,
+		Twox64Concat,
+		ProposalIndex,
 		Proposal<T::AccountId, T::ClusterProposalCall>,
 		OptionQuery,
 	>;
@@ -160,8 +240,34 @@ pub mod pallet {
 	/// Votes on a given cluster proposal, if it is ongoing.
 	#[pallet::storage]
 	#[pallet::getter(fn voting)]
-	pub type ClusterProposalVoting<T: Config> =
-		StorageMap<_, Identity, ClusterId, Votes<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+	pub type ClusterProposalVoting<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		ClusterId,
+		Twox64Concat,
+		ProposalIndex,
+		Votes<T::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The index assigned to the next proposal of a given cluster.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_count)]
+	pub type ClusterProposalCount<T: Config> =
+		StorageMap<_, Identity, ClusterId, ProposalIndex, ValueQuery>;
+
+	/// Cluster members the votes of the nodes are delegated to by their providers.
+	#[pallet::storage]
+	#[pallet::getter(fn vote_delegation)]
+	pub type VoteDelegations<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		ClusterId,
+		Blake2_128Concat,
+		NodePubKey,
+		ClusterMember,
+		OptionQuery,
+	>;
 
 	/// Public referendums initiated by clusters
 	#[pallet::storage]
@@ -174,26 +280,38 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// A proposal (given hash) has been proposed (by given account) with a threshold (given
 		/// `MemberCount`).
-		Proposed { account: T::AccountId, cluster_id: ClusterId, threshold: MemberCount },
+		Proposed {
+			account: T::AccountId,
+			cluster_id: ClusterId,
+			proposal_index: ProposalIndex,
+			threshold: MemberCount,
+		},
 		/// A proposal (given hash) has been voted on by given account, leaving
 		/// a tally (yes votes and no votes given respectively as `MemberCount`).
 		Voted {
 			account: T::AccountId,
 			cluster_id: ClusterId,
+			proposal_index: ProposalIndex,
 			voted: bool,
 			yes: MemberCount,
 			no: MemberCount,
 		},
 		/// A proposal was approved by the required threshold.
-		Approved { cluster_id: ClusterId },
+		Approved { cluster_id: ClusterId, proposal_index: ProposalIndex },
 		/// A proposal was not approved by the required threshold.
-		Disapproved { cluster_id: ClusterId },
+		Disapproved { cluster_id: ClusterId, proposal_index: ProposalIndex },
 		/// A proposal was executed; result will be `Ok` if it returned without error.
-		ReferendumSubmitted { cluster_id: ClusterId },
-		/// A proposal was closed because its threshold was reached or after its duration was up.
-		Closed { cluster_id: ClusterId, yes: MemberCount, no: MemberCount },
+		ReferendumSubmitted { cluster_id: ClusterId, proposal_index: ProposalIndex },
+		/// A proposal was closed because its threshold was reached or after its duration was up,
+		/// leaving a tally (yes votes and no votes given respectively as `VoteWeight`).
+		Closed {
+			cluster_id: ClusterId,
+			proposal_index: ProposalIndex,
+			yes: VoteWeight,
+			no: VoteWeight,
+		},
 		/// A proposal was not removed by its author.
-		Removed { cluster_id: ClusterId },
+		Removed { cluster_id: ClusterId, proposal_index: ProposalIndex },
 		/// The submission deposit has been refunded.
 		SubmissionDepositRetained {
 			/// Index of the referendum.
@@ -212,6 +330,16 @@ pub mod pallet {
 			/// The amount placed by the account.
 			amount: BalanceOf<T>,
 		},
+		/// Funds of the cluster reserve have been spent by the referendum.
+		ClusterReserveSpent {
+			cluster_id: ClusterId,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// The vote of a node has been delegated to another cluster member.
+		VoteDelegated { cluster_id: ClusterId, node_pub_key: NodePubKey, delegate: ClusterMember },
+		/// The vote of a node is cast by its provider again.
+		VoteDelegationRemoved { cluster_id: ClusterId, node_pub_key: NodePubKey },
 	}
 
 	#[pallet::error]
@@ -230,8 +358,10 @@ pub mod pallet {
 		NotProposalAuthor,
 		/// Proposal must exist
 		ProposalMissing,
-		/// Active proposal is ongoing
+		/// Identical proposal is ongoing
 		ActiveProposal,
+		/// Cluster has the maximum number of ongoing proposals
+		TooManyProposals,
 		/// Duplicate vote ignored
 		DuplicateVote,
 		/// The close call was made too early, before the end of the voting.
@@ -242,12 +372,23 @@ pub mod pallet {
 		VoteProhibited,
 		NoSubmissionDeposit,
 		NotNodeProvider,
+		/// The vote of the node is delegated to another member
+		NodeVoteDelegated,
+		/// The delegate is not a validated member of the cluster
+		InvalidDelegate,
+		/// The vote of the node is not delegated
+		NoVoteDelegation,
+		/// Cluster has more nodes than the proposals are allowed for
+		TooManyClusterNodes,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_activate_cluster_protocol())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_activate_cluster_protocol(
+			T::MaxClusterProposals::get(),
+			T::MaxClusterNodes::get(),
+		))]
 		pub fn propose_activate_cluster_protocol(
 			origin: OriginFor<T>,
 			cluster_id: ClusterId,
@@ -256,49 +397,24 @@ pub mod pallet {
 			let caller_id = ensure_signed(origin)?;
 			Self::ensure_cluster_manager(caller_id.clone(), cluster_id)?;
 
-			ensure!(!<ClusterProposal<T>>::contains_key(cluster_id), Error::<T>::ActiveProposal);
-
-			let cluster_status =
-				<T::ClusterProtocol as ClusterQuery<T>>::get_cluster_status(&cluster_id)
-					.map_err(|_| Error::<T>::NoCluster)?;
-			ensure!(cluster_status == ClusterStatus::Bonded, Error::<T>::UnexpectedState);
-
-			let cluster_nodes_stats = T::ClusterManager::get_nodes_stats(&cluster_id)
-				.map_err(|_| Error::<T>::NoCluster)?;
-			ensure!(cluster_nodes_stats.await_validation == 0, Error::<T>::AwaitsValidation);
-			ensure!(
-				cluster_nodes_stats.validation_succeeded >= T::MinValidatedNodesCount::get(),
-				Error::<T>::NotEnoughValidatedNodes
-			);
-
-			// All Nodes validated by this moment + 1 Cluster Manager
-			let seats = cluster_nodes_stats.validation_succeeded as u32 + 1;
-			let threshold = T::SeatsConsensus::get_threshold(seats);
-			let votes = {
-				let start = frame_system::Pallet::<T>::block_number();
-				let end = start + T::ClusterProposalDuration::get();
-				Votes { seats, threshold, ayes: vec![], nays: vec![], start, end }
-			};
-			let call: <T as Config>::ClusterProposalCall =
-				T::ClusterProposalCall::from(Call::<T>::activate_cluster_protocol {
-					cluster_id,
-					cluster_protocol_params,
-				});
-			let proposal = Proposal {
+			let call = T::ClusterProposalCall::from(Call::<T>::activate_cluster_protocol {
+				cluster_id,
+				cluster_protocol_params,
+			});
+			Self::do_propose(
+				caller_id,
+				cluster_id,
+				ProposalKind::ActivateClusterProtocol,
 				call,
-				author: caller_id.clone(),
-				kind: ProposalKind::ActivateClusterProtocol,
-			};
-
-			<ClusterProposal<T>>::insert(cluster_id, proposal);
-			<ClusterProposalVoting<T>>::insert(cluster_id, votes);
-			Self::deposit_event(Event::Proposed { account: caller_id, cluster_id, threshold });
-
-			Ok(())
+				ClusterStatus::Bonded,
+			)
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_update_cluster_protocol())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_update_cluster_protocol(
+			T::MaxClusterProposals::get(),
+			T::MaxClusterNodes::get(),
+		))]
 		pub fn propose_update_cluster_protocol(
 			origin: OriginFor<T>,
 			cluster_id: ClusterId,
@@ -308,45 +424,17 @@ pub mod pallet {
 			let caller_id = ensure_signed(origin)?;
 			Self::ensure_validated_member(caller_id.clone(), cluster_id, member)?;
 
-			ensure!(!<ClusterProposal<T>>::contains_key(cluster_id), Error::<T>::ActiveProposal);
-
-			let cluster_status =
-				<T::ClusterProtocol as ClusterQuery<T>>::get_cluster_status(&cluster_id)
-					.map_err(|_| Error::<T>::NoCluster)?;
-			ensure!(cluster_status == ClusterStatus::Activated, Error::<T>::UnexpectedState);
-
-			let cluster_nodes_stats = T::ClusterManager::get_nodes_stats(&cluster_id)
-				.map_err(|_| Error::<T>::NoCluster)?;
-			ensure!(cluster_nodes_stats.await_validation == 0, Error::<T>::AwaitsValidation);
-			ensure!(
-				cluster_nodes_stats.validation_succeeded >= T::MinValidatedNodesCount::get(),
-				Error::<T>::NotEnoughValidatedNodes
-			);
-
-			// All Nodes validated by this moment + 1 Cluster Manager
-			let seats = cluster_nodes_stats.validation_succeeded as u32 + 1;
-			let threshold = T::SeatsConsensus::get_threshold(seats);
-			let votes = {
-				let start = frame_system::Pallet::<T>::block_number();
-				let end = start + T::ClusterProposalDuration::get();
-				Votes { seats, threshold, ayes: vec![], nays: vec![], start, end }
-			};
-			let call: <T as Config>::ClusterProposalCall =
-				T::ClusterProposalCall::from(Call::<T>::update_cluster_protocol {
-					cluster_id,
-					cluster_protocol_params,
-				});
-			let proposal = Proposal {
+			let call = T::ClusterProposalCall::from(Call::<T>::update_cluster_protocol {
+				cluster_id,
+				cluster_protocol_params,
+			});
+			Self::do_propose(
+				caller_id,
+				cluster_id,
+				ProposalKind::UpdateClusterProtocol,
 				call,
-				author: caller_id.clone(),
-				kind: ProposalKind::UpdateClusterProtocol,
-			};
-
-			<ClusterProposal<T>>::insert(cluster_id, proposal);
-			<ClusterProposalVoting<T>>::insert(cluster_id, votes);
-			Self::deposit_event(Event::Proposed { account: caller_id, cluster_id, threshold });
-
-			Ok(())
+				ClusterStatus::Activated,
+			)
 		}
 
 		#[pallet::call_index(2)]
@@ -354,19 +442,20 @@ pub mod pallet {
 		pub fn vote_proposal(
 			origin: OriginFor<T>,
 			cluster_id: ClusterId,
+			proposal_index: ProposalIndex,
 			approve: bool,
 			member: ClusterMember,
 		) -> DispatchResult {
 			let caller_id = ensure_signed(origin)?;
-			Self::ensure_allowed_voter(caller_id.clone(), cluster_id, member)?;
-			let _ = Self::do_vote(caller_id, cluster_id, approve)?;
+			Self::ensure_allowed_voter(caller_id.clone(), cluster_id, proposal_index, member)?;
+			let _ = Self::do_vote(caller_id, cluster_id, proposal_index, approve)?;
 			Ok(())
 		}
 
 		#[pallet::call_index(3)]
 		#[pallet::weight(
 			{
-				let m = 64;
+				let m = T::MaxClusterNodes::get();
 				<T as pallet::Config>::WeightInfo::close_early_approved(m)
 					.max(<T as pallet::Config>::WeightInfo::close_early_disapproved(m))
 					.max(<T as pallet::Config>::WeightInfo::close_approved(m))
@@ -376,23 +465,28 @@ pub mod pallet {
 		pub fn close_proposal(
 			origin: OriginFor<T>,
 			cluster_id: ClusterId,
+			proposal_index: ProposalIndex,
 			member: ClusterMember,
 		) -> DispatchResultWithPostInfo {
 			let caller_id = ensure_signed(origin)?;
 			Self::ensure_validated_member(caller_id.clone(), cluster_id, member)?;
-			Self::do_close(cluster_id, caller_id)
+			Self::do_close(cluster_id, proposal_index, caller_id)
 		}
 
 		#[pallet::call_index(4)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::retract_proposal())]
-		pub fn retract_proposal(origin: OriginFor<T>, cluster_id: ClusterId) -> DispatchResult {
+		pub fn retract_proposal(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			proposal_index: ProposalIndex,
+		) -> DispatchResult {
 			let caller_id = ensure_signed(origin)?;
-			let proposal =
-				ClusterProposal::<T>::get(cluster_id).ok_or(Error::<T>::ProposalMissing)?;
+			let proposal = ClusterProposal::<T>::get(cluster_id, proposal_index)
+				.ok_or(Error::<T>::ProposalMissing)?;
 			if proposal.author != caller_id {
 				Err(Error::<T>::NotProposalAuthor.into())
 			} else {
-				Self::do_remove_proposal(cluster_id);
+				Self::do_remove_proposal(cluster_id, proposal_index);
 				Ok(())
 			}
 		}
@@ -453,6 +547,314 @@ pub mod pallet {
 			T::OpenGovUpdaterOrigin::ensure_origin(origin)?;
 			T::ClusterProtocol::update_cluster_protocol(&cluster_id, cluster_protocol_params)
 		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_transfer_cluster_manager(
+			T::MaxClusterProposals::get(),
+			T::MaxClusterNodes::get(),
+		))]
+		pub fn propose_transfer_cluster_manager(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			new_manager_id: T::AccountId,
+			member: ClusterMember,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			Self::ensure_validated_member(caller_id.clone(), cluster_id, member)?;
+
+			let call = T::ClusterProposalCall::from(Call::<T>::transfer_cluster_manager {
+				cluster_id,
+				new_manager_id,
+			});
+			Self::do_propose(
+				caller_id,
+				cluster_id,
+				ProposalKind::TransferClusterManager,
+				call,
+				ClusterStatus::Activated,
+			)
+		}
+
+		/// Propose the new cluster manager approved by the referendum, the proposed account
+		/// takes over the cluster management once it accepts the transfer in the clusters pallet.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_cluster_manager())]
+		pub fn transfer_cluster_manager(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			new_manager_id: T::AccountId,
+		) -> DispatchResult {
+			T::OpenGovUpdaterOrigin::ensure_origin(origin)?;
+			T::ClusterManager::propose_manager_transfer(&cluster_id, &new_manager_id)
+		}
+
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_remove_cluster_node(
+			T::MaxClusterProposals::get(),
+			T::MaxClusterNodes::get(),
+		))]
+		pub fn propose_remove_cluster_node(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+			member: ClusterMember,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			Self::ensure_validated_member(caller_id.clone(), cluster_id, member)?;
+			ensure!(
+				T::ClusterManager::contains_node(&cluster_id, &node_pub_key, None),
+				Error::<T>::NoClusterNode
+			);
+
+			let call = T::ClusterProposalCall::from(Call::<T>::remove_cluster_node {
+				cluster_id,
+				node_pub_key,
+			});
+			Self::do_propose(
+				caller_id,
+				cluster_id,
+				ProposalKind::RemoveClusterNode,
+				call,
+				ClusterStatus::Activated,
+			)
+		}
+
+		/// Remove the node approved by the referendum, the node stays in the cluster until the
+		/// notice period of the cluster ends.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_cluster_node())]
+		pub fn remove_cluster_node(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+		) -> DispatchResult {
+			T::OpenGovUpdaterOrigin::ensure_origin(origin)?;
+			T::ClusterManager::remove_node(&cluster_id, &node_pub_key)
+		}
+
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_update_cluster_params(
+			T::MaxClusterProposals::get(),
+			T::MaxClusterNodes::get(),
+		))]
+		pub fn propose_update_cluster_params(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			cluster_params: ClusterParams<T::AccountId>,
+			member: ClusterMember,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			Self::ensure_validated_member(caller_id.clone(), cluster_id, member)?;
+
+			let call = T::ClusterProposalCall::from(Call::<T>::update_cluster_params {
+				cluster_id,
+				cluster_params,
+			});
+			Self::do_propose(
+				caller_id,
+				cluster_id,
+				ProposalKind::UpdateClusterParams,
+				call,
+				ClusterStatus::Activated,
+			)
+		}
+
+		/// Set the auth contract and the erasure coding and replication parameters of the cluster
+		/// approved by the referendum.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_cluster_params())]
+		pub fn update_cluster_params(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			cluster_params: ClusterParams<T::AccountId>,
+		) -> DispatchResult {
+			T::OpenGovUpdaterOrigin::ensure_origin(origin)?;
+			T::ClusterManager::update_cluster_params(&cluster_id, cluster_params)
+		}
+
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_spend_cluster_reserve(
+			T::MaxClusterProposals::get(),
+			T::MaxClusterNodes::get(),
+		))]
+		pub fn propose_spend_cluster_reserve(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+			member: ClusterMember,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			Self::ensure_validated_member(caller_id.clone(), cluster_id, member)?;
+
+			let call = T::ClusterProposalCall::from(Call::<T>::spend_cluster_reserve {
+				cluster_id,
+				beneficiary,
+				amount,
+			});
+			Self::do_propose(
+				caller_id,
+				cluster_id,
+				ProposalKind::SpendClusterReserve,
+				call,
+				ClusterStatus::Activated,
+			)
+		}
+
+		/// Transfer the funds approved by the referendum from the cluster reserve to the
+		/// beneficiary. The reserve is an account derived from the pallet id for each cluster, so
+		/// that only the referendum spends its funds.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::spend_cluster_reserve())]
+		pub fn spend_cluster_reserve(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::OpenGovUpdaterOrigin::ensure_origin(origin)?;
+			<T as pallet::Config>::Currency::transfer(
+				&Self::cluster_reserve_account_id(&cluster_id),
+				&beneficiary,
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			Self::deposit_event(Event::ClusterReserveSpent { cluster_id, beneficiary, amount });
+
+			Ok(())
+		}
+
+		/// Delegate the vote of the node to another validated member of the cluster, the ballot
+		/// of the delegate is counted with the voting power of the node.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::delegate_vote())]
+		pub fn delegate_vote(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+			delegate: ClusterMember,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			Self::ensure_validated_member(
+				caller_id,
+				cluster_id,
+				ClusterMember::NodeProvider(node_pub_key.clone()),
+			)?;
+			ensure!(
+				delegate != ClusterMember::NodeProvider(node_pub_key.clone()),
+				Error::<T>::InvalidDelegate
+			);
+			ensure!(
+				Self::get_member_account(&cluster_id, &delegate).is_some(),
+				Error::<T>::InvalidDelegate
+			);
+
+			VoteDelegations::<T>::insert(cluster_id, &node_pub_key, delegate.clone());
+			Self::deposit_event(Event::VoteDelegated { cluster_id, node_pub_key, delegate });
+
+			Ok(())
+		}
+
+		/// Remove the delegation of the node vote, the provider votes for the node again.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_vote_delegation())]
+		pub fn remove_vote_delegation(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			let node_provider = T::NodeManager::get_node_provider_id(&node_pub_key)?;
+			ensure!(caller_id == node_provider, Error::<T>::NotNodeProvider);
+			ensure!(
+				VoteDelegations::<T>::contains_key(cluster_id, &node_pub_key),
+				Error::<T>::NoVoteDelegation
+			);
+
+			VoteDelegations::<T>::remove(cluster_id, &node_pub_key);
+			Self::deposit_event(Event::VoteDelegationRemoved { cluster_id, node_pub_key });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_set_pricing_override(
+			T::MaxClusterProposals::get(),
+			T::MaxClusterNodes::get(),
+		))]
+		pub fn propose_set_pricing_override(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			target: PricingOverrideTarget<T::AccountId>,
+			pricing_override: Option<PricingOverride>,
+			member: ClusterMember,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			Self::ensure_validated_member(caller_id.clone(), cluster_id, member)?;
+
+			let call = T::ClusterProposalCall::from(Call::<T>::set_pricing_override {
+				cluster_id,
+				target,
+				pricing_override,
+			});
+			Self::do_propose(
+				caller_id,
+				cluster_id,
+				ProposalKind::SetPricingOverride,
+				call,
+				ClusterStatus::Activated,
+			)
+		}
+
+		/// Set or remove the pricing negotiated with the customer approved by the referendum.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_pricing_override())]
+		pub fn set_pricing_override(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			target: PricingOverrideTarget<T::AccountId>,
+			pricing_override: Option<PricingOverride>,
+		) -> DispatchResult {
+			T::OpenGovUpdaterOrigin::ensure_origin(origin)?;
+			T::ClusterManager::set_pricing_override(&cluster_id, &target, pricing_override)
+		}
+
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_cancel_cluster_manager_transfer(
+			T::MaxClusterProposals::get(),
+			T::MaxClusterNodes::get(),
+		))]
+		pub fn propose_cancel_cluster_manager_transfer(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			member: ClusterMember,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			Self::ensure_validated_member(caller_id.clone(), cluster_id, member)?;
+
+			let call = T::ClusterProposalCall::from(Call::<T>::cancel_cluster_manager_transfer {
+				cluster_id,
+			});
+			Self::do_propose(
+				caller_id,
+				cluster_id,
+				ProposalKind::CancelClusterManagerTransfer,
+				call,
+				ClusterStatus::Activated,
+			)
+		}
+
+		/// Cancel the pending manager transfer of the cluster, including the one proposed
+		/// through the cluster governance, as approved by the referendum.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_cluster_manager_transfer())]
+		pub fn cancel_cluster_manager_transfer(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+		) -> DispatchResult {
+			T::OpenGovUpdaterOrigin::ensure_origin(origin)?;
+			T::ClusterManager::cancel_manager_transfer(&cluster_id)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -460,6 +862,14 @@ pub mod pallet {
 			T::PalletId::get().into_account_truncating()
 		}
 
+		/// Account holding the cluster reserve spent by the cluster referendums.
+		pub fn cluster_reserve_account_id(cluster_id: &ClusterId) -> T::AccountId {
+			let hash = blake2_128(&cluster_id[..]);
+
+			// the 16 byte hash fits into the sub account after the pallet id without truncation
+			T::PalletId::get().into_sub_account_truncating(hash)
+		}
+
 		fn ensure_cluster_manager(
 			origin: T::AccountId,
 			cluster_id: ClusterId,
@@ -500,6 +910,7 @@ pub mod pallet {
 		fn ensure_allowed_voter(
 			origin: T::AccountId,
 			cluster_id: ClusterId,
+			proposal_index: ProposalIndex,
 			member: ClusterMember,
 		) -> Result<(), DispatchError> {
 			match member {
@@ -512,12 +923,18 @@ pub mod pallet {
 					} else {
 						let node_provider = T::NodeManager::get_node_provider_id(&node_pub_key)?;
 						if origin == node_provider {
-							let voting = ClusterProposalVoting::<T>::get(cluster_id)
-								.ok_or(Error::<T>::ProposalMissing)?;
-							if node_state.added_at < voting.start {
-								Ok(())
-							} else {
-								Err(Error::<T>::VoteProhibited.into())
+							let voting =
+								ClusterProposalVoting::<T>::get(cluster_id, proposal_index)
+									.ok_or(Error::<T>::ProposalMissing)?;
+							// Only the nodes validated before the proposal was initiated are
+							// allowed to vote.
+							let member = ClusterMember::NodeProvider(node_pub_key);
+							let elector =
+								voting.electorate.iter().find(|elector| elector.member == member);
+							match elector {
+								Some(elector) if elector.voter == member => Ok(()),
+								Some(_) => Err(Error::<T>::NodeVoteDelegated.into()),
+								None => Err(Error::<T>::VoteProhibited.into()),
 							}
 						} else {
 							Err(Error::<T>::NotNodeProvider.into())
@@ -527,12 +944,80 @@ pub mod pallet {
 			}
 		}
 
+		fn do_propose(
+			author: T::AccountId,
+			cluster_id: ClusterId,
+			kind: ProposalKind,
+			call: T::ClusterProposalCall,
+			required_status: ClusterStatus,
+		) -> DispatchResult {
+			let mut ongoing_proposals = 0;
+			for proposal in ClusterProposal::<T>::iter_prefix_values(cluster_id) {
+				ensure!(proposal.call != call, Error::<T>::ActiveProposal);
+				ongoing_proposals += 1;
+			}
+			ensure!(
+				ongoing_proposals < T::MaxClusterProposals::get(),
+				Error::<T>::TooManyProposals
+			);
+
+			let cluster_status =
+				<T::ClusterProtocol as ClusterQuery<T>>::get_cluster_status(&cluster_id)
+					.map_err(|_| Error::<T>::NoCluster)?;
+			ensure!(cluster_status == required_status, Error::<T>::UnexpectedState);
+
+			let cluster_nodes_stats = T::ClusterManager::get_nodes_stats(&cluster_id)
+				.map_err(|_| Error::<T>::NoCluster)?;
+			ensure!(cluster_nodes_stats.await_validation == 0, Error::<T>::AwaitsValidation);
+			ensure!(
+				cluster_nodes_stats.validation_succeeded >= T::MinValidatedNodesCount::get(),
+				Error::<T>::NotEnoughValidatedNodes
+			);
+
+			ensure!(
+				cluster_nodes_stats
+					.validation_succeeded
+					.saturating_add(cluster_nodes_stats.validation_failed) as u32 <=
+					T::MaxClusterNodes::get(),
+				Error::<T>::TooManyClusterNodes
+			);
+
+			// All Nodes validated by this moment + 1 Cluster Manager
+			let seats = cluster_nodes_stats.validation_succeeded as u32 + 1;
+			let threshold = T::SeatsConsensus::get_threshold(seats);
+			let votes = {
+				let start = frame_system::Pallet::<T>::block_number();
+				let end = start + T::ClusterProposalDuration::get();
+				let electorate = Self::get_electorate(&cluster_id, start)?;
+				Votes { seats, threshold, ayes: vec![], nays: vec![], start, end, electorate }
+			};
+			let proposal = Proposal { call, author: author.clone(), kind };
+
+			let proposal_index = ClusterProposalCount::<T>::get(cluster_id);
+			let next_proposal_index =
+				proposal_index.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+
+			<ClusterProposal<T>>::insert(cluster_id, proposal_index, proposal);
+			<ClusterProposalVoting<T>>::insert(cluster_id, proposal_index, votes);
+			<ClusterProposalCount<T>>::insert(cluster_id, next_proposal_index);
+			Self::deposit_event(Event::Proposed {
+				account: author,
+				cluster_id,
+				proposal_index,
+				threshold,
+			});
+
+			Ok(())
+		}
+
 		fn do_vote(
 			voter_id: T::AccountId,
 			cluster_id: ClusterId,
+			proposal_index: ProposalIndex,
 			approve: bool,
 		) -> Result<bool, DispatchError> {
-			let mut voting = Self::voting(cluster_id).ok_or(Error::<T>::ProposalMissing)?;
+			let mut voting =
+				Self::voting(cluster_id, proposal_index).ok_or(Error::<T>::ProposalMissing)?;
 
 			let position_yes = voting.ayes.iter().position(|a| a == &voter_id);
 			let position_no = voting.nays.iter().position(|a| a == &voter_id);
@@ -565,45 +1050,155 @@ pub mod pallet {
 			Self::deposit_event(Event::Voted {
 				account: voter_id,
 				cluster_id,
+				proposal_index,
 				voted: approve,
 				yes: yes_votes,
 				no: no_votes,
 			});
 
-			ClusterProposalVoting::<T>::insert(cluster_id, voting);
+			ClusterProposalVoting::<T>::insert(cluster_id, proposal_index, voting);
 
 			Ok(is_account_voting_first_time)
 		}
 
+		/// Returns the account that votes on behalf of the cluster member, if the member is
+		/// validated in the cluster.
+		fn get_member_account(
+			cluster_id: &ClusterId,
+			member: &ClusterMember,
+		) -> Option<T::AccountId> {
+			match member {
+				ClusterMember::ClusterManager =>
+					T::ClusterManager::get_manager_account_id(cluster_id).ok(),
+				ClusterMember::NodeProvider(node_pub_key) => {
+					let is_validated_node = T::ClusterManager::contains_node(
+						cluster_id,
+						node_pub_key,
+						Some(ClusterNodeStatus::ValidationSucceeded),
+					);
+					if is_validated_node {
+						T::NodeManager::get_node_provider_id(node_pub_key).ok()
+					} else {
+						None
+					}
+				},
+			}
+		}
+
+		/// Snapshots the voting power of the cluster members on a proposal initiated at `start`.
+		/// The vote of every node validated before the proposal was initiated is cast by its
+		/// provider or the member it is delegated to, and weighs either one seat or the amount
+		/// bonded for the node if the voting is stake-weighted.
+		pub(crate) fn get_electorate(
+			cluster_id: &ClusterId,
+			start: BlockNumberFor<T>,
+		) -> Result<Vec<Elector>, DispatchError> {
+			let stake_weighted = T::StakeWeightedVoting::get();
+
+			let cluster_manager_weight = if stake_weighted {
+				let cluster_stash = T::ClusterProtocol::get_reserve_account_id(cluster_id)?;
+				T::StakingVisitor::get_cluster_active_stake(&cluster_stash).unwrap_or_default()
+			} else {
+				1
+			};
+			let mut electorate = vec![Elector {
+				member: ClusterMember::ClusterManager,
+				voter: ClusterMember::ClusterManager,
+				weight: cluster_manager_weight,
+			}];
+
+			for node_pub_key in T::ClusterManager::get_nodes(cluster_id)? {
+				match T::ClusterManager::get_node_state(cluster_id, &node_pub_key) {
+					Ok(node_state)
+						if node_state.status == ClusterNodeStatus::ValidationSucceeded &&
+							node_state.added_at < start => {},
+					_ => continue,
+				}
+
+				let member = ClusterMember::NodeProvider(node_pub_key.clone());
+				let voter = VoteDelegations::<T>::get(cluster_id, &node_pub_key)
+					.unwrap_or_else(|| member.clone());
+				let weight = if stake_weighted {
+					T::StakingVisitor::get_node_active_stake(&node_pub_key).unwrap_or_default()
+				} else {
+					1
+				};
+				electorate.push(Elector { member, voter, weight });
+			}
+
+			Ok(electorate)
+		}
+
+		/// Counts the votes on a proposal for its electorate, the ballot of a voter which is no
+		/// longer a validated member of the cluster is not counted.
+		fn get_tally(
+			cluster_id: &ClusterId,
+			voting: &Votes<T::AccountId, BlockNumberFor<T>>,
+		) -> Tally {
+			let mut tally = Tally::default();
+			for elector in voting.electorate.iter() {
+				let voter = Self::get_member_account(cluster_id, &elector.voter);
+				tally.count(voting, voter, elector.weight);
+			}
+
+			if T::StakeWeightedVoting::get() {
+				tally.threshold = T::SeatsConsensus::get_weighted_threshold(tally.seats);
+			} else {
+				tally.seats = voting.seats.into();
+				tally.threshold = voting.threshold.into();
+			}
+
+			tally
+		}
+
 		/// Close a vote that is either approved, disapproved or whose voting period has ended.
-		fn do_close(cluster_id: ClusterId, caller_id: T::AccountId) -> DispatchResultWithPostInfo {
-			let voting = Self::voting(cluster_id).ok_or(Error::<T>::ProposalMissing)?;
-
-			let mut no_votes = voting.nays.len() as MemberCount;
-			let mut yes_votes = voting.ayes.len() as MemberCount;
-			let seats = voting.seats as MemberCount;
-			let approved = yes_votes >= voting.threshold;
-			let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
+		fn do_close(
+			cluster_id: ClusterId,
+			proposal_index: ProposalIndex,
+			caller_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let voting =
+				Self::voting(cluster_id, proposal_index).ok_or(Error::<T>::ProposalMissing)?;
+			let tally = Self::get_tally(&cluster_id, &voting);
+
+			let mut no_votes = tally.nays;
+			let mut yes_votes = tally.ayes;
+			let seats = tally.seats;
+			let members = tally.members;
+			let approved = yes_votes >= tally.threshold;
+			let disapproved = seats.saturating_sub(no_votes) < tally.threshold;
 			// Allow (dis-)approving the proposal as soon as there are enough votes.
 			if approved {
-				let (proposal, _) = Self::validate_and_get_public_proposal(&cluster_id)?;
-				Self::deposit_event(Event::Closed { cluster_id, yes: yes_votes, no: no_votes });
-				let proposal_weight = Self::do_approve_proposal(cluster_id, proposal, caller_id)?;
+				let (proposal, _) =
+					Self::validate_and_get_public_proposal(&cluster_id, proposal_index)?;
+				Self::deposit_event(Event::Closed {
+					cluster_id,
+					proposal_index,
+					yes: yes_votes,
+					no: no_votes,
+				});
+				let proposal_weight =
+					Self::do_approve_proposal(cluster_id, proposal_index, proposal, caller_id)?;
 
 				return Ok((
 					Some(
-						<T as pallet::Config>::WeightInfo::close_early_approved(seats)
+						<T as pallet::Config>::WeightInfo::close_early_approved(members)
 							.saturating_add(proposal_weight),
 					),
 					Pays::Yes,
 				)
 					.into());
 			} else if disapproved {
-				Self::deposit_event(Event::Closed { cluster_id, yes: yes_votes, no: no_votes });
-				Self::do_disapprove_proposal(cluster_id);
+				Self::deposit_event(Event::Closed {
+					cluster_id,
+					proposal_index,
+					yes: yes_votes,
+					no: no_votes,
+				});
+				Self::do_disapprove_proposal(cluster_id, proposal_index);
 
 				return Ok((
-					Some(<T as pallet::Config>::WeightInfo::close_early_disapproved(seats)),
+					Some(<T as pallet::Config>::WeightInfo::close_early_disapproved(members)),
 					Pays::Yes,
 				)
 					.into());
@@ -618,46 +1213,59 @@ pub mod pallet {
 			// default voting strategy.
 			let default = T::DefaultVote::default_vote(
 				Some(cluster_manager_vote),
-				yes_votes,
-				no_votes,
-				seats,
+				voting.ayes.len() as MemberCount,
+				voting.nays.len() as MemberCount,
+				voting.seats,
 			);
 
-			let abstentions = seats - (yes_votes + no_votes);
+			let abstentions = seats.saturating_sub(yes_votes.saturating_add(no_votes));
 			match default {
-				true => yes_votes += abstentions,
-				false => no_votes += abstentions,
+				true => yes_votes = yes_votes.saturating_add(abstentions),
+				false => no_votes = no_votes.saturating_add(abstentions),
 			}
-			let approved = yes_votes >= voting.threshold;
+			let approved = yes_votes >= tally.threshold;
 
 			if approved {
-				let (proposal, _) = Self::validate_and_get_public_proposal(&cluster_id)?;
-				Self::deposit_event(Event::Closed { cluster_id, yes: yes_votes, no: no_votes });
-				let proposal_weight = Self::do_approve_proposal(cluster_id, proposal, caller_id)?;
+				let (proposal, _) =
+					Self::validate_and_get_public_proposal(&cluster_id, proposal_index)?;
+				Self::deposit_event(Event::Closed {
+					cluster_id,
+					proposal_index,
+					yes: yes_votes,
+					no: no_votes,
+				});
+				let proposal_weight =
+					Self::do_approve_proposal(cluster_id, proposal_index, proposal, caller_id)?;
 
 				Ok((
 					Some(
-						<T as pallet::Config>::WeightInfo::close_approved(seats)
+						<T as pallet::Config>::WeightInfo::close_approved(members)
 							.saturating_add(proposal_weight),
 					),
 					Pays::Yes,
 				)
 					.into())
 			} else {
-				Self::deposit_event(Event::Closed { cluster_id, yes: yes_votes, no: no_votes });
-				Self::do_disapprove_proposal(cluster_id);
+				Self::deposit_event(Event::Closed {
+					cluster_id,
+					proposal_index,
+					yes: yes_votes,
+					no: no_votes,
+				});
+				Self::do_disapprove_proposal(cluster_id, proposal_index);
 
-				Ok((Some(<T as pallet::Config>::WeightInfo::close_disapproved(seats)), Pays::Yes)
+				Ok((Some(<T as pallet::Config>::WeightInfo::close_disapproved(members)), Pays::Yes)
 					.into())
 			}
 		}
 
 		fn do_approve_proposal(
 			cluster_id: ClusterId,
+			proposal_index: ProposalIndex,
 			proposal: ReferendaCall<T>,
 			depositor: T::AccountId,
 		) -> Result<Weight, DispatchError> {
-			Self::deposit_event(Event::Approved { cluster_id });
+			Self::deposit_event(Event::Approved { cluster_id, proposal_index });
 
 			let dispatch_weight = proposal.get_dispatch_info().weight;
 			let submission_deposit = Self::do_submission_deposit(depositor.clone())?;
@@ -665,34 +1273,35 @@ pub mod pallet {
 			let post_info = proposal
 				.dispatch_bypass_filter(frame_system::RawOrigin::Signed(Self::account_id()).into())
 				.map_err(|e| e.error)?;
-			Self::deposit_event(Event::ReferendumSubmitted { cluster_id });
+			Self::deposit_event(Event::ReferendumSubmitted { cluster_id, proposal_index });
 
 			let referenda_index = pallet_referenda::ReferendumCount::<T>::get() - 1;
 			Self::do_retain_submission_deposit(referenda_index, depositor, submission_deposit);
 			let proposal_weight = post_info.actual_weight.unwrap_or(dispatch_weight);
 
-			Self::do_remove_proposal(cluster_id);
+			Self::do_remove_proposal(cluster_id, proposal_index);
 			Ok(proposal_weight)
 		}
 
 		/// Removes a proposal from the pallet, and deposit the `Disapproved` event.
-		fn do_disapprove_proposal(cluster_id: ClusterId) {
-			Self::deposit_event(Event::Disapproved { cluster_id });
-			Self::do_remove_proposal(cluster_id)
+		fn do_disapprove_proposal(cluster_id: ClusterId, proposal_index: ProposalIndex) {
+			Self::deposit_event(Event::Disapproved { cluster_id, proposal_index });
+			Self::do_remove_proposal(cluster_id, proposal_index)
 		}
 
 		/// Removes a proposal from the pallet, cleaning up votes and the vector of proposals.
-		fn do_remove_proposal(cluster_id: ClusterId) {
-			ClusterProposal::<T>::remove(cluster_id);
-			ClusterProposalVoting::<T>::remove(cluster_id);
-			Self::deposit_event(Event::Removed { cluster_id });
+		fn do_remove_proposal(cluster_id: ClusterId, proposal_index: ProposalIndex) {
+			ClusterProposal::<T>::remove(cluster_id, proposal_index);
+			ClusterProposalVoting::<T>::remove(cluster_id, proposal_index);
+			Self::deposit_event(Event::Removed { cluster_id, proposal_index });
 		}
 
 		fn validate_and_get_public_proposal(
 			cluster_id: &ClusterId,
+			proposal_index: ProposalIndex,
 		) -> Result<(ReferendaCall<T>, usize), DispatchError> {
-			let proposal =
-				ClusterProposal::<T>::get(cluster_id).ok_or(Error::<T>::ProposalMissing)?;
+			let proposal = ClusterProposal::<T>::get(cluster_id, proposal_index)
+				.ok_or(Error::<T>::ProposalMissing)?;
 
 			let call: <T as pallet_referenda::Config>::RuntimeCall = proposal.call.into();
 			let bounded_call =
@@ -700,7 +1309,13 @@ pub mod pallet {
 
 			let proposal_origin = match proposal.kind {
 				ProposalKind::ActivateClusterProtocol => T::OpenGovActivatorTrackOrigin::get(),
-				ProposalKind::UpdateClusterProtocol => T::OpenGovUpdaterTrackOrigin::get(),
+				ProposalKind::UpdateClusterProtocol |
+				ProposalKind::TransferClusterManager |
+				ProposalKind::RemoveClusterNode |
+				ProposalKind::UpdateClusterParams |
+				ProposalKind::SpendClusterReserve |
+				ProposalKind::SetPricingOverride |
+				ProposalKind::CancelClusterManagerTransfer => T::OpenGovUpdaterTrackOrigin::get(),
 			};
 
 			let pallets_origin: <T::RuntimeOrigin as OriginTrait>::PalletsOrigin =
@@ -766,6 +1381,44 @@ pub mod pallet {
 			Ok(())
 		}
 	}
+
+	/// Ongoing proposals and vote delegations of a decommissioned cluster are removed. The funds
+	/// left in the cluster reserve are not moved, as the cluster proposals can not be made any
+	/// more they are spent by a referendum submitted directly on the updater track.
+	impl<T: Config> ClusterDecommissionHandler for Pallet<T> {
+		fn clean_up_weight(limit: u32) -> Weight {
+			let limit: u64 = limit.into();
+			T::DbWeight::get()
+				.reads_writes(limit.saturating_add(2), limit.saturating_mul(2).saturating_add(1))
+		}
+
+		fn clean_up(
+			cluster_id: &ClusterId,
+			_buckets_migrate_to: Option<ClusterId>,
+			limit: u32,
+		) -> Result<u32, DispatchError> {
+			let proposal_indexes: Vec<ProposalIndex> =
+				ClusterProposal::<T>::iter_key_prefix(cluster_id).take(limit as usize).collect();
+			for proposal_index in proposal_indexes.iter() {
+				Self::do_remove_proposal(*cluster_id, *proposal_index);
+			}
+
+			let remaining = limit.saturating_sub(proposal_indexes.len() as u32);
+			let delegators: Vec<NodePubKey> = VoteDelegations::<T>::iter_key_prefix(cluster_id)
+				.take(remaining as usize)
+				.collect();
+			for node_pub_key in delegators.iter() {
+				VoteDelegations::<T>::remove(cluster_id, node_pub_key);
+			}
+
+			let processed = (proposal_indexes.len() + delegators.len()) as u32;
+			if processed < limit {
+				ClusterProposalCount::<T>::remove(cluster_id);
+			}
+
+			Ok(processed)
+		}
+	}
 }
 
 /// Set 'Nay' as default vote.
@@ -798,10 +1451,18 @@ impl SeatsConsensus for Unanimous {
 	fn get_threshold(seats: MemberCount) -> MemberCount {
 		seats
 	}
+
+	fn get_weighted_threshold(total_weight: VoteWeight) -> VoteWeight {
+		total_weight
+	}
 }
 pub struct Supermajority;
 impl SeatsConsensus for Supermajority {
 	fn get_threshold(seats: MemberCount) -> MemberCount {
 		(seats * 2 + 2 - 1) / 3
 	}
+
+	fn get_weighted_threshold(total_weight: VoteWeight) -> VoteWeight {
+		total_weight - total_weight / 3
+	}
 }
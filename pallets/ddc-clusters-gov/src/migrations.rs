@@ -0,0 +1,245 @@
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+use frame_support::{
+	storage_alias,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use log::info;
+use sp_runtime::Saturating;
+
+use super::*;
+
+const LOG_TARGET: &str = "ddc-clusters-gov";
+
+pub mod v0 {
+	use super::*;
+
+	/// Votes on a proposal before the electorate was snapshotted when the proposal is initiated.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct Votes<AccountId, BlockNumber> {
+		pub seats: MemberCount,
+		pub threshold: MemberCount,
+		pub ayes: Vec<AccountId>,
+		pub nays: Vec<AccountId>,
+		pub start: BlockNumber,
+		pub end: BlockNumber,
+	}
+
+	#[storage_alias]
+	pub type ClusterProposal<T: Config> = StorageMap<
+		crate::Pallet<T>,
+		Identity,
+		ClusterId,
+		Proposal<<T as frame_system::Config>::AccountId, <T as Config>::ClusterProposalCall>,
+	>;
+
+	#[storage_alias]
+	pub type ClusterProposalVoting<T: Config> = StorageMap<
+		crate::Pallet<T>,
+		Identity,
+		ClusterId,
+		v0::Votes<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+	>;
+}
+
+pub mod v1 {
+	use super::*;
+
+	#[storage_alias]
+	pub type ClusterProposalVoting<T: Config> = StorageDoubleMap<
+		crate::Pallet<T>,
+		Identity,
+		ClusterId,
+		Twox64Concat,
+		ProposalIndex,
+		v0::Votes<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+	>;
+
+	/// Moves the single ongoing proposal of a cluster to the first proposal index.
+	pub fn migrate_to_v1<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		let current_version = Pallet::<T>::current_storage_version();
+
+		info!(
+			target: LOG_TARGET,
+			"Running migration with current storage version {:?} / onchain {:?}",
+			current_version,
+			on_chain_version
+		);
+
+		if on_chain_version == 0 {
+			let proposals = v0::ClusterProposal::<T>::drain().collect::<Vec<_>>();
+			let votings = v0::ClusterProposalVoting::<T>::drain().collect::<Vec<_>>();
+			let mut migrated = 0u64;
+
+			info!(
+				target: LOG_TARGET,
+				" >>> Migrating {} cluster proposals...",
+				proposals.len()
+			);
+
+			for (cluster_id, proposal) in proposals {
+				ClusterProposal::<T>::insert(cluster_id, 0, proposal);
+				ClusterProposalCount::<T>::insert(cluster_id, 1);
+				migrated.saturating_inc();
+			}
+
+			for (cluster_id, votes) in votings {
+				v1::ClusterProposalVoting::<T>::insert(cluster_id, 0, votes);
+				migrated.saturating_inc();
+			}
+
+			// Update storage version.
+			StorageVersion::new(1).put::<Pallet<T>>();
+			info!(
+				target: LOG_TARGET,
+				"Upgraded {} records, storage to version {:?}",
+				migrated,
+				current_version
+			);
+
+			T::DbWeight::get().reads_writes(migrated + 1, migrated * 2 + 1)
+		} else {
+			info!(target: LOG_TARGET, " >>> Unused migration!");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrate_to_v1::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+			let prev_count = v0::ClusterProposal::<T>::iter().count();
+
+			Ok((prev_count as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+			let prev_count: u64 = Decode::decode(&mut &prev_state[..])
+				.expect("pre_upgrade provides a valid state; qed");
+
+			let post_count = ClusterProposal::<T>::iter().count() as u64;
+			ensure!(
+				prev_count == post_count,
+				"the cluster proposal count before and after the migration should be the same"
+			);
+
+			let current_version = Pallet::<T>::current_storage_version();
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+
+			ensure!(on_chain_version >= 1, "must_upgrade");
+			ensure!(
+				current_version >= on_chain_version,
+				"after migration, the on_chain_version should not be ahead of the current_version"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Snapshots the electorate of the ongoing proposals at the moment they were initiated.
+	pub fn migrate_to_v2<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		let current_version = Pallet::<T>::current_storage_version();
+
+		info!(
+			target: LOG_TARGET,
+			"Running migration with current storage version {:?} / onchain {:?}",
+			current_version,
+			on_chain_version
+		);
+
+		if on_chain_version == 1 {
+			let votings = v1::ClusterProposalVoting::<T>::drain().collect::<Vec<_>>();
+			let mut migrated = 0u64;
+			let mut reads = 0u64;
+
+			info!(
+				target: LOG_TARGET,
+				" >>> Migrating {} cluster proposal votings...",
+				votings.len()
+			);
+
+			for (cluster_id, proposal_index, votes) in votings {
+				let electorate =
+					Pallet::<T>::get_electorate(&cluster_id, votes.start).unwrap_or_default();
+				reads.saturating_accrue(electorate.len() as u64 * 5 + 3);
+				ClusterProposalVoting::<T>::insert(
+					cluster_id,
+					proposal_index,
+					Votes {
+						seats: votes.seats,
+						threshold: votes.threshold,
+						ayes: votes.ayes,
+						nays: votes.nays,
+						start: votes.start,
+						end: votes.end,
+						electorate,
+					},
+				);
+				migrated.saturating_inc();
+			}
+
+			// Update storage version.
+			StorageVersion::new(2).put::<Pallet<T>>();
+			info!(
+				target: LOG_TARGET,
+				"Upgraded {} records, storage to version {:?}",
+				migrated,
+				current_version
+			);
+
+			T::DbWeight::get().reads_writes(migrated + reads + 1, migrated * 2 + 1)
+		} else {
+			info!(target: LOG_TARGET, " >>> Unused migration!");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrate_to_v2::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+			let prev_count = v1::ClusterProposalVoting::<T>::iter().count();
+
+			Ok((prev_count as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+			let prev_count: u64 = Decode::decode(&mut &prev_state[..])
+				.expect("pre_upgrade provides a valid state; qed");
+
+			let post_count = ClusterProposalVoting::<T>::iter().count() as u64;
+			ensure!(
+				prev_count == post_count,
+				"the cluster proposal voting count before and after the migration should be the same"
+			);
+
+			let current_version = Pallet::<T>::current_storage_version();
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+
+			ensure!(on_chain_version >= 2, "must_upgrade");
+			ensure!(
+				current_version >= on_chain_version,
+				"after migration, the on_chain_version should not be ahead of the current_version"
+			);
+			Ok(())
+		}
+	}
+}
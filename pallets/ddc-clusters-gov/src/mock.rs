@@ -6,11 +6,12 @@ use std::cell::RefCell;
 
 use ddc_primitives::{
 	traits::{
+		customer::CustomerVisitor,
 		pallet::{GetDdcOrigin, PalletsOriginOf},
 		SeatsConsensus,
 	},
-	ClusterId, ClusterNodeKind, ClusterParams, ClusterProtocolParams, NodeParams, NodePubKey,
-	StorageNodeParams, DOLLARS,
+	BucketId, ClusterId, ClusterNodeKind, ClusterParams, ClusterProtocolParams, NodeParams,
+	NodePubKey, StorageNodeParams, DOLLARS,
 };
 use frame_support::{
 	parameter_types,
@@ -277,6 +278,15 @@ impl pallet_ddc_clusters::Config for Test {
 	type MinErasureCodingRequiredLimit = ConstU32<0>;
 	type MinErasureCodingTotalLimit = ConstU32<0>;
 	type MinReplicationTotalLimit = ConstU32<0>;
+	type ManagerTransferRequiresGov = ConstBool<true>;
+	type MaxAllowlistLen = ConstU32<10>;
+	type IdentityVerifier = ();
+	type DecommissionNoticePeriod = ConstU64<10>;
+	type DecommissionBatchSize = ConstU32<10>;
+	type ClusterDecommissionHandler = ();
+	type MaxAdmissionQueueLen = ConstU32<10>;
+	type PricingOverrideRequiresGov = ConstBool<true>;
+	type CustomerVisitor = MockCustomerVisitor;
 }
 
 parameter_types! {
@@ -305,6 +315,9 @@ parameter_types! {
 	pub ClusterProtocolActivatorTrackOrigin: RuntimeOrigin = pallet_mock_origins::Origin::ClusterProtocolActivator.into();
 	pub ClusterProtocolUpdaterTrackOrigin: RuntimeOrigin = pallet_mock_origins::Origin::ClusterProtocolUpdater.into();
 	pub const ReferendumEnactmentDuration: BlockNumber = 1;
+	pub const MaxClusterProposals: u32 = 3;
+	pub const MaxClusterNodes: u32 = 64;
+	pub static StakeWeightedVoting: bool = false;
 }
 
 impl crate::pallet::Config for Test {
@@ -326,6 +339,10 @@ impl crate::pallet::Config for Test {
 	type DefaultVote = MockedDefaultVote; // pallet_ddc_clusters_gov::PrimeDefaultVote;
 	type MinValidatedNodesCount = MinValidatedNodesCount;
 	type ReferendumEnactmentDuration = ReferendumEnactmentDuration;
+	type MaxClusterProposals = MaxClusterProposals;
+	type MaxClusterNodes = MaxClusterNodes;
+	type StakeWeightedVoting = StakeWeightedVoting;
+	type StakingVisitor = pallet_ddc_staking::Pallet<Test>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type StakerCreator = pallet_ddc_staking::Pallet<Test>;
 }
@@ -437,6 +454,27 @@ impl SeatsConsensus for MockedSeatsConsensus {
 			ConsensusVariant::Unanimous => Unanimous::get_threshold(seats),
 		}
 	}
+
+	fn get_weighted_threshold(total_weight: VoteWeight) -> VoteWeight {
+		let lock = MOCK_SEATS_CONSENSUS.lock();
+		let mock_ref = lock.borrow();
+		match mock_ref.consensus {
+			ConsensusVariant::Supermajority => Supermajority::get_weighted_threshold(total_weight),
+			ConsensusVariant::Unanimous => Unanimous::get_weighted_threshold(total_weight),
+		}
+	}
+}
+
+/// Buckets of the mock belong to the cluster `CLUSTER_ID`.
+pub struct MockCustomerVisitor;
+impl<T: frame_system::Config> CustomerVisitor<T> for MockCustomerVisitor {
+	fn get_bucket_owner(_bucket_id: &BucketId) -> Result<T::AccountId, DispatchError> {
+		unimplemented!()
+	}
+
+	fn get_bucket_cluster_id(_bucket_id: &BucketId) -> Result<ClusterId, DispatchError> {
+		Ok(ClusterId::from(CLUSTER_ID))
+	}
 }
 
 pub struct DdcOriginAsNative<DdcOrigin, RuntimeOrigin>(PhantomData<(DdcOrigin, RuntimeOrigin)>);
@@ -1,11 +1,14 @@
 //! Tests for the module.
 
-use ddc_primitives::{ClusterNodeKind, ClusterParams, ClusterProtocolParams, StorageNodeParams};
+use ddc_primitives::{
+	ClusterNodeKind, ClusterParams, ClusterProtocolParams, CustomerPricing, PriceSchedule,
+	PricingOverride, PricingOverrideTarget, StorageNodeParams,
+};
 use frame_support::{assert_noop, assert_ok};
 use pallet_conviction_voting::{AccountVote, Conviction, Vote};
 use pallet_ddc_clusters::Event::{ClusterActivated, ClusterProtocolParamsSet};
 use pallet_referenda::ReferendumInfo;
-use sp_runtime::Perquintill;
+use sp_runtime::{Percent, Perquintill};
 
 use super::{mock::*, *};
 use crate::SubmissionDeposit as ReferendaSubmissionDeposit;
@@ -92,7 +95,7 @@ fn cluster_protocol_activation_proposal_initiated() {
 			cluster_protocol_params.clone()
 		));
 
-		let proposal = ClusterProposal::<Test>::get(cluster_id);
+		let proposal = ClusterProposal::<Test>::get(cluster_id, 0);
 		assert_eq!(
 			proposal,
 			Some(Proposal {
@@ -107,14 +110,20 @@ fn cluster_protocol_activation_proposal_initiated() {
 			})
 		);
 
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id);
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0);
 		let start = BlockNumber::from(1_u64);
 		let end = start + <Test as pallet::Config>::ClusterProposalDuration::get();
 		let seats = 4; // 3 validated nodes + 1 cluster manager
 		let threshold = <Test as pallet::Config>::SeatsConsensus::get_threshold(seats);
-		assert_eq!(votes, Some(Votes { seats, threshold, ayes: vec![], nays: vec![], start, end }));
+		let electorate = DdcClustersGov::get_electorate(&cluster_id, start).unwrap();
+		assert_eq!(electorate.len() as MemberCount, seats);
+		assert_eq!(
+			votes,
+			Some(Votes { seats, threshold, ayes: vec![], nays: vec![], start, end, electorate })
+		);
 		System::assert_last_event(
-			Event::Proposed { account: cluster_manager, cluster_id, threshold }.into(),
+			Event::Proposed { account: cluster_manager, cluster_id, proposal_index: 0, threshold }
+				.into(),
 		)
 	})
 }
@@ -335,10 +344,12 @@ fn cluster_protocol_activation_is_allowed_for_referenda_cluster_protocol_activat
 			storage_bond_size: 10 * CERE,
 			storage_chill_delay: 20,
 			storage_unbonding_delay: 20,
+			node_removal_notice_period: 20,
 			unit_per_mb_stored: 97656,
 			unit_per_mb_streamed: 48828,
 			unit_per_put_request: 10,
 			unit_per_get_request: 5,
+			pricing_tiers: Default::default(),
 		};
 		let open_gov_activator = <Test as pallet::Config>::OpenGovActivatorTrackOrigin::get();
 		assert_ok!(DdcClustersGov::activate_cluster_protocol(
@@ -413,13 +424,14 @@ fn cluster_protocol_activation_proposal_can_be_retracted_by_its_author() {
 			cluster_protocol_params.clone()
 		));
 
-		assert!(ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
 		assert_noop!(
 			DdcClustersGov::retract_proposal(
 				RuntimeOrigin::signed(cluster_manager.clone()),
 				not_cluster_id,
+				0,
 			),
 			Error::<Test>::ProposalMissing
 		);
@@ -428,6 +440,7 @@ fn cluster_protocol_activation_proposal_can_be_retracted_by_its_author() {
 			DdcClustersGov::retract_proposal(
 				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 				cluster_id,
+				0,
 			),
 			Error::<Test>::NotProposalAuthor
 		);
@@ -435,11 +448,12 @@ fn cluster_protocol_activation_proposal_can_be_retracted_by_its_author() {
 		assert_ok!(DdcClustersGov::retract_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 		));
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
-		System::assert_last_event(Event::Removed { cluster_id }.into());
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
+		System::assert_last_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 	})
 }
 
@@ -549,10 +563,12 @@ fn cluster_protocol_update_proposal_initiated() {
 			storage_bond_size: 10 * CERE,
 			storage_chill_delay: 20,
 			storage_unbonding_delay: 20,
+			node_removal_notice_period: 20,
 			unit_per_mb_stored: 97656,
 			unit_per_mb_streamed: 48828,
 			unit_per_put_request: 10,
 			unit_per_get_request: 5,
+			pricing_tiers: Default::default(),
 		};
 
 		let not_cluster_member = AccountId::from([0; 32]);
@@ -586,7 +602,7 @@ fn cluster_protocol_update_proposal_initiated() {
 			ClusterMember::NodeProvider(cluster_node_1_key)
 		));
 
-		let proposal = ClusterProposal::<Test>::get(cluster_id);
+		let proposal = ClusterProposal::<Test>::get(cluster_id, 0);
 		assert_eq!(
 			proposal,
 			Some(Proposal {
@@ -601,14 +617,25 @@ fn cluster_protocol_update_proposal_initiated() {
 			})
 		);
 
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id);
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0);
 		let start = BlockNumber::from(1_u64);
 		let end = start + <Test as pallet::Config>::ClusterProposalDuration::get();
 		let seats = 4; // 3 validated nodes + 1 cluster manager
 		let threshold = <Test as pallet::Config>::SeatsConsensus::get_threshold(seats);
-		assert_eq!(votes, Some(Votes { seats, threshold, ayes: vec![], nays: vec![], start, end }));
+		let electorate = DdcClustersGov::get_electorate(&cluster_id, start).unwrap();
+		assert_eq!(electorate.len() as MemberCount, seats);
+		assert_eq!(
+			votes,
+			Some(Votes { seats, threshold, ayes: vec![], nays: vec![], start, end, electorate })
+		);
 		System::assert_last_event(
-			Event::Proposed { account: cluster_node_1_provider, cluster_id, threshold }.into(),
+			Event::Proposed {
+				account: cluster_node_1_provider,
+				cluster_id,
+				proposal_index: 0,
+				threshold,
+			}
+			.into(),
 		)
 	})
 }
@@ -842,10 +869,12 @@ fn cluster_protocol_update_is_allowed_for_referenda_cluster_protocol_updater_tra
 			storage_bond_size: 10 * CERE,
 			storage_chill_delay: 20,
 			storage_unbonding_delay: 20,
+			node_removal_notice_period: 20,
 			unit_per_mb_stored: 97656,
 			unit_per_mb_streamed: 48828,
 			unit_per_put_request: 10,
 			unit_per_get_request: 5,
+			pricing_tiers: Default::default(),
 		};
 		let open_gov_updater = <Test as pallet::Config>::OpenGovUpdaterTrackOrigin::get();
 		assert_ok!(DdcClustersGov::update_cluster_protocol(
@@ -918,13 +947,14 @@ fn cluster_protocol_update_proposal_can_be_retracted_by_its_author() {
 			ClusterMember::NodeProvider(cluster_node_1_key)
 		));
 
-		assert!(ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
 		assert_noop!(
 			DdcClustersGov::retract_proposal(
 				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 				not_cluster_id,
+				0,
 			),
 			Error::<Test>::ProposalMissing
 		);
@@ -933,6 +963,7 @@ fn cluster_protocol_update_proposal_can_be_retracted_by_its_author() {
 			DdcClustersGov::retract_proposal(
 				RuntimeOrigin::signed(cluster_manager.clone()),
 				cluster_id,
+				0,
 			),
 			Error::<Test>::NotProposalAuthor
 		);
@@ -940,11 +971,12 @@ fn cluster_protocol_update_proposal_can_be_retracted_by_its_author() {
 		assert_ok!(DdcClustersGov::retract_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 		));
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
-		System::assert_last_event(Event::Removed { cluster_id }.into());
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
+		System::assert_last_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 	})
 }
 
@@ -1017,7 +1049,13 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 		let seats = 4; // 3 validated nodes + 1 cluster manager
 		let threshold = <Test as pallet::Config>::SeatsConsensus::get_threshold(seats);
 		System::assert_last_event(
-			Event::Proposed { account: cluster_manager.clone(), cluster_id, threshold }.into(),
+			Event::Proposed {
+				account: cluster_manager.clone(),
+				cluster_id,
+				proposal_index: 0,
+				threshold,
+			}
+			.into(),
 		);
 
 		let not_cluster_manager = AccountId::from([0; 32]);
@@ -1025,6 +1063,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 			DdcClustersGov::vote_proposal(
 				RuntimeOrigin::signed(not_cluster_manager.clone()),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::ClusterManager,
 			),
@@ -1034,16 +1073,18 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
 
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(votes.ayes, vec![cluster_manager.clone()]);
 		System::assert_last_event(
 			Event::Voted {
 				account: cluster_manager.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 1,
 				no: 0,
@@ -1057,6 +1098,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 			DdcClustersGov::vote_proposal(
 				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::NodeProvider(not_cluster_node_key.clone()),
 			),
@@ -1067,6 +1109,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 			DdcClustersGov::vote_proposal(
 				RuntimeOrigin::signed(not_node_provider.clone()),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 			),
@@ -1076,15 +1119,17 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(votes.ayes, vec![cluster_manager.clone(), cluster_node_1_provider.clone()]);
 		System::assert_last_event(
 			Event::Voted {
 				account: cluster_node_1_provider.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 2,
 				no: 0,
@@ -1095,10 +1140,11 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_2_key),
 		));
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(
 			votes.ayes,
 			vec![
@@ -1111,6 +1157,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 			Event::Voted {
 				account: cluster_node_2_provider.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 3,
 				no: 0,
@@ -1121,10 +1168,11 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_3_key),
 		));
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(
 			votes.ayes,
 			vec![
@@ -1138,6 +1186,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 			Event::Voted {
 				account: cluster_node_3_provider.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 4,
 				no: 0,
@@ -1149,6 +1198,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(not_cluster_manager.clone()),
 				cluster_id,
+				0,
 				ClusterMember::ClusterManager,
 			),
 			Error::<Test>::NotClusterManager
@@ -1158,6 +1208,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 				cluster_id,
+				0,
 				ClusterMember::NodeProvider(not_cluster_node_key.clone()),
 			),
 			Error::<Test>::NotValidatedNode
@@ -1167,6 +1218,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(not_node_provider.clone()),
 				cluster_id,
+				0,
 				ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 			),
 			Error::<Test>::NotNodeProvider
@@ -1176,6 +1228,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			ClusterMember::ClusterManager,
 		));
 
@@ -1186,8 +1239,8 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 		let submission_deposit_amount =
 			<Test as pallet_referenda::Config>::SubmissionDeposit::get().saturated_into::<u128>();
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
 		assert_eq!(
 			SubmissionDeposits::<Test>::get(referenda_index),
@@ -1203,9 +1256,13 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 			balance_after_submission_deposit
 		);
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 4, no: 0 }.into());
-		System::assert_has_event(Event::Approved { cluster_id }.into());
-		System::assert_has_event(Event::ReferendumSubmitted { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 4, no: 0 }.into(),
+		);
+		System::assert_has_event(Event::Approved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(
+			Event::ReferendumSubmitted { cluster_id, proposal_index: 0 }.into(),
+		);
 		System::assert_has_event(
 			Event::SubmissionDepositRetained {
 				referenda_index,
@@ -1214,7 +1271,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_supermajority_consen
 			}
 			.into(),
 		);
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		// OpenGov
 
@@ -1341,6 +1398,7 @@ fn cluster_protocol_activation_proposal_approved_with_supermajority_consensus_an
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
@@ -1348,6 +1406,7 @@ fn cluster_protocol_activation_proposal_approved_with_supermajority_consensus_an
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			false,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
@@ -1360,6 +1419,7 @@ fn cluster_protocol_activation_proposal_approved_with_supermajority_consensus_an
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			ClusterMember::ClusterManager,
 		));
 
@@ -1370,8 +1430,8 @@ fn cluster_protocol_activation_proposal_approved_with_supermajority_consensus_an
 		let submission_deposit_amount =
 			<Test as pallet_referenda::Config>::SubmissionDeposit::get().saturated_into::<u128>();
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
 		assert_eq!(
 			SubmissionDeposits::<Test>::get(referenda_index),
@@ -1387,9 +1447,13 @@ fn cluster_protocol_activation_proposal_approved_with_supermajority_consensus_an
 			balance_after_submission_deposit
 		);
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 3, no: 1 }.into());
-		System::assert_has_event(Event::Approved { cluster_id }.into());
-		System::assert_has_event(Event::ReferendumSubmitted { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 3, no: 1 }.into(),
+		);
+		System::assert_has_event(Event::Approved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(
+			Event::ReferendumSubmitted { cluster_id, proposal_index: 0 }.into(),
+		);
 		System::assert_has_event(
 			Event::SubmissionDepositRetained {
 				referenda_index,
@@ -1398,7 +1462,7 @@ fn cluster_protocol_activation_proposal_approved_with_supermajority_consensus_an
 			}
 			.into(),
 		);
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		MockedDefaultVote::reset_and_release_lock(lock1);
 		MockedSeatsConsensus::reset_and_release_lock(lock2);
@@ -1475,6 +1539,7 @@ fn cluster_protocol_activation_proposal_early_disapproved_with_supermajority_con
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
@@ -1482,6 +1547,7 @@ fn cluster_protocol_activation_proposal_early_disapproved_with_supermajority_con
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			false,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
@@ -1489,6 +1555,7 @@ fn cluster_protocol_activation_proposal_early_disapproved_with_supermajority_con
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			false,
 			ClusterMember::NodeProvider(cluster_node_2_key.clone()),
 		));
@@ -1496,6 +1563,7 @@ fn cluster_protocol_activation_proposal_early_disapproved_with_supermajority_con
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
 			cluster_id,
+			0,
 			false,
 			ClusterMember::NodeProvider(cluster_node_3_key),
 		));
@@ -1503,18 +1571,21 @@ fn cluster_protocol_activation_proposal_early_disapproved_with_supermajority_con
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			ClusterMember::ClusterManager,
 		));
 
 		// As the quorum is not reached, the proposal gets rejected and no referenda is created
 		assert_eq!(pallet_referenda::ReferendumCount::<Test>::get(), 0);
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 1, no: 3 }.into());
-		System::assert_has_event(Event::Disapproved { cluster_id }.into());
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 1, no: 3 }.into(),
+		);
+		System::assert_has_event(Event::Disapproved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		MockedDefaultVote::reset_and_release_lock(lock1);
 		MockedSeatsConsensus::reset_and_release_lock(lock2);
@@ -1585,6 +1656,7 @@ fn cluster_protocol_activation_proposal_disapproved_with_supermajority_consensus
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			false,
 			ClusterMember::ClusterManager,
 		));
@@ -1592,6 +1664,7 @@ fn cluster_protocol_activation_proposal_disapproved_with_supermajority_consensus
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
@@ -1603,18 +1676,21 @@ fn cluster_protocol_activation_proposal_disapproved_with_supermajority_consensus
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			ClusterMember::ClusterManager,
 		));
 
 		// As the quorum is not reached, the proposal gets rejected and no referenda is created
 		assert_eq!(pallet_referenda::ReferendumCount::<Test>::get(), 0);
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 1, no: 3 }.into());
-		System::assert_has_event(Event::Disapproved { cluster_id }.into());
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 1, no: 3 }.into(),
+		);
+		System::assert_has_event(Event::Disapproved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		MockedDefaultVote::reset_and_release_lock(lock1);
 		MockedSeatsConsensus::reset_and_release_lock(lock2);
@@ -1682,6 +1758,7 @@ fn cluster_protocol_activation_proposal_cannot_be_closed_if_threshold_is_not_rea
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
@@ -1690,6 +1767,7 @@ fn cluster_protocol_activation_proposal_cannot_be_closed_if_threshold_is_not_rea
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(cluster_manager.clone()),
 				cluster_id,
+				0,
 				ClusterMember::ClusterManager,
 			),
 			Error::<Test>::TooEarly
@@ -1769,7 +1847,13 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 		let seats = 4; // 3 validated nodes + 1 cluster manager
 		let threshold = <Test as pallet::Config>::SeatsConsensus::get_threshold(seats);
 		System::assert_last_event(
-			Event::Proposed { account: cluster_manager.clone(), cluster_id, threshold }.into(),
+			Event::Proposed {
+				account: cluster_manager.clone(),
+				cluster_id,
+				proposal_index: 0,
+				threshold,
+			}
+			.into(),
 		);
 
 		let not_cluster_manager = AccountId::from([0; 32]);
@@ -1777,6 +1861,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 			DdcClustersGov::vote_proposal(
 				RuntimeOrigin::signed(not_cluster_manager.clone()),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::ClusterManager,
 			),
@@ -1786,16 +1871,18 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
 
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(votes.ayes, vec![cluster_manager.clone()]);
 		System::assert_last_event(
 			Event::Voted {
 				account: cluster_manager.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 1,
 				no: 0,
@@ -1809,6 +1896,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 			DdcClustersGov::vote_proposal(
 				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::NodeProvider(not_cluster_node_key.clone()),
 			),
@@ -1819,6 +1907,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 			DdcClustersGov::vote_proposal(
 				RuntimeOrigin::signed(not_node_provider.clone()),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 			),
@@ -1828,15 +1917,17 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(votes.ayes, vec![cluster_manager.clone(), cluster_node_1_provider.clone()]);
 		System::assert_last_event(
 			Event::Voted {
 				account: cluster_node_1_provider.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 2,
 				no: 0,
@@ -1847,10 +1938,11 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_2_key),
 		));
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(
 			votes.ayes,
 			vec![
@@ -1863,6 +1955,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 			Event::Voted {
 				account: cluster_node_2_provider.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 3,
 				no: 0,
@@ -1873,10 +1966,11 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_3_key),
 		));
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(
 			votes.ayes,
 			vec![
@@ -1890,6 +1984,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 			Event::Voted {
 				account: cluster_node_3_provider.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 4,
 				no: 0,
@@ -1901,6 +1996,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(not_cluster_manager.clone()),
 				cluster_id,
+				0,
 				ClusterMember::ClusterManager,
 			),
 			Error::<Test>::NotClusterManager
@@ -1910,6 +2006,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 				cluster_id,
+				0,
 				ClusterMember::NodeProvider(not_cluster_node_key.clone()),
 			),
 			Error::<Test>::NotValidatedNode
@@ -1919,6 +2016,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(not_node_provider.clone()),
 				cluster_id,
+				0,
 				ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 			),
 			Error::<Test>::NotNodeProvider
@@ -1928,6 +2026,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			ClusterMember::ClusterManager,
 		));
 
@@ -1938,8 +2037,8 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 		let submission_deposit_amount =
 			<Test as pallet_referenda::Config>::SubmissionDeposit::get().saturated_into::<u128>();
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
 		assert_eq!(
 			SubmissionDeposits::<Test>::get(referenda_index),
@@ -1955,9 +2054,13 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 			balance_after_submission_deposit
 		);
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 4, no: 0 }.into());
-		System::assert_has_event(Event::Approved { cluster_id }.into());
-		System::assert_has_event(Event::ReferendumSubmitted { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 4, no: 0 }.into(),
+		);
+		System::assert_has_event(Event::Approved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(
+			Event::ReferendumSubmitted { cluster_id, proposal_index: 0 }.into(),
+		);
 		System::assert_has_event(
 			Event::SubmissionDepositRetained {
 				referenda_index,
@@ -1966,7 +2069,7 @@ fn cluster_protocol_activation_proposal_early_approved_with_unanimous_consensus_
 			}
 			.into(),
 		);
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		// OpenGov
 
@@ -2098,6 +2201,7 @@ fn cluster_protocol_activation_proposal_approved_with_unanimous_consensus_and_na
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
@@ -2105,6 +2209,7 @@ fn cluster_protocol_activation_proposal_approved_with_unanimous_consensus_and_na
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
@@ -2112,6 +2217,7 @@ fn cluster_protocol_activation_proposal_approved_with_unanimous_consensus_and_na
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_2_key.clone()),
 		));
@@ -2119,6 +2225,7 @@ fn cluster_protocol_activation_proposal_approved_with_unanimous_consensus_and_na
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_3_key.clone()),
 		));
@@ -2131,6 +2238,7 @@ fn cluster_protocol_activation_proposal_approved_with_unanimous_consensus_and_na
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			ClusterMember::ClusterManager,
 		));
 
@@ -2141,8 +2249,8 @@ fn cluster_protocol_activation_proposal_approved_with_unanimous_consensus_and_na
 		let submission_deposit_amount =
 			<Test as pallet_referenda::Config>::SubmissionDeposit::get().saturated_into::<u128>();
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
 		assert_eq!(
 			SubmissionDeposits::<Test>::get(referenda_index),
@@ -2158,9 +2266,13 @@ fn cluster_protocol_activation_proposal_approved_with_unanimous_consensus_and_na
 			balance_after_submission_deposit
 		);
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 4, no: 0 }.into());
-		System::assert_has_event(Event::Approved { cluster_id }.into());
-		System::assert_has_event(Event::ReferendumSubmitted { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 4, no: 0 }.into(),
+		);
+		System::assert_has_event(Event::Approved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(
+			Event::ReferendumSubmitted { cluster_id, proposal_index: 0 }.into(),
+		);
 		System::assert_has_event(
 			Event::SubmissionDepositRetained {
 				referenda_index,
@@ -2169,7 +2281,7 @@ fn cluster_protocol_activation_proposal_approved_with_unanimous_consensus_and_na
 			}
 			.into(),
 		);
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		MockedDefaultVote::reset_and_release_lock(lock1);
 		MockedSeatsConsensus::reset_and_release_lock(lock2);
@@ -2246,6 +2358,7 @@ fn cluster_protocol_activation_proposal_early_disapproved_with_unanimous_consens
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
@@ -2253,6 +2366,7 @@ fn cluster_protocol_activation_proposal_early_disapproved_with_unanimous_consens
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
@@ -2260,6 +2374,7 @@ fn cluster_protocol_activation_proposal_early_disapproved_with_unanimous_consens
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_2_key.clone()),
 		));
@@ -2267,6 +2382,7 @@ fn cluster_protocol_activation_proposal_early_disapproved_with_unanimous_consens
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
 			cluster_id,
+			0,
 			false,
 			ClusterMember::NodeProvider(cluster_node_3_key),
 		));
@@ -2274,18 +2390,21 @@ fn cluster_protocol_activation_proposal_early_disapproved_with_unanimous_consens
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			ClusterMember::ClusterManager,
 		));
 
 		// As the quorum is not reached, the proposal gets rejected and no referenda is created
 		assert_eq!(pallet_referenda::ReferendumCount::<Test>::get(), 0);
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 3, no: 1 }.into());
-		System::assert_has_event(Event::Disapproved { cluster_id }.into());
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 3, no: 1 }.into(),
+		);
+		System::assert_has_event(Event::Disapproved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		MockedDefaultVote::reset_and_release_lock(lock1);
 		MockedSeatsConsensus::reset_and_release_lock(lock2);
@@ -2360,6 +2479,7 @@ fn cluster_protocol_activation_proposal_disapproved_with_unanimous_consensus_and
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
@@ -2367,6 +2487,7 @@ fn cluster_protocol_activation_proposal_disapproved_with_unanimous_consensus_and
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
@@ -2374,6 +2495,7 @@ fn cluster_protocol_activation_proposal_disapproved_with_unanimous_consensus_and
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_2_key.clone()),
 		));
@@ -2385,18 +2507,21 @@ fn cluster_protocol_activation_proposal_disapproved_with_unanimous_consensus_and
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			ClusterMember::ClusterManager,
 		));
 
 		// As the quorum is not reached, the proposal gets rejected and no referenda is created
 		assert_eq!(pallet_referenda::ReferendumCount::<Test>::get(), 0);
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 3, no: 1 }.into());
-		System::assert_has_event(Event::Disapproved { cluster_id }.into());
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 3, no: 1 }.into(),
+		);
+		System::assert_has_event(Event::Disapproved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		MockedDefaultVote::reset_and_release_lock(lock1);
 		MockedSeatsConsensus::reset_and_release_lock(lock2);
@@ -2470,6 +2595,7 @@ fn cluster_protocol_activation_proposal_cannot_be_closed_if_threshold_is_not_rea
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
@@ -2477,6 +2603,7 @@ fn cluster_protocol_activation_proposal_cannot_be_closed_if_threshold_is_not_rea
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
@@ -2484,6 +2611,7 @@ fn cluster_protocol_activation_proposal_cannot_be_closed_if_threshold_is_not_rea
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_2_key.clone()),
 		));
@@ -2492,6 +2620,7 @@ fn cluster_protocol_activation_proposal_cannot_be_closed_if_threshold_is_not_rea
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(cluster_manager.clone()),
 				cluster_id,
+				0,
 				ClusterMember::ClusterManager,
 			),
 			Error::<Test>::TooEarly
@@ -2572,8 +2701,13 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 		let seats = 4; // 3 validated nodes + 1 cluster manager
 		let threshold = <Test as pallet::Config>::SeatsConsensus::get_threshold(seats);
 		System::assert_last_event(
-			Event::Proposed { account: cluster_node_3_provider.clone(), cluster_id, threshold }
-				.into(),
+			Event::Proposed {
+				account: cluster_node_3_provider.clone(),
+				cluster_id,
+				proposal_index: 0,
+				threshold,
+			}
+			.into(),
 		);
 
 		let not_cluster_manager = AccountId::from([0; 32]);
@@ -2581,6 +2715,7 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 			DdcClustersGov::vote_proposal(
 				RuntimeOrigin::signed(not_cluster_manager.clone()),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::ClusterManager,
 			),
@@ -2590,16 +2725,18 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
 
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(votes.ayes, vec![cluster_manager.clone()]);
 		System::assert_last_event(
 			Event::Voted {
 				account: cluster_manager.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 1,
 				no: 0,
@@ -2613,6 +2750,7 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 			DdcClustersGov::vote_proposal(
 				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::NodeProvider(not_cluster_node_key.clone()),
 			),
@@ -2623,6 +2761,7 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 			DdcClustersGov::vote_proposal(
 				RuntimeOrigin::signed(not_node_provider.clone()),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 			),
@@ -2632,15 +2771,17 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(votes.ayes, vec![cluster_manager.clone(), cluster_node_1_provider.clone()]);
 		System::assert_last_event(
 			Event::Voted {
 				account: cluster_node_1_provider.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 2,
 				no: 0,
@@ -2651,10 +2792,11 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_2_key),
 		));
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(
 			votes.ayes,
 			vec![
@@ -2667,6 +2809,7 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 			Event::Voted {
 				account: cluster_node_2_provider.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 3,
 				no: 0,
@@ -2677,10 +2820,11 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_3_key.clone()),
 		));
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(
 			votes.ayes,
 			vec![
@@ -2694,6 +2838,7 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 			Event::Voted {
 				account: cluster_node_3_provider.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 4,
 				no: 0,
@@ -2705,6 +2850,7 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(not_cluster_manager.clone()),
 				cluster_id,
+				0,
 				ClusterMember::ClusterManager,
 			),
 			Error::<Test>::NotClusterManager
@@ -2714,6 +2860,7 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 				cluster_id,
+				0,
 				ClusterMember::NodeProvider(not_cluster_node_key.clone()),
 			),
 			Error::<Test>::NotValidatedNode
@@ -2723,6 +2870,7 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(not_node_provider.clone()),
 				cluster_id,
+				0,
 				ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 			),
 			Error::<Test>::NotNodeProvider
@@ -2732,6 +2880,7 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
 			cluster_id,
+			0,
 			ClusterMember::NodeProvider(cluster_node_3_key.clone()),
 		));
 
@@ -2742,8 +2891,8 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 		let submission_deposit_amount =
 			<Test as pallet_referenda::Config>::SubmissionDeposit::get().saturated_into::<u128>();
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
 		assert_eq!(
 			SubmissionDeposits::<Test>::get(referenda_index),
@@ -2760,9 +2909,13 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 			balance_after_submission_deposit
 		);
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 4, no: 0 }.into());
-		System::assert_has_event(Event::Approved { cluster_id }.into());
-		System::assert_has_event(Event::ReferendumSubmitted { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 4, no: 0 }.into(),
+		);
+		System::assert_has_event(Event::Approved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(
+			Event::ReferendumSubmitted { cluster_id, proposal_index: 0 }.into(),
+		);
 		System::assert_has_event(
 			Event::SubmissionDepositRetained {
 				referenda_index,
@@ -2771,7 +2924,7 @@ fn cluster_protocol_update_proposal_early_approved_with_supermajority_consensus_
 			}
 			.into(),
 		);
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		// OpenGov
 
@@ -2902,6 +3055,7 @@ fn cluster_protocol_update_proposal_approved_with_supermajority_consensus_and_pr
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
@@ -2909,6 +3063,7 @@ fn cluster_protocol_update_proposal_approved_with_supermajority_consensus_and_pr
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			false,
 			ClusterMember::NodeProvider(cluster_node_2_key.clone()),
 		));
@@ -2921,6 +3076,7 @@ fn cluster_protocol_update_proposal_approved_with_supermajority_consensus_and_pr
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			ClusterMember::ClusterManager,
 		));
 
@@ -2931,8 +3087,8 @@ fn cluster_protocol_update_proposal_approved_with_supermajority_consensus_and_pr
 		let submission_deposit_amount =
 			<Test as pallet_referenda::Config>::SubmissionDeposit::get().saturated_into::<u128>();
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
 		assert_eq!(
 			SubmissionDeposits::<Test>::get(referenda_index),
@@ -2948,9 +3104,13 @@ fn cluster_protocol_update_proposal_approved_with_supermajority_consensus_and_pr
 			balance_after_submission_deposit
 		);
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 3, no: 1 }.into());
-		System::assert_has_event(Event::Approved { cluster_id }.into());
-		System::assert_has_event(Event::ReferendumSubmitted { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 3, no: 1 }.into(),
+		);
+		System::assert_has_event(Event::Approved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(
+			Event::ReferendumSubmitted { cluster_id, proposal_index: 0 }.into(),
+		);
 		System::assert_has_event(
 			Event::SubmissionDepositRetained {
 				referenda_index,
@@ -2959,7 +3119,7 @@ fn cluster_protocol_update_proposal_approved_with_supermajority_consensus_and_pr
 			}
 			.into(),
 		);
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		MockedDefaultVote::reset_and_release_lock(lock1);
 		MockedSeatsConsensus::reset_and_release_lock(lock2);
@@ -3037,6 +3197,7 @@ fn cluster_protocol_update_proposal_early_disapproved_with_supermajority_consens
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
@@ -3044,6 +3205,7 @@ fn cluster_protocol_update_proposal_early_disapproved_with_supermajority_consens
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			false,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
@@ -3051,6 +3213,7 @@ fn cluster_protocol_update_proposal_early_disapproved_with_supermajority_consens
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			false,
 			ClusterMember::NodeProvider(cluster_node_2_key.clone()),
 		));
@@ -3058,6 +3221,7 @@ fn cluster_protocol_update_proposal_early_disapproved_with_supermajority_consens
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
 			cluster_id,
+			0,
 			false,
 			ClusterMember::NodeProvider(cluster_node_3_key),
 		));
@@ -3065,18 +3229,21 @@ fn cluster_protocol_update_proposal_early_disapproved_with_supermajority_consens
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			ClusterMember::ClusterManager,
 		));
 
 		// As the quorum is not reached, the proposal gets rejected and no referenda is created
 		assert_eq!(pallet_referenda::ReferendumCount::<Test>::get(), 0);
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 1, no: 3 }.into());
-		System::assert_has_event(Event::Disapproved { cluster_id }.into());
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 1, no: 3 }.into(),
+		);
+		System::assert_has_event(Event::Disapproved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		MockedDefaultVote::reset_and_release_lock(lock1);
 		MockedSeatsConsensus::reset_and_release_lock(lock2);
@@ -3148,6 +3315,7 @@ fn cluster_protocol_update_proposal_disapproved_with_supermajority_consensus_and
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			false,
 			ClusterMember::ClusterManager
 		));
@@ -3155,6 +3323,7 @@ fn cluster_protocol_update_proposal_disapproved_with_supermajority_consensus_and
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone())
 		));
@@ -3166,18 +3335,21 @@ fn cluster_protocol_update_proposal_disapproved_with_supermajority_consensus_and
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone())
 		));
 
 		// As the quorum is not reached, the proposal gets rejected and no referenda is created
 		assert_eq!(pallet_referenda::ReferendumCount::<Test>::get(), 0);
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 1, no: 3 }.into());
-		System::assert_has_event(Event::Disapproved { cluster_id }.into());
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 1, no: 3 }.into(),
+		);
+		System::assert_has_event(Event::Disapproved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		MockedDefaultVote::reset_and_release_lock(lock1);
 		MockedSeatsConsensus::reset_and_release_lock(lock2);
@@ -3248,6 +3420,7 @@ fn cluster_protocol_update_proposal_cannot_be_closed_if_threshold_is_not_reached
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone())
 		));
@@ -3256,6 +3429,7 @@ fn cluster_protocol_update_proposal_cannot_be_closed_if_threshold_is_not_reached
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 				cluster_id,
+				0,
 				ClusterMember::NodeProvider(cluster_node_1_key)
 			),
 			Error::<Test>::TooEarly
@@ -3335,8 +3509,13 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 		let seats = 4; // 3 validated nodes + 1 cluster manager
 		let threshold = <Test as pallet::Config>::SeatsConsensus::get_threshold(seats);
 		System::assert_last_event(
-			Event::Proposed { account: cluster_node_3_provider.clone(), cluster_id, threshold }
-				.into(),
+			Event::Proposed {
+				account: cluster_node_3_provider.clone(),
+				cluster_id,
+				proposal_index: 0,
+				threshold,
+			}
+			.into(),
 		);
 
 		let not_cluster_manager = AccountId::from([0; 32]);
@@ -3344,6 +3523,7 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 			DdcClustersGov::vote_proposal(
 				RuntimeOrigin::signed(not_cluster_manager.clone()),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::ClusterManager,
 			),
@@ -3353,16 +3533,18 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
 
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(votes.ayes, vec![cluster_manager.clone()]);
 		System::assert_last_event(
 			Event::Voted {
 				account: cluster_manager.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 1,
 				no: 0,
@@ -3376,6 +3558,7 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 			DdcClustersGov::vote_proposal(
 				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::NodeProvider(not_cluster_node_key.clone()),
 			),
@@ -3386,6 +3569,7 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 			DdcClustersGov::vote_proposal(
 				RuntimeOrigin::signed(not_node_provider.clone()),
 				cluster_id,
+				0,
 				true,
 				ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 			),
@@ -3395,15 +3579,17 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(votes.ayes, vec![cluster_manager.clone(), cluster_node_1_provider.clone()]);
 		System::assert_last_event(
 			Event::Voted {
 				account: cluster_node_1_provider.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 2,
 				no: 0,
@@ -3414,10 +3600,11 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_2_key),
 		));
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(
 			votes.ayes,
 			vec![
@@ -3430,6 +3617,7 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 			Event::Voted {
 				account: cluster_node_2_provider.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 3,
 				no: 0,
@@ -3440,10 +3628,11 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_3_key.clone()),
 		));
-		let votes = ClusterProposalVoting::<Test>::get(cluster_id).unwrap();
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap();
 		assert_eq!(
 			votes.ayes,
 			vec![
@@ -3457,6 +3646,7 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 			Event::Voted {
 				account: cluster_node_3_provider.clone(),
 				cluster_id,
+				proposal_index: 0,
 				voted: true,
 				yes: 4,
 				no: 0,
@@ -3468,6 +3658,7 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(not_cluster_manager.clone()),
 				cluster_id,
+				0,
 				ClusterMember::ClusterManager,
 			),
 			Error::<Test>::NotClusterManager
@@ -3477,6 +3668,7 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 				cluster_id,
+				0,
 				ClusterMember::NodeProvider(not_cluster_node_key.clone()),
 			),
 			Error::<Test>::NotValidatedNode
@@ -3486,6 +3678,7 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(not_node_provider.clone()),
 				cluster_id,
+				0,
 				ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 			),
 			Error::<Test>::NotNodeProvider
@@ -3495,6 +3688,7 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
 			cluster_id,
+			0,
 			ClusterMember::NodeProvider(cluster_node_3_key.clone()),
 		));
 
@@ -3505,8 +3699,8 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 		let submission_deposit_amount =
 			<Test as pallet_referenda::Config>::SubmissionDeposit::get().saturated_into::<u128>();
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
 		assert_eq!(
 			SubmissionDeposits::<Test>::get(referenda_index),
@@ -3523,9 +3717,13 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 			balance_after_submission_deposit
 		);
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 4, no: 0 }.into());
-		System::assert_has_event(Event::Approved { cluster_id }.into());
-		System::assert_has_event(Event::ReferendumSubmitted { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 4, no: 0 }.into(),
+		);
+		System::assert_has_event(Event::Approved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(
+			Event::ReferendumSubmitted { cluster_id, proposal_index: 0 }.into(),
+		);
 		System::assert_has_event(
 			Event::SubmissionDepositRetained {
 				referenda_index,
@@ -3534,7 +3732,7 @@ fn cluster_protocol_update_proposal_early_approved_with_unanimous_consensus_and_
 			}
 			.into(),
 		);
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		// OpenGov
 
@@ -3668,6 +3866,7 @@ fn cluster_protocol_update_proposal_approved_with_unanimous_consensus_and_nay_de
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
@@ -3675,6 +3874,7 @@ fn cluster_protocol_update_proposal_approved_with_unanimous_consensus_and_nay_de
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
@@ -3682,6 +3882,7 @@ fn cluster_protocol_update_proposal_approved_with_unanimous_consensus_and_nay_de
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_2_key.clone()),
 		));
@@ -3689,6 +3890,7 @@ fn cluster_protocol_update_proposal_approved_with_unanimous_consensus_and_nay_de
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_3_key.clone()),
 		));
@@ -3701,6 +3903,7 @@ fn cluster_protocol_update_proposal_approved_with_unanimous_consensus_and_nay_de
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			ClusterMember::ClusterManager,
 		));
 
@@ -3711,8 +3914,8 @@ fn cluster_protocol_update_proposal_approved_with_unanimous_consensus_and_nay_de
 		let submission_deposit_amount =
 			<Test as pallet_referenda::Config>::SubmissionDeposit::get().saturated_into::<u128>();
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
 		assert_eq!(
 			SubmissionDeposits::<Test>::get(referenda_index),
@@ -3728,9 +3931,13 @@ fn cluster_protocol_update_proposal_approved_with_unanimous_consensus_and_nay_de
 			balance_after_submission_deposit
 		);
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 4, no: 0 }.into());
-		System::assert_has_event(Event::Approved { cluster_id }.into());
-		System::assert_has_event(Event::ReferendumSubmitted { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 4, no: 0 }.into(),
+		);
+		System::assert_has_event(Event::Approved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(
+			Event::ReferendumSubmitted { cluster_id, proposal_index: 0 }.into(),
+		);
 		System::assert_has_event(
 			Event::SubmissionDepositRetained {
 				referenda_index,
@@ -3739,7 +3946,7 @@ fn cluster_protocol_update_proposal_approved_with_unanimous_consensus_and_nay_de
 			}
 			.into(),
 		);
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		MockedDefaultVote::reset_and_release_lock(lock1);
 		MockedSeatsConsensus::reset_and_release_lock(lock2);
@@ -3817,6 +4024,7 @@ fn cluster_protocol_update_proposal_early_disapproved_with_unanimous_consensus_a
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
@@ -3824,6 +4032,7 @@ fn cluster_protocol_update_proposal_early_disapproved_with_unanimous_consensus_a
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
@@ -3831,6 +4040,7 @@ fn cluster_protocol_update_proposal_early_disapproved_with_unanimous_consensus_a
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_2_key.clone()),
 		));
@@ -3838,6 +4048,7 @@ fn cluster_protocol_update_proposal_early_disapproved_with_unanimous_consensus_a
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
 			cluster_id,
+			0,
 			false,
 			ClusterMember::NodeProvider(cluster_node_3_key),
 		));
@@ -3845,18 +4056,21 @@ fn cluster_protocol_update_proposal_early_disapproved_with_unanimous_consensus_a
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			ClusterMember::ClusterManager,
 		));
 
 		// As the quorum is not reached, the proposal gets rejected and no referenda is created
 		assert_eq!(pallet_referenda::ReferendumCount::<Test>::get(), 0);
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 3, no: 1 }.into());
-		System::assert_has_event(Event::Disapproved { cluster_id }.into());
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 3, no: 1 }.into(),
+		);
+		System::assert_has_event(Event::Disapproved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		MockedDefaultVote::reset_and_release_lock(lock1);
 		MockedSeatsConsensus::reset_and_release_lock(lock2);
@@ -3931,6 +4145,7 @@ fn cluster_protocol_update_proposal_disapproved_with_unanimous_consensus_and_nay
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::ClusterManager,
 		));
@@ -3938,6 +4153,7 @@ fn cluster_protocol_update_proposal_disapproved_with_unanimous_consensus_and_nay
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone()),
 		));
@@ -3945,6 +4161,7 @@ fn cluster_protocol_update_proposal_disapproved_with_unanimous_consensus_and_nay
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_2_key.clone()),
 		));
@@ -3956,18 +4173,21 @@ fn cluster_protocol_update_proposal_disapproved_with_unanimous_consensus_and_nay
 		assert_ok!(DdcClustersGov::close_proposal(
 			RuntimeOrigin::signed(cluster_manager.clone()),
 			cluster_id,
+			0,
 			ClusterMember::ClusterManager,
 		));
 
 		// As the quorum is not reached, the proposal gets rejected and no referenda is created
 		assert_eq!(pallet_referenda::ReferendumCount::<Test>::get(), 0);
 
-		assert!(!ClusterProposal::<Test>::contains_key(cluster_id));
-		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id));
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(!ClusterProposalVoting::<Test>::contains_key(cluster_id, 0));
 
-		System::assert_has_event(Event::Closed { cluster_id, yes: 3, no: 1 }.into());
-		System::assert_has_event(Event::Disapproved { cluster_id }.into());
-		System::assert_has_event(Event::Removed { cluster_id }.into());
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 3, no: 1 }.into(),
+		);
+		System::assert_has_event(Event::Disapproved { cluster_id, proposal_index: 0 }.into());
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 0 }.into());
 
 		MockedDefaultVote::reset_and_release_lock(lock1);
 		MockedSeatsConsensus::reset_and_release_lock(lock2);
@@ -4044,6 +4264,7 @@ fn cluster_protocol_update_proposal_cannot_be_closed_if_threshold_is_not_reached
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_1_key.clone())
 		));
@@ -4051,6 +4272,7 @@ fn cluster_protocol_update_proposal_cannot_be_closed_if_threshold_is_not_reached
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_2_key.clone()),
 		));
@@ -4058,6 +4280,7 @@ fn cluster_protocol_update_proposal_cannot_be_closed_if_threshold_is_not_reached
 		assert_ok!(DdcClustersGov::vote_proposal(
 			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
 			cluster_id,
+			0,
 			true,
 			ClusterMember::NodeProvider(cluster_node_3_key),
 		));
@@ -4066,6 +4289,7 @@ fn cluster_protocol_update_proposal_cannot_be_closed_if_threshold_is_not_reached
 			DdcClustersGov::close_proposal(
 				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
 				cluster_id,
+				0,
 				ClusterMember::NodeProvider(cluster_node_1_key)
 			),
 			Error::<Test>::TooEarly
@@ -4075,3 +4299,996 @@ fn cluster_protocol_update_proposal_cannot_be_closed_if_threshold_is_not_reached
 		MockedSeatsConsensus::reset_and_release_lock(lock2);
 	})
 }
+
+#[test]
+fn cluster_manager_transfer_proposal_initiated() {
+	let cluster = build_cluster(
+		CLUSTER_ID,
+		CLUSTER_MANAGER_ID,
+		CLUSTER_RESERVE_ID,
+		ClusterParams::default(),
+		ClusterProtocolParams::default(),
+		ClusterStatus::Activated,
+	);
+
+	let node_1 = build_cluster_node(
+		NODE_PUB_KEY_1,
+		NODE_PROVIDER_ID_1,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	let node_2 = build_cluster_node(
+		NODE_PUB_KEY_2,
+		NODE_PROVIDER_ID_2,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	let node_3 = build_cluster_node(
+		NODE_PUB_KEY_3,
+		NODE_PROVIDER_ID_3,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	ExtBuilder.build_and_execute(cluster, vec![node_1, node_2, node_3], || {
+		fast_forward_to(1);
+
+		let cluster_id = ClusterId::from(CLUSTER_ID);
+		let cluster_manager = AccountId::from(CLUSTER_MANAGER_ID);
+		let new_manager = AccountId::from([128; 32]);
+
+		// The manager of an activated cluster can't be changed without the cluster governance
+		assert_noop!(
+			DdcClusters::propose_manager_transfer(
+				RuntimeOrigin::signed(cluster_manager.clone()),
+				cluster_id,
+				new_manager.clone()
+			),
+			pallet_ddc_clusters::Error::<Test>::ManagerTransferRequiresGov
+		);
+
+		assert_ok!(DdcClustersGov::propose_transfer_cluster_manager(
+			RuntimeOrigin::signed(cluster_manager.clone()),
+			cluster_id,
+			new_manager.clone(),
+			ClusterMember::ClusterManager
+		));
+
+		let proposal = ClusterProposal::<Test>::get(cluster_id, 0);
+		assert_eq!(
+			proposal,
+			Some(Proposal {
+				author: cluster_manager.clone(),
+				kind: ProposalKind::TransferClusterManager,
+				call: <Test as pallet::Config>::ClusterProposalCall::from(
+					Call::<Test>::transfer_cluster_manager {
+						cluster_id,
+						new_manager_id: new_manager.clone(),
+					}
+				)
+			})
+		);
+
+		let votes = ClusterProposalVoting::<Test>::get(cluster_id, 0);
+		let start = BlockNumber::from(1_u64);
+		let end = start + <Test as pallet::Config>::ClusterProposalDuration::get();
+		let seats = 4; // 3 validated nodes + 1 cluster manager
+		let threshold = <Test as pallet::Config>::SeatsConsensus::get_threshold(seats);
+		let electorate = DdcClustersGov::get_electorate(&cluster_id, start).unwrap();
+		assert_eq!(electorate.len() as MemberCount, seats);
+		assert_eq!(
+			votes,
+			Some(Votes { seats, threshold, ayes: vec![], nays: vec![], start, end, electorate })
+		);
+		System::assert_last_event(
+			Event::Proposed {
+				account: cluster_manager.clone(),
+				cluster_id,
+				proposal_index: 0,
+				threshold,
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			DdcClustersGov::propose_transfer_cluster_manager(
+				RuntimeOrigin::signed(cluster_manager),
+				cluster_id,
+				new_manager,
+				ClusterMember::ClusterManager
+			),
+			Error::<Test>::ActiveProposal
+		);
+	})
+}
+
+#[test]
+fn cluster_manager_transfer_is_allowed_for_referenda_cluster_protocol_updater_track_origin() {
+	let cluster = build_cluster(
+		CLUSTER_ID,
+		CLUSTER_MANAGER_ID,
+		CLUSTER_RESERVE_ID,
+		ClusterParams::default(),
+		ClusterProtocolParams::default(),
+		ClusterStatus::Activated,
+	);
+
+	ExtBuilder.build_and_execute(cluster, vec![], || {
+		fast_forward_to(1);
+
+		let cluster_id = ClusterId::from(CLUSTER_ID);
+		let new_manager = AccountId::from([128; 32]);
+
+		assert_noop!(
+			DdcClustersGov::transfer_cluster_manager(
+				RuntimeOrigin::signed(AccountId::from(CLUSTER_MANAGER_ID)),
+				cluster_id,
+				new_manager.clone()
+			),
+			DispatchError::BadOrigin
+		);
+
+		let open_gov_updater = <Test as pallet::Config>::OpenGovUpdaterTrackOrigin::get();
+		assert_ok!(DdcClustersGov::transfer_cluster_manager(
+			open_gov_updater,
+			cluster_id,
+			new_manager.clone()
+		));
+		assert_eq!(
+			pallet_ddc_clusters::PendingManagerTransfers::<Test>::get(cluster_id),
+			Some(new_manager.clone())
+		);
+
+		// The new manager takes over the cluster once it accepts the transfer
+		assert_ok!(DdcClusters::accept_manager_transfer(
+			RuntimeOrigin::signed(new_manager.clone()),
+			cluster_id
+		));
+		assert_eq!(
+			<DdcClusters as ClusterManager<Test>>::get_manager_account_id(&cluster_id),
+			Ok(new_manager)
+		);
+	})
+}
+
+#[test]
+fn cluster_manager_transfer_proposed_by_gov_is_cancelled_by_gov() {
+	let cluster = build_cluster(
+		CLUSTER_ID,
+		CLUSTER_MANAGER_ID,
+		CLUSTER_RESERVE_ID,
+		ClusterParams::default(),
+		ClusterProtocolParams::default(),
+		ClusterStatus::Activated,
+	);
+
+	ExtBuilder.build_and_execute(cluster, vec![], || {
+		fast_forward_to(1);
+
+		let cluster_id = ClusterId::from(CLUSTER_ID);
+		let cluster_manager = AccountId::from(CLUSTER_MANAGER_ID);
+		let new_manager = AccountId::from([128; 32]);
+
+		let open_gov_updater = <Test as pallet::Config>::OpenGovUpdaterTrackOrigin::get();
+		assert_ok!(DdcClustersGov::transfer_cluster_manager(
+			open_gov_updater.clone(),
+			cluster_id,
+			new_manager.clone()
+		));
+
+		// The manager can't cancel the transfer approved by the referendum
+		assert_noop!(
+			DdcClusters::cancel_manager_transfer(
+				RuntimeOrigin::signed(cluster_manager.clone()),
+				cluster_id
+			),
+			pallet_ddc_clusters::Error::<Test>::ManagerTransferRequiresGov
+		);
+		assert_noop!(
+			DdcClustersGov::cancel_cluster_manager_transfer(
+				RuntimeOrigin::signed(cluster_manager),
+				cluster_id
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(DdcClustersGov::cancel_cluster_manager_transfer(open_gov_updater, cluster_id));
+		assert_eq!(pallet_ddc_clusters::PendingManagerTransfers::<Test>::get(cluster_id), None);
+		assert_noop!(
+			DdcClusters::accept_manager_transfer(RuntimeOrigin::signed(new_manager), cluster_id),
+			pallet_ddc_clusters::Error::<Test>::NoPendingManagerTransfer
+		);
+	})
+}
+
+#[test]
+fn concurrent_cluster_proposals_are_voted_and_closed_independently() {
+	let cluster = build_cluster(
+		CLUSTER_ID,
+		CLUSTER_MANAGER_ID,
+		CLUSTER_RESERVE_ID,
+		ClusterParams::default(),
+		ClusterProtocolParams::default(),
+		ClusterStatus::Activated,
+	);
+
+	let node_1 = build_cluster_node(
+		NODE_PUB_KEY_1,
+		NODE_PROVIDER_ID_1,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	let node_2 = build_cluster_node(
+		NODE_PUB_KEY_2,
+		NODE_PROVIDER_ID_2,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	let node_3 = build_cluster_node(
+		NODE_PUB_KEY_3,
+		NODE_PROVIDER_ID_3,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	ExtBuilder.build_and_execute(cluster, vec![node_1, node_2, node_3], || {
+		fast_forward_to(1);
+
+		let cluster_id = ClusterId::from(CLUSTER_ID);
+		let cluster_manager = AccountId::from(CLUSTER_MANAGER_ID);
+		let cluster_node_1_provider = AccountId::from(NODE_PROVIDER_ID_1);
+		let cluster_node_1_key = NodePubKey::StoragePubKey(AccountId::from(NODE_PUB_KEY_1));
+		let cluster_node_2_provider = AccountId::from(NODE_PROVIDER_ID_2);
+		let cluster_node_2_key = NodePubKey::StoragePubKey(AccountId::from(NODE_PUB_KEY_2));
+		let cluster_node_3_key = NodePubKey::StoragePubKey(AccountId::from(NODE_PUB_KEY_3));
+		let cluster_params = ClusterParams {
+			node_provider_auth_contract: None,
+			erasure_coding_required: 4,
+			erasure_coding_total: 6,
+			replication_total: 3,
+		};
+
+		assert_ok!(DdcClustersGov::propose_update_cluster_params(
+			RuntimeOrigin::signed(cluster_manager.clone()),
+			cluster_id,
+			cluster_params.clone(),
+			ClusterMember::ClusterManager
+		));
+		assert_ok!(DdcClustersGov::propose_spend_cluster_reserve(
+			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
+			cluster_id,
+			cluster_node_1_provider.clone(),
+			100,
+			ClusterMember::NodeProvider(cluster_node_1_key.clone())
+		));
+		assert_ok!(DdcClustersGov::propose_remove_cluster_node(
+			RuntimeOrigin::signed(cluster_manager.clone()),
+			cluster_id,
+			cluster_node_3_key.clone(),
+			ClusterMember::ClusterManager
+		));
+		assert_eq!(ClusterProposalCount::<Test>::get(cluster_id), 3);
+		assert_eq!(
+			ClusterProposal::<Test>::get(cluster_id, 2).map(|proposal| proposal.kind),
+			Some(ProposalKind::RemoveClusterNode)
+		);
+
+		// An identical proposal can not be submitted while the previous one is ongoing
+		assert_noop!(
+			DdcClustersGov::propose_update_cluster_params(
+				RuntimeOrigin::signed(cluster_manager.clone()),
+				cluster_id,
+				cluster_params,
+				ClusterMember::ClusterManager
+			),
+			Error::<Test>::ActiveProposal
+		);
+
+		let new_manager = AccountId::from([128; 32]);
+		assert_noop!(
+			DdcClustersGov::propose_transfer_cluster_manager(
+				RuntimeOrigin::signed(cluster_manager.clone()),
+				cluster_id,
+				new_manager.clone(),
+				ClusterMember::ClusterManager
+			),
+			Error::<Test>::TooManyProposals
+		);
+
+		// The reserve spending is approved without affecting other proposals
+		for (voter, member) in [
+			(cluster_manager.clone(), ClusterMember::ClusterManager),
+			(
+				cluster_node_1_provider.clone(),
+				ClusterMember::NodeProvider(cluster_node_1_key.clone()),
+			),
+			(
+				cluster_node_2_provider.clone(),
+				ClusterMember::NodeProvider(cluster_node_2_key.clone()),
+			),
+		] {
+			assert_ok!(DdcClustersGov::vote_proposal(
+				RuntimeOrigin::signed(voter),
+				cluster_id,
+				1,
+				true,
+				member
+			));
+		}
+		assert_eq!(ClusterProposalVoting::<Test>::get(cluster_id, 0).unwrap().ayes, vec![]);
+
+		assert_ok!(DdcClustersGov::close_proposal(
+			RuntimeOrigin::signed(cluster_manager.clone()),
+			cluster_id,
+			1,
+			ClusterMember::ClusterManager
+		));
+		System::assert_has_event(
+			Event::ReferendumSubmitted { cluster_id, proposal_index: 1 }.into(),
+		);
+		System::assert_last_event(Event::Removed { cluster_id, proposal_index: 1 }.into());
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 1));
+		assert!(ClusterProposal::<Test>::contains_key(cluster_id, 0));
+		assert!(ClusterProposal::<Test>::contains_key(cluster_id, 2));
+
+		// The node removal is disapproved
+		for (voter, member) in [
+			(
+				cluster_node_1_provider.clone(),
+				ClusterMember::NodeProvider(cluster_node_1_key.clone()),
+			),
+			(cluster_node_2_provider, ClusterMember::NodeProvider(cluster_node_2_key)),
+		] {
+			assert_ok!(DdcClustersGov::vote_proposal(
+				RuntimeOrigin::signed(voter),
+				cluster_id,
+				2,
+				false,
+				member
+			));
+		}
+
+		assert_ok!(DdcClustersGov::close_proposal(
+			RuntimeOrigin::signed(cluster_node_1_provider),
+			cluster_id,
+			2,
+			ClusterMember::NodeProvider(cluster_node_1_key)
+		));
+		System::assert_has_event(Event::Disapproved { cluster_id, proposal_index: 2 }.into());
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 2));
+		assert!(ClusterProposal::<Test>::contains_key(cluster_id, 0));
+
+		// New proposals get new indices once there is room for them
+		assert_ok!(DdcClustersGov::propose_transfer_cluster_manager(
+			RuntimeOrigin::signed(cluster_manager.clone()),
+			cluster_id,
+			new_manager,
+			ClusterMember::ClusterManager
+		));
+		let threshold = ClusterProposalVoting::<Test>::get(cluster_id, 3).unwrap().threshold;
+		System::assert_last_event(
+			Event::Proposed { account: cluster_manager, cluster_id, proposal_index: 3, threshold }
+				.into(),
+		);
+		assert_eq!(ClusterProposalCount::<Test>::get(cluster_id), 4);
+	})
+}
+
+#[test]
+fn cluster_actions_are_allowed_for_referenda_cluster_protocol_updater_track_origin() {
+	let cluster = build_cluster(
+		CLUSTER_ID,
+		CLUSTER_MANAGER_ID,
+		CLUSTER_RESERVE_ID,
+		ClusterParams::default(),
+		ClusterProtocolParams::default(),
+		ClusterStatus::Activated,
+	);
+
+	let node_1 = build_cluster_node(
+		NODE_PUB_KEY_1,
+		NODE_PROVIDER_ID_1,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	ExtBuilder.build_and_execute(cluster, vec![node_1], || {
+		fast_forward_to(1);
+
+		let cluster_id = ClusterId::from(CLUSTER_ID);
+		let cluster_manager = AccountId::from(CLUSTER_MANAGER_ID);
+		let cluster_reserve = DdcClustersGov::cluster_reserve_account_id(&cluster_id);
+		let cluster_stash = AccountId::from(CLUSTER_RESERVE_ID);
+		let cluster_node_1_key = NodePubKey::StoragePubKey(AccountId::from(NODE_PUB_KEY_1));
+		let beneficiary = AccountId::from([128; 32]);
+		let open_gov_updater = <Test as pallet::Config>::OpenGovUpdaterTrackOrigin::get();
+		let _ = Balances::deposit_creating(&cluster_reserve, 1000);
+
+		// Reserve spending
+		assert_noop!(
+			DdcClustersGov::spend_cluster_reserve(
+				RuntimeOrigin::signed(cluster_manager.clone()),
+				cluster_id,
+				beneficiary.clone(),
+				100
+			),
+			DispatchError::BadOrigin
+		);
+
+		let stash_balance = Balances::free_balance(cluster_stash.clone());
+		assert_ok!(DdcClustersGov::spend_cluster_reserve(
+			open_gov_updater.clone(),
+			cluster_id,
+			beneficiary.clone(),
+			100
+		));
+		assert_eq!(Balances::free_balance(cluster_reserve), 900);
+		// The cluster stash is not spent from without its signature
+		assert_eq!(Balances::free_balance(cluster_stash), stash_balance);
+		assert_eq!(Balances::free_balance(beneficiary.clone()), 100);
+		System::assert_last_event(
+			Event::ClusterReserveSpent { cluster_id, beneficiary, amount: 100 }.into(),
+		);
+
+		// Cluster params update
+		let cluster_params = ClusterParams {
+			node_provider_auth_contract: Some(cluster_manager.clone()),
+			erasure_coding_required: 4,
+			erasure_coding_total: 6,
+			replication_total: 3,
+		};
+		assert_noop!(
+			DdcClustersGov::update_cluster_params(
+				RuntimeOrigin::signed(cluster_manager.clone()),
+				cluster_id,
+				cluster_params.clone()
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(DdcClustersGov::update_cluster_params(
+			open_gov_updater.clone(),
+			cluster_id,
+			cluster_params
+		));
+		let cluster = pallet_ddc_clusters::Clusters::<Test>::get(cluster_id).unwrap();
+		assert_eq!(cluster.props.node_provider_auth_contract, Some(cluster_manager.clone()));
+		assert_eq!(cluster.props.erasure_coding_total, 6);
+
+		// Node removal
+		assert_noop!(
+			DdcClustersGov::remove_cluster_node(
+				RuntimeOrigin::signed(cluster_manager),
+				cluster_id,
+				cluster_node_1_key.clone()
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(DdcClustersGov::remove_cluster_node(
+			open_gov_updater,
+			cluster_id,
+			cluster_node_1_key.clone()
+		));
+		assert!(!<DdcClusters as ClusterManager<Test>>::contains_node(
+			&cluster_id,
+			&cluster_node_1_key,
+			None
+		));
+	})
+}
+
+#[test]
+fn stake_weighted_cluster_proposal_is_closed_by_bonded_amounts() {
+	let cluster = build_cluster(
+		CLUSTER_ID,
+		CLUSTER_MANAGER_ID,
+		CLUSTER_RESERVE_ID,
+		ClusterParams::default(),
+		ClusterProtocolParams::default(),
+		ClusterStatus::Activated,
+	);
+
+	let node_1 = build_cluster_node(
+		NODE_PUB_KEY_1,
+		NODE_PROVIDER_ID_1,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	let node_2 = build_cluster_node(
+		NODE_PUB_KEY_2,
+		NODE_PROVIDER_ID_2,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	let node_3 = build_cluster_node(
+		NODE_PUB_KEY_3,
+		NODE_PROVIDER_ID_3,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	ExtBuilder.build_and_execute(cluster, vec![node_1, node_2, node_3], || {
+		fast_forward_to(1);
+		StakeWeightedVoting::set(true);
+
+		let cluster_id = ClusterId::from(CLUSTER_ID);
+		let cluster_manager = AccountId::from(CLUSTER_MANAGER_ID);
+		let cluster_node_1_provider = AccountId::from(NODE_PROVIDER_ID_1);
+		let cluster_node_1_key = NodePubKey::StoragePubKey(AccountId::from(NODE_PUB_KEY_1));
+		let cluster_node_2_provider = AccountId::from(NODE_PROVIDER_ID_2);
+		let cluster_node_2_key = NodePubKey::StoragePubKey(AccountId::from(NODE_PUB_KEY_2));
+		let cluster_node_3_provider = AccountId::from(NODE_PROVIDER_ID_3);
+		let cluster_node_3_key = NodePubKey::StoragePubKey(AccountId::from(NODE_PUB_KEY_3));
+
+		for (provider, node_pub_key, value) in [
+			(cluster_node_1_provider.clone(), cluster_node_1_key.clone(), 100),
+			(cluster_node_2_provider.clone(), cluster_node_2_key.clone(), 100),
+			(cluster_node_3_provider.clone(), cluster_node_3_key.clone(), 600),
+		] {
+			assert_ok!(DdcStaking::bond(
+				RuntimeOrigin::signed(provider.clone()),
+				provider,
+				node_pub_key,
+				value
+			));
+		}
+
+		assert_ok!(DdcClustersGov::propose_spend_cluster_reserve(
+			RuntimeOrigin::signed(cluster_manager.clone()),
+			cluster_id,
+			cluster_manager.clone(),
+			100,
+			ClusterMember::ClusterManager
+		));
+
+		// The majority of the members approve the proposal
+		for (voter, member) in [
+			(cluster_manager.clone(), ClusterMember::ClusterManager),
+			(
+				cluster_node_1_provider.clone(),
+				ClusterMember::NodeProvider(cluster_node_1_key.clone()),
+			),
+			(cluster_node_2_provider, ClusterMember::NodeProvider(cluster_node_2_key)),
+		] {
+			assert_ok!(DdcClustersGov::vote_proposal(
+				RuntimeOrigin::signed(voter),
+				cluster_id,
+				0,
+				true,
+				member
+			));
+		}
+
+		// The approving members hold less than the threshold of the bonded amount
+		assert_noop!(
+			DdcClustersGov::close_proposal(
+				RuntimeOrigin::signed(cluster_manager.clone()),
+				cluster_id,
+				0,
+				ClusterMember::ClusterManager
+			),
+			Error::<Test>::TooEarly
+		);
+
+		// The node with the largest stake rejects the proposal
+		assert_ok!(DdcClustersGov::vote_proposal(
+			RuntimeOrigin::signed(cluster_node_3_provider.clone()),
+			cluster_id,
+			0,
+			false,
+			ClusterMember::NodeProvider(cluster_node_3_key)
+		));
+
+		// The voting power is snapshotted when the proposal is initiated, so the stake unbonded
+		// during the voting still counts
+		assert_ok!(DdcStaking::unbond(RuntimeOrigin::signed(cluster_node_3_provider), 500));
+
+		assert_ok!(DdcClustersGov::close_proposal(
+			RuntimeOrigin::signed(cluster_node_1_provider),
+			cluster_id,
+			0,
+			ClusterMember::NodeProvider(cluster_node_1_key)
+		));
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 200, no: 600 }.into(),
+		);
+		System::assert_has_event(Event::Disapproved { cluster_id, proposal_index: 0 }.into());
+		assert!(!ClusterProposal::<Test>::contains_key(cluster_id, 0));
+
+		StakeWeightedVoting::set(false);
+	})
+}
+
+#[test]
+fn cluster_node_vote_is_cast_by_delegate() {
+	let cluster = build_cluster(
+		CLUSTER_ID,
+		CLUSTER_MANAGER_ID,
+		CLUSTER_RESERVE_ID,
+		ClusterParams::default(),
+		ClusterProtocolParams::default(),
+		ClusterStatus::Activated,
+	);
+
+	let node_1 = build_cluster_node(
+		NODE_PUB_KEY_1,
+		NODE_PROVIDER_ID_1,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	let node_2 = build_cluster_node(
+		NODE_PUB_KEY_2,
+		NODE_PROVIDER_ID_2,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	let node_3 = build_cluster_node(
+		NODE_PUB_KEY_3,
+		NODE_PROVIDER_ID_3,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	ExtBuilder.build_and_execute(cluster, vec![node_1, node_2, node_3], || {
+		fast_forward_to(1);
+
+		let cluster_id = ClusterId::from(CLUSTER_ID);
+		let cluster_manager = AccountId::from(CLUSTER_MANAGER_ID);
+		let cluster_node_1_provider = AccountId::from(NODE_PROVIDER_ID_1);
+		let cluster_node_1_key = NodePubKey::StoragePubKey(AccountId::from(NODE_PUB_KEY_1));
+		let cluster_node_2_provider = AccountId::from(NODE_PROVIDER_ID_2);
+		let cluster_node_2_key = NodePubKey::StoragePubKey(AccountId::from(NODE_PUB_KEY_2));
+		let not_cluster_node_key = NodePubKey::StoragePubKey(AccountId::from([128; 32]));
+
+		assert_noop!(
+			DdcClustersGov::delegate_vote(
+				RuntimeOrigin::signed(cluster_node_2_provider.clone()),
+				cluster_id,
+				cluster_node_1_key.clone(),
+				ClusterMember::ClusterManager
+			),
+			Error::<Test>::NotNodeProvider
+		);
+		assert_noop!(
+			DdcClustersGov::delegate_vote(
+				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
+				cluster_id,
+				cluster_node_1_key.clone(),
+				ClusterMember::NodeProvider(cluster_node_1_key.clone())
+			),
+			Error::<Test>::InvalidDelegate
+		);
+		assert_noop!(
+			DdcClustersGov::delegate_vote(
+				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
+				cluster_id,
+				cluster_node_1_key.clone(),
+				ClusterMember::NodeProvider(not_cluster_node_key)
+			),
+			Error::<Test>::InvalidDelegate
+		);
+		assert_noop!(
+			DdcClustersGov::remove_vote_delegation(
+				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
+				cluster_id,
+				cluster_node_1_key.clone()
+			),
+			Error::<Test>::NoVoteDelegation
+		);
+
+		assert_ok!(DdcClustersGov::delegate_vote(
+			RuntimeOrigin::signed(cluster_node_1_provider.clone()),
+			cluster_id,
+			cluster_node_1_key.clone(),
+			ClusterMember::ClusterManager
+		));
+		assert_eq!(
+			VoteDelegations::<Test>::get(cluster_id, cluster_node_1_key.clone()),
+			Some(ClusterMember::ClusterManager)
+		);
+		System::assert_last_event(
+			Event::VoteDelegated {
+				cluster_id,
+				node_pub_key: cluster_node_1_key.clone(),
+				delegate: ClusterMember::ClusterManager,
+			}
+			.into(),
+		);
+
+		assert_ok!(DdcClustersGov::propose_spend_cluster_reserve(
+			RuntimeOrigin::signed(cluster_manager.clone()),
+			cluster_id,
+			cluster_manager.clone(),
+			100,
+			ClusterMember::ClusterManager
+		));
+
+		// The provider can not vote for the node while its vote is delegated
+		assert_noop!(
+			DdcClustersGov::vote_proposal(
+				RuntimeOrigin::signed(cluster_node_1_provider.clone()),
+				cluster_id,
+				0,
+				true,
+				ClusterMember::NodeProvider(cluster_node_1_key.clone())
+			),
+			Error::<Test>::NodeVoteDelegated
+		);
+
+		assert_ok!(DdcClustersGov::vote_proposal(
+			RuntimeOrigin::signed(cluster_manager.clone()),
+			cluster_id,
+			0,
+			true,
+			ClusterMember::ClusterManager
+		));
+		assert_ok!(DdcClustersGov::vote_proposal(
+			RuntimeOrigin::signed(cluster_node_2_provider.clone()),
+			cluster_id,
+			0,
+			true,
+			ClusterMember::NodeProvider(cluster_node_2_key)
+		));
+
+		// The ballot of the cluster manager is counted for the delegated node as well
+		assert_ok!(DdcClustersGov::close_proposal(
+			RuntimeOrigin::signed(cluster_manager.clone()),
+			cluster_id,
+			0,
+			ClusterMember::ClusterManager
+		));
+		System::assert_has_event(
+			Event::Closed { cluster_id, proposal_index: 0, yes: 3, no: 0 }.into(),
+		);
+		System::assert_has_event(Event::Approved { cluster_id, proposal_index: 0 }.into());
+
+		assert_noop!(
+			DdcClustersGov::remove_vote_delegation(
+				RuntimeOrigin::signed(cluster_node_2_provider),
+				cluster_id,
+				cluster_node_1_key.clone()
+			),
+			Error::<Test>::NotNodeProvider
+		);
+		assert_ok!(DdcClustersGov::remove_vote_delegation(
+			RuntimeOrigin::signed(cluster_node_1_provider),
+			cluster_id,
+			cluster_node_1_key.clone()
+		));
+		assert!(!VoteDelegations::<Test>::contains_key(cluster_id, cluster_node_1_key.clone()));
+		System::assert_last_event(
+			Event::VoteDelegationRemoved { cluster_id, node_pub_key: cluster_node_1_key }.into(),
+		);
+	})
+}
+
+#[test]
+fn cluster_proposals_and_delegations_are_removed_on_decommission() {
+	let cluster = build_cluster(
+		CLUSTER_ID,
+		CLUSTER_MANAGER_ID,
+		CLUSTER_RESERVE_ID,
+		ClusterParams::default(),
+		ClusterProtocolParams::default(),
+		ClusterStatus::Activated,
+	);
+
+	let node_1 = build_cluster_node(
+		NODE_PUB_KEY_1,
+		NODE_PROVIDER_ID_1,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	ExtBuilder.build_and_execute(cluster, vec![node_1], || {
+		fast_forward_to(1);
+
+		let cluster_id = ClusterId::from(CLUSTER_ID);
+		let cluster_manager = AccountId::from(CLUSTER_MANAGER_ID);
+		let cluster_node_1_provider = AccountId::from(NODE_PROVIDER_ID_1);
+		let cluster_node_1_key = NodePubKey::StoragePubKey(AccountId::from(NODE_PUB_KEY_1));
+
+		for amount in [100, 200] {
+			assert_ok!(DdcClustersGov::propose_spend_cluster_reserve(
+				RuntimeOrigin::signed(cluster_manager.clone()),
+				cluster_id,
+				cluster_manager.clone(),
+				amount,
+				ClusterMember::ClusterManager
+			));
+		}
+		assert_ok!(DdcClustersGov::delegate_vote(
+			RuntimeOrigin::signed(cluster_node_1_provider),
+			cluster_id,
+			cluster_node_1_key.clone(),
+			ClusterMember::ClusterManager
+		));
+
+		assert_eq!(
+			<DdcClustersGov as ClusterDecommissionHandler>::clean_up(&cluster_id, None, 2),
+			Ok(2)
+		);
+		assert_eq!(ClusterProposal::<Test>::iter_prefix(cluster_id).count(), 0);
+		assert_eq!(ClusterProposalVoting::<Test>::iter_prefix(cluster_id).count(), 0);
+		System::assert_has_event(Event::Removed { cluster_id, proposal_index: 1 }.into());
+		assert_eq!(ClusterProposalCount::<Test>::get(cluster_id), 2);
+
+		assert_eq!(
+			<DdcClustersGov as ClusterDecommissionHandler>::clean_up(&cluster_id, None, 2),
+			Ok(1)
+		);
+		assert!(!VoteDelegations::<Test>::contains_key(cluster_id, cluster_node_1_key));
+		assert!(!ClusterProposalCount::<Test>::contains_key(cluster_id));
+	})
+}
+
+#[test]
+fn customer_pricing_override_is_set_through_cluster_governance() {
+	let cluster = build_cluster(
+		CLUSTER_ID,
+		CLUSTER_MANAGER_ID,
+		CLUSTER_RESERVE_ID,
+		ClusterParams::default(),
+		ClusterProtocolParams::default(),
+		ClusterStatus::Activated,
+	);
+
+	let node_1 = build_cluster_node(
+		NODE_PUB_KEY_1,
+		NODE_PROVIDER_ID_1,
+		StorageNodeParams::default(),
+		CLUSTER_ID,
+		ClusterNodeStatus::ValidationSucceeded,
+		ClusterNodeKind::Genesis,
+	);
+
+	ExtBuilder.build_and_execute(cluster, vec![node_1], || {
+		fast_forward_to(1);
+
+		let cluster_id = ClusterId::from(CLUSTER_ID);
+		let cluster_manager = AccountId::from(CLUSTER_MANAGER_ID);
+		let customer = AccountId::from([128; 32]);
+		let target = PricingOverrideTarget::Customer(customer.clone());
+		let pricing_override = PricingOverride {
+			pricing: CustomerPricing::Discount(Percent::from_percent(15)),
+			valid_from: 1_000,
+			valid_till: 2_000,
+		};
+
+		// The pricing of an activated cluster can't be negotiated without the cluster governance
+		assert_noop!(
+			DdcClusters::set_pricing_override(
+				RuntimeOrigin::signed(cluster_manager.clone()),
+				cluster_id,
+				target.clone(),
+				Some(pricing_override.clone())
+			),
+			pallet_ddc_clusters::Error::<Test>::PricingOverrideRequiresGov
+		);
+
+		assert_ok!(DdcClustersGov::propose_set_pricing_override(
+			RuntimeOrigin::signed(cluster_manager.clone()),
+			cluster_id,
+			target.clone(),
+			Some(pricing_override.clone()),
+			ClusterMember::ClusterManager
+		));
+		assert_eq!(
+			ClusterProposal::<Test>::get(cluster_id, 0),
+			Some(Proposal {
+				author: cluster_manager.clone(),
+				kind: ProposalKind::SetPricingOverride,
+				call: <Test as pallet::Config>::ClusterProposalCall::from(
+					Call::<Test>::set_pricing_override {
+						cluster_id,
+						target: target.clone(),
+						pricing_override: Some(pricing_override.clone()),
+					}
+				)
+			})
+		);
+
+		assert_noop!(
+			DdcClustersGov::set_pricing_override(
+				RuntimeOrigin::signed(cluster_manager),
+				cluster_id,
+				target.clone(),
+				Some(pricing_override.clone())
+			),
+			DispatchError::BadOrigin
+		);
+
+		let open_gov_updater = <Test as pallet::Config>::OpenGovUpdaterTrackOrigin::get();
+		assert_noop!(
+			DdcClustersGov::set_pricing_override(
+				open_gov_updater.clone(),
+				cluster_id,
+				target.clone(),
+				Some(PricingOverride { valid_till: 1_000, ..pricing_override.clone() })
+			),
+			pallet_ddc_clusters::Error::<Test>::InvalidPricingOverride
+		);
+
+		assert_ok!(DdcClustersGov::set_pricing_override(
+			open_gov_updater.clone(),
+			cluster_id,
+			target.clone(),
+			Some(pricing_override.clone())
+		));
+		assert_eq!(
+			pallet_ddc_clusters::PricingOverrides::<Test>::get(cluster_id, target.clone()),
+			Some(pricing_override.clone())
+		);
+
+		// The pricing applies to the buckets of the customer within its validity period only
+		let price_schedule = |time| {
+			<DdcClusters as ClusterProtocol<Test, Balance>>::get_price_schedule(
+				&cluster_id,
+				&customer,
+				1,
+				time,
+			)
+		};
+		assert_eq!(price_schedule(999), PriceSchedule::Cluster);
+		assert_eq!(
+			price_schedule(1_000),
+			PriceSchedule::Customer(pricing_override.pricing.clone())
+		);
+		assert_eq!(price_schedule(2_000), PriceSchedule::Cluster);
+
+		// The pricing negotiated for a bucket takes precedence
+		let bucket_pricing = CustomerPricing::Discount(Percent::from_percent(25));
+		assert_ok!(DdcClustersGov::set_pricing_override(
+			open_gov_updater.clone(),
+			cluster_id,
+			PricingOverrideTarget::Bucket(1),
+			Some(PricingOverride { pricing: bucket_pricing.clone(), ..pricing_override })
+		));
+		assert_eq!(price_schedule(1_000), PriceSchedule::Bucket(bucket_pricing));
+
+		assert_ok!(DdcClustersGov::set_pricing_override(
+			open_gov_updater,
+			cluster_id,
+			target.clone(),
+			None
+		));
+		assert!(!pallet_ddc_clusters::PricingOverrides::<Test>::contains_key(cluster_id, target));
+	})
+}
@@ -28,8 +28,8 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_ddc_clusters_gov.
 pub trait WeightInfo {
-	fn propose_activate_cluster_protocol() -> Weight;
-	fn propose_update_cluster_protocol() -> Weight;
+	fn propose_activate_cluster_protocol(p: u32, m: u32, ) -> Weight;
+	fn propose_update_cluster_protocol(p: u32, m: u32, ) -> Weight;
 	fn vote_proposal() -> Weight;
 	fn close_early_approved(m: u32, ) -> Weight;
 	fn close_approved(m: u32, ) -> Weight;
@@ -39,6 +39,20 @@ pub trait WeightInfo {
 	fn refund_submission_deposit() -> Weight;
 	fn activate_cluster_protocol() -> Weight;
 	fn update_cluster_protocol() -> Weight;
+	fn propose_transfer_cluster_manager(p: u32, m: u32, ) -> Weight;
+	fn transfer_cluster_manager() -> Weight;
+	fn propose_remove_cluster_node(p: u32, m: u32, ) -> Weight;
+	fn remove_cluster_node() -> Weight;
+	fn propose_update_cluster_params(p: u32, m: u32, ) -> Weight;
+	fn update_cluster_params() -> Weight;
+	fn propose_spend_cluster_reserve(p: u32, m: u32, ) -> Weight;
+	fn spend_cluster_reserve() -> Weight;
+	fn delegate_vote() -> Weight;
+	fn remove_vote_delegation() -> Weight;
+	fn propose_set_pricing_override(p: u32, m: u32, ) -> Weight;
+	fn set_pricing_override() -> Weight;
+	fn propose_cancel_cluster_manager_transfer(p: u32, m: u32, ) -> Weight;
+	fn cancel_cluster_manager_transfer() -> Weight;
 }
 
 /// Weights for pallet_ddc_clusters_gov using the Substrate node and recommended hardware.
@@ -46,29 +60,77 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcClustersGov::ClusterProposal` (r:1 w:1)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
 	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
 	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
 	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn propose_activate_cluster_protocol() -> Weight {
-		Weight::from_parts(40_987_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_activate_cluster_protocol(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(42_318_114_u64, 0)
+			// Standard Error: 5_128
+			.saturating_add(Weight::from_parts(2_941_307_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 4_912
+			.saturating_add(Weight::from_parts(1_183_406_u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcClustersGov::ClusterProposal` (r:1 w:1)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
 	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
 	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
 	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn propose_update_cluster_protocol() -> Weight {
-		Weight::from_parts(40_476_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_update_cluster_protocol(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(41_906_572_u64, 0)
+			// Standard Error: 4_862
+			.saturating_add(Weight::from_parts(2_917_845_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 4_788
+			.saturating_add(Weight::from_parts(1_176_281_u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: `DdcClusters::ClustersNodes` (r:1 w:0)
 	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -101,12 +163,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: `DdcClustersGov::SubmissionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Preimage::PreimageFor` (r:0 w:1)
 	// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `MaxEncodedLen`)
+	// Storage: `DdcClusters::ClustersNodes` (r:64 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:64 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[4, 64]`.
 	fn close_early_approved(m: u32, ) -> Weight {
 		Weight::from_parts(200_906_983_u64, 0)
 			// Standard Error: 6_098
-			.saturating_add(Weight::from_parts(224_016_u64, 0).saturating_mul(m as u64))
+			.saturating_add(Weight::from_parts(187_342_u64, 0).saturating_mul(m as u64))
 			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m as u64)))
 			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
@@ -129,12 +196,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: `DdcClustersGov::SubmissionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Preimage::PreimageFor` (r:0 w:1)
 	// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `MaxEncodedLen`)
+	// Storage: `DdcClusters::ClustersNodes` (r:64 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:64 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[4, 64]`.
 	fn close_approved(m: u32, ) -> Weight {
 		Weight::from_parts(253_269_234_u64, 0)
 			// Standard Error: 11_311
-			.saturating_add(Weight::from_parts(102_968_u64, 0).saturating_mul(m as u64))
+			.saturating_add(Weight::from_parts(86_115_u64, 0).saturating_mul(m as u64))
 			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m as u64)))
 			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
@@ -143,12 +215,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClustersGov::ClusterProposal` (r:0 w:1)
 	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:64 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:64 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[4, 64]`.
 	fn close_early_disapproved(m: u32, ) -> Weight {
 		Weight::from_parts(44_770_482_u64, 0)
 			// Standard Error: 2_212
-			.saturating_add(Weight::from_parts(126_113_u64, 0).saturating_mul(m as u64))
+			.saturating_add(Weight::from_parts(104_927_u64, 0).saturating_mul(m as u64))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m as u64)))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
@@ -157,12 +234,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClustersGov::ClusterProposal` (r:0 w:1)
 	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:64 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:64 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[4, 64]`.
 	fn close_disapproved(m: u32, ) -> Weight {
 		Weight::from_parts(73_437_989_u64, 0)
 			// Standard Error: 3_685
-			.saturating_add(Weight::from_parts(6_156_u64, 0).saturating_mul(m as u64))
+			.saturating_add(Weight::from_parts(5_231_u64, 0).saturating_mul(m as u64))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m as u64)))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: `DdcClustersGov::ClusterProposal` (r:1 w:1)
@@ -201,35 +283,389 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
+	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_transfer_cluster_manager(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(42_137_290_u64, 0)
+			// Standard Error: 5_011
+			.saturating_add(Weight::from_parts(2_935_016_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 4_851
+			.saturating_add(Weight::from_parts(1_179_947_u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PendingManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::PendingManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::GovManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::GovManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_cluster_manager() -> Weight {
+		Weight::from_parts(24_012_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
+	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_remove_cluster_node(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(47_551_209_u64, 0)
+			// Standard Error: 5_342
+			.saturating_add(Weight::from_parts(2_952_671_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 5_034
+			.saturating_add(Weight::from_parts(1_190_532_u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersGovParams` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersGovParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::LeavingNodes` (r:1 w:1)
+	// Proof: `DdcClusters::LeavingNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_cluster_node() -> Weight {
+		Weight::from_parts(61_274_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
+	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_update_cluster_params(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(41_773_436_u64, 0)
+			// Standard Error: 4_937
+			.saturating_add(Weight::from_parts(2_928_519_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 4_903
+			.saturating_add(Weight::from_parts(1_181_118_u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:1)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_cluster_params() -> Weight {
+		Weight::from_parts(24_391_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
+	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_spend_cluster_reserve(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(42_094_851_u64, 0)
+			// Standard Error: 5_076
+			.saturating_add(Weight::from_parts(2_939_204_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 4_967
+			.saturating_add(Weight::from_parts(1_186_724_u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn spend_cluster_reserve() -> Weight {
+		Weight::from_parts(64_211_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::ClustersNodes` (r:2 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:0 w:1)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn delegate_vote() -> Weight {
+		Weight::from_parts(32_861_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:1 w:1)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_vote_delegation() -> Weight {
+		Weight::from_parts(21_470_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
+	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_set_pricing_override(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(42_318_207_u64, 0)
+			// Standard Error: 5_012
+			.saturating_add(Weight::from_parts(2_934_681_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 5_012
+			.saturating_add(Weight::from_parts(1_188_390_u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcCustomers::Buckets` (r:1 w:0)
+	// Proof: `DdcCustomers::Buckets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PricingOverrides` (r:0 w:1)
+	// Proof: `DdcClusters::PricingOverrides` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_pricing_override() -> Weight {
+		Weight::from_parts(22_914_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
+	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_cancel_cluster_manager_transfer(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(41_806_133_u64, 0)
+			// Standard Error: 4_987
+			.saturating_add(Weight::from_parts(2_931_274_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 4_765
+			.saturating_add(Weight::from_parts(1_174_863_u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PendingManagerTransfers` (r:1 w:1)
+	// Proof: `DdcClusters::PendingManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::GovManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::GovManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_cluster_manager_transfer() -> Weight {
+		Weight::from_parts(21_374_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcClustersGov::ClusterProposal` (r:1 w:1)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
 	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
 	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
 	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn propose_activate_cluster_protocol() -> Weight {
-		Weight::from_parts(40_987_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_activate_cluster_protocol(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(42_318_114_u64, 0)
+			// Standard Error: 5_128
+			.saturating_add(Weight::from_parts(2_941_307_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 4_912
+			.saturating_add(Weight::from_parts(1_183_406_u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcClustersGov::ClusterProposal` (r:1 w:1)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
 	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
 	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
 	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn propose_update_cluster_protocol() -> Weight {
-		Weight::from_parts(40_476_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_update_cluster_protocol(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(41_906_572_u64, 0)
+			// Standard Error: 4_862
+			.saturating_add(Weight::from_parts(2_917_845_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 4_788
+			.saturating_add(Weight::from_parts(1_176_281_u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	// Storage: `DdcClusters::ClustersNodes` (r:1 w:0)
 	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -262,12 +698,17 @@ impl WeightInfo for () {
 	// Proof: `DdcClustersGov::SubmissionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Preimage::PreimageFor` (r:0 w:1)
 	// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `MaxEncodedLen`)
+	// Storage: `DdcClusters::ClustersNodes` (r:64 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:64 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[4, 64]`.
 	fn close_early_approved(m: u32, ) -> Weight {
 		Weight::from_parts(200_906_983_u64, 0)
 			// Standard Error: 6_098
-			.saturating_add(Weight::from_parts(224_016_u64, 0).saturating_mul(m as u64))
+			.saturating_add(Weight::from_parts(187_342_u64, 0).saturating_mul(m as u64))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(m as u64)))
 			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
@@ -290,12 +731,17 @@ impl WeightInfo for () {
 	// Proof: `DdcClustersGov::SubmissionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Preimage::PreimageFor` (r:0 w:1)
 	// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `MaxEncodedLen`)
+	// Storage: `DdcClusters::ClustersNodes` (r:64 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:64 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[4, 64]`.
 	fn close_approved(m: u32, ) -> Weight {
 		Weight::from_parts(253_269_234_u64, 0)
 			// Standard Error: 11_311
-			.saturating_add(Weight::from_parts(102_968_u64, 0).saturating_mul(m as u64))
+			.saturating_add(Weight::from_parts(86_115_u64, 0).saturating_mul(m as u64))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(m as u64)))
 			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
@@ -304,12 +750,17 @@ impl WeightInfo for () {
 	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClustersGov::ClusterProposal` (r:0 w:1)
 	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:64 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:64 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[4, 64]`.
 	fn close_early_disapproved(m: u32, ) -> Weight {
 		Weight::from_parts(44_770_482_u64, 0)
 			// Standard Error: 2_212
-			.saturating_add(Weight::from_parts(126_113_u64, 0).saturating_mul(m as u64))
+			.saturating_add(Weight::from_parts(104_927_u64, 0).saturating_mul(m as u64))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(m as u64)))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
@@ -318,12 +769,17 @@ impl WeightInfo for () {
 	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClustersGov::ClusterProposal` (r:0 w:1)
 	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:64 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:64 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[4, 64]`.
 	fn close_disapproved(m: u32, ) -> Weight {
 		Weight::from_parts(73_437_989_u64, 0)
 			// Standard Error: 3_685
-			.saturating_add(Weight::from_parts(6_156_u64, 0).saturating_mul(m as u64))
+			.saturating_add(Weight::from_parts(5_231_u64, 0).saturating_mul(m as u64))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(m as u64)))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	// Storage: `DdcClustersGov::ClusterProposal` (r:1 w:1)
@@ -362,4 +818,310 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
+	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_transfer_cluster_manager(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(42_137_290_u64, 0)
+			// Standard Error: 5_011
+			.saturating_add(Weight::from_parts(2_935_016_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 4_851
+			.saturating_add(Weight::from_parts(1_179_947_u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PendingManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::PendingManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::GovManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::GovManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_cluster_manager() -> Weight {
+		Weight::from_parts(24_012_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
+	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_remove_cluster_node(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(47_551_209_u64, 0)
+			// Standard Error: 5_342
+			.saturating_add(Weight::from_parts(2_952_671_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 5_034
+			.saturating_add(Weight::from_parts(1_190_532_u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersGovParams` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersGovParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::LeavingNodes` (r:1 w:1)
+	// Proof: `DdcClusters::LeavingNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_cluster_node() -> Weight {
+		Weight::from_parts(61_274_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
+	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_update_cluster_params(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(41_773_436_u64, 0)
+			// Standard Error: 4_937
+			.saturating_add(Weight::from_parts(2_928_519_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 4_903
+			.saturating_add(Weight::from_parts(1_181_118_u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:1)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_cluster_params() -> Weight {
+		Weight::from_parts(24_391_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
+	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_spend_cluster_reserve(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(42_094_851_u64, 0)
+			// Standard Error: 5_076
+			.saturating_add(Weight::from_parts(2_939_204_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 4_967
+			.saturating_add(Weight::from_parts(1_186_724_u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn spend_cluster_reserve() -> Weight {
+		Weight::from_parts(64_211_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::ClustersNodes` (r:2 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:0 w:1)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn delegate_vote() -> Weight {
+		Weight::from_parts(32_861_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:1 w:1)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_vote_delegation() -> Weight {
+		Weight::from_parts(21_470_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
+	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_set_pricing_override(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(42_318_207_u64, 0)
+			// Standard Error: 5_012
+			.saturating_add(Weight::from_parts(2_934_681_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 5_012
+			.saturating_add(Weight::from_parts(1_188_390_u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcCustomers::Buckets` (r:1 w:0)
+	// Proof: `DdcCustomers::Buckets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PricingOverrides` (r:0 w:1)
+	// Proof: `DdcClusters::PricingOverrides` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_pricing_override() -> Weight {
+		Weight::from_parts(22_914_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposal` (r:9 w:1)
+	// Proof: `DdcClustersGov::ClusterProposal` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:1 w:0)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:65 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::VoteDelegations` (r:64 w:0)
+	// Proof: `DdcClustersGov::VoteDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:64 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:64 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:64 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalCount` (r:1 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClustersGov::ClusterProposalVoting` (r:0 w:1)
+	// Proof: `DdcClustersGov::ClusterProposalVoting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 9]`.
+	/// The range of component `m` is `[4, 64]`.
+	fn propose_cancel_cluster_manager_transfer(p: u32, m: u32, ) -> Weight {
+		Weight::from_parts(41_806_133_u64, 0)
+			// Standard Error: 4_987
+			.saturating_add(Weight::from_parts(2_931_274_u64, 0).saturating_mul(p as u64))
+			// Standard Error: 4_765
+			.saturating_add(Weight::from_parts(1_174_863_u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(m as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PendingManagerTransfers` (r:1 w:1)
+	// Proof: `DdcClusters::PendingManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::GovManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::GovManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_cluster_manager_transfer() -> Weight {
+		Weight::from_parts(21_374_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
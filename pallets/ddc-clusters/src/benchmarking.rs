@@ -1,7 +1,7 @@
 //! DdcStaking pallet benchmarking.
 
 use ddc_primitives::{
-	ClusterId, ClusterNodeKind, ClusterParams, ClusterProtocolParams, NodePubKey,
+	ClusterId, ClusterNodeKind, ClusterParams, ClusterProtocolParams, CustomerPricing, NodePubKey,
 };
 pub use frame_benchmarking::{
 	account, benchmarks, impl_benchmark_test_suite, whitelist_account, whitelisted_caller,
@@ -9,12 +9,18 @@ pub use frame_benchmarking::{
 };
 use frame_system::RawOrigin;
 use sp_core::crypto::UncheckedFrom;
-use sp_runtime::{AccountId32, Perquintill};
+use sp_runtime::{AccountId32, Percent, Perquintill};
 use sp_std::prelude::*;
 use testing_utils::*;
 
 use super::*;
-use crate::{cluster::ClusterProps, Pallet as DdcClusters};
+use crate::{
+	cluster::{
+		AllowlistEntry, ClusterDecommission, ClusterDecommissionStage, ClusterProps, NodeLeaving,
+		NodeProviderAllowlistParams,
+	},
+	Pallet as DdcClusters,
+};
 
 const USER_SEED: u32 = 999666;
 const USER_SEED_2: u32 = 999555;
@@ -43,10 +49,12 @@ benchmarks! {
 			storage_bond_size: 100u32.into(),
 			storage_chill_delay: 50u32.into(),
 			storage_unbonding_delay: 50u32.into(),
+			node_removal_notice_period: 50u32.into(),
 			unit_per_mb_stored: 10,
 			unit_per_mb_streamed: 10,
 			unit_per_put_request: 10,
 			unit_per_get_request: 10,
+			pricing_tiers: Default::default(),
 		};
 	}: _(RawOrigin::Signed(user.clone()), cluster_id, user.clone(), cluster_params, cluster_protocol_params)
 	verify {
@@ -93,9 +101,19 @@ benchmarks! {
 			node_pub_key.clone(),
 			ClusterNodeKind::Genesis
 		);
+		// The node is removed right away without a notice period
+		ClustersGovParams::<T>::mutate(cluster_id, |maybe_params| {
+			if let Some(params) = maybe_params {
+				params.node_removal_notice_period = 0u32.into();
+			}
+		});
+		// The slot of the node goes to a queued node
+		ClustersNodesLimits::<T>::insert(cluster_id, StorageNodeMode::Storage, 1);
+		let queued = queue_nodes::<T>(cluster_id, StorageNodeMode::Storage, 1);
 	}: _(RawOrigin::Signed(user.clone()), cluster_id, node_pub_key.clone())
 	verify {
 		assert!(!ClustersNodes::<T>::contains_key(cluster_id, node_pub_key));
+		assert!(ClustersNodes::<T>::contains_key(cluster_id, queued[0].clone()));
 	}
 
 	set_cluster_params {
@@ -137,6 +155,275 @@ benchmarks! {
 		assert_last_event::<T>(Event::ClusterNodeValidated { cluster_id, node_pub_key, succeeded: true}.into());
 	}
 
+	add_aggregator {
+		let bytes = [0u8; 32];
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::from(bytes));
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let balance = <T as pallet::Config>::Currency::minimum_balance() * 1_000_000u32.into();
+		let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user, balance);
+		let _ = config_cluster_and_node::<T>(user.clone(), node_pub_key.clone(), cluster_id);
+		DdcClusters::<T>::add_node(RawOrigin::Signed(user.clone()).into(), cluster_id, node_pub_key.clone(), ClusterNodeKind::Genesis)?;
+		switch_node_to_dac_mode::<T>(node_pub_key.clone());
+	}: _(RawOrigin::Signed(user.clone()), cluster_id, node_pub_key.clone())
+	verify {
+		assert!(ClustersAggregators::<T>::contains_key(cluster_id, node_pub_key));
+	}
+
+	remove_aggregator {
+		let bytes = [0u8; 32];
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::from(bytes));
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let balance = <T as pallet::Config>::Currency::minimum_balance() * 1_000_000u32.into();
+		let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user, balance);
+		let _ = config_cluster_and_node::<T>(user.clone(), node_pub_key.clone(), cluster_id);
+		DdcClusters::<T>::add_node(RawOrigin::Signed(user.clone()).into(), cluster_id, node_pub_key.clone(), ClusterNodeKind::Genesis)?;
+		switch_node_to_dac_mode::<T>(node_pub_key.clone());
+		DdcClusters::<T>::add_aggregator(RawOrigin::Signed(user.clone()).into(), cluster_id, node_pub_key.clone())?;
+	}: _(RawOrigin::Signed(user.clone()), cluster_id, node_pub_key.clone())
+	verify {
+		assert!(!ClustersAggregators::<T>::contains_key(cluster_id, node_pub_key));
+	}
+
+	propose_manager_transfer {
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let user_2 = account::<T::AccountId>("user", USER_SEED_2, 0u32);
+		let _ = config_cluster::<T>(user.clone(), cluster_id);
+	}: _(RawOrigin::Signed(user.clone()), cluster_id, user_2.clone())
+	verify {
+		assert_eq!(PendingManagerTransfers::<T>::get(cluster_id), Some(user_2));
+	}
+
+	accept_manager_transfer {
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let user_2 = account::<T::AccountId>("user", USER_SEED_2, 0u32);
+		let _ = config_cluster::<T>(user.clone(), cluster_id);
+		DdcClusters::<T>::propose_manager_transfer(RawOrigin::Signed(user.clone()).into(), cluster_id, user_2.clone())?;
+	}: _(RawOrigin::Signed(user_2.clone()), cluster_id)
+	verify {
+		assert_eq!(Clusters::<T>::try_get(cluster_id).unwrap().manager_id, user_2);
+	}
+
+	cancel_manager_transfer {
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let user_2 = account::<T::AccountId>("user", USER_SEED_2, 0u32);
+		let _ = config_cluster::<T>(user.clone(), cluster_id);
+		DdcClusters::<T>::propose_manager_transfer(RawOrigin::Signed(user.clone()).into(), cluster_id, user_2)?;
+	}: _(RawOrigin::Signed(user.clone()), cluster_id)
+	verify {
+		assert!(!PendingManagerTransfers::<T>::contains_key(cluster_id));
+	}
+
+	set_node_provider_allowlist {
+		let n in 0 .. T::MaxAllowlistLen::get();
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let _ = config_cluster::<T>(user.clone(), cluster_id);
+		let params = NodeProviderAllowlistParams { accepts_requests: true, required_judgement: None };
+		DdcClusters::<T>::set_node_provider_allowlist(RawOrigin::Signed(user.clone()).into(), cluster_id, Some(params))?;
+		for i in 0 .. n {
+			let provider = account::<T::AccountId>("provider", i, 0u32);
+			let mut bytes = [0u8; 32];
+			bytes[..4].copy_from_slice(&i.to_le_bytes());
+			let node_pub_key = NodePubKey::StoragePubKey(AccountId32::from(bytes));
+			DdcClusters::<T>::add_allowlist_entry(RawOrigin::Signed(user.clone()).into(), cluster_id, AllowlistEntry::Provider(provider.clone()))?;
+			NodeAuthorizationRequests::<T>::insert(cluster_id, node_pub_key, provider);
+		}
+	}: _(RawOrigin::Signed(user.clone()), cluster_id, None)
+	verify {
+		assert!(!NodeProviderAllowlists::<T>::contains_key(cluster_id));
+		assert_eq!(NodeProviderAllowlistEntries::<T>::iter_prefix(cluster_id).count(), 0);
+		assert_eq!(NodeAuthorizationRequests::<T>::iter_prefix(cluster_id).count(), 0);
+	}
+
+	add_allowlist_entry {
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let user_2 = account::<T::AccountId>("user", USER_SEED_2, 0u32);
+		let _ = config_cluster::<T>(user.clone(), cluster_id);
+		let params = NodeProviderAllowlistParams { accepts_requests: false, required_judgement: None };
+		DdcClusters::<T>::set_node_provider_allowlist(RawOrigin::Signed(user.clone()).into(), cluster_id, Some(params))?;
+	}: _(RawOrigin::Signed(user.clone()), cluster_id, AllowlistEntry::Provider(user_2.clone()))
+	verify {
+		assert!(NodeProviderAllowlistEntries::<T>::contains_key(cluster_id, AllowlistEntry::Provider(user_2)));
+	}
+
+	remove_allowlist_entry {
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let user_2 = account::<T::AccountId>("user", USER_SEED_2, 0u32);
+		let _ = config_cluster::<T>(user.clone(), cluster_id);
+		let params = NodeProviderAllowlistParams { accepts_requests: false, required_judgement: None };
+		DdcClusters::<T>::set_node_provider_allowlist(RawOrigin::Signed(user.clone()).into(), cluster_id, Some(params))?;
+		DdcClusters::<T>::add_allowlist_entry(RawOrigin::Signed(user.clone()).into(), cluster_id, AllowlistEntry::Provider(user_2.clone()))?;
+	}: _(RawOrigin::Signed(user.clone()), cluster_id, AllowlistEntry::Provider(user_2.clone()))
+	verify {
+		assert!(!NodeProviderAllowlistEntries::<T>::contains_key(cluster_id, AllowlistEntry::Provider(user_2)));
+	}
+
+	request_node_authorization {
+		let bytes = [0u8; 32];
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::from(bytes));
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let balance = <T as pallet::Config>::Currency::minimum_balance() * 1_000_000u32.into();
+		let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user, balance);
+		let _ = config_cluster_and_node::<T>(user.clone(), node_pub_key.clone(), cluster_id);
+		let params = NodeProviderAllowlistParams { accepts_requests: true, required_judgement: None };
+		DdcClusters::<T>::set_node_provider_allowlist(RawOrigin::Signed(user.clone()).into(), cluster_id, Some(params))?;
+	}: _(RawOrigin::Signed(user.clone()), cluster_id, node_pub_key.clone())
+	verify {
+		assert_eq!(NodeAuthorizationRequests::<T>::get(cluster_id, node_pub_key), Some(user));
+	}
+
+	resolve_node_authorization {
+		let bytes = [0u8; 32];
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::from(bytes));
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let balance = <T as pallet::Config>::Currency::minimum_balance() * 1_000_000u32.into();
+		let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user, balance);
+		let _ = config_cluster_and_node::<T>(user.clone(), node_pub_key.clone(), cluster_id);
+		let params = NodeProviderAllowlistParams { accepts_requests: true, required_judgement: None };
+		DdcClusters::<T>::set_node_provider_allowlist(RawOrigin::Signed(user.clone()).into(), cluster_id, Some(params))?;
+		DdcClusters::<T>::request_node_authorization(RawOrigin::Signed(user.clone()).into(), cluster_id, node_pub_key.clone())?;
+	}: _(RawOrigin::Signed(user.clone()), cluster_id, node_pub_key.clone(), true)
+	verify {
+		assert!(NodeProviderAllowlistEntries::<T>::contains_key(cluster_id, AllowlistEntry::Node(node_pub_key)));
+	}
+
+	decommission_cluster {
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_id_2 = ClusterId::from([2; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let _ = config_cluster::<T>(user.clone(), cluster_id);
+		let _ = config_cluster::<T>(user.clone(), cluster_id_2);
+	}: _(RawOrigin::Signed(user.clone()), cluster_id, Some(cluster_id_2))
+	verify {
+		assert!(ClusterDecommissions::<T>::contains_key(cluster_id));
+	}
+
+	drain_cluster_nodes {
+		let n in 0 .. T::DecommissionBatchSize::get();
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let nodes = config_cluster_with_nodes::<T>(user.clone(), cluster_id, n, StorageNodeMode::Storage);
+		let decommission = ClusterDecommission {
+			stage: ClusterDecommissionStage::DrainingNodes,
+			drain_nodes_at: frame_system::Pallet::<T>::block_number(),
+			buckets_migrate_to: None,
+		};
+		ClusterDecommissions::<T>::insert(cluster_id, decommission.clone());
+	}: {
+		DdcClusters::<T>::do_decommission_step(cluster_id, decommission, n + 1);
+	}
+	verify {
+		assert_eq!(ClustersNodes::<T>::iter_key_prefix(cluster_id).count(), 0);
+		assert!(nodes.into_iter().all(|node_pub_key| {
+			T::NodeRepository::get(node_pub_key).unwrap().get_cluster_id().is_none()
+		}));
+	}
+
+	remove_decommissioned_cluster {
+		let n in 0 .. T::MaxAllowlistLen::get();
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let _ = config_cluster::<T>(user.clone(), cluster_id);
+		let params = NodeProviderAllowlistParams { accepts_requests: true, required_judgement: None };
+		DdcClusters::<T>::set_node_provider_allowlist(RawOrigin::Signed(user.clone()).into(), cluster_id, Some(params))?;
+		for i in 0 .. n {
+			let provider = account::<T::AccountId>("provider", i, 0u32);
+			NodeProviderAllowlistEntries::<T>::insert(cluster_id, AllowlistEntry::Provider(provider.clone()), ());
+			let mut bytes = [0u8; 32];
+			bytes[..4].copy_from_slice(&i.to_le_bytes());
+			let node_pub_key = NodePubKey::StoragePubKey(AccountId32::from(bytes));
+			NodeAuthorizationRequests::<T>::insert(cluster_id, node_pub_key, provider);
+		}
+	}: {
+		DdcClusters::<T>::do_remove_decommissioned_cluster(cluster_id);
+	}
+	verify {
+		assert!(!Clusters::<T>::contains_key(cluster_id));
+		assert_eq!(NodeProviderAllowlistEntries::<T>::iter_key_prefix(cluster_id).count(), 0);
+	}
+
+	remove_leaving_node {
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let nodes = config_cluster_with_nodes::<T>(user.clone(), cluster_id, 1, StorageNodeMode::DAC);
+		let node_pub_key = nodes[0].clone();
+		DdcClusters::<T>::add_aggregator(RawOrigin::Signed(user.clone()).into(), cluster_id, node_pub_key.clone())?;
+		let leaving = NodeLeaving { leave_at: frame_system::Pallet::<T>::block_number(), by_provider: false };
+		LeavingNodes::<T>::insert(cluster_id, node_pub_key.clone(), leaving);
+		ClustersNodesLimits::<T>::insert(cluster_id, StorageNodeMode::DAC, 1);
+		let queued = queue_nodes::<T>(cluster_id, StorageNodeMode::DAC, 1);
+	}: {
+		DdcClusters::<T>::do_release_node(cluster_id, node_pub_key.clone())?;
+	}
+	verify {
+		assert!(!ClustersNodes::<T>::contains_key(cluster_id, node_pub_key.clone()));
+		assert!(!LeavingNodes::<T>::contains_key(cluster_id, node_pub_key));
+		assert!(ClustersNodes::<T>::contains_key(cluster_id, queued[0].clone()));
+	}
+
+	set_nodes_limit {
+		let n in 0 .. T::MaxAdmissionQueueLen::get();
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let _ = config_cluster_with_nodes::<T>(user.clone(), cluster_id, 0, StorageNodeMode::Storage);
+		ClustersNodesLimits::<T>::insert(cluster_id, StorageNodeMode::Storage, 0);
+		let queued = queue_nodes::<T>(cluster_id, StorageNodeMode::Storage, n);
+	}: _(RawOrigin::Signed(user.clone()), cluster_id, StorageNodeMode::Storage, Some(n as ClusterNodesCount))
+	verify {
+		assert!(AdmissionQueues::<T>::get(cluster_id, StorageNodeMode::Storage).is_empty());
+		assert!(queued.into_iter().all(|node_pub_key| ClustersNodes::<T>::contains_key(cluster_id, node_pub_key)));
+	}
+
+	leave_admission_queue {
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let _ = config_cluster_with_nodes::<T>(user.clone(), cluster_id, 0, StorageNodeMode::Storage);
+		let queued = queue_nodes::<T>(cluster_id, StorageNodeMode::Storage, T::MaxAdmissionQueueLen::get());
+		let node_pub_key = queued[queued.len() - 1].clone();
+		let provider = account::<T::AccountId>("provider", T::MaxAdmissionQueueLen::get() - 1, 0u32);
+	}: _(RawOrigin::Signed(provider), cluster_id, node_pub_key.clone())
+	verify {
+		assert!(!AdmissionQueues::<T>::get(cluster_id, StorageNodeMode::Storage).contains(&node_pub_key));
+	}
+
+	set_pricing_override {
+		let cluster_id = ClusterId::from([1; 20]);
+		let user = account::<T::AccountId>("user", USER_SEED, 0u32);
+		let customer = account::<T::AccountId>("customer", USER_SEED_2, 0u32);
+		let _ = config_cluster::<T>(user.clone(), cluster_id);
+		let target = PricingOverrideTarget::Customer(customer);
+		let pricing_override = PricingOverride {
+			pricing: CustomerPricing::Discount(Percent::from_percent(10)),
+			valid_from: 0,
+			valid_till: i64::MAX,
+		};
+	}: {
+		// The call is rejected right after the cluster is read when the pricing is set through the
+		// cluster governance only
+		let result = DdcClusters::<T>::set_pricing_override(
+			RawOrigin::Signed(user).into(),
+			cluster_id,
+			target.clone(),
+			Some(pricing_override),
+		);
+		assert_eq!(result.is_ok(), !T::PricingOverrideRequiresGov::get());
+	}
+	verify {
+		assert_eq!(
+			PricingOverrides::<T>::contains_key(cluster_id, target),
+			!T::PricingOverrideRequiresGov::get()
+		);
+	}
+
 	impl_benchmark_test_suite!(
 		DdcClusters,
 		crate::mock::ExtBuilder.build(),
@@ -1,5 +1,7 @@
 use codec::{Decode, Encode};
-use ddc_primitives::{ClusterId, ClusterParams, ClusterStatus, DdcEra};
+use ddc_primitives::{
+	ClusterId, ClusterParams, ClusterStatus, DdcEra, IdentityJudgement, NodePubKey,
+};
 use frame_support::{pallet_prelude::*, parameter_types};
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
@@ -26,6 +28,57 @@ pub struct ClusterProps<AccountId> {
 	pub replication_total: u32,
 }
 
+/// Settings of the built-in node provider authorization of a cluster.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Serialize, Deserialize)]
+pub struct NodeProviderAllowlistParams {
+	/// Whether node providers can request the cluster manager to authorize their nodes.
+	pub accepts_requests: bool,
+	/// Judgement node providers must have on their identity to join the cluster.
+	pub required_judgement: Option<IdentityJudgement>,
+}
+
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Serialize, Deserialize)]
+pub struct NodeProviderAllowlist {
+	pub params: NodeProviderAllowlistParams,
+	pub entries_count: u32,
+	pub requests_count: u32,
+}
+
+/// Node provider account or node key authorized to join a cluster.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AllowlistEntry<AccountId> {
+	Provider(AccountId),
+	Node(NodePubKey),
+}
+
+/// Stage of the cluster decommissioning.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Serialize, Deserialize)]
+pub enum ClusterDecommissionStage {
+	/// Nodes of the cluster are removed once the notice period ends.
+	DrainingNodes,
+	/// The state kept for the cluster is removed once its eras are settled and its stake is
+	/// unbonded.
+	CleaningUp,
+}
+
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Serialize, Deserialize)]
+pub struct ClusterDecommission<BlockNumber> {
+	pub stage: ClusterDecommissionStage,
+	/// Block the nodes of the cluster start to be removed at.
+	pub drain_nodes_at: BlockNumber,
+	/// Cluster the buckets are migrated to, the buckets are marked as removed otherwise.
+	pub buckets_migrate_to: Option<ClusterId>,
+}
+
+/// Removal of a node from the cluster after the notice period.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Serialize, Deserialize)]
+pub struct NodeLeaving<BlockNumber> {
+	/// Block the node is removed from the cluster at.
+	pub leave_at: BlockNumber,
+	/// Whether the removal is requested by the node provider, only such removal can be cancelled.
+	pub by_provider: bool,
+}
+
 impl<AccountId> Cluster<AccountId> {
 	pub fn new(
 		cluster_id: ClusterId,
@@ -29,14 +29,25 @@ mod tests;
 pub mod migrations;
 const LOG_TARGET: &str = "runtime::ddc-clusters";
 
+/// Maximum number of nodes taken from an admission queue for the slot a removed node leaves.
+/// A node which can no longer be admitted is dropped from the queue instead, so the rest of the
+/// queue waits for the next removal or nodes limit change.
+const MAX_DEQUEUED_ON_REMOVAL: u32 = 1;
+
 use ddc_primitives::{
 	traits::{
-		cluster::{ClusterCreator, ClusterProtocol, ClusterQuery, ClusterValidator},
+		cluster::{
+			ClusterCreator, ClusterDecommissionHandler, ClusterProtocol, ClusterQuery,
+			ClusterValidator,
+		},
+		customer::CustomerVisitor,
+		identity::IdentityVerifier,
 		staking::{StakerCreator, StakingVisitor, StakingVisitorError},
 	},
-	ClusterBondingParams, ClusterFeesParams, ClusterId, ClusterNodeKind, ClusterNodeState,
-	ClusterNodeStatus, ClusterNodesStats, ClusterParams, ClusterPricingParams,
-	ClusterProtocolParams, ClusterStatus, DdcEra, NodePubKey, NodeType,
+	BucketId, ClusterBondingParams, ClusterFeesParams, ClusterId, ClusterNodeKind,
+	ClusterNodeState, ClusterNodeStatus, ClusterNodesCount, ClusterNodesStats, ClusterParams,
+	ClusterPricingParams, ClusterProtocolParams, ClusterStatus, DdcEra, NodePubKey, NodeType,
+	PriceSchedule, PricingOverride, PricingOverrideTarget, StorageNodeMode,
 };
 use frame_support::{
 	assert_ok,
@@ -45,13 +56,16 @@ use frame_support::{
 };
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
-use pallet_ddc_nodes::{NodeRepository, NodeTrait};
+use pallet_ddc_nodes::{NodeProps, NodeRepository, NodeTrait};
 use sp_core::crypto::UncheckedFrom;
 use sp_runtime::SaturatedConversion;
 use sp_std::prelude::*;
 
 use crate::{
-	cluster::Cluster,
+	cluster::{
+		AllowlistEntry, Cluster, ClusterDecommission, ClusterDecommissionStage, NodeLeaving,
+		NodeProviderAllowlist, NodeProviderAllowlistParams,
+	},
 	node_provider_auth::{NodeProviderAuthContract, NodeProviderAuthContractError},
 };
 
@@ -70,7 +84,7 @@ pub mod pallet {
 
 	/// The current storage version.
 	const STORAGE_VERSION: frame_support::traits::StorageVersion =
-		frame_support::traits::StorageVersion::new(3);
+		frame_support::traits::StorageVersion::new(6);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -91,6 +105,34 @@ pub mod pallet {
 		type MinErasureCodingTotalLimit: Get<u32>;
 		#[pallet::constant]
 		type MinReplicationTotalLimit: Get<u32>;
+		/// Whether the manager of an activated cluster can be changed through the cluster
+		/// governance only.
+		#[pallet::constant]
+		type ManagerTransferRequiresGov: Get<bool>;
+		/// Maximum number of entries and of pending authorization requests in the node provider
+		/// allowlist of a cluster.
+		#[pallet::constant]
+		type MaxAllowlistLen: Get<u32>;
+		type IdentityVerifier: IdentityVerifier<Self::AccountId>;
+		/// Number of blocks node providers are given to migrate the data off a decommissioned
+		/// cluster before its nodes are removed.
+		#[pallet::constant]
+		type DecommissionNoticePeriod: Get<BlockNumberFor<Self>>;
+		/// Maximum number of nodes or state items of a decommissioned cluster processed in a
+		/// block.
+		#[pallet::constant]
+		type DecommissionBatchSize: Get<u32>;
+		/// State kept by other pallets for a decommissioned cluster. Handlers relying on the state
+		/// of other handlers, e.g. payouts relying on buckets for charging customers, go first.
+		type ClusterDecommissionHandler: ClusterDecommissionHandler;
+		/// Maximum number of nodes waiting for a free slot of a mode in a full cluster.
+		#[pallet::constant]
+		type MaxAdmissionQueueLen: Get<u32>;
+		/// Whether the pricing negotiated with the customers of a cluster can be set through the
+		/// cluster governance only.
+		#[pallet::constant]
+		type PricingOverrideRequiresGov: Get<bool>;
+		type CustomerVisitor: CustomerVisitor<Self>;
 	}
 
 	#[pallet::event]
@@ -107,6 +149,28 @@ pub mod pallet {
 		ClusterUnbonded { cluster_id: ClusterId },
 		ClusterNodeValidated { cluster_id: ClusterId, node_pub_key: NodePubKey, succeeded: bool },
 		ClusterEraPaid { cluster_id: ClusterId, era_id: DdcEra },
+		ClusterAggregatorAdded { cluster_id: ClusterId, node_pub_key: NodePubKey },
+		ClusterAggregatorRemoved { cluster_id: ClusterId, node_pub_key: NodePubKey },
+		ClusterManagerTransferProposed { cluster_id: ClusterId, new_manager_id: T::AccountId },
+		ClusterManagerTransferCancelled { cluster_id: ClusterId },
+		ClusterManagerTransferred { cluster_id: ClusterId, manager_id: T::AccountId },
+		NodeProviderAllowlistSet { cluster_id: ClusterId },
+		NodeProviderAllowlistRemoved { cluster_id: ClusterId },
+		AllowlistEntryAdded { cluster_id: ClusterId, entry: AllowlistEntry<T::AccountId> },
+		AllowlistEntryRemoved { cluster_id: ClusterId, entry: AllowlistEntry<T::AccountId> },
+		NodeAuthorizationRequested { cluster_id: ClusterId, node_pub_key: NodePubKey },
+		NodeAuthorizationApproved { cluster_id: ClusterId, node_pub_key: NodePubKey },
+		NodeAuthorizationRejected { cluster_id: ClusterId, node_pub_key: NodePubKey },
+		ClusterDecommissionStarted { cluster_id: ClusterId, drain_nodes_at: BlockNumberFor<T> },
+		ClusterNodesDrained { cluster_id: ClusterId },
+		ClusterDecommissioned { cluster_id: ClusterId },
+		ClusterNodeLeaving { cluster_id: ClusterId, node_pub_key: NodePubKey },
+		ClusterNodeLeavingCancelled { cluster_id: ClusterId, node_pub_key: NodePubKey },
+		ClusterNodesLimitSet { cluster_id: ClusterId, mode: StorageNodeMode },
+		ClusterNodeQueued { cluster_id: ClusterId, node_pub_key: NodePubKey },
+		ClusterNodeDequeued { cluster_id: ClusterId, node_pub_key: NodePubKey },
+		PricingOverrideSet { cluster_id: ClusterId, target: PricingOverrideTarget<T::AccountId> },
+		PricingOverrideUnset { cluster_id: ClusterId, target: PricingOverrideTarget<T::AccountId> },
 	}
 
 	#[pallet::error]
@@ -138,6 +202,54 @@ pub mod pallet {
 		ArithmeticOverflow,
 		NodeIsNotAssignedToCluster,
 		ControllerDoesNotExist,
+		/// Only nodes running in DAC mode can be registered as aggregators.
+		NodeIsNotDAC,
+		AggregatorAlreadyRegistered,
+		AggregatorIsNotRegistered,
+		/// The cluster has no pending manager transfer.
+		NoPendingManagerTransfer,
+		/// Only the proposed manager can accept the cluster manager transfer.
+		OnlyProposedManager,
+		/// The manager of an activated cluster is changed through the cluster governance.
+		ManagerTransferRequiresGov,
+		/// The proposed manager is already the cluster manager.
+		AlreadyClusterManager,
+		/// The cluster has no node provider allowlist.
+		NodeProviderAllowlistNotSet,
+		NodeProviderAllowlistIsFull,
+		AllowlistEntryAlreadyExists,
+		AllowlistEntryDoesNotExist,
+		/// The cluster manager does not accept node authorization requests.
+		NodeAuthorizationRequestsNotAccepted,
+		NodeAuthorizationRequestAlreadyExists,
+		NodeAuthorizationRequestDoesNotExist,
+		TooManyNodeAuthorizationRequests,
+		/// The identity of the node provider has no judgement required by the cluster.
+		NodeProviderIdentityNotVerified,
+		ClusterIsDecommissioning,
+		/// Buckets can be migrated to another existing cluster which is not decommissioned.
+		InvalidBucketsMigrationTarget,
+		NodeIsLeaving,
+		NodeIsNotLeaving,
+		/// Removal of the node started by the cluster manager can not be cancelled.
+		NodeLeavingIsNotCancellable,
+		/// The cluster has no free slots for nodes of the mode. Node providers joining such a
+		/// cluster wait in the admission queue instead.
+		ClusterNodesLimitReached,
+		AdmissionQueueIsFull,
+		NodeIsQueued,
+		NodeIsNotQueued,
+		/// The thresholds of the pricing tiers are not in strictly ascending order.
+		InvalidPricingTiers,
+		/// The validity period of the pricing override is empty or its pricing tiers are not in
+		/// strictly ascending order.
+		InvalidPricingOverride,
+		/// The pricing negotiated with the customers of the cluster is set through the cluster
+		/// governance.
+		PricingOverrideRequiresGov,
+		NoPricingOverride,
+		/// The bucket the pricing override is set for does not belong to the cluster.
+		BucketDoesNotBelongToCluster,
 	}
 
 	#[pallet::storage]
@@ -171,6 +283,147 @@ pub mod pallet {
 	pub type ClustersNodesStats<T: Config> =
 		StorageMap<_, Twox64Concat, ClusterId, ClusterNodesStats>;
 
+	/// Dedicated DAC aggregators of a cluster. When a cluster has no registered aggregators, any
+	/// validated DAC node of the cluster is eligible.
+	#[pallet::storage]
+	#[pallet::getter(fn clusters_aggregators)]
+	pub type ClustersAggregators<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClusterId,
+		Blake2_128Concat,
+		NodePubKey,
+		(),
+		OptionQuery,
+	>;
+
+	/// Accounts proposed by cluster managers to take over the management of their clusters.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_manager_transfers)]
+	pub type PendingManagerTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClusterId, T::AccountId>;
+
+	/// Pending manager transfers proposed through the cluster governance. The manager of an
+	/// activated cluster can not cancel them if the manager is required to be changed through the
+	/// cluster governance.
+	#[pallet::storage]
+	#[pallet::getter(fn gov_manager_transfers)]
+	pub type GovManagerTransfers<T: Config> = StorageMap<_, Blake2_128Concat, ClusterId, ()>;
+
+	/// Built-in node provider authorization of a cluster. Clusters without it authorize node
+	/// providers with the auth smart contract from their params.
+	#[pallet::storage]
+	#[pallet::getter(fn node_provider_allowlists)]
+	pub type NodeProviderAllowlists<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClusterId, NodeProviderAllowlist>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn node_provider_allowlist_entries)]
+	pub type NodeProviderAllowlistEntries<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClusterId,
+		Blake2_128Concat,
+		AllowlistEntry<T::AccountId>,
+		(),
+		OptionQuery,
+	>;
+
+	/// Nodes whose providers requested the cluster manager to add them to the allowlist.
+	#[pallet::storage]
+	#[pallet::getter(fn node_authorization_requests)]
+	pub type NodeAuthorizationRequests<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClusterId,
+		Blake2_128Concat,
+		NodePubKey,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	/// Clusters being decommissioned.
+	#[pallet::storage]
+	#[pallet::getter(fn cluster_decommissions)]
+	pub type ClusterDecommissions<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClusterId, ClusterDecommission<BlockNumberFor<T>>>;
+
+	/// Nodes leaving clusters. A node stays in the cluster until the notice period configured for
+	/// the cluster ends, so that the data stored by the node can be migrated to other nodes.
+	#[pallet::storage]
+	#[pallet::getter(fn leaving_nodes)]
+	pub type LeavingNodes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClusterId,
+		Blake2_128Concat,
+		NodePubKey,
+		NodeLeaving<BlockNumberFor<T>>,
+	>;
+
+	/// Maximum number of nodes of each mode in a cluster, the number is not limited unless set
+	/// by the cluster manager.
+	#[pallet::storage]
+	#[pallet::getter(fn clusters_nodes_limits)]
+	pub type ClustersNodesLimits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClusterId,
+		Twox64Concat,
+		StorageNodeMode,
+		ClusterNodesCount,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn clusters_nodes_counts)]
+	pub type ClustersNodesCounts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClusterId,
+		Twox64Concat,
+		StorageNodeMode,
+		ClusterNodesCount,
+		ValueQuery,
+	>;
+
+	/// Nodes waiting for a free slot of their mode in a full cluster. Nodes are admitted in the
+	/// order they joined the queue once nodes leave the cluster or the limit is raised.
+	#[pallet::storage]
+	#[pallet::getter(fn admission_queues)]
+	pub type AdmissionQueues<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClusterId,
+		Twox64Concat,
+		StorageNodeMode,
+		BoundedVec<NodePubKey, T::MaxAdmissionQueueLen>,
+		ValueQuery,
+	>;
+
+	/// Pricing negotiated by the cluster managers with the customers of their clusters, it is
+	/// charged instead of the cluster pricing.
+	#[pallet::storage]
+	#[pallet::getter(fn pricing_overrides)]
+	pub type PricingOverrides<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClusterId,
+		Blake2_128Concat,
+		PricingOverrideTarget<T::AccountId>,
+		PricingOverride,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let consumed_weight = Self::remove_leaving_nodes(now, remaining_weight);
+			consumed_weight.saturating_add(Self::decommission_clusters(
+				now,
+				remaining_weight.saturating_sub(consumed_weight),
+			))
+		}
+	}
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub clusters: Vec<Cluster<T::AccountId>>,
@@ -242,6 +495,13 @@ pub mod pallet {
 								added_at: frame_system::Pallet::<T>::block_number(),
 							},
 						);
+						if let Ok(node) = T::NodeRepository::get(node_pub_key.clone()) {
+							ClustersNodesCounts::<T>::mutate(
+								cluster_id,
+								Pallet::<T>::node_mode(&node),
+								|count| *count = count.saturating_add(1),
+							);
+						}
 						match status {
 							ClusterNodeStatus::AwaitsValidation => {
 								stats.await_validation = stats.await_validation.saturating_add(1);
@@ -331,7 +591,7 @@ pub mod pallet {
 
 			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
 
-			Self::do_remove_node(cluster, node_pub_key)
+			Self::do_remove_node(cluster, node_pub_key, false)
 		}
 
 		// Sets Governance non-sensetive parameters only
@@ -343,26 +603,11 @@ pub mod pallet {
 			cluster_params: ClusterParams<T::AccountId>,
 		) -> DispatchResult {
 			let caller_id = ensure_signed(origin)?;
-			let mut cluster =
+			let cluster =
 				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
 			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
-			ensure!(
-				cluster_params.erasure_coding_required >= T::MinErasureCodingRequiredLimit::get(),
-				Error::<T>::ErasureCodingRequiredDidNotMeetMinimum
-			);
-			ensure!(
-				cluster_params.erasure_coding_total >= T::MinErasureCodingTotalLimit::get(),
-				Error::<T>::ErasureCodingTotalNotMeetMinimum
-			);
-			ensure!(
-				cluster_params.replication_total >= T::MinReplicationTotalLimit::get(),
-				Error::<T>::ReplicationTotalDidNotMeetMinimum
-			);
-			cluster.set_params(cluster_params);
-			Clusters::<T>::insert(cluster_id, cluster);
-			Self::deposit_event(Event::<T>::ClusterParamsSet { cluster_id });
 
-			Ok(())
+			Self::do_set_cluster_params(cluster, cluster_params)
 		}
 
 		#[pallet::call_index(4)]
@@ -376,7 +621,7 @@ pub mod pallet {
 			let caller_id = ensure_signed(origin)?;
 			let cluster =
 				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
-			// todo: allow to execute this extrinsic to Validator's manager only
+			// Nodes are validated by DAC validators, the cluster manager can override the outcome
 			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
 
 			Self::do_validate_node(cluster_id, node_pub_key, succeeded)
@@ -391,14 +636,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			let caller_id = ensure_signed(origin)?;
 
-			// Cluster with a given id exists and has an auth smart contract.
+			// Cluster with a given id exists and has an allowlist or an auth smart contract.
 			let cluster =
 				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
-			let node_provider_auth_contract_address = cluster
-				.props
-				.node_provider_auth_contract
-				.clone()
-				.ok_or(Error::<T>::NodeIsNotAuthorized)?;
+			let allowlist = NodeProviderAllowlists::<T>::get(cluster_id);
+			ensure!(
+				allowlist.is_some() || cluster.props.node_provider_auth_contract.is_some(),
+				Error::<T>::NodeIsNotAuthorized
+			);
 
 			// Node with this public key exists and belongs to the caller.
 			let node = T::NodeRepository::get(node_pub_key.clone())
@@ -416,139 +661,537 @@ pub mod pallet {
 				.map_err(Into::<Error<T>>::into)?;
 			ensure!(!has_chilling_attempt, Error::<T>::NodeChillingIsProhibited);
 
-			// Cluster auth smart contract allows joining.
-			let auth_contract =
-				NodeProviderAuthContract::<T>::new(node_provider_auth_contract_address, caller_id);
-			let is_authorized = auth_contract
-				.is_authorized(
-					node.get_provider_id().to_owned(),
-					node.get_pub_key(),
-					node.get_type(),
-				)
-				.map_err(Into::<Error<T>>::into)?;
-			ensure!(is_authorized, Error::<T>::NodeIsNotAuthorized);
+			// Cluster allowlist or auth smart contract allows joining.
+			if let Some(allowlist) = allowlist {
+				Self::ensure_allowlisted(cluster_id, &allowlist, &caller_id, &node_pub_key)?;
+			} else if let Some(node_provider_auth_contract_address) =
+				cluster.props.node_provider_auth_contract.clone()
+			{
+				let auth_contract = NodeProviderAuthContract::<T>::new(
+					node_provider_auth_contract_address,
+					caller_id,
+				);
+				let is_authorized = auth_contract
+					.is_authorized(
+						node.get_provider_id().to_owned(),
+						node.get_pub_key(),
+						node.get_type(),
+					)
+					.map_err(Into::<Error<T>>::into)?;
+				ensure!(is_authorized, Error::<T>::NodeIsNotAuthorized);
+			}
 
 			Self::do_join_cluster(cluster, node_pub_key)
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		fn do_create_cluster(
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_aggregator())]
+		pub fn add_aggregator(
+			origin: OriginFor<T>,
 			cluster_id: ClusterId,
-			cluster_manager_id: T::AccountId,
-			cluster_reserve_id: T::AccountId,
-			cluster_params: ClusterParams<T::AccountId>,
-			initial_protocol_params: ClusterProtocolParams<BalanceOf<T>, BlockNumberFor<T>>,
+			node_pub_key: NodePubKey,
 		) -> DispatchResult {
-			ensure!(!Clusters::<T>::contains_key(cluster_id), Error::<T>::ClusterAlreadyExists);
+			let caller_id = ensure_signed(origin)?;
+			let cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
 
-			ensure!(
-				cluster_params.erasure_coding_required >= T::MinErasureCodingRequiredLimit::get(),
-				Error::<T>::ErasureCodingRequiredDidNotMeetMinimum
-			);
-			ensure!(
-				cluster_params.erasure_coding_total >= T::MinErasureCodingTotalLimit::get(),
-				Error::<T>::ErasureCodingTotalNotMeetMinimum
-			);
-			ensure!(
-				cluster_params.replication_total >= T::MinReplicationTotalLimit::get(),
-				Error::<T>::ReplicationTotalDidNotMeetMinimum
-			);
+			Self::do_add_aggregator(cluster_id, node_pub_key)
+		}
 
-			ensure!(!Clusters::<T>::contains_key(cluster_id), Error::<T>::ClusterAlreadyExists);
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_aggregator())]
+		pub fn remove_aggregator(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
 			let cluster =
-				Cluster::new(cluster_id, cluster_manager_id, cluster_reserve_id, cluster_params);
-
-			Clusters::<T>::insert(cluster_id, cluster);
-			Self::deposit_event(Event::<T>::ClusterCreated { cluster_id });
-			ClustersGovParams::<T>::insert(cluster_id, initial_protocol_params);
-			Self::deposit_event(Event::<T>::ClusterProtocolParamsSet { cluster_id });
-			ClustersNodesStats::<T>::insert(cluster_id, ClusterNodesStats::default());
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
 
-			Ok(())
+			Self::do_remove_aggregator(cluster_id, node_pub_key)
 		}
 
-		fn do_bond_cluster(cluster_id: &ClusterId) -> DispatchResult {
-			let mut cluster =
+		/// Propose a new manager for the cluster.
+		///
+		/// The origin must be the cluster manager. The proposed account, e.g. a multisig account,
+		/// becomes the cluster manager and the controller of the cluster stake once it accepts the
+		/// transfer with `accept_manager_transfer`. A new proposal replaces the pending one. The
+		/// manager of an activated cluster may be required to be changed through the cluster
+		/// governance.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::propose_manager_transfer())]
+		pub fn propose_manager_transfer(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			new_manager_id: T::AccountId,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			let cluster =
 				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
-			ensure!(cluster.status == ClusterStatus::Unbonded, Error::<T>::UnexpectedClusterStatus);
-
-			cluster.set_status(ClusterStatus::Bonded);
-			Clusters::<T>::insert(cluster_id, cluster);
-			Self::deposit_event(Event::<T>::ClusterBonded { cluster_id: *cluster_id });
+			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
+			ensure!(
+				!(T::ManagerTransferRequiresGov::get() &&
+					cluster.status == ClusterStatus::Activated),
+				Error::<T>::ManagerTransferRequiresGov
+			);
 
-			Ok(())
+			Self::do_propose_manager_transfer(cluster, new_manager_id, false)
 		}
 
-		fn do_activate_cluster_protocol(cluster_id: &ClusterId) -> DispatchResult {
+		/// Accept the management of the cluster proposed to the caller.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_manager_transfer())]
+		pub fn accept_manager_transfer(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
 			let mut cluster =
 				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
-			ensure!(cluster.status == ClusterStatus::Bonded, Error::<T>::UnexpectedClusterStatus);
+			let new_manager_id = PendingManagerTransfers::<T>::get(cluster_id)
+				.ok_or(Error::<T>::NoPendingManagerTransfer)?;
+			ensure!(new_manager_id == caller_id, Error::<T>::OnlyProposedManager);
 
-			cluster.set_status(ClusterStatus::Activated);
+			T::StakerCreator::set_cluster_controller(&cluster.reserve_id, &new_manager_id)?;
+
+			cluster.manager_id = new_manager_id.clone();
 			Clusters::<T>::insert(cluster_id, cluster);
-			Self::deposit_event(Event::<T>::ClusterActivated { cluster_id: *cluster_id });
+			PendingManagerTransfers::<T>::remove(cluster_id);
+			GovManagerTransfers::<T>::remove(cluster_id);
+			Self::deposit_event(Event::<T>::ClusterManagerTransferred {
+				cluster_id,
+				manager_id: new_manager_id,
+			});
 
 			Ok(())
 		}
 
-		fn do_start_unbond_cluster(cluster_id: &ClusterId) -> DispatchResult {
-			let mut cluster =
+		/// Cancel the pending manager transfer of the cluster.
+		///
+		/// The origin must be the cluster manager. The transfer proposed through the cluster
+		/// governance may be required to be cancelled through the cluster governance once the
+		/// cluster is activated.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_manager_transfer())]
+		pub fn cancel_manager_transfer(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			let cluster =
 				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
-			ensure!(Self::can_unbond_cluster(cluster_id), Error::<T>::UnexpectedClusterStatus);
+			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
+			ensure!(
+				!(T::ManagerTransferRequiresGov::get() &&
+					cluster.status == ClusterStatus::Activated &&
+					GovManagerTransfers::<T>::contains_key(cluster_id)),
+				Error::<T>::ManagerTransferRequiresGov
+			);
 
-			cluster.set_status(ClusterStatus::Unbonding);
-			Clusters::<T>::insert(cluster_id, cluster);
-			Self::deposit_event(Event::<T>::ClusterUnbonding { cluster_id: *cluster_id });
+			Self::do_cancel_manager_transfer(cluster_id)
+		}
 
-			Ok(())
+		/// Start decommissioning the cluster.
+		///
+		/// The cluster stops accepting nodes and buckets. Its nodes are removed once the notice
+		/// period ends, so that node providers can migrate the data. The state kept for the
+		/// cluster is removed in bounded steps once its eras are settled through payouts and the
+		/// cluster stake is unbonded. Buckets are migrated to `buckets_migrate_to` if it is set
+		/// and marked as removed otherwise, customer deposits are not bound to clusters and stay
+		/// withdrawable.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::decommission_cluster())]
+		pub fn decommission_cluster(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			buckets_migrate_to: Option<ClusterId>,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			let cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
+
+			Self::do_decommission_cluster(cluster_id, buckets_migrate_to)
 		}
 
-		fn do_end_unbond_cluster(cluster_id: &ClusterId) -> DispatchResult {
-			let mut cluster =
+		/// Set or remove the node provider allowlist of the cluster.
+		///
+		/// While the cluster has an allowlist, node providers join the cluster when either their
+		/// account or their node is in the allowlist and their identity has the judgement required
+		/// by the cluster, if any. The auth smart contract of the cluster is not called then.
+		/// Removing the allowlist drops all its entries and pending authorization requests.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_node_provider_allowlist(
+			T::MaxAllowlistLen::get(),
+		))]
+		pub fn set_node_provider_allowlist(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			params: Option<NodeProviderAllowlistParams>,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			let cluster =
 				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
-			ensure!(
-				cluster.status == ClusterStatus::Unbonding,
-				Error::<T>::UnexpectedClusterStatus
-			);
+			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
 
-			cluster.set_status(ClusterStatus::Unbonded);
-			Clusters::<T>::insert(cluster_id, cluster);
-			Self::deposit_event(Event::<T>::ClusterUnbonded { cluster_id: *cluster_id });
+			Self::do_set_node_provider_allowlist(cluster_id, params)
+		}
 
-			Ok(())
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_allowlist_entry())]
+		pub fn add_allowlist_entry(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			entry: AllowlistEntry<T::AccountId>,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			let cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
+
+			Self::do_add_allowlist_entry(cluster_id, entry)
 		}
 
-		fn do_update_cluster_protocol(
-			cluster_id: &ClusterId,
-			cluster_protocol_params: ClusterProtocolParams<BalanceOf<T>, BlockNumberFor<T>>,
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_allowlist_entry())]
+		pub fn remove_allowlist_entry(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			entry: AllowlistEntry<T::AccountId>,
 		) -> DispatchResult {
-			ensure!(
-				ClustersGovParams::<T>::contains_key(cluster_id),
-				Error::<T>::ClusterProtocolParamsNotSet
-			);
+			let caller_id = ensure_signed(origin)?;
+			let cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
 
-			ClustersGovParams::<T>::insert(cluster_id, cluster_protocol_params);
-			Self::deposit_event(Event::<T>::ClusterProtocolParamsSet { cluster_id: *cluster_id });
+			let mut allowlist = NodeProviderAllowlists::<T>::get(cluster_id)
+				.ok_or(Error::<T>::NodeProviderAllowlistNotSet)?;
+			NodeProviderAllowlistEntries::<T>::take(cluster_id, entry.clone())
+				.ok_or(Error::<T>::AllowlistEntryDoesNotExist)?;
+			allowlist.entries_count = allowlist.entries_count.saturating_sub(1);
+			NodeProviderAllowlists::<T>::insert(cluster_id, allowlist);
+			Self::deposit_event(Event::<T>::AllowlistEntryRemoved { cluster_id, entry });
 
 			Ok(())
 		}
 
-		fn do_add_node(
-			cluster: Cluster<T::AccountId>,
+		/// Request the cluster manager to add the node of the caller to the cluster allowlist.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::request_node_authorization())]
+		pub fn request_node_authorization(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
 			node_pub_key: NodePubKey,
-			node_kind: ClusterNodeKind,
 		) -> DispatchResult {
-			ensure!(cluster.can_manage_nodes(), Error::<T>::UnexpectedClusterStatus);
+			let caller_id = ensure_signed(origin)?;
+			ensure!(Clusters::<T>::contains_key(cluster_id), Error::<T>::ClusterDoesNotExist);
+			let mut allowlist = NodeProviderAllowlists::<T>::get(cluster_id)
+				.ok_or(Error::<T>::NodeProviderAllowlistNotSet)?;
+			ensure!(
+				allowlist.params.accepts_requests,
+				Error::<T>::NodeAuthorizationRequestsNotAccepted
+			);
 
-			let mut node: pallet_ddc_nodes::Node<T> = T::NodeRepository::get(node_pub_key.clone())
+			let node = T::NodeRepository::get(node_pub_key.clone())
 				.map_err(|_| Error::<T>::AttemptToAddNonExistentNode)?;
+			ensure!(*node.get_provider_id() == caller_id, Error::<T>::OnlyNodeProvider);
 
-			ensure!(node.get_cluster_id().is_none(), Error::<T>::AttemptToAddAlreadyAssignedNode);
-			node.set_cluster_id(Some(cluster.cluster_id));
-			T::NodeRepository::update(node).map_err(|_| Error::<T>::AttemptToAddNonExistentNode)?;
-
-			ClustersNodes::<T>::insert(
+			ensure!(
+				!NodeProviderAllowlistEntries::<T>::contains_key(
+					cluster_id,
+					AllowlistEntry::Node(node_pub_key.clone())
+				),
+				Error::<T>::AllowlistEntryAlreadyExists
+			);
+			ensure!(
+				!NodeAuthorizationRequests::<T>::contains_key(cluster_id, node_pub_key.clone()),
+				Error::<T>::NodeAuthorizationRequestAlreadyExists
+			);
+			ensure!(
+				allowlist.requests_count < T::MaxAllowlistLen::get(),
+				Error::<T>::TooManyNodeAuthorizationRequests
+			);
+
+			NodeAuthorizationRequests::<T>::insert(cluster_id, node_pub_key.clone(), caller_id);
+			allowlist.requests_count = allowlist.requests_count.saturating_add(1);
+			NodeProviderAllowlists::<T>::insert(cluster_id, allowlist);
+			Self::deposit_event(Event::<T>::NodeAuthorizationRequested {
+				cluster_id,
+				node_pub_key,
+			});
+
+			Ok(())
+		}
+
+		/// Approve or reject the authorization request of a node. An approved node is added to
+		/// the cluster allowlist.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::resolve_node_authorization())]
+		pub fn resolve_node_authorization(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+			approved: bool,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			let cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
+
+			let mut allowlist = NodeProviderAllowlists::<T>::get(cluster_id)
+				.ok_or(Error::<T>::NodeProviderAllowlistNotSet)?;
+			NodeAuthorizationRequests::<T>::take(cluster_id, node_pub_key.clone())
+				.ok_or(Error::<T>::NodeAuthorizationRequestDoesNotExist)?;
+			allowlist.requests_count = allowlist.requests_count.saturating_sub(1);
+			NodeProviderAllowlists::<T>::insert(cluster_id, allowlist);
+
+			if approved {
+				Self::do_add_allowlist_entry(
+					cluster_id,
+					AllowlistEntry::Node(node_pub_key.clone()),
+				)?;
+				Self::deposit_event(Event::<T>::NodeAuthorizationApproved {
+					cluster_id,
+					node_pub_key,
+				});
+			} else {
+				Self::deposit_event(Event::<T>::NodeAuthorizationRejected {
+					cluster_id,
+					node_pub_key,
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Set or remove the maximum number of nodes of the mode in the cluster.
+		///
+		/// Nodes already in the cluster are kept when the limit is lowered. Raising or removing
+		/// the limit admits nodes from the admission queue of the mode.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_nodes_limit(
+			T::MaxAdmissionQueueLen::get(),
+		))]
+		pub fn set_nodes_limit(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			mode: StorageNodeMode,
+			limit: Option<ClusterNodesCount>,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			let cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
+
+			match limit {
+				Some(limit) => ClustersNodesLimits::<T>::insert(cluster_id, mode.clone(), limit),
+				None => ClustersNodesLimits::<T>::remove(cluster_id, mode.clone()),
+			}
+			Self::deposit_event(Event::<T>::ClusterNodesLimitSet {
+				cluster_id,
+				mode: mode.clone(),
+			});
+
+			Self::admit_queued_nodes(&cluster, mode, T::MaxAdmissionQueueLen::get());
+
+			Ok(())
+		}
+
+		/// Remove the node from the admission queue of the cluster.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::leave_admission_queue())]
+		pub fn leave_admission_queue(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			let node = T::NodeRepository::get(node_pub_key.clone())
+				.map_err(|_| Error::<T>::AttemptToRemoveNonExistentNode)?;
+			ensure!(*node.get_provider_id() == caller_id, Error::<T>::OnlyNodeProvider);
+
+			AdmissionQueues::<T>::try_mutate(cluster_id, Self::node_mode(&node), |queue| {
+				let position = queue
+					.iter()
+					.position(|queued| *queued == node_pub_key)
+					.ok_or(Error::<T>::NodeIsNotQueued)?;
+				queue.remove(position);
+				Ok::<(), Error<T>>(())
+			})?;
+			Self::deposit_event(Event::<T>::ClusterNodeDequeued { cluster_id, node_pub_key });
+
+			Ok(())
+		}
+
+		/// Set or remove the pricing negotiated with the customer or for the bucket.
+		///
+		/// The origin must be the cluster manager. The pricing applies to the eras started within
+		/// its validity period, the pricing set for a bucket takes precedence over the pricing set
+		/// for its owner. The pricing may be required to be set through the cluster governance
+		/// only. The manager then can not set it before the activation either, it is set once the
+		/// cluster is activated, as the governance proposals are made in activated clusters only.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_pricing_override())]
+		pub fn set_pricing_override(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			target: PricingOverrideTarget<T::AccountId>,
+			pricing_override: Option<PricingOverride>,
+		) -> DispatchResult {
+			let caller_id = ensure_signed(origin)?;
+			let cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			ensure!(cluster.manager_id == caller_id, Error::<T>::OnlyClusterManager);
+			ensure!(!T::PricingOverrideRequiresGov::get(), Error::<T>::PricingOverrideRequiresGov);
+
+			Self::do_set_pricing_override(cluster_id, target, pricing_override)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn do_create_cluster(
+			cluster_id: ClusterId,
+			cluster_manager_id: T::AccountId,
+			cluster_reserve_id: T::AccountId,
+			cluster_params: ClusterParams<T::AccountId>,
+			initial_protocol_params: ClusterProtocolParams<BalanceOf<T>, BlockNumberFor<T>>,
+		) -> DispatchResult {
+			ensure!(!Clusters::<T>::contains_key(cluster_id), Error::<T>::ClusterAlreadyExists);
+
+			ensure!(
+				cluster_params.erasure_coding_required >= T::MinErasureCodingRequiredLimit::get(),
+				Error::<T>::ErasureCodingRequiredDidNotMeetMinimum
+			);
+			ensure!(
+				cluster_params.erasure_coding_total >= T::MinErasureCodingTotalLimit::get(),
+				Error::<T>::ErasureCodingTotalNotMeetMinimum
+			);
+			ensure!(
+				cluster_params.replication_total >= T::MinReplicationTotalLimit::get(),
+				Error::<T>::ReplicationTotalDidNotMeetMinimum
+			);
+			ensure!(
+				initial_protocol_params.pricing_tiers.is_valid(),
+				Error::<T>::InvalidPricingTiers
+			);
+
+			ensure!(!Clusters::<T>::contains_key(cluster_id), Error::<T>::ClusterAlreadyExists);
+			let cluster =
+				Cluster::new(cluster_id, cluster_manager_id, cluster_reserve_id, cluster_params);
+
+			Clusters::<T>::insert(cluster_id, cluster);
+			Self::deposit_event(Event::<T>::ClusterCreated { cluster_id });
+			ClustersGovParams::<T>::insert(cluster_id, initial_protocol_params);
+			Self::deposit_event(Event::<T>::ClusterProtocolParamsSet { cluster_id });
+			ClustersNodesStats::<T>::insert(cluster_id, ClusterNodesStats::default());
+
+			Ok(())
+		}
+
+		fn do_bond_cluster(cluster_id: &ClusterId) -> DispatchResult {
+			let mut cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			ensure!(
+				!ClusterDecommissions::<T>::contains_key(cluster_id),
+				Error::<T>::ClusterIsDecommissioning
+			);
+			ensure!(cluster.status == ClusterStatus::Unbonded, Error::<T>::UnexpectedClusterStatus);
+
+			cluster.set_status(ClusterStatus::Bonded);
+			Clusters::<T>::insert(cluster_id, cluster);
+			Self::deposit_event(Event::<T>::ClusterBonded { cluster_id: *cluster_id });
+
+			Ok(())
+		}
+
+		fn do_activate_cluster_protocol(cluster_id: &ClusterId) -> DispatchResult {
+			let mut cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			ensure!(cluster.status == ClusterStatus::Bonded, Error::<T>::UnexpectedClusterStatus);
+
+			cluster.set_status(ClusterStatus::Activated);
+			Clusters::<T>::insert(cluster_id, cluster);
+			Self::deposit_event(Event::<T>::ClusterActivated { cluster_id: *cluster_id });
+
+			Ok(())
+		}
+
+		fn do_start_unbond_cluster(cluster_id: &ClusterId) -> DispatchResult {
+			let mut cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			ensure!(Self::can_unbond_cluster(cluster_id), Error::<T>::UnexpectedClusterStatus);
+
+			cluster.set_status(ClusterStatus::Unbonding);
+			Clusters::<T>::insert(cluster_id, cluster);
+			Self::deposit_event(Event::<T>::ClusterUnbonding { cluster_id: *cluster_id });
+
+			Ok(())
+		}
+
+		fn do_end_unbond_cluster(cluster_id: &ClusterId) -> DispatchResult {
+			let mut cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			ensure!(
+				cluster.status == ClusterStatus::Unbonding,
+				Error::<T>::UnexpectedClusterStatus
+			);
+
+			cluster.set_status(ClusterStatus::Unbonded);
+			Clusters::<T>::insert(cluster_id, cluster);
+			Self::deposit_event(Event::<T>::ClusterUnbonded { cluster_id: *cluster_id });
+
+			Ok(())
+		}
+
+		fn do_update_cluster_protocol(
+			cluster_id: &ClusterId,
+			cluster_protocol_params: ClusterProtocolParams<BalanceOf<T>, BlockNumberFor<T>>,
+		) -> DispatchResult {
+			ensure!(
+				ClustersGovParams::<T>::contains_key(cluster_id),
+				Error::<T>::ClusterProtocolParamsNotSet
+			);
+			ensure!(
+				cluster_protocol_params.pricing_tiers.is_valid(),
+				Error::<T>::InvalidPricingTiers
+			);
+
+			ClustersGovParams::<T>::insert(cluster_id, cluster_protocol_params);
+			Self::deposit_event(Event::<T>::ClusterProtocolParamsSet { cluster_id: *cluster_id });
+
+			Ok(())
+		}
+
+		fn do_add_node(
+			cluster: Cluster<T::AccountId>,
+			node_pub_key: NodePubKey,
+			node_kind: ClusterNodeKind,
+		) -> DispatchResult {
+			ensure!(cluster.can_manage_nodes(), Error::<T>::UnexpectedClusterStatus);
+			ensure!(
+				!ClusterDecommissions::<T>::contains_key(cluster.cluster_id),
+				Error::<T>::ClusterIsDecommissioning
+			);
+
+			let mut node: pallet_ddc_nodes::Node<T> = T::NodeRepository::get(node_pub_key.clone())
+				.map_err(|_| Error::<T>::AttemptToAddNonExistentNode)?;
+
+			ensure!(node.get_cluster_id().is_none(), Error::<T>::AttemptToAddAlreadyAssignedNode);
+			let mode = Self::node_mode(&node);
+			ensure!(
+				Self::has_free_slot(&cluster.cluster_id, &mode),
+				Error::<T>::ClusterNodesLimitReached
+			);
+			node.set_cluster_id(Some(cluster.cluster_id));
+			T::NodeRepository::update(node).map_err(|_| Error::<T>::AttemptToAddNonExistentNode)?;
+			ClustersNodesCounts::<T>::mutate(cluster.cluster_id, mode, |count| {
+				*count = count.saturating_add(1)
+			});
+
+			ClustersNodes::<T>::insert(
 				cluster.cluster_id,
 				node_pub_key.clone(),
 				ClusterNodeState {
@@ -579,16 +1222,48 @@ pub mod pallet {
 			node_pub_key: NodePubKey,
 		) -> DispatchResult {
 			ensure!(cluster.can_manage_nodes(), Error::<T>::UnexpectedClusterStatus);
+			ensure!(
+				!ClusterDecommissions::<T>::contains_key(cluster.cluster_id),
+				Error::<T>::ClusterIsDecommissioning
+			);
 
-			let mut node: pallet_ddc_nodes::Node<T> = T::NodeRepository::get(node_pub_key.clone())
+			let node: pallet_ddc_nodes::Node<T> = T::NodeRepository::get(node_pub_key.clone())
 				.map_err(|_| Error::<T>::AttemptToAddNonExistentNode)?;
 			ensure!(node.get_cluster_id().is_none(), Error::<T>::AttemptToAddAlreadyAssignedNode);
 
-			node.set_cluster_id(Some(cluster.cluster_id));
+			let mode = Self::node_mode(&node);
+			let mut queue = AdmissionQueues::<T>::get(cluster.cluster_id, &mode);
+			ensure!(!queue.contains(&node_pub_key), Error::<T>::NodeIsQueued);
+			if !Self::has_free_slot(&cluster.cluster_id, &mode) {
+				queue
+					.try_push(node_pub_key.clone())
+					.map_err(|_| Error::<T>::AdmissionQueueIsFull)?;
+				AdmissionQueues::<T>::insert(cluster.cluster_id, mode, queue);
+				Self::deposit_event(Event::<T>::ClusterNodeQueued {
+					cluster_id: cluster.cluster_id,
+					node_pub_key,
+				});
+				return Ok(());
+			}
+
+			Self::do_admit_node(cluster.cluster_id, node, node_pub_key)
+		}
+
+		/// Adds the node which passed the joining checks to the cluster.
+		fn do_admit_node(
+			cluster_id: ClusterId,
+			mut node: pallet_ddc_nodes::Node<T>,
+			node_pub_key: NodePubKey,
+		) -> DispatchResult {
+			let mode = Self::node_mode(&node);
+			node.set_cluster_id(Some(cluster_id));
 			T::NodeRepository::update(node).map_err(|_| Error::<T>::AttemptToAddNonExistentNode)?;
+			ClustersNodesCounts::<T>::mutate(cluster_id, mode, |count| {
+				*count = count.saturating_add(1)
+			});
 
 			ClustersNodes::<T>::insert(
-				cluster.cluster_id,
+				cluster_id,
 				node_pub_key.clone(),
 				ClusterNodeState {
 					kind: ClusterNodeKind::External,
@@ -596,49 +1271,204 @@ pub mod pallet {
 					added_at: frame_system::Pallet::<T>::block_number(),
 				},
 			);
-			Self::deposit_event(Event::<T>::ClusterNodeAdded {
-				cluster_id: cluster.cluster_id,
-				node_pub_key,
-			});
+			Self::deposit_event(Event::<T>::ClusterNodeAdded { cluster_id, node_pub_key });
 
-			let mut current_stats = ClustersNodesStats::<T>::try_get(cluster.cluster_id)
+			let mut current_stats = ClustersNodesStats::<T>::try_get(cluster_id)
 				.map_err(|_| Error::<T>::ClusterDoesNotExist)?;
 			current_stats.validation_succeeded = current_stats
 				.validation_succeeded
 				.checked_add(1)
 				.ok_or(Error::<T>::ArithmeticOverflow)?;
-			ClustersNodesStats::<T>::insert(cluster.cluster_id, current_stats);
+			ClustersNodesStats::<T>::insert(cluster_id, current_stats);
 
 			Ok(())
 		}
 
+		fn node_mode(node: &pallet_ddc_nodes::Node<T>) -> StorageNodeMode {
+			match node.get_props() {
+				NodeProps::StorageProps(props) => props.mode,
+			}
+		}
+
+		fn has_free_slot(cluster_id: &ClusterId, mode: &StorageNodeMode) -> bool {
+			match ClustersNodesLimits::<T>::get(cluster_id, mode) {
+				Some(limit) => ClustersNodesCounts::<T>::get(cluster_id, mode) < limit,
+				None => true,
+			}
+		}
+
+		/// Admits nodes from the admission queue of the mode while the cluster has free slots.
+		/// Nodes which can no longer join the cluster, e.g. joined another cluster or chilled,
+		/// are dropped from the queue.
+		fn admit_queued_nodes(
+			cluster: &Cluster<T::AccountId>,
+			mode: StorageNodeMode,
+			max_dequeued: u32,
+		) {
+			if !cluster.can_manage_nodes() ||
+				ClusterDecommissions::<T>::contains_key(cluster.cluster_id)
+			{
+				return;
+			}
+
+			let mut queue = AdmissionQueues::<T>::get(cluster.cluster_id, &mode);
+			if queue.is_empty() {
+				return;
+			}
+			let mut dequeued = 0;
+			while !queue.is_empty() &&
+				dequeued < max_dequeued &&
+				Self::has_free_slot(&cluster.cluster_id, &mode)
+			{
+				dequeued += 1;
+				let node_pub_key = queue.remove(0);
+				let admitted = match Self::queued_node_to_admit(&cluster.cluster_id, &node_pub_key)
+				{
+					Some(node) =>
+						Self::do_admit_node(cluster.cluster_id, node, node_pub_key.clone()).is_ok(),
+					None => false,
+				};
+				if !admitted {
+					Self::deposit_event(Event::<T>::ClusterNodeDequeued {
+						cluster_id: cluster.cluster_id,
+						node_pub_key,
+					});
+				}
+			}
+			AdmissionQueues::<T>::insert(cluster.cluster_id, mode, queue);
+		}
+
+		fn queued_node_to_admit(
+			cluster_id: &ClusterId,
+			node_pub_key: &NodePubKey,
+		) -> Option<pallet_ddc_nodes::Node<T>> {
+			let node = T::NodeRepository::get(node_pub_key.clone()).ok()?;
+			let has_activated_stake =
+				T::StakingVisitor::has_activated_stake(node_pub_key, cluster_id).unwrap_or(false);
+			let has_chilling_attempt =
+				T::StakingVisitor::has_chilling_attempt(node_pub_key).unwrap_or(true);
+
+			if node.get_cluster_id().is_none() && has_activated_stake && !has_chilling_attempt {
+				Some(node)
+			} else {
+				None
+			}
+		}
+
 		fn do_remove_node(
 			cluster: Cluster<T::AccountId>,
 			node_pub_key: NodePubKey,
+			by_provider: bool,
 		) -> DispatchResult {
 			ensure!(cluster.can_manage_nodes(), Error::<T>::UnexpectedClusterStatus);
 
-			let mut node = T::NodeRepository::get(node_pub_key.clone())
+			let node = T::NodeRepository::get(node_pub_key.clone())
 				.map_err(|_| Error::<T>::AttemptToRemoveNonExistentNode)?;
 
 			ensure!(
 				node.get_cluster_id() == &Some(cluster.cluster_id),
 				Error::<T>::AttemptToRemoveNotAssignedNode
 			);
+			ensure!(
+				!LeavingNodes::<T>::contains_key(cluster.cluster_id, &node_pub_key),
+				Error::<T>::NodeIsLeaving
+			);
 
-			node.set_cluster_id(None);
-			T::NodeRepository::update(node)
-				.map_err(|_| Error::<T>::AttemptToRemoveNonExistentNode)?;
+			let notice_period = ClustersGovParams::<T>::try_get(cluster.cluster_id)
+				.map_err(|_| Error::<T>::ClusterProtocolParamsNotSet)?
+				.node_removal_notice_period;
+			if notice_period == BlockNumberFor::<T>::from(0u32) {
+				return Self::do_release_node(cluster.cluster_id, node_pub_key);
+			}
 
-			let current_node_state =
-				ClustersNodes::<T>::take(cluster.cluster_id, node_pub_key.clone())
-					.ok_or(Error::<T>::AttemptToRemoveNotAssignedNode)?;
-			Self::deposit_event(Event::<T>::ClusterNodeRemoved {
+			let leave_at = frame_system::Pallet::<T>::block_number().saturating_add(notice_period);
+			LeavingNodes::<T>::insert(
+				cluster.cluster_id,
+				node_pub_key.clone(),
+				NodeLeaving { leave_at, by_provider },
+			);
+			Self::deposit_event(Event::<T>::ClusterNodeLeaving {
 				cluster_id: cluster.cluster_id,
 				node_pub_key,
 			});
 
-			let mut current_stats = ClustersNodesStats::<T>::try_get(cluster.cluster_id)
+			Ok(())
+		}
+
+		fn do_cancel_node_leaving(
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+		) -> DispatchResult {
+			let leaving = LeavingNodes::<T>::try_get(cluster_id, &node_pub_key)
+				.map_err(|_| Error::<T>::NodeIsNotLeaving)?;
+			ensure!(leaving.by_provider, Error::<T>::NodeLeavingIsNotCancellable);
+
+			LeavingNodes::<T>::remove(cluster_id, &node_pub_key);
+			Self::deposit_event(Event::<T>::ClusterNodeLeavingCancelled {
+				cluster_id,
+				node_pub_key,
+			});
+
+			Ok(())
+		}
+
+		/// Removes the node from the cluster right away.
+		pub(crate) fn do_release_node(
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+		) -> DispatchResult {
+			LeavingNodes::<T>::remove(cluster_id, &node_pub_key);
+			if let Ok(mut node) = T::NodeRepository::get(node_pub_key.clone()) {
+				if node.get_cluster_id() == &Some(cluster_id) {
+					node.set_cluster_id(None);
+					T::NodeRepository::update(node)
+						.map_err(|_| Error::<T>::AttemptToRemoveNonExistentNode)?;
+				}
+			}
+
+			Self::do_remove_cluster_node(cluster_id, node_pub_key)
+		}
+
+		fn remove_leaving_nodes(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let remove_weight = T::WeightInfo::remove_leaving_node();
+			let mut consumed_weight = Weight::zero();
+
+			for (cluster_id, node_pub_key, leaving) in LeavingNodes::<T>::iter() {
+				if remaining_weight.any_lt(consumed_weight.saturating_add(remove_weight)) {
+					break;
+				}
+				if leaving.leave_at > now {
+					consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
+					continue;
+				}
+
+				consumed_weight = consumed_weight.saturating_add(remove_weight);
+				if let Err(err) = Self::do_release_node(cluster_id, node_pub_key.clone()) {
+					log::error!(
+						target: LOG_TARGET,
+						"Failed to remove leaving node {:?} from cluster {:?}: {:?}",
+						node_pub_key,
+						cluster_id,
+						err
+					);
+				}
+			}
+
+			consumed_weight
+		}
+
+		fn do_remove_cluster_node(
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+		) -> DispatchResult {
+			let current_node_state = ClustersNodes::<T>::take(cluster_id, node_pub_key.clone())
+				.ok_or(Error::<T>::AttemptToRemoveNotAssignedNode)?;
+			Self::deposit_event(Event::<T>::ClusterNodeRemoved {
+				cluster_id,
+				node_pub_key: node_pub_key.clone(),
+			});
+
+			let mut current_stats = ClustersNodesStats::<T>::try_get(cluster_id)
 				.map_err(|_| Error::<T>::ClusterDoesNotExist)?;
 
 			let updated_stats = match current_node_state.status {
@@ -665,11 +1495,399 @@ pub mod pallet {
 				},
 			};
 
-			ClustersNodesStats::<T>::insert(cluster.cluster_id, updated_stats);
+			ClustersNodesStats::<T>::insert(cluster_id, updated_stats);
+
+			let mode = T::NodeRepository::get(node_pub_key.clone())
+				.ok()
+				.map(|node| Self::node_mode(&node));
+			if let Some(mode) = &mode {
+				ClustersNodesCounts::<T>::mutate(cluster_id, mode, |count| {
+					*count = count.saturating_sub(1)
+				});
+			}
+
+			if ClustersAggregators::<T>::take(cluster_id, node_pub_key.clone()).is_some() {
+				Self::deposit_event(Event::<T>::ClusterAggregatorRemoved {
+					cluster_id,
+					node_pub_key,
+				});
+			}
+
+			// The slot left by the node goes to the next node in the admission queue.
+			if let (Some(mode), Ok(cluster)) = (mode, Clusters::<T>::try_get(cluster_id)) {
+				Self::admit_queued_nodes(&cluster, mode, MAX_DEQUEUED_ON_REMOVAL);
+			}
+
+			Ok(())
+		}
+
+		fn do_add_aggregator(cluster_id: ClusterId, node_pub_key: NodePubKey) -> DispatchResult {
+			ensure!(
+				ClustersNodes::<T>::contains_key(cluster_id, node_pub_key.clone()),
+				Error::<T>::NodeIsNotAssignedToCluster
+			);
+			ensure!(
+				!ClustersAggregators::<T>::contains_key(cluster_id, node_pub_key.clone()),
+				Error::<T>::AggregatorAlreadyRegistered
+			);
+
+			let node = T::NodeRepository::get(node_pub_key.clone())
+				.map_err(|_| Error::<T>::AttemptToAddNonExistentNode)?;
+			let is_dac = match node.get_props() {
+				NodeProps::StorageProps(props) => props.mode == StorageNodeMode::DAC,
+			};
+			ensure!(is_dac, Error::<T>::NodeIsNotDAC);
+
+			ClustersAggregators::<T>::insert(cluster_id, node_pub_key.clone(), ());
+			Self::deposit_event(Event::<T>::ClusterAggregatorAdded { cluster_id, node_pub_key });
+
+			Ok(())
+		}
+
+		fn do_remove_aggregator(cluster_id: ClusterId, node_pub_key: NodePubKey) -> DispatchResult {
+			ClustersAggregators::<T>::take(cluster_id, node_pub_key.clone())
+				.ok_or(Error::<T>::AggregatorIsNotRegistered)?;
+			Self::deposit_event(Event::<T>::ClusterAggregatorRemoved { cluster_id, node_pub_key });
+
+			Ok(())
+		}
+
+		fn do_set_cluster_params(
+			mut cluster: Cluster<T::AccountId>,
+			cluster_params: ClusterParams<T::AccountId>,
+		) -> DispatchResult {
+			ensure!(
+				cluster_params.erasure_coding_required >= T::MinErasureCodingRequiredLimit::get(),
+				Error::<T>::ErasureCodingRequiredDidNotMeetMinimum
+			);
+			ensure!(
+				cluster_params.erasure_coding_total >= T::MinErasureCodingTotalLimit::get(),
+				Error::<T>::ErasureCodingTotalNotMeetMinimum
+			);
+			ensure!(
+				cluster_params.replication_total >= T::MinReplicationTotalLimit::get(),
+				Error::<T>::ReplicationTotalDidNotMeetMinimum
+			);
+			let cluster_id = cluster.cluster_id;
+			cluster.set_params(cluster_params);
+			Clusters::<T>::insert(cluster_id, cluster);
+			Self::deposit_event(Event::<T>::ClusterParamsSet { cluster_id });
+
+			Ok(())
+		}
+
+		fn do_propose_manager_transfer(
+			cluster: Cluster<T::AccountId>,
+			new_manager_id: T::AccountId,
+			by_gov: bool,
+		) -> DispatchResult {
+			ensure!(cluster.manager_id != new_manager_id, Error::<T>::AlreadyClusterManager);
+
+			PendingManagerTransfers::<T>::insert(cluster.cluster_id, new_manager_id.clone());
+			if by_gov {
+				GovManagerTransfers::<T>::insert(cluster.cluster_id, ());
+			} else {
+				GovManagerTransfers::<T>::remove(cluster.cluster_id);
+			}
+			Self::deposit_event(Event::<T>::ClusterManagerTransferProposed {
+				cluster_id: cluster.cluster_id,
+				new_manager_id,
+			});
+
+			Ok(())
+		}
+
+		fn do_cancel_manager_transfer(cluster_id: ClusterId) -> DispatchResult {
+			PendingManagerTransfers::<T>::take(cluster_id)
+				.ok_or(Error::<T>::NoPendingManagerTransfer)?;
+			GovManagerTransfers::<T>::remove(cluster_id);
+			Self::deposit_event(Event::<T>::ClusterManagerTransferCancelled { cluster_id });
+
+			Ok(())
+		}
+
+		fn do_set_node_provider_allowlist(
+			cluster_id: ClusterId,
+			params: Option<NodeProviderAllowlistParams>,
+		) -> DispatchResult {
+			match params {
+				Some(params) => {
+					let allowlist = match NodeProviderAllowlists::<T>::get(cluster_id) {
+						Some(allowlist) => NodeProviderAllowlist { params, ..allowlist },
+						None =>
+							NodeProviderAllowlist { params, entries_count: 0, requests_count: 0 },
+					};
+					NodeProviderAllowlists::<T>::insert(cluster_id, allowlist);
+					Self::deposit_event(Event::<T>::NodeProviderAllowlistSet { cluster_id });
+				},
+				None => {
+					NodeProviderAllowlists::<T>::take(cluster_id)
+						.ok_or(Error::<T>::NodeProviderAllowlistNotSet)?;
+					let _ = NodeProviderAllowlistEntries::<T>::clear_prefix(
+						cluster_id,
+						T::MaxAllowlistLen::get(),
+						None,
+					);
+					let _ = NodeAuthorizationRequests::<T>::clear_prefix(
+						cluster_id,
+						T::MaxAllowlistLen::get(),
+						None,
+					);
+					Self::deposit_event(Event::<T>::NodeProviderAllowlistRemoved { cluster_id });
+				},
+			}
+
+			Ok(())
+		}
+
+		fn do_add_allowlist_entry(
+			cluster_id: ClusterId,
+			entry: AllowlistEntry<T::AccountId>,
+		) -> DispatchResult {
+			let mut allowlist = NodeProviderAllowlists::<T>::get(cluster_id)
+				.ok_or(Error::<T>::NodeProviderAllowlistNotSet)?;
+			ensure!(
+				!NodeProviderAllowlistEntries::<T>::contains_key(cluster_id, entry.clone()),
+				Error::<T>::AllowlistEntryAlreadyExists
+			);
+			ensure!(
+				allowlist.entries_count < T::MaxAllowlistLen::get(),
+				Error::<T>::NodeProviderAllowlistIsFull
+			);
+
+			NodeProviderAllowlistEntries::<T>::insert(cluster_id, entry.clone(), ());
+			allowlist.entries_count = allowlist.entries_count.saturating_add(1);
+			NodeProviderAllowlists::<T>::insert(cluster_id, allowlist);
+			Self::deposit_event(Event::<T>::AllowlistEntryAdded { cluster_id, entry });
+
+			Ok(())
+		}
+
+		fn ensure_allowlisted(
+			cluster_id: ClusterId,
+			allowlist: &NodeProviderAllowlist,
+			provider_id: &T::AccountId,
+			node_pub_key: &NodePubKey,
+		) -> DispatchResult {
+			let is_allowlisted = NodeProviderAllowlistEntries::<T>::contains_key(
+				cluster_id,
+				AllowlistEntry::Provider(provider_id.clone()),
+			) || NodeProviderAllowlistEntries::<T>::contains_key(
+				cluster_id,
+				AllowlistEntry::Node(node_pub_key.clone()),
+			);
+			ensure!(is_allowlisted, Error::<T>::NodeIsNotAuthorized);
+
+			if let Some(judgement) = &allowlist.params.required_judgement {
+				ensure!(
+					T::IdentityVerifier::has_judgement(provider_id, judgement),
+					Error::<T>::NodeProviderIdentityNotVerified
+				);
+			}
+
+			Ok(())
+		}
+
+		fn do_decommission_cluster(
+			cluster_id: ClusterId,
+			buckets_migrate_to: Option<ClusterId>,
+		) -> DispatchResult {
+			ensure!(
+				!ClusterDecommissions::<T>::contains_key(cluster_id),
+				Error::<T>::ClusterIsDecommissioning
+			);
+			if let Some(target_cluster_id) = buckets_migrate_to {
+				ensure!(
+					target_cluster_id != cluster_id &&
+						Self::can_migrate_buckets_to(&target_cluster_id),
+					Error::<T>::InvalidBucketsMigrationTarget
+				);
+			}
+
+			let drain_nodes_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::DecommissionNoticePeriod::get());
+			// Nodes waiting to join the cluster are not admitted anymore.
+			let _ = AdmissionQueues::<T>::clear_prefix(cluster_id, u32::MAX, None);
+			ClusterDecommissions::<T>::insert(
+				cluster_id,
+				ClusterDecommission {
+					stage: ClusterDecommissionStage::DrainingNodes,
+					drain_nodes_at,
+					buckets_migrate_to,
+				},
+			);
+			Self::deposit_event(Event::<T>::ClusterDecommissionStarted {
+				cluster_id,
+				drain_nodes_at,
+			});
+
+			Ok(())
+		}
+
+		fn can_migrate_buckets_to(cluster_id: &ClusterId) -> bool {
+			Clusters::<T>::contains_key(cluster_id) &&
+				!ClusterDecommissions::<T>::contains_key(cluster_id)
+		}
+
+		fn decommission_clusters(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let limit = T::DecommissionBatchSize::get();
+			let step_weight = Self::decommission_step_weight(limit);
+			let mut consumed_weight = Weight::zero();
+
+			for (cluster_id, decommission) in ClusterDecommissions::<T>::iter() {
+				if remaining_weight.any_lt(consumed_weight.saturating_add(step_weight)) {
+					break;
+				}
+				if decommission.drain_nodes_at > now {
+					consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
+					continue;
+				}
+
+				consumed_weight = consumed_weight.saturating_add(step_weight);
+				// Clusters waiting for their eras to settle let other clusters progress.
+				if Self::do_decommission_step(cluster_id, decommission, limit) {
+					break;
+				}
+			}
+
+			consumed_weight
+		}
+
+		pub(crate) fn decommission_step_weight(limit: u32) -> Weight {
+			let clean_up_weight = T::ClusterDecommissionHandler::clean_up_weight(limit)
+				.saturating_add(T::DbWeight::get().writes(limit.into()))
+				.saturating_add(T::WeightInfo::remove_decommissioned_cluster(
+					T::MaxAllowlistLen::get(),
+				));
+			T::WeightInfo::drain_cluster_nodes(limit).max(clean_up_weight)
+		}
+
+		/// Processes up to `limit` nodes or state items of the decommissioned cluster and returns
+		/// whether the decommissioning has progressed.
+		pub(crate) fn do_decommission_step(
+			cluster_id: ClusterId,
+			mut decommission: ClusterDecommission<BlockNumberFor<T>>,
+			limit: u32,
+		) -> bool {
+			match decommission.stage {
+				ClusterDecommissionStage::DrainingNodes => {
+					let nodes: Vec<NodePubKey> = ClustersNodes::<T>::iter_key_prefix(cluster_id)
+						.take(limit as usize)
+						.collect();
+					for node_pub_key in nodes.iter() {
+						if let Err(err) = Self::do_release_node(cluster_id, node_pub_key.clone()) {
+							log::error!(
+								target: LOG_TARGET,
+								"Failed to remove node {:?} from decommissioned cluster {:?}: {:?}",
+								node_pub_key,
+								cluster_id,
+								err
+							);
+							// The node is dropped regardless, so that the next step does not pick
+							// it again and the draining does not stall.
+							ClustersNodes::<T>::remove(cluster_id, node_pub_key);
+						}
+					}
+
+					if (nodes.len() as u32) < limit {
+						decommission.stage = ClusterDecommissionStage::CleaningUp;
+						ClusterDecommissions::<T>::insert(cluster_id, decommission);
+						Self::deposit_event(Event::<T>::ClusterNodesDrained { cluster_id });
+					}
+
+					true
+				},
+				ClusterDecommissionStage::CleaningUp => {
+					let buckets_migrate_to =
+						decommission.buckets_migrate_to.filter(|target_cluster_id| {
+							Self::can_migrate_buckets_to(target_cluster_id)
+						});
+					match T::ClusterDecommissionHandler::clean_up(
+						&cluster_id,
+						buckets_migrate_to,
+						limit,
+					) {
+						Ok(processed) if processed < limit => {
+							// The cluster stake is withdrawn before the cluster is removed.
+							let is_unbonded = Clusters::<T>::get(cluster_id)
+								.map_or(true, |cluster| cluster.status == ClusterStatus::Unbonded);
+							// Pricing overrides are kept until the customers are charged for the
+							// last era of the cluster.
+							if is_unbonded &&
+								PricingOverrides::<T>::clear_prefix(cluster_id, limit, None)
+									.maybe_cursor
+									.is_none()
+							{
+								Self::do_remove_decommissioned_cluster(cluster_id);
+							}
+							is_unbonded
+						},
+						Ok(_) => true,
+						Err(err) => {
+							log::debug!(
+								target: LOG_TARGET,
+								"State of decommissioned cluster {:?} can not be removed yet: {:?}",
+								cluster_id,
+								err
+							);
+							false
+						},
+					}
+				},
+			}
+		}
+
+		fn do_set_pricing_override(
+			cluster_id: ClusterId,
+			target: PricingOverrideTarget<T::AccountId>,
+			pricing_override: Option<PricingOverride>,
+		) -> DispatchResult {
+			match pricing_override {
+				Some(pricing_override) => {
+					ensure!(pricing_override.is_valid(), Error::<T>::InvalidPricingOverride);
+					if let PricingOverrideTarget::Bucket(bucket_id) = target {
+						ensure!(
+							T::CustomerVisitor::get_bucket_cluster_id(&bucket_id)? == cluster_id,
+							Error::<T>::BucketDoesNotBelongToCluster
+						);
+					}
+					PricingOverrides::<T>::insert(cluster_id, target.clone(), pricing_override);
+					Self::deposit_event(Event::<T>::PricingOverrideSet { cluster_id, target });
+				},
+				None => {
+					PricingOverrides::<T>::take(cluster_id, target.clone())
+						.ok_or(Error::<T>::NoPricingOverride)?;
+					Self::deposit_event(Event::<T>::PricingOverrideUnset { cluster_id, target });
+				},
+			}
 
 			Ok(())
 		}
 
+		pub(crate) fn do_remove_decommissioned_cluster(cluster_id: ClusterId) {
+			let _ = NodeProviderAllowlistEntries::<T>::clear_prefix(
+				cluster_id,
+				T::MaxAllowlistLen::get(),
+				None,
+			);
+			let _ = NodeAuthorizationRequests::<T>::clear_prefix(
+				cluster_id,
+				T::MaxAllowlistLen::get(),
+				None,
+			);
+			// There is an entry per node mode at most.
+			let _ = ClustersNodesLimits::<T>::clear_prefix(cluster_id, u32::MAX, None);
+			let _ = ClustersNodesCounts::<T>::clear_prefix(cluster_id, u32::MAX, None);
+			NodeProviderAllowlists::<T>::remove(cluster_id);
+			PendingManagerTransfers::<T>::remove(cluster_id);
+			GovManagerTransfers::<T>::remove(cluster_id);
+			ClustersGovParams::<T>::remove(cluster_id);
+			ClustersNodesStats::<T>::remove(cluster_id);
+			Clusters::<T>::remove(cluster_id);
+			ClusterDecommissions::<T>::remove(cluster_id);
+			Self::deposit_event(Event::<T>::ClusterDecommissioned { cluster_id });
+		}
+
 		fn do_validate_node(
 			cluster_id: ClusterId,
 			node_pub_key: NodePubKey,
@@ -775,6 +1993,10 @@ pub mod pallet {
 			Ok(cluster.status)
 		}
 
+		fn is_decommissioning(cluster_id: &ClusterId) -> bool {
+			ClusterDecommissions::<T>::contains_key(cluster_id)
+		}
+
 		fn get_manager_and_reserve_id(
 			cluster_id: &ClusterId,
 		) -> Result<(T::AccountId, T::AccountId), DispatchError> {
@@ -810,6 +2032,7 @@ pub mod pallet {
 				unit_per_mb_streamed: cluster_protocol_params.unit_per_mb_streamed,
 				unit_per_put_request: cluster_protocol_params.unit_per_put_request,
 				unit_per_get_request: cluster_protocol_params.unit_per_get_request,
+				pricing_tiers: cluster_protocol_params.pricing_tiers,
 			})
 		}
 
@@ -888,6 +2111,27 @@ pub mod pallet {
 		fn end_unbond_cluster(cluster_id: &ClusterId) -> DispatchResult {
 			Self::do_end_unbond_cluster(cluster_id)
 		}
+
+		fn get_price_schedule(
+			cluster_id: &ClusterId,
+			customer_id: &T::AccountId,
+			bucket_id: BucketId,
+			time: i64,
+		) -> PriceSchedule {
+			let pricing = |target: PricingOverrideTarget<T::AccountId>| {
+				PricingOverrides::<T>::get(cluster_id, target)
+					.filter(|pricing_override| pricing_override.applies_at(time))
+					.map(|pricing_override| pricing_override.pricing)
+			};
+
+			pricing(PricingOverrideTarget::Bucket(bucket_id))
+				.map(PriceSchedule::Bucket)
+				.or_else(|| {
+					pricing(PricingOverrideTarget::Customer(customer_id.clone()))
+						.map(PriceSchedule::Customer)
+				})
+				.unwrap_or(PriceSchedule::Cluster)
+		}
 	}
 	impl<T: Config> ClusterValidator<T> for Pallet<T> {
 		fn set_last_paid_era(cluster_id: &ClusterId, era_id: DdcEra) -> Result<(), DispatchError> {
@@ -956,7 +2200,23 @@ pub mod pallet {
 		) -> Result<(), DispatchError> {
 			let cluster =
 				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
-			Self::do_remove_node(cluster, node_pub_key.clone())
+			Self::do_remove_node(cluster, node_pub_key.clone(), false)
+		}
+
+		fn start_node_leaving(
+			cluster_id: &ClusterId,
+			node_pub_key: &NodePubKey,
+		) -> Result<(), DispatchError> {
+			let cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			Self::do_remove_node(cluster, node_pub_key.clone(), true)
+		}
+
+		fn cancel_node_leaving(
+			cluster_id: &ClusterId,
+			node_pub_key: &NodePubKey,
+		) -> Result<(), DispatchError> {
+			Self::do_cancel_node_leaving(*cluster_id, node_pub_key.clone())
 		}
 
 		fn get_node_state(
@@ -991,6 +2251,51 @@ pub mod pallet {
 			}
 			Ok(clusters_ids)
 		}
+
+		fn get_aggregators(cluster_id: &ClusterId) -> Result<Vec<NodePubKey>, DispatchError> {
+			let aggregators = ClustersAggregators::<T>::iter_key_prefix(cluster_id).collect();
+			Ok(aggregators)
+		}
+
+		fn get_admission_queue(
+			cluster_id: &ClusterId,
+			mode: StorageNodeMode,
+		) -> Result<Vec<NodePubKey>, DispatchError> {
+			ensure!(Clusters::<T>::contains_key(cluster_id), Error::<T>::ClusterDoesNotExist);
+			Ok(AdmissionQueues::<T>::get(cluster_id, mode).into_inner())
+		}
+
+		fn propose_manager_transfer(
+			cluster_id: &ClusterId,
+			new_manager_id: &T::AccountId,
+		) -> DispatchResult {
+			let cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			Self::do_propose_manager_transfer(cluster, new_manager_id.clone(), true)
+		}
+
+		fn cancel_manager_transfer(cluster_id: &ClusterId) -> DispatchResult {
+			ensure!(Clusters::<T>::contains_key(cluster_id), Error::<T>::ClusterDoesNotExist);
+			Self::do_cancel_manager_transfer(*cluster_id)
+		}
+
+		fn update_cluster_params(
+			cluster_id: &ClusterId,
+			cluster_params: ClusterParams<T::AccountId>,
+		) -> DispatchResult {
+			let cluster =
+				Clusters::<T>::try_get(cluster_id).map_err(|_| Error::<T>::ClusterDoesNotExist)?;
+			Self::do_set_cluster_params(cluster, cluster_params)
+		}
+
+		fn set_pricing_override(
+			cluster_id: &ClusterId,
+			target: &PricingOverrideTarget<T::AccountId>,
+			pricing_override: Option<PricingOverride>,
+		) -> DispatchResult {
+			ensure!(Clusters::<T>::contains_key(cluster_id), Error::<T>::ClusterDoesNotExist);
+			Self::do_set_pricing_override(*cluster_id, target.clone(), pricing_override)
+		}
 	}
 
 	impl<T: Config> ClusterCreator<T, BalanceOf<T>> for Pallet<T>
@@ -496,7 +496,7 @@ pub mod v3 {
 			on_chain_version
 		);
 
-		if on_chain_version == 2 && current_version == 3 {
+		if on_chain_version == 2 {
 			let mut translated = 0u64;
 			let count = v2::Clusters::<T>::iter().count();
 			info!(
@@ -570,10 +570,10 @@ pub mod v3 {
 			let current_version = Pallet::<T>::current_storage_version();
 			let on_chain_version = Pallet::<T>::on_chain_storage_version();
 
-			ensure!(current_version == 3, "must_upgrade");
+			ensure!(on_chain_version >= 3, "must_upgrade");
 			ensure!(
-				current_version == on_chain_version,
-				"after migration, the current_version and on_chain_version should be the same"
+				current_version >= on_chain_version,
+				"after migration, the on_chain_version should not be ahead of the current_version"
 			);
 			Ok(())
 		}
@@ -666,3 +666,346 @@ pub mod v3 {
 		}
 	}
 }
+
+pub mod v4 {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Get, OnRuntimeUpgrade},
+		weights::Weight,
+	};
+	use sp_runtime::Perquintill;
+	use sp_std::marker::PhantomData;
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	use super::*;
+
+	#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq)]
+	pub struct ClusterProtocolParams<Balance, BlockNumber> {
+		pub treasury_share: Perquintill,
+		pub validators_share: Perquintill,
+		pub cluster_reserve_share: Perquintill,
+		pub storage_bond_size: Balance,
+		pub storage_chill_delay: BlockNumber,
+		pub storage_unbonding_delay: BlockNumber,
+		pub unit_per_mb_stored: u128,
+		pub unit_per_mb_streamed: u128,
+		pub unit_per_put_request: u128,
+		pub unit_per_get_request: u128,
+	}
+
+	#[storage_alias]
+	pub(super) type ClustersGovParams<T: Config> = StorageMap<
+		crate::Pallet<T>,
+		Twox64Concat,
+		ClusterId,
+		ClusterProtocolParams<BalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
+	/// Counts the nodes of each mode in clusters for the cluster nodes limits.
+	pub fn migrate_to_v4<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		let current_version = Pallet::<T>::current_storage_version();
+
+		info!(
+			target: LOG_TARGET,
+			"Running migration with current storage version {:?} / onchain {:?}",
+			current_version,
+			on_chain_version
+		);
+
+		if on_chain_version == 3 {
+			let mut counted = 0u64;
+			for (cluster_id, node_pub_key, _) in ClustersNodes::<T>::iter() {
+				counted.saturating_inc();
+				if let Ok(node) = T::NodeRepository::get(node_pub_key) {
+					let NodeProps::StorageProps(props) = node.get_props();
+					ClustersNodesCounts::<T>::mutate(cluster_id, props.mode, |count| {
+						*count = count.saturating_add(1)
+					});
+				}
+			}
+
+			// Update storage version.
+			StorageVersion::new(4).put::<Pallet<T>>();
+			info!(
+				target: LOG_TARGET,
+				"Counted {} cluster nodes, storage to version {:?}",
+				counted,
+				current_version
+			);
+
+			T::DbWeight::get().reads_writes(counted * 3 + 1, counted + 1)
+		} else {
+			info!(target: LOG_TARGET, " >>> Unused migration!");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	pub struct MigrateToV4<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrate_to_v4::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+			let prev_count = ClustersNodes::<T>::iter().count();
+
+			Ok((prev_count as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+			let prev_count: u64 = Decode::decode(&mut &prev_state[..])
+				.expect("pre_upgrade provides a valid state; qed");
+
+			let post_count: u64 = ClustersNodesCounts::<T>::iter_values().map(u64::from).sum();
+			ensure!(
+				prev_count == post_count,
+				"every cluster node should be counted once after the migration"
+			);
+
+			let current_version = Pallet::<T>::current_storage_version();
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+
+			ensure!(on_chain_version >= 4, "must_upgrade");
+			ensure!(
+				current_version >= on_chain_version,
+				"after migration, the on_chain_version should not be ahead of the current_version"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v5 {
+	use ddc_primitives::ClusterPricingTiers;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Get, OnRuntimeUpgrade},
+		weights::Weight,
+	};
+	use sp_runtime::Perquintill;
+	use sp_std::marker::PhantomData;
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	use super::*;
+
+	#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq)]
+	pub struct ClusterProtocolParams<Balance, BlockNumber> {
+		pub treasury_share: Perquintill,
+		pub validators_share: Perquintill,
+		pub cluster_reserve_share: Perquintill,
+		pub storage_bond_size: Balance,
+		pub storage_chill_delay: BlockNumber,
+		pub storage_unbonding_delay: BlockNumber,
+		pub unit_per_mb_stored: u128,
+		pub unit_per_mb_streamed: u128,
+		pub unit_per_put_request: u128,
+		pub unit_per_get_request: u128,
+		pub pricing_tiers: ClusterPricingTiers,
+	}
+
+	#[storage_alias]
+	pub(super) type ClustersGovParams<T: Config> = StorageMap<
+		crate::Pallet<T>,
+		Twox64Concat,
+		ClusterId,
+		ClusterProtocolParams<BalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
+	/// Sets empty pricing tiers in the protocol params of clusters, so they keep being charged by
+	/// the flat unit prices until the tiers are set through the cluster governance.
+	pub fn migrate_to_v5<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		let current_version = Pallet::<T>::current_storage_version();
+
+		info!(
+			target: LOG_TARGET,
+			"Running migration with current storage version {:?} / onchain {:?}",
+			current_version,
+			on_chain_version
+		);
+
+		if on_chain_version == 4 {
+			let mut translated = 0u64;
+			v5::ClustersGovParams::<T>::translate::<
+				v4::ClusterProtocolParams<BalanceOf<T>, BlockNumberFor<T>>,
+				_,
+			>(|cluster_id: ClusterId, old_params| {
+				info!(target: LOG_TARGET, "     Migrating protocol params for cluster ID {:?}...", cluster_id);
+				translated.saturating_inc();
+				Some(v5::ClusterProtocolParams {
+					treasury_share: old_params.treasury_share,
+					validators_share: old_params.validators_share,
+					cluster_reserve_share: old_params.cluster_reserve_share,
+					storage_bond_size: old_params.storage_bond_size,
+					storage_chill_delay: old_params.storage_chill_delay,
+					storage_unbonding_delay: old_params.storage_unbonding_delay,
+					unit_per_mb_stored: old_params.unit_per_mb_stored,
+					unit_per_mb_streamed: old_params.unit_per_mb_streamed,
+					unit_per_put_request: old_params.unit_per_put_request,
+					unit_per_get_request: old_params.unit_per_get_request,
+					pricing_tiers: Default::default(),
+				})
+			});
+
+			// Update storage version.
+			StorageVersion::new(5).put::<Pallet<T>>();
+			info!(
+				target: LOG_TARGET,
+				"Upgraded {} records, storage to version {:?}",
+				translated,
+				current_version
+			);
+
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		} else {
+			info!(target: LOG_TARGET, " >>> Unused migration!");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	pub struct MigrateToV5<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrate_to_v5::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+			let prev_count = v4::ClustersGovParams::<T>::iter().count();
+
+			Ok((prev_count as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+			let prev_count: u64 = Decode::decode(&mut &prev_state[..])
+				.expect("pre_upgrade provides a valid state; qed");
+
+			let post_count = v5::ClustersGovParams::<T>::iter().count() as u64;
+			ensure!(
+				prev_count == post_count,
+				"the cluster protocol params count before and after the migration should be the same"
+			);
+
+			let current_version = Pallet::<T>::current_storage_version();
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+
+			ensure!(on_chain_version >= 5, "must_upgrade");
+			ensure!(
+				current_version >= on_chain_version,
+				"after migration, the on_chain_version should not be ahead of the current_version"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v6 {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Get, OnRuntimeUpgrade},
+		weights::Weight,
+	};
+	use sp_std::marker::PhantomData;
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	use super::*;
+
+	/// Sets the node removal notice period in the protocol params of clusters to their chill
+	/// delay, which has been used as the notice period so far.
+	pub fn migrate_to_v6<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		let current_version = Pallet::<T>::current_storage_version();
+
+		info!(
+			target: LOG_TARGET,
+			"Running migration with current storage version {:?} / onchain {:?}",
+			current_version,
+			on_chain_version
+		);
+
+		if on_chain_version == 5 {
+			let mut translated = 0u64;
+			ClustersGovParams::<T>::translate::<
+				v5::ClusterProtocolParams<BalanceOf<T>, BlockNumberFor<T>>,
+				_,
+			>(|cluster_id: ClusterId, old_params| {
+				info!(target: LOG_TARGET, "     Migrating protocol params for cluster ID {:?}...", cluster_id);
+				translated.saturating_inc();
+				Some(ClusterProtocolParams {
+					treasury_share: old_params.treasury_share,
+					validators_share: old_params.validators_share,
+					cluster_reserve_share: old_params.cluster_reserve_share,
+					storage_bond_size: old_params.storage_bond_size,
+					storage_chill_delay: old_params.storage_chill_delay,
+					storage_unbonding_delay: old_params.storage_unbonding_delay,
+					node_removal_notice_period: old_params.storage_chill_delay,
+					unit_per_mb_stored: old_params.unit_per_mb_stored,
+					unit_per_mb_streamed: old_params.unit_per_mb_streamed,
+					unit_per_put_request: old_params.unit_per_put_request,
+					unit_per_get_request: old_params.unit_per_get_request,
+					pricing_tiers: old_params.pricing_tiers,
+				})
+			});
+
+			// Update storage version.
+			StorageVersion::new(6).put::<Pallet<T>>();
+			info!(
+				target: LOG_TARGET,
+				"Upgraded {} records, storage to version {:?}",
+				translated,
+				current_version
+			);
+
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		} else {
+			info!(target: LOG_TARGET, " >>> Unused migration!");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	pub struct MigrateToV6<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrate_to_v6::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+			let prev_count = v5::ClustersGovParams::<T>::iter().count();
+
+			Ok((prev_count as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+			let prev_count: u64 = Decode::decode(&mut &prev_state[..])
+				.expect("pre_upgrade provides a valid state; qed");
+
+			let post_count = ClustersGovParams::<T>::iter().count() as u64;
+			ensure!(
+				prev_count == post_count,
+				"the cluster protocol params count before and after the migration should be the same"
+			);
+
+			let current_version = Pallet::<T>::current_storage_version();
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+
+			ensure!(on_chain_version >= 6, "must_upgrade");
+			ensure!(
+				current_version >= on_chain_version,
+				"after migration, the on_chain_version should not be ahead of the current_version"
+			);
+			Ok(())
+		}
+	}
+}
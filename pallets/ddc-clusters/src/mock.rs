@@ -3,8 +3,13 @@
 #![allow(dead_code)]
 
 use ddc_primitives::{
-	traits::staking::{StakerCreator, StakingVisitor, StakingVisitorError},
-	ClusterId, NodePubKey,
+	traits::{
+		cluster::ClusterDecommissionHandler,
+		customer::CustomerVisitor,
+		identity::IdentityVerifier,
+		staking::{StakerCreator, StakingVisitor, StakingVisitorError},
+	},
+	BucketId, ClusterId, IdentityJudgement, NodePubKey,
 };
 use frame_support::{
 	construct_runtime, parameter_types,
@@ -20,7 +25,7 @@ use sp_runtime::{
 	traits::{
 		BlakeTwo256, Convert, Extrinsic as ExtrinsicT, IdentifyAccount, IdentityLookup, Verify,
 	},
-	BuildStorage, DispatchResult, MultiSignature, Perbill, Perquintill,
+	BuildStorage, DispatchError, DispatchResult, MultiSignature, Perbill, Perquintill,
 };
 
 use crate::{self as pallet_ddc_clusters, *};
@@ -140,6 +145,12 @@ where
 
 parameter_types! {
 	pub static ExistentialDeposit: Balance = 1;
+	pub static VerifiedIdentities: Vec<(AccountId, IdentityJudgement)> = vec![];
+	pub static ClusterStateSettled: bool = true;
+	pub static BucketsMigratedTo: Option<ClusterId> = None;
+	pub static PricingOverrideRequiresGov: bool = true;
+	pub static BucketClusterIds: Vec<(BucketId, ClusterId)> = vec![];
+	pub static ChillingNodes: Vec<NodePubKey> = vec![];
 }
 
 impl frame_system::Config for Test {
@@ -208,6 +219,15 @@ impl crate::pallet::Config for Test {
 	type MinErasureCodingRequiredLimit = ConstU32<4>;
 	type MinErasureCodingTotalLimit = ConstU32<6>;
 	type MinReplicationTotalLimit = ConstU32<3>;
+	type ManagerTransferRequiresGov = ConstBool<true>;
+	type MaxAllowlistLen = ConstU32<10>;
+	type IdentityVerifier = TestIdentityVerifier;
+	type DecommissionNoticePeriod = ConstU64<10>;
+	type DecommissionBatchSize = ConstU32<2>;
+	type ClusterDecommissionHandler = TestClusterDecommissionHandler;
+	type MaxAdmissionQueueLen = ConstU32<2>;
+	type PricingOverrideRequiresGov = PricingOverrideRequiresGov;
+	type CustomerVisitor = TestCustomerVisitor;
 }
 
 pub(crate) type DdcStakingCall = crate::Call<Test>;
@@ -225,12 +245,22 @@ impl<T: Config> StakingVisitor<T> for TestStakingVisitor {
 	fn has_stake(_node_pub_key: &NodePubKey) -> bool {
 		true
 	}
-	fn has_chilling_attempt(_node_pub_key: &NodePubKey) -> Result<bool, StakingVisitorError> {
-		Ok(false)
+	fn has_chilling_attempt(node_pub_key: &NodePubKey) -> Result<bool, StakingVisitorError> {
+		Ok(ChillingNodes::get().contains(node_pub_key))
 	}
 	fn stash_by_ctrl(_controller: &T::AccountId) -> Result<T::AccountId, StakingVisitorError> {
 		todo!()
 	}
+
+	fn get_node_active_stake(_node_pub_key: &NodePubKey) -> Result<u128, StakingVisitorError> {
+		todo!()
+	}
+
+	fn get_cluster_active_stake(
+		_cluster_stash: &T::AccountId,
+	) -> Result<u128, StakingVisitorError> {
+		todo!()
+	}
 }
 
 impl<T: Config> StakerCreator<T, BalanceOf<T>> for TestStaker {
@@ -251,6 +281,61 @@ impl<T: Config> StakerCreator<T, BalanceOf<T>> for TestStaker {
 	) -> DispatchResult {
 		Ok(())
 	}
+
+	fn set_cluster_controller(
+		_cluster_stash: &T::AccountId,
+		_cluster_controller: &T::AccountId,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
+pub struct TestIdentityVerifier;
+
+impl IdentityVerifier<AccountId> for TestIdentityVerifier {
+	fn has_judgement(who: &AccountId, judgement: &IdentityJudgement) -> bool {
+		VerifiedIdentities::get().iter().any(|(account, account_judgement)| {
+			account == who &&
+				(account_judgement == judgement ||
+					*account_judgement == IdentityJudgement::KnownGood)
+		})
+	}
+}
+
+pub struct TestClusterDecommissionHandler;
+
+impl ClusterDecommissionHandler for TestClusterDecommissionHandler {
+	fn clean_up_weight(_limit: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn clean_up(
+		_cluster_id: &ClusterId,
+		buckets_migrate_to: Option<ClusterId>,
+		_limit: u32,
+	) -> Result<u32, DispatchError> {
+		if !ClusterStateSettled::get() {
+			return Err(DispatchError::Other("Cluster state is not settled"));
+		}
+		BucketsMigratedTo::set(buckets_migrate_to);
+		Ok(0)
+	}
+}
+
+pub struct TestCustomerVisitor;
+
+impl<T: Config> CustomerVisitor<T> for TestCustomerVisitor {
+	fn get_bucket_owner(_bucket_id: &BucketId) -> Result<T::AccountId, DispatchError> {
+		unimplemented!()
+	}
+
+	fn get_bucket_cluster_id(bucket_id: &BucketId) -> Result<ClusterId, DispatchError> {
+		BucketClusterIds::get()
+			.into_iter()
+			.find(|(id, _)| id == bucket_id)
+			.map(|(_, cluster_id)| cluster_id)
+			.ok_or(DispatchError::Other("Bucket does not exist"))
+	}
 }
 
 pub struct ExtBuilder;
@@ -278,10 +363,12 @@ impl ExtBuilder {
 			storage_bond_size: 100,
 			storage_chill_delay: 50,
 			storage_unbonding_delay: 50,
+			node_removal_notice_period: 50,
 			unit_per_mb_stored: 10,
 			unit_per_mb_streamed: 10,
 			unit_per_put_request: 10,
 			unit_per_get_request: 10,
+			pricing_tiers: Default::default(),
 		};
 
 		let node_pub_key = NodePubKey::StoragePubKey(AccountId::from([0; 32]));
@@ -1,17 +1,17 @@
 //! DdcStaking pallet benchmarking.
 
 use ddc_primitives::{
-	ClusterId, ClusterParams, ClusterProtocolParams, NodeParams, NodePubKey, StorageNodeMode,
-	StorageNodeParams,
+	ClusterId, ClusterNodeKind, ClusterParams, ClusterProtocolParams, NodeParams, NodePubKey,
+	StorageNodeMode, StorageNodeParams,
 };
 pub use frame_benchmarking::{
 	account, benchmarks, impl_benchmark_test_suite, whitelist_account, whitelisted_caller,
 	BenchmarkError,
 };
 use frame_system::RawOrigin;
-use pallet_ddc_nodes::Node;
+use pallet_ddc_nodes::{Node, NodeProps, NodeRepository, NodeTrait};
 use sp_core::crypto::UncheckedFrom;
-use sp_runtime::Perquintill;
+use sp_runtime::{AccountId32, Perquintill};
 use sp_std::prelude::*;
 
 use crate::{Pallet as DdcClusters, *};
@@ -34,10 +34,12 @@ where
 			storage_bond_size: 100u32.into(),
 			storage_chill_delay: 50u32.into(),
 			storage_unbonding_delay: 50u32.into(),
+			node_removal_notice_period: 50u32.into(),
 			unit_per_mb_stored: 10,
 			unit_per_mb_streamed: 10,
 			unit_per_put_request: 10,
 			unit_per_get_request: 10,
+			pricing_tiers: Default::default(),
 		};
 
 	let _ = DdcClusters::<T>::create_cluster(
@@ -81,10 +83,12 @@ where
 			storage_bond_size: 100u32.into(),
 			storage_chill_delay: 50u32.into(),
 			storage_unbonding_delay: 50u32.into(),
+			node_removal_notice_period: 50u32.into(),
 			unit_per_mb_stored: 10,
 			unit_per_mb_streamed: 10,
 			unit_per_put_request: 10,
 			unit_per_get_request: 10,
+			pricing_tiers: Default::default(),
 		};
 
 	let _ = DdcClusters::<T>::create_cluster(
@@ -136,6 +140,108 @@ where
 	Ok(())
 }
 
+pub fn config_cluster_with_nodes<T: Config>(
+	user: T::AccountId,
+	cluster_id: ClusterId,
+	nodes_count: u32,
+	mode: StorageNodeMode,
+) -> Vec<NodePubKey>
+where
+	T::AccountId: UncheckedFrom<T::Hash> + AsRef<[u8]>,
+{
+	config_cluster::<T>(user.clone(), cluster_id);
+	<DdcClusters<T> as ClusterProtocol<T, BalanceOf<T>>>::bond_cluster(&cluster_id).unwrap();
+
+	let mut nodes = Vec::new();
+	for i in 0..nodes_count {
+		let mut bytes = [0u8; 32];
+		bytes[..4].copy_from_slice(&i.to_le_bytes());
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::from(bytes));
+		let storage_node_params = StorageNodeParams {
+			mode: mode.clone(),
+			host: vec![1u8; 255],
+			domain: vec![2u8; 255],
+			ssl: true,
+			http_port: 35000u16,
+			grpc_port: 25000u16,
+			p2p_port: 15000u16,
+		};
+		let node = Node::<T>::new(
+			node_pub_key.clone(),
+			user.clone(),
+			NodeParams::StorageParams(storage_node_params),
+		)
+		.unwrap();
+		T::NodeRepository::create(node).unwrap();
+		<DdcClusters<T> as ClusterManager<T>>::add_node(
+			&cluster_id,
+			&node_pub_key,
+			&ClusterNodeKind::External,
+		)
+		.unwrap();
+		nodes.push(node_pub_key);
+	}
+
+	nodes
+}
+
+/// Creates nodes with activated stake in the cluster and puts them to the admission queue.
+pub fn queue_nodes<T: Config>(
+	cluster_id: ClusterId,
+	mode: StorageNodeMode,
+	nodes_count: u32,
+) -> Vec<NodePubKey> {
+	let mut queue = AdmissionQueues::<T>::get(cluster_id, &mode);
+	let mut nodes = Vec::new();
+	for i in 0..nodes_count {
+		let provider = account::<T::AccountId>("provider", i, 0u32);
+		let balance = T::Currency::minimum_balance() * 1_000_000u32.into();
+		let _ = T::Currency::make_free_balance_be(&provider, balance);
+
+		let mut bytes = [1u8; 32];
+		bytes[..4].copy_from_slice(&i.to_le_bytes());
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::from(bytes));
+		let storage_node_params = StorageNodeParams {
+			mode: mode.clone(),
+			host: vec![1u8; 255],
+			domain: vec![2u8; 255],
+			ssl: true,
+			http_port: 35000u16,
+			grpc_port: 25000u16,
+			p2p_port: 15000u16,
+		};
+		let node = Node::<T>::new(
+			node_pub_key.clone(),
+			provider.clone(),
+			NodeParams::StorageParams(storage_node_params),
+		)
+		.unwrap();
+		T::NodeRepository::create(node).unwrap();
+		T::StakerCreator::bond_stake_and_participate(
+			provider.clone(),
+			provider,
+			node_pub_key.clone(),
+			10_000u32.into(),
+			cluster_id,
+		)
+		.unwrap();
+
+		queue.try_push(node_pub_key.clone()).unwrap();
+		nodes.push(node_pub_key);
+	}
+	AdmissionQueues::<T>::insert(cluster_id, mode, queue);
+
+	nodes
+}
+
+pub fn switch_node_to_dac_mode<T: Config>(node_pub_key: NodePubKey) {
+	let mut node = T::NodeRepository::get(node_pub_key).unwrap();
+	let NodeProps::StorageProps(mut props) = node.get_props();
+	props.mode = StorageNodeMode::DAC;
+	node.set_props(NodeProps::StorageProps(props)).unwrap();
+	T::NodeRepository::update(node).unwrap();
+}
+
 impl From<NodeProviderAuthContractError> for Box<BenchmarkError> {
 	fn from(error: NodeProviderAuthContractError) -> Self {
 		match error {
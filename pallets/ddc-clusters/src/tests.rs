@@ -3,13 +3,14 @@
 use codec::Compact;
 use ddc_primitives::{
 	traits::cluster::ClusterManager, ClusterBondingParams, ClusterFeesParams, ClusterId,
-	ClusterParams, ClusterPricingParams, NodeParams, NodePubKey, StorageNodeMode,
+	ClusterParams, ClusterPricingParams, ClusterPricingTiers, CustomerPricing, IdentityJudgement,
+	NodeParams, NodePubKey, PricingOverride, PricingOverrideTarget, PricingTier, StorageNodeMode,
 	StorageNodeParams,
 };
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, bounded_vec};
 use frame_system::Config;
 use hex_literal::hex;
-use sp_runtime::{traits::Hash, Perquintill};
+use sp_runtime::{traits::Hash, Percent, Perquintill};
 
 use super::{mock::*, *};
 
@@ -30,10 +31,12 @@ fn create_cluster_works() {
 			storage_bond_size: 100,
 			storage_chill_delay: 50,
 			storage_unbonding_delay: 50,
+			node_removal_notice_period: 50,
 			unit_per_mb_stored: 10,
 			unit_per_mb_streamed: 10,
 			unit_per_put_request: 10,
 			unit_per_get_request: 10,
+			pricing_tiers: Default::default(),
 		};
 
 		// Creating 1 cluster should work fine
@@ -82,6 +85,10 @@ fn create_cluster_works() {
 			created_cluster_protocol_params.storage_unbonding_delay,
 			cluster_protocol_params.storage_unbonding_delay
 		);
+		assert_eq!(
+			created_cluster_protocol_params.node_removal_notice_period,
+			cluster_protocol_params.node_removal_notice_period
+		);
 		assert_eq!(
 			created_cluster_protocol_params.unit_per_mb_stored,
 			cluster_protocol_params.unit_per_mb_stored
@@ -99,6 +106,32 @@ fn create_cluster_works() {
 			cluster_protocol_params.unit_per_get_request
 		);
 
+		// Creating cluster with pricing tiers out of order should fail
+		assert_noop!(
+			DdcClusters::create_cluster(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				ClusterId::from([2; 20]),
+				cluster_reserve_id.clone(),
+				ClusterParams {
+					node_provider_auth_contract: Some(auth_contract.clone()),
+					erasure_coding_required: 4,
+					erasure_coding_total: 6,
+					replication_total: 3
+				},
+				ClusterProtocolParams {
+					pricing_tiers: ClusterPricingTiers {
+						put_requests: bounded_vec![
+							PricingTier { threshold: 100, unit_price: 5 },
+							PricingTier { threshold: 10, unit_price: 1 }
+						],
+						..Default::default()
+					},
+					..cluster_protocol_params.clone()
+				}
+			),
+			Error::<Test>::InvalidPricingTiers
+		);
+
 		// Creating cluster with same id should fail
 		assert_noop!(
 			DdcClusters::create_cluster(
@@ -175,10 +208,12 @@ fn add_join_and_delete_node_works() {
 				storage_bond_size: 100,
 				storage_chill_delay: 50,
 				storage_unbonding_delay: 50,
+				node_removal_notice_period: 50,
 				unit_per_mb_stored: 10,
 				unit_per_mb_streamed: 10,
 				unit_per_put_request: 10,
 				unit_per_get_request: 10,
+				pricing_tiers: Default::default(),
 			}
 		));
 
@@ -339,6 +374,43 @@ fn add_join_and_delete_node_works() {
 			NodePubKey::StoragePubKey(node_pub_key.clone()),
 		));
 
+		// Node stays in the cluster during the notice period
+		System::assert_last_event(
+			Event::ClusterNodeLeaving {
+				cluster_id,
+				node_pub_key: NodePubKey::StoragePubKey(node_pub_key.clone()),
+			}
+			.into(),
+		);
+		assert_eq!(
+			DdcClusters::leaving_nodes(cluster_id, NodePubKey::StoragePubKey(node_pub_key.clone())),
+			Some(NodeLeaving { leave_at: 51, by_provider: false })
+		);
+		assert_noop!(
+			DdcClusters::remove_node(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				NodePubKey::StoragePubKey(node_pub_key.clone()),
+			),
+			Error::<Test>::NodeIsLeaving
+		);
+		assert_noop!(
+			<DdcClusters as ClusterManager<Test>>::cancel_node_leaving(
+				&cluster_id,
+				&NodePubKey::StoragePubKey(node_pub_key.clone()),
+			),
+			Error::<Test>::NodeLeavingIsNotCancellable
+		);
+		DdcClusters::on_idle(50, Weight::MAX);
+		assert!(<DdcClusters as ClusterManager<Test>>::contains_node(
+			&cluster_id,
+			&NodePubKey::StoragePubKey(node_pub_key.clone()),
+			None
+		));
+
+		// Node is removed once the notice period ends
+		DdcClusters::on_idle(51, Weight::MAX);
+
 		// Checking that event was emitted
 		System::assert_last_event(
 			Event::ClusterNodeRemoved {
@@ -347,6 +419,10 @@ fn add_join_and_delete_node_works() {
 			}
 			.into(),
 		);
+		assert_eq!(
+			DdcClusters::leaving_nodes(cluster_id, NodePubKey::StoragePubKey(node_pub_key.clone())),
+			None
+		);
 
 		// Remove node should fail
 		assert_noop!(
@@ -447,6 +523,172 @@ fn add_join_and_delete_node_works() {
 	})
 }
 
+#[test]
+fn add_and_remove_aggregator_works() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = AccountId::from([1; 32]);
+		let cluster_reserve_id = AccountId::from([2; 32]);
+		let dac_node_pub_key = NodePubKey::StoragePubKey(AccountId::from([3; 32]));
+		let storage_node_pub_key = NodePubKey::StoragePubKey(AccountId::from([4; 32]));
+		let unassigned_node_pub_key = NodePubKey::StoragePubKey(AccountId::from([5; 32]));
+
+		assert_ok!(DdcClusters::create_cluster(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			cluster_reserve_id.clone(),
+			ClusterParams {
+				node_provider_auth_contract: None,
+				erasure_coding_required: 4,
+				erasure_coding_total: 6,
+				replication_total: 3
+			},
+			ClusterProtocolParams {
+				treasury_share: Perquintill::from_float(0.05),
+				validators_share: Perquintill::from_float(0.01),
+				cluster_reserve_share: Perquintill::from_float(0.02),
+				storage_bond_size: 100,
+				storage_chill_delay: 50,
+				storage_unbonding_delay: 50,
+				node_removal_notice_period: 50,
+				unit_per_mb_stored: 10,
+				unit_per_mb_streamed: 10,
+				unit_per_put_request: 10,
+				unit_per_get_request: 10,
+				pricing_tiers: Default::default(),
+			}
+		));
+		assert_ok!(DdcClusters::bond_cluster(&cluster_id));
+
+		let storage_node_params = StorageNodeParams {
+			mode: StorageNodeMode::Storage,
+			host: vec![1u8; 255],
+			domain: vec![2u8; 255],
+			ssl: true,
+			http_port: 35000u16,
+			grpc_port: 25000u16,
+			p2p_port: 15000u16,
+		};
+		let dac_node_params =
+			StorageNodeParams { mode: StorageNodeMode::DAC, ..storage_node_params.clone() };
+
+		for (node_pub_key, params) in [
+			(dac_node_pub_key.clone(), dac_node_params.clone()),
+			(storage_node_pub_key.clone(), storage_node_params),
+			(unassigned_node_pub_key.clone(), dac_node_params),
+		] {
+			assert_ok!(DdcNodes::create_node(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				node_pub_key,
+				NodeParams::StorageParams(params),
+			));
+		}
+		for node_pub_key in [dac_node_pub_key.clone(), storage_node_pub_key.clone()] {
+			assert_ok!(DdcClusters::add_node(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				node_pub_key,
+				ClusterNodeKind::Genesis
+			));
+		}
+
+		// Not Cluster Manager
+		assert_noop!(
+			DdcClusters::add_aggregator(
+				RuntimeOrigin::signed(cluster_reserve_id.clone()),
+				cluster_id,
+				dac_node_pub_key.clone(),
+			),
+			Error::<Test>::OnlyClusterManager
+		);
+
+		// Node is not assigned to the cluster
+		assert_noop!(
+			DdcClusters::add_aggregator(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				unassigned_node_pub_key,
+			),
+			Error::<Test>::NodeIsNotAssignedToCluster
+		);
+
+		// Node is not running in DAC mode
+		assert_noop!(
+			DdcClusters::add_aggregator(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				storage_node_pub_key,
+			),
+			Error::<Test>::NodeIsNotDAC
+		);
+
+		assert_eq!(
+			<DdcClusters as ClusterManager<Test>>::get_aggregators(&cluster_id).unwrap(),
+			vec![]
+		);
+
+		// Aggregator added successfully
+		assert_ok!(DdcClusters::add_aggregator(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			dac_node_pub_key.clone(),
+		));
+		System::assert_last_event(
+			Event::ClusterAggregatorAdded { cluster_id, node_pub_key: dac_node_pub_key.clone() }
+				.into(),
+		);
+		assert_eq!(
+			<DdcClusters as ClusterManager<Test>>::get_aggregators(&cluster_id).unwrap(),
+			vec![dac_node_pub_key.clone()]
+		);
+
+		assert_noop!(
+			DdcClusters::add_aggregator(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				dac_node_pub_key.clone(),
+			),
+			Error::<Test>::AggregatorAlreadyRegistered
+		);
+
+		// Aggregator removed successfully
+		assert_ok!(DdcClusters::remove_aggregator(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			dac_node_pub_key.clone(),
+		));
+		System::assert_last_event(
+			Event::ClusterAggregatorRemoved { cluster_id, node_pub_key: dac_node_pub_key.clone() }
+				.into(),
+		);
+		assert_noop!(
+			DdcClusters::remove_aggregator(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				dac_node_pub_key.clone(),
+			),
+			Error::<Test>::AggregatorIsNotRegistered
+		);
+
+		// Removing the node from the cluster deregisters it as an aggregator
+		assert_ok!(DdcClusters::add_aggregator(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			dac_node_pub_key.clone(),
+		));
+		assert_ok!(DdcClusters::remove_node(
+			RuntimeOrigin::signed(cluster_manager_id),
+			cluster_id,
+			dac_node_pub_key.clone(),
+		));
+		assert!(ClustersAggregators::<Test>::contains_key(cluster_id, dac_node_pub_key.clone()));
+		DdcClusters::on_idle(51, Weight::MAX);
+		assert!(!ClustersAggregators::<Test>::contains_key(cluster_id, dac_node_pub_key));
+	})
+}
+
 #[test]
 fn set_cluster_params_works() {
 	ExtBuilder.build_and_execute(|| {
@@ -491,10 +733,12 @@ fn set_cluster_params_works() {
 				storage_bond_size: 100,
 				storage_chill_delay: 50,
 				storage_unbonding_delay: 50,
+				node_removal_notice_period: 50,
 				unit_per_mb_stored: 10,
 				unit_per_mb_streamed: 10,
 				unit_per_put_request: 10,
 				unit_per_get_request: 10,
+				pricing_tiers: Default::default(),
 			}
 		));
 
@@ -612,10 +856,12 @@ fn set_last_validated_era_works() {
 				storage_bond_size: 100,
 				storage_chill_delay: 50,
 				storage_unbonding_delay: 50,
+				node_removal_notice_period: 50,
 				unit_per_mb_stored: 10,
 				unit_per_mb_streamed: 10,
 				unit_per_put_request: 10,
 				unit_per_get_request: 10,
+				pricing_tiers: Default::default(),
 			}
 		));
 
@@ -647,10 +893,12 @@ fn cluster_visitor_works() {
 			storage_bond_size: 100,
 			storage_chill_delay: 50,
 			storage_unbonding_delay: 50,
+			node_removal_notice_period: 50,
 			unit_per_mb_stored: 10,
 			unit_per_mb_streamed: 10,
 			unit_per_put_request: 10,
 			unit_per_get_request: 10,
+			pricing_tiers: Default::default(),
 		};
 
 		// Creating 1 cluster should work fine
@@ -696,6 +944,7 @@ fn cluster_visitor_works() {
 				unit_per_mb_streamed: 10,
 				unit_per_put_request: 10,
 				unit_per_get_request: 10,
+				pricing_tiers: Default::default(),
 			}
 		);
 
@@ -760,6 +1009,7 @@ fn cluster_visitor_works() {
 				storage_bond_size: 100,
 				storage_chill_delay: 50,
 				storage_unbonding_delay: 50,
+				node_removal_notice_period: 50,
 			}
 		);
 	})
@@ -782,10 +1032,12 @@ fn cluster_creator_works() {
 			storage_bond_size: 100,
 			storage_chill_delay: 50,
 			storage_unbonding_delay: 50,
+			node_removal_notice_period: 50,
 			unit_per_mb_stored: 10,
 			unit_per_mb_streamed: 10,
 			unit_per_put_request: 10,
 			unit_per_get_request: 10,
+			pricing_tiers: Default::default(),
 		};
 
 		assert_ok!(<DdcClusters as ClusterCreator<Test, BalanceOf<Test>>>::create_cluster(
@@ -805,3 +1057,1220 @@ fn cluster_creator_works() {
 		assert!(ClustersGovParams::<Test>::contains_key(cluster_id));
 	})
 }
+
+#[test]
+fn manager_transfer_works() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = AccountId::from([1; 32]);
+		let cluster_reserve_id = AccountId::from([2; 32]);
+		let new_manager_id = AccountId::from([3; 32]);
+
+		assert_ok!(DdcClusters::create_cluster(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			cluster_reserve_id,
+			ClusterParams {
+				node_provider_auth_contract: None,
+				erasure_coding_required: 4,
+				erasure_coding_total: 6,
+				replication_total: 3
+			},
+			ClusterProtocolParams::default()
+		));
+
+		// Only the cluster manager can propose a new manager
+		assert_noop!(
+			DdcClusters::propose_manager_transfer(
+				RuntimeOrigin::signed(new_manager_id.clone()),
+				cluster_id,
+				new_manager_id.clone()
+			),
+			Error::<Test>::OnlyClusterManager
+		);
+		assert_noop!(
+			DdcClusters::propose_manager_transfer(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				cluster_manager_id.clone()
+			),
+			Error::<Test>::AlreadyClusterManager
+		);
+		assert_noop!(
+			DdcClusters::accept_manager_transfer(
+				RuntimeOrigin::signed(new_manager_id.clone()),
+				cluster_id
+			),
+			Error::<Test>::NoPendingManagerTransfer
+		);
+
+		assert_ok!(DdcClusters::propose_manager_transfer(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			new_manager_id.clone()
+		));
+		System::assert_last_event(
+			Event::ClusterManagerTransferProposed {
+				cluster_id,
+				new_manager_id: new_manager_id.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(
+			DdcClusters::pending_manager_transfers(cluster_id),
+			Some(new_manager_id.clone())
+		);
+
+		// The proposed manager is the only one who can accept the transfer
+		assert_noop!(
+			DdcClusters::accept_manager_transfer(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id
+			),
+			Error::<Test>::OnlyProposedManager
+		);
+
+		// The pending transfer can be cancelled by the cluster manager
+		assert_ok!(DdcClusters::cancel_manager_transfer(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id
+		));
+		System::assert_last_event(Event::ClusterManagerTransferCancelled { cluster_id }.into());
+		assert_eq!(DdcClusters::pending_manager_transfers(cluster_id), None);
+
+		assert_ok!(DdcClusters::propose_manager_transfer(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			new_manager_id.clone()
+		));
+		assert_ok!(DdcClusters::accept_manager_transfer(
+			RuntimeOrigin::signed(new_manager_id.clone()),
+			cluster_id
+		));
+		System::assert_last_event(
+			Event::ClusterManagerTransferred { cluster_id, manager_id: new_manager_id.clone() }
+				.into(),
+		);
+		assert_eq!(DdcClusters::clusters(cluster_id).unwrap().manager_id, new_manager_id);
+		assert_eq!(DdcClusters::pending_manager_transfers(cluster_id), None);
+		assert_eq!(
+			<DdcClusters as ClusterManager<Test>>::get_manager_account_id(&cluster_id),
+			Ok(new_manager_id.clone())
+		);
+
+		// The manager of an activated cluster is changed through the cluster governance
+		Clusters::<Test>::mutate(cluster_id, |cluster| {
+			cluster.as_mut().unwrap().set_status(ClusterStatus::Activated);
+		});
+		assert_noop!(
+			DdcClusters::propose_manager_transfer(
+				RuntimeOrigin::signed(new_manager_id.clone()),
+				cluster_id,
+				cluster_manager_id.clone()
+			),
+			Error::<Test>::ManagerTransferRequiresGov
+		);
+		assert_ok!(<DdcClusters as ClusterManager<Test>>::propose_manager_transfer(
+			&cluster_id,
+			&cluster_manager_id
+		));
+		assert_ok!(DdcClusters::accept_manager_transfer(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id
+		));
+		assert_eq!(DdcClusters::clusters(cluster_id).unwrap().manager_id, cluster_manager_id);
+		assert_eq!(DdcClusters::gov_manager_transfers(cluster_id), None);
+
+		// The transfer proposed through the cluster governance is cancelled through it as well
+		assert_ok!(<DdcClusters as ClusterManager<Test>>::propose_manager_transfer(
+			&cluster_id,
+			&new_manager_id
+		));
+		assert_noop!(
+			DdcClusters::cancel_manager_transfer(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id
+			),
+			Error::<Test>::ManagerTransferRequiresGov
+		);
+		assert_ok!(<DdcClusters as ClusterManager<Test>>::cancel_manager_transfer(&cluster_id));
+		System::assert_last_event(Event::ClusterManagerTransferCancelled { cluster_id }.into());
+		assert_eq!(DdcClusters::pending_manager_transfers(cluster_id), None);
+		assert_eq!(DdcClusters::gov_manager_transfers(cluster_id), None);
+		assert_noop!(
+			<DdcClusters as ClusterManager<Test>>::cancel_manager_transfer(&cluster_id),
+			Error::<Test>::NoPendingManagerTransfer
+		);
+	})
+}
+
+#[test]
+fn node_provider_allowlist_works() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = AccountId::from([1; 32]);
+		let cluster_reserve_id = AccountId::from([2; 32]);
+		let node_provider_id = AccountId::from([3; 32]);
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId::from([4; 32]));
+		let node_pub_key2 = NodePubKey::StoragePubKey(AccountId::from([5; 32]));
+
+		assert_ok!(DdcClusters::create_cluster(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			cluster_reserve_id,
+			ClusterParams {
+				node_provider_auth_contract: None,
+				erasure_coding_required: 4,
+				erasure_coding_total: 6,
+				replication_total: 3
+			},
+			ClusterProtocolParams::default()
+		));
+		Clusters::<Test>::mutate(cluster_id, |cluster| {
+			cluster.as_mut().unwrap().set_status(ClusterStatus::Bonded);
+		});
+
+		let storage_node_params = StorageNodeParams {
+			mode: StorageNodeMode::Storage,
+			host: vec![1u8; 255],
+			domain: vec![2u8; 255],
+			ssl: true,
+			http_port: 35000u16,
+			grpc_port: 25000u16,
+			p2p_port: 15000u16,
+		};
+		for key in [node_pub_key.clone(), node_pub_key2.clone()] {
+			assert_ok!(DdcNodes::create_node(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				key,
+				NodeParams::StorageParams(storage_node_params.clone()),
+			));
+		}
+
+		// Cluster has neither an allowlist nor an auth smart contract
+		assert_noop!(
+			DdcClusters::join_cluster(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				node_pub_key.clone(),
+			),
+			Error::<Test>::NodeIsNotAuthorized
+		);
+		assert_noop!(
+			DdcClusters::add_allowlist_entry(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				AllowlistEntry::Node(node_pub_key.clone())
+			),
+			Error::<Test>::NodeProviderAllowlistNotSet
+		);
+
+		// Only the cluster manager sets the allowlist
+		let params =
+			NodeProviderAllowlistParams { accepts_requests: false, required_judgement: None };
+		assert_noop!(
+			DdcClusters::set_node_provider_allowlist(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				Some(params.clone())
+			),
+			Error::<Test>::OnlyClusterManager
+		);
+		assert_ok!(DdcClusters::set_node_provider_allowlist(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			Some(params)
+		));
+		System::assert_last_event(Event::NodeProviderAllowlistSet { cluster_id }.into());
+
+		// Node is not in the allowlist and requests are not accepted
+		assert_noop!(
+			DdcClusters::join_cluster(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				node_pub_key.clone(),
+			),
+			Error::<Test>::NodeIsNotAuthorized
+		);
+		assert_noop!(
+			DdcClusters::request_node_authorization(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				node_pub_key.clone(),
+			),
+			Error::<Test>::NodeAuthorizationRequestsNotAccepted
+		);
+
+		assert_ok!(DdcClusters::set_node_provider_allowlist(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			Some(NodeProviderAllowlistParams {
+				accepts_requests: true,
+				required_judgement: Some(IdentityJudgement::Reasonable),
+			})
+		));
+
+		// Only the node provider requests the authorization of the node
+		assert_noop!(
+			DdcClusters::request_node_authorization(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				node_pub_key.clone(),
+			),
+			Error::<Test>::OnlyNodeProvider
+		);
+		for key in [node_pub_key.clone(), node_pub_key2.clone()] {
+			assert_ok!(DdcClusters::request_node_authorization(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				key.clone(),
+			));
+			System::assert_last_event(
+				Event::NodeAuthorizationRequested { cluster_id, node_pub_key: key }.into(),
+			);
+		}
+		assert_noop!(
+			DdcClusters::request_node_authorization(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				node_pub_key.clone(),
+			),
+			Error::<Test>::NodeAuthorizationRequestAlreadyExists
+		);
+		assert_eq!(DdcClusters::node_provider_allowlists(cluster_id).unwrap().requests_count, 2);
+
+		// Cluster manager approves the first node and rejects the second one
+		assert_noop!(
+			DdcClusters::resolve_node_authorization(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				node_pub_key.clone(),
+				true
+			),
+			Error::<Test>::OnlyClusterManager
+		);
+		assert_ok!(DdcClusters::resolve_node_authorization(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			node_pub_key.clone(),
+			true
+		));
+		System::assert_last_event(
+			Event::NodeAuthorizationApproved { cluster_id, node_pub_key: node_pub_key.clone() }
+				.into(),
+		);
+		assert_ok!(DdcClusters::resolve_node_authorization(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			node_pub_key2.clone(),
+			false
+		));
+		System::assert_last_event(
+			Event::NodeAuthorizationRejected { cluster_id, node_pub_key: node_pub_key2.clone() }
+				.into(),
+		);
+		assert_noop!(
+			DdcClusters::resolve_node_authorization(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				node_pub_key2.clone(),
+				true
+			),
+			Error::<Test>::NodeAuthorizationRequestDoesNotExist
+		);
+		let allowlist = DdcClusters::node_provider_allowlists(cluster_id).unwrap();
+		assert_eq!((allowlist.entries_count, allowlist.requests_count), (1, 0));
+
+		// Node provider has no required identity judgement
+		assert_noop!(
+			DdcClusters::join_cluster(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				node_pub_key.clone(),
+			),
+			Error::<Test>::NodeProviderIdentityNotVerified
+		);
+		VerifiedIdentities::set(vec![(node_provider_id.clone(), IdentityJudgement::KnownGood)]);
+		assert_ok!(DdcClusters::join_cluster(
+			RuntimeOrigin::signed(node_provider_id.clone()),
+			cluster_id,
+			node_pub_key.clone(),
+		));
+		assert!(ClustersNodes::<Test>::contains_key(cluster_id, node_pub_key.clone()));
+
+		// Node provider account in the allowlist authorizes all its nodes
+		assert_noop!(
+			DdcClusters::join_cluster(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				node_pub_key2.clone(),
+			),
+			Error::<Test>::NodeIsNotAuthorized
+		);
+		assert_ok!(DdcClusters::add_allowlist_entry(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			AllowlistEntry::Provider(node_provider_id.clone())
+		));
+		assert_noop!(
+			DdcClusters::add_allowlist_entry(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				AllowlistEntry::Provider(node_provider_id.clone())
+			),
+			Error::<Test>::AllowlistEntryAlreadyExists
+		);
+		assert_ok!(DdcClusters::join_cluster(
+			RuntimeOrigin::signed(node_provider_id.clone()),
+			cluster_id,
+			node_pub_key2.clone(),
+		));
+
+		assert_ok!(DdcClusters::remove_allowlist_entry(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			AllowlistEntry::Provider(node_provider_id.clone())
+		));
+		System::assert_last_event(
+			Event::AllowlistEntryRemoved {
+				cluster_id,
+				entry: AllowlistEntry::Provider(node_provider_id.clone()),
+			}
+			.into(),
+		);
+		assert_noop!(
+			DdcClusters::remove_allowlist_entry(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				AllowlistEntry::Provider(node_provider_id.clone())
+			),
+			Error::<Test>::AllowlistEntryDoesNotExist
+		);
+
+		// Allowlist is bounded
+		for i in 10..19 {
+			assert_ok!(DdcClusters::add_allowlist_entry(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				AllowlistEntry::Provider(AccountId::from([i; 32]))
+			));
+		}
+		assert_noop!(
+			DdcClusters::add_allowlist_entry(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				AllowlistEntry::Provider(AccountId::from([19; 32]))
+			),
+			Error::<Test>::NodeProviderAllowlistIsFull
+		);
+
+		// Removing the allowlist drops its entries
+		assert_ok!(DdcClusters::set_node_provider_allowlist(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			None
+		));
+		System::assert_last_event(Event::NodeProviderAllowlistRemoved { cluster_id }.into());
+		assert_eq!(DdcClusters::node_provider_allowlists(cluster_id), None);
+		assert_eq!(NodeProviderAllowlistEntries::<Test>::iter_prefix(cluster_id).count(), 0);
+	})
+}
+
+#[test]
+fn decommission_cluster_works() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_id2 = ClusterId::from([2; 20]);
+		let cluster_manager_id = AccountId::from([1; 32]);
+		let cluster_reserve_id = AccountId::from([2; 32]);
+		let node_provider_id = AccountId::from([3; 32]);
+		let node_pub_keys: Vec<NodePubKey> =
+			(4..7).map(|i| NodePubKey::StoragePubKey(AccountId::from([i; 32]))).collect();
+
+		for id in [cluster_id, cluster_id2] {
+			assert_ok!(DdcClusters::create_cluster(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				id,
+				cluster_reserve_id.clone(),
+				ClusterParams {
+					node_provider_auth_contract: None,
+					erasure_coding_required: 4,
+					erasure_coding_total: 6,
+					replication_total: 3
+				},
+				ClusterProtocolParams::default()
+			));
+		}
+		Clusters::<Test>::mutate(cluster_id, |cluster| {
+			cluster.as_mut().unwrap().set_status(ClusterStatus::Bonded);
+		});
+
+		let storage_node_params = StorageNodeParams {
+			mode: StorageNodeMode::Storage,
+			host: vec![1u8; 255],
+			domain: vec![2u8; 255],
+			ssl: true,
+			http_port: 35000u16,
+			grpc_port: 25000u16,
+			p2p_port: 15000u16,
+		};
+		for key in node_pub_keys.iter() {
+			assert_ok!(DdcNodes::create_node(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				key.clone(),
+				NodeParams::StorageParams(storage_node_params.clone()),
+			));
+		}
+		for key in node_pub_keys.iter().take(2) {
+			assert_ok!(<DdcClusters as ClusterManager<Test>>::add_node(
+				&cluster_id,
+				key,
+				&ClusterNodeKind::External
+			));
+		}
+
+		// Only the cluster manager can decommission the cluster
+		assert_noop!(
+			DdcClusters::decommission_cluster(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				None
+			),
+			Error::<Test>::OnlyClusterManager
+		);
+		// Buckets can not be migrated to the decommissioned cluster itself
+		assert_noop!(
+			DdcClusters::decommission_cluster(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				Some(cluster_id)
+			),
+			Error::<Test>::InvalidBucketsMigrationTarget
+		);
+
+		assert_ok!(DdcClusters::decommission_cluster(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			Some(cluster_id2)
+		));
+		System::assert_last_event(
+			Event::ClusterDecommissionStarted { cluster_id, drain_nodes_at: 11 }.into(),
+		);
+		assert!(<DdcClusters as ClusterQuery<Test>>::is_decommissioning(&cluster_id));
+
+		assert_noop!(
+			DdcClusters::decommission_cluster(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				None
+			),
+			Error::<Test>::ClusterIsDecommissioning
+		);
+		assert_noop!(
+			<DdcClusters as ClusterManager<Test>>::add_node(
+				&cluster_id,
+				&node_pub_keys[2],
+				&ClusterNodeKind::External
+			),
+			Error::<Test>::ClusterIsDecommissioning
+		);
+		// Buckets can not be migrated to a decommissioned cluster
+		assert_noop!(
+			DdcClusters::decommission_cluster(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id2,
+				Some(cluster_id)
+			),
+			Error::<Test>::InvalidBucketsMigrationTarget
+		);
+
+		// Nodes are kept during the notice period
+		DdcClusters::on_idle(10, Weight::MAX);
+		assert_eq!(ClustersNodes::<Test>::iter_key_prefix(cluster_id).count(), 2);
+
+		// Nodes are drained in batches
+		System::set_block_number(11);
+		DdcClusters::on_idle(11, Weight::MAX);
+		assert_eq!(ClustersNodes::<Test>::iter_key_prefix(cluster_id).count(), 0);
+		assert_eq!(
+			DdcClusters::cluster_decommissions(cluster_id).unwrap().stage,
+			ClusterDecommissionStage::DrainingNodes
+		);
+		for key in node_pub_keys.iter().take(2) {
+			assert_eq!(
+				<DdcNodes as NodeRepository<Test>>::get(key.clone()).unwrap().get_cluster_id(),
+				&None
+			);
+		}
+
+		DdcClusters::on_idle(11, Weight::MAX);
+		System::assert_last_event(Event::ClusterNodesDrained { cluster_id }.into());
+		assert_eq!(
+			DdcClusters::cluster_decommissions(cluster_id).unwrap().stage,
+			ClusterDecommissionStage::CleaningUp
+		);
+
+		// The state is kept until the eras of the cluster are settled
+		ClusterStateSettled::set(false);
+		DdcClusters::on_idle(12, Weight::MAX);
+		assert!(Clusters::<Test>::contains_key(cluster_id));
+
+		// The state is kept until the cluster stake is unbonded
+		ClusterStateSettled::set(true);
+		DdcClusters::on_idle(12, Weight::MAX);
+		assert!(Clusters::<Test>::contains_key(cluster_id));
+
+		Clusters::<Test>::mutate(cluster_id, |cluster| {
+			cluster.as_mut().unwrap().set_status(ClusterStatus::Unbonded);
+		});
+		DdcClusters::on_idle(12, Weight::MAX);
+		System::assert_last_event(Event::ClusterDecommissioned { cluster_id }.into());
+		assert_eq!(BucketsMigratedTo::get(), Some(cluster_id2));
+		assert!(!Clusters::<Test>::contains_key(cluster_id));
+		assert!(!ClustersNodesStats::<Test>::contains_key(cluster_id));
+		assert!(!ClusterDecommissions::<Test>::contains_key(cluster_id));
+		assert!(Clusters::<Test>::contains_key(cluster_id2));
+	})
+}
+
+#[test]
+fn decommission_drains_nodes_failing_to_be_removed() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = AccountId::from([1; 32]);
+		let cluster_reserve_id = AccountId::from([2; 32]);
+		let node_provider_id = AccountId::from([3; 32]);
+		let node_pub_keys: Vec<NodePubKey> =
+			(4..6).map(|i| NodePubKey::StoragePubKey(AccountId::from([i; 32]))).collect();
+
+		assert_ok!(DdcClusters::create_cluster(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			cluster_reserve_id,
+			ClusterParams {
+				node_provider_auth_contract: None,
+				erasure_coding_required: 4,
+				erasure_coding_total: 6,
+				replication_total: 3
+			},
+			ClusterProtocolParams::default()
+		));
+
+		let storage_node_params = StorageNodeParams {
+			mode: StorageNodeMode::Storage,
+			host: vec![1u8; 255],
+			domain: vec![2u8; 255],
+			ssl: true,
+			http_port: 35000u16,
+			grpc_port: 25000u16,
+			p2p_port: 15000u16,
+		};
+		for key in node_pub_keys.iter() {
+			assert_ok!(DdcNodes::create_node(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				key.clone(),
+				NodeParams::StorageParams(storage_node_params.clone()),
+			));
+			assert_ok!(<DdcClusters as ClusterManager<Test>>::add_node(
+				&cluster_id,
+				key,
+				&ClusterNodeKind::External
+			));
+		}
+
+		assert_ok!(DdcClusters::decommission_cluster(
+			RuntimeOrigin::signed(cluster_manager_id),
+			cluster_id,
+			None
+		));
+
+		// Removal of the nodes fails on the inconsistent node stats
+		ClustersNodesStats::<Test>::insert(cluster_id, ClusterNodesStats::default());
+
+		System::set_block_number(11);
+		DdcClusters::on_idle(11, Weight::MAX);
+		assert_eq!(ClustersNodes::<Test>::iter_key_prefix(cluster_id).count(), 0);
+
+		DdcClusters::on_idle(11, Weight::MAX);
+		System::assert_last_event(Event::ClusterNodesDrained { cluster_id }.into());
+		assert_eq!(
+			DdcClusters::cluster_decommissions(cluster_id).unwrap().stage,
+			ClusterDecommissionStage::CleaningUp
+		);
+	})
+}
+
+#[test]
+fn node_leaving_by_provider_can_be_cancelled() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = AccountId::from([1; 32]);
+		let cluster_reserve_id = AccountId::from([2; 32]);
+		let node_provider_id = AccountId::from([3; 32]);
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId::from([4; 32]));
+
+		assert_ok!(DdcClusters::create_cluster(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			cluster_reserve_id,
+			ClusterParams {
+				node_provider_auth_contract: None,
+				erasure_coding_required: 4,
+				erasure_coding_total: 6,
+				replication_total: 3
+			},
+			ClusterProtocolParams { node_removal_notice_period: 10, ..Default::default() }
+		));
+		Clusters::<Test>::mutate(cluster_id, |cluster| {
+			cluster.as_mut().unwrap().set_status(ClusterStatus::Bonded);
+		});
+		assert_ok!(DdcNodes::create_node(
+			RuntimeOrigin::signed(node_provider_id),
+			node_pub_key.clone(),
+			NodeParams::StorageParams(StorageNodeParams {
+				mode: StorageNodeMode::Storage,
+				host: vec![1u8; 255],
+				domain: vec![2u8; 255],
+				ssl: true,
+				http_port: 35000u16,
+				grpc_port: 25000u16,
+				p2p_port: 15000u16,
+			}),
+		));
+		assert_ok!(<DdcClusters as ClusterManager<Test>>::add_node(
+			&cluster_id,
+			&node_pub_key,
+			&ClusterNodeKind::External
+		));
+
+		assert_noop!(
+			<DdcClusters as ClusterManager<Test>>::cancel_node_leaving(&cluster_id, &node_pub_key),
+			Error::<Test>::NodeIsNotLeaving
+		);
+		assert_ok!(<DdcClusters as ClusterManager<Test>>::start_node_leaving(
+			&cluster_id,
+			&node_pub_key
+		));
+		assert_eq!(
+			DdcClusters::leaving_nodes(cluster_id, node_pub_key.clone()),
+			Some(NodeLeaving { leave_at: 11, by_provider: true })
+		);
+
+		assert_ok!(<DdcClusters as ClusterManager<Test>>::cancel_node_leaving(
+			&cluster_id,
+			&node_pub_key
+		));
+		System::assert_last_event(
+			Event::ClusterNodeLeavingCancelled { cluster_id, node_pub_key: node_pub_key.clone() }
+				.into(),
+		);
+
+		// Node is kept in the cluster
+		DdcClusters::on_idle(11, Weight::MAX);
+		assert!(<DdcClusters as ClusterManager<Test>>::contains_node(
+			&cluster_id,
+			&node_pub_key,
+			None
+		));
+		assert_eq!(DdcClusters::leaving_nodes(cluster_id, node_pub_key), None);
+	})
+}
+
+#[test]
+fn cluster_nodes_limit_and_admission_queue_work() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = AccountId::from([1; 32]);
+		let cluster_reserve_id = AccountId::from([2; 32]);
+		let node_provider_id = AccountId::from([3; 32]);
+		let node_pub_keys: Vec<NodePubKey> =
+			(4..8).map(|i| NodePubKey::StoragePubKey(AccountId::from([i; 32]))).collect();
+
+		assert_ok!(DdcClusters::create_cluster(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			cluster_reserve_id,
+			ClusterParams {
+				node_provider_auth_contract: None,
+				erasure_coding_required: 4,
+				erasure_coding_total: 6,
+				replication_total: 3
+			},
+			ClusterProtocolParams::default()
+		));
+		Clusters::<Test>::mutate(cluster_id, |cluster| {
+			cluster.as_mut().unwrap().set_status(ClusterStatus::Bonded);
+		});
+		assert_ok!(DdcClusters::set_node_provider_allowlist(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			Some(NodeProviderAllowlistParams { accepts_requests: false, required_judgement: None })
+		));
+		assert_ok!(DdcClusters::add_allowlist_entry(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			AllowlistEntry::Provider(node_provider_id.clone())
+		));
+		for node_pub_key in node_pub_keys.iter() {
+			assert_ok!(DdcNodes::create_node(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				node_pub_key.clone(),
+				NodeParams::StorageParams(StorageNodeParams {
+					mode: StorageNodeMode::Storage,
+					host: vec![1u8; 255],
+					domain: vec![2u8; 255],
+					ssl: true,
+					http_port: 35000u16,
+					grpc_port: 25000u16,
+					p2p_port: 15000u16,
+				}),
+			));
+		}
+
+		// Only the cluster manager sets the limit
+		assert_noop!(
+			DdcClusters::set_nodes_limit(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				StorageNodeMode::Storage,
+				Some(1)
+			),
+			Error::<Test>::OnlyClusterManager
+		);
+		assert_ok!(DdcClusters::set_nodes_limit(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			StorageNodeMode::Storage,
+			Some(1)
+		));
+		System::assert_last_event(
+			Event::ClusterNodesLimitSet { cluster_id, mode: StorageNodeMode::Storage }.into(),
+		);
+
+		assert_ok!(DdcClusters::join_cluster(
+			RuntimeOrigin::signed(node_provider_id.clone()),
+			cluster_id,
+			node_pub_keys[0].clone(),
+		));
+		assert_eq!(DdcClusters::clusters_nodes_counts(cluster_id, StorageNodeMode::Storage), 1);
+
+		// Cluster is full
+		assert_noop!(
+			DdcClusters::add_node(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				node_pub_keys[1].clone(),
+				ClusterNodeKind::Genesis
+			),
+			Error::<Test>::ClusterNodesLimitReached
+		);
+
+		// Joining nodes wait in the queue
+		assert_ok!(DdcClusters::join_cluster(
+			RuntimeOrigin::signed(node_provider_id.clone()),
+			cluster_id,
+			node_pub_keys[1].clone(),
+		));
+		System::assert_last_event(
+			Event::ClusterNodeQueued { cluster_id, node_pub_key: node_pub_keys[1].clone() }.into(),
+		);
+		assert_noop!(
+			DdcClusters::join_cluster(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				node_pub_keys[1].clone(),
+			),
+			Error::<Test>::NodeIsQueued
+		);
+		assert_ok!(DdcClusters::join_cluster(
+			RuntimeOrigin::signed(node_provider_id.clone()),
+			cluster_id,
+			node_pub_keys[2].clone(),
+		));
+		assert_noop!(
+			DdcClusters::join_cluster(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				node_pub_keys[3].clone(),
+			),
+			Error::<Test>::AdmissionQueueIsFull
+		);
+		assert_eq!(
+			<DdcClusters as ClusterManager<Test>>::get_admission_queue(
+				&cluster_id,
+				StorageNodeMode::Storage
+			),
+			Ok(vec![node_pub_keys[1].clone(), node_pub_keys[2].clone()])
+		);
+		assert!(!<DdcClusters as ClusterManager<Test>>::contains_node(
+			&cluster_id,
+			&node_pub_keys[1],
+			None
+		));
+
+		// Only the node provider leaves the queue
+		assert_noop!(
+			DdcClusters::leave_admission_queue(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				node_pub_keys[2].clone(),
+			),
+			Error::<Test>::OnlyNodeProvider
+		);
+		assert_ok!(DdcClusters::leave_admission_queue(
+			RuntimeOrigin::signed(node_provider_id.clone()),
+			cluster_id,
+			node_pub_keys[2].clone(),
+		));
+		System::assert_last_event(
+			Event::ClusterNodeDequeued { cluster_id, node_pub_key: node_pub_keys[2].clone() }
+				.into(),
+		);
+		assert_noop!(
+			DdcClusters::leave_admission_queue(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				node_pub_keys[2].clone(),
+			),
+			Error::<Test>::NodeIsNotQueued
+		);
+
+		// The next queued node takes the slot of the removed node
+		assert_ok!(DdcClusters::remove_node(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			node_pub_keys[0].clone(),
+		));
+		System::assert_last_event(
+			Event::ClusterNodeAdded { cluster_id, node_pub_key: node_pub_keys[1].clone() }.into(),
+		);
+		assert!(DdcClusters::admission_queues(cluster_id, StorageNodeMode::Storage).is_empty());
+		assert_eq!(DdcClusters::clusters_nodes_counts(cluster_id, StorageNodeMode::Storage), 1);
+
+		// Removing the limit admits the queued nodes
+		assert_ok!(DdcClusters::join_cluster(
+			RuntimeOrigin::signed(node_provider_id.clone()),
+			cluster_id,
+			node_pub_keys[2].clone(),
+		));
+		assert_ok!(DdcClusters::set_nodes_limit(
+			RuntimeOrigin::signed(cluster_manager_id),
+			cluster_id,
+			StorageNodeMode::Storage,
+			None
+		));
+		System::assert_last_event(
+			Event::ClusterNodeAdded { cluster_id, node_pub_key: node_pub_keys[2].clone() }.into(),
+		);
+		assert_eq!(DdcClusters::clusters_nodes_counts(cluster_id, StorageNodeMode::Storage), 2);
+		assert_eq!(DdcClusters::clusters_nodes_limits(cluster_id, StorageNodeMode::Storage), None);
+	})
+}
+
+#[test]
+fn node_removal_takes_one_node_from_admission_queue() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = AccountId::from([1; 32]);
+		let cluster_reserve_id = AccountId::from([2; 32]);
+		let node_provider_id = AccountId::from([3; 32]);
+		let node_pub_keys: Vec<NodePubKey> =
+			(4..7).map(|i| NodePubKey::StoragePubKey(AccountId::from([i; 32]))).collect();
+
+		assert_ok!(DdcClusters::create_cluster(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			cluster_reserve_id,
+			ClusterParams {
+				node_provider_auth_contract: None,
+				erasure_coding_required: 4,
+				erasure_coding_total: 6,
+				replication_total: 3
+			},
+			ClusterProtocolParams::default()
+		));
+		Clusters::<Test>::mutate(cluster_id, |cluster| {
+			cluster.as_mut().unwrap().set_status(ClusterStatus::Bonded);
+		});
+		assert_ok!(DdcClusters::set_node_provider_allowlist(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			Some(NodeProviderAllowlistParams { accepts_requests: false, required_judgement: None })
+		));
+		assert_ok!(DdcClusters::add_allowlist_entry(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			AllowlistEntry::Provider(node_provider_id.clone())
+		));
+		for node_pub_key in node_pub_keys.iter() {
+			assert_ok!(DdcNodes::create_node(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				node_pub_key.clone(),
+				NodeParams::StorageParams(StorageNodeParams {
+					mode: StorageNodeMode::Storage,
+					host: vec![1u8; 255],
+					domain: vec![2u8; 255],
+					ssl: true,
+					http_port: 35000u16,
+					grpc_port: 25000u16,
+					p2p_port: 15000u16,
+				}),
+			));
+		}
+		assert_ok!(DdcClusters::set_nodes_limit(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			StorageNodeMode::Storage,
+			Some(1)
+		));
+		for node_pub_key in node_pub_keys.iter() {
+			assert_ok!(DdcClusters::join_cluster(
+				RuntimeOrigin::signed(node_provider_id.clone()),
+				cluster_id,
+				node_pub_key.clone(),
+			));
+		}
+
+		// The first queued node can no longer be admitted, it is dropped for the freed slot
+		ChillingNodes::set(vec![node_pub_keys[1].clone()]);
+		assert_ok!(DdcClusters::remove_node(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			node_pub_keys[0].clone(),
+		));
+		System::assert_last_event(
+			Event::ClusterNodeDequeued { cluster_id, node_pub_key: node_pub_keys[1].clone() }
+				.into(),
+		);
+		assert_eq!(
+			DdcClusters::admission_queues(cluster_id, StorageNodeMode::Storage).into_inner(),
+			vec![node_pub_keys[2].clone()]
+		);
+		assert_eq!(DdcClusters::clusters_nodes_counts(cluster_id, StorageNodeMode::Storage), 0);
+
+		// The rest of the queue is admitted on the next nodes limit change
+		assert_ok!(DdcClusters::set_nodes_limit(
+			RuntimeOrigin::signed(cluster_manager_id),
+			cluster_id,
+			StorageNodeMode::Storage,
+			Some(1)
+		));
+		System::assert_last_event(
+			Event::ClusterNodeAdded { cluster_id, node_pub_key: node_pub_keys[2].clone() }.into(),
+		);
+		assert!(DdcClusters::admission_queues(cluster_id, StorageNodeMode::Storage).is_empty());
+	})
+}
+
+#[test]
+fn pricing_override_works() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+		PricingOverrideRequiresGov::set(false);
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = AccountId::from([1; 32]);
+		let cluster_reserve_id = AccountId::from([2; 32]);
+		let customer_id = AccountId::from([3; 32]);
+		let target = PricingOverrideTarget::Customer(customer_id);
+		let pricing_override = PricingOverride {
+			pricing: CustomerPricing::Discount(Percent::from_percent(10)),
+			valid_from: 0,
+			valid_till: 100,
+		};
+
+		assert_noop!(
+			DdcClusters::set_pricing_override(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				target.clone(),
+				Some(pricing_override.clone())
+			),
+			Error::<Test>::ClusterDoesNotExist
+		);
+
+		assert_ok!(DdcClusters::create_cluster(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			cluster_reserve_id.clone(),
+			ClusterParams {
+				node_provider_auth_contract: None,
+				erasure_coding_required: 4,
+				erasure_coding_total: 6,
+				replication_total: 3
+			},
+			ClusterProtocolParams::default()
+		));
+
+		assert_noop!(
+			DdcClusters::set_pricing_override(
+				RuntimeOrigin::signed(cluster_reserve_id),
+				cluster_id,
+				target.clone(),
+				Some(pricing_override.clone())
+			),
+			Error::<Test>::OnlyClusterManager
+		);
+
+		assert_noop!(
+			DdcClusters::set_pricing_override(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				target.clone(),
+				Some(PricingOverride { valid_from: 100, ..pricing_override.clone() })
+			),
+			Error::<Test>::InvalidPricingOverride
+		);
+
+		assert_noop!(
+			DdcClusters::set_pricing_override(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				target.clone(),
+				None
+			),
+			Error::<Test>::NoPricingOverride
+		);
+
+		assert_ok!(DdcClusters::set_pricing_override(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			target.clone(),
+			Some(pricing_override.clone())
+		));
+		assert_eq!(
+			PricingOverrides::<Test>::get(cluster_id, target.clone()),
+			Some(pricing_override)
+		);
+		System::assert_last_event(
+			Event::PricingOverrideSet { cluster_id, target: target.clone() }.into(),
+		);
+
+		assert_ok!(DdcClusters::set_pricing_override(
+			RuntimeOrigin::signed(cluster_manager_id),
+			cluster_id,
+			target.clone(),
+			None
+		));
+		assert!(!PricingOverrides::<Test>::contains_key(cluster_id, target.clone()));
+		System::assert_last_event(Event::PricingOverrideUnset { cluster_id, target }.into());
+	})
+}
+
+#[test]
+fn bucket_pricing_override_requires_bucket_of_cluster() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+		PricingOverrideRequiresGov::set(false);
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let other_cluster_id = ClusterId::from([2; 20]);
+		let cluster_manager_id = AccountId::from([1; 32]);
+		let cluster_reserve_id = AccountId::from([2; 32]);
+		let pricing_override = PricingOverride {
+			pricing: CustomerPricing::Discount(Percent::from_percent(10)),
+			valid_from: 0,
+			valid_till: 100,
+		};
+		BucketClusterIds::set(vec![(1, cluster_id), (2, other_cluster_id)]);
+
+		assert_ok!(DdcClusters::create_cluster(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			cluster_reserve_id,
+			ClusterParams {
+				node_provider_auth_contract: None,
+				erasure_coding_required: 4,
+				erasure_coding_total: 6,
+				replication_total: 3
+			},
+			ClusterProtocolParams::default()
+		));
+
+		assert_noop!(
+			DdcClusters::set_pricing_override(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				PricingOverrideTarget::Bucket(2),
+				Some(pricing_override.clone())
+			),
+			Error::<Test>::BucketDoesNotBelongToCluster
+		);
+
+		assert_noop!(
+			DdcClusters::set_pricing_override(
+				RuntimeOrigin::signed(cluster_manager_id.clone()),
+				cluster_id,
+				PricingOverrideTarget::Bucket(3),
+				Some(pricing_override.clone())
+			),
+			DispatchError::Other("Bucket does not exist")
+		);
+
+		assert_ok!(DdcClusters::set_pricing_override(
+			RuntimeOrigin::signed(cluster_manager_id),
+			cluster_id,
+			PricingOverrideTarget::Bucket(1),
+			Some(pricing_override.clone())
+		));
+		assert_eq!(
+			PricingOverrides::<Test>::get(cluster_id, PricingOverrideTarget::Bucket(1)),
+			Some(pricing_override)
+		);
+	})
+}
+
+#[test]
+fn pricing_override_requires_gov_before_activation() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_manager_id = AccountId::from([1; 32]);
+		let cluster_reserve_id = AccountId::from([2; 32]);
+		let target = PricingOverrideTarget::Customer(AccountId::from([3; 32]));
+
+		assert_ok!(DdcClusters::create_cluster(
+			RuntimeOrigin::signed(cluster_manager_id.clone()),
+			cluster_id,
+			cluster_reserve_id,
+			ClusterParams {
+				node_provider_auth_contract: None,
+				erasure_coding_required: 4,
+				erasure_coding_total: 6,
+				replication_total: 3
+			},
+			ClusterProtocolParams::default()
+		));
+		assert_eq!(DdcClusters::clusters(cluster_id).unwrap().status, ClusterStatus::Unbonded);
+
+		// The pricing set before the activation would survive it without the governance review
+		assert_noop!(
+			DdcClusters::set_pricing_override(
+				RuntimeOrigin::signed(cluster_manager_id),
+				cluster_id,
+				target,
+				Some(PricingOverride {
+					pricing: CustomerPricing::Discount(Percent::from_percent(100)),
+					valid_from: 0,
+					valid_till: i64::MAX,
+				})
+			),
+			Error::<Test>::PricingOverrideRequiresGov
+		);
+	})
+}
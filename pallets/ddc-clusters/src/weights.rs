@@ -34,6 +34,23 @@ pub trait WeightInfo {
 	fn remove_node() -> Weight;
 	fn set_cluster_params() -> Weight;
 	fn validate_node() -> Weight;
+	fn add_aggregator() -> Weight;
+	fn remove_aggregator() -> Weight;
+	fn propose_manager_transfer() -> Weight;
+	fn accept_manager_transfer() -> Weight;
+	fn cancel_manager_transfer() -> Weight;
+	fn set_node_provider_allowlist(n: u32, ) -> Weight;
+	fn add_allowlist_entry() -> Weight;
+	fn remove_allowlist_entry() -> Weight;
+	fn request_node_authorization() -> Weight;
+	fn resolve_node_authorization() -> Weight;
+	fn decommission_cluster() -> Weight;
+	fn drain_cluster_nodes(n: u32, ) -> Weight;
+	fn remove_decommissioned_cluster(n: u32, ) -> Weight;
+	fn remove_leaving_node() -> Weight;
+	fn set_nodes_limit(n: u32, ) -> Weight;
+	fn leave_admission_queue() -> Weight;
+	fn set_pricing_override() -> Weight;
 }
 
 /// Weights for pallet_ddc_clusters using the Substrate node and recommended hardware.
@@ -66,10 +83,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::ClustersNodes` (r:0 w:1)
 	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesLimits` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn add_node() -> Weight {
-		Weight::from_parts(82_055_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(86_412_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -105,23 +126,53 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: UNKNOWN KEY `0x11d2df4e979aa105cf552e9544ebd2b500000000` (r:1 w:0)
 	// Storage: UNKNOWN KEY `0xee61cd03857d4d6515cbe7367c56239d5b1f4a8e800000000000000000000000` (r:1 w:0)
 	// Proof: UNKNOWN KEY `0xee61cd03857d4d6515cbe7367c56239d5b1f4a8e800000000000000000000000` (r:1 w:0)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:1 w:0)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:0)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesLimits` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn join_cluster() -> Weight {
-		Weight::from_parts(656_290_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(17_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+		Weight::from_parts(664_731_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcNodes::StorageNodes` (r:1 w:1)
+	// Storage: `DdcNodes::StorageNodes` (r:2 w:2)
 	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcClusters::ClustersNodes` (r:1 w:1)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:2)
 	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:1)
 	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersAggregators` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersAggregators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::LeavingNodes` (r:1 w:1)
+	// Proof: `DdcClusters::LeavingNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersGovParams` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersGovParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesLimits` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:1)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:1 w:0)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:1 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Storages` (r:1 w:0)
+	// Proof: `DdcStaking::Storages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:1 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:1 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn remove_node() -> Weight {
-		Weight::from_parts(55_664_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(118_532_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:1)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -141,6 +192,282 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersAggregators` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersAggregators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_aggregator() -> Weight {
+		Weight::from_parts(31_520_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersAggregators` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersAggregators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_aggregator() -> Weight {
+		Weight::from_parts(22_417_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PendingManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::PendingManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::GovManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::GovManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_manager_transfer() -> Weight {
+		Weight::from_parts(24_681_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:1)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PendingManagerTransfers` (r:1 w:1)
+	// Proof: `DdcClusters::PendingManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:1)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:2 w:2)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::GovManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::GovManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_manager_transfer() -> Weight {
+		Weight::from_parts(43_916_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::GovManagerTransfers` (r:1 w:1)
+	// Proof: `DdcClusters::GovManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PendingManagerTransfers` (r:1 w:1)
+	// Proof: `DdcClusters::PendingManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_manager_transfer() -> Weight {
+		Weight::from_parts(24_305_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlistEntries` (r:0 w:100)
+	// Proof: `DdcClusters::NodeProviderAllowlistEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeAuthorizationRequests` (r:0 w:100)
+	// Proof: `DdcClusters::NodeAuthorizationRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 100]`.
+	fn set_node_provider_allowlist(n: u32, ) -> Weight {
+		Weight::from_parts(24_351_209_u64, 0)
+			// Standard Error: 2_114
+			.saturating_add(Weight::from_parts(1_806_542_u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n as u64)))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlistEntries` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlistEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_allowlist_entry() -> Weight {
+		Weight::from_parts(25_729_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlistEntries` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlistEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_allowlist_entry() -> Weight {
+		Weight::from_parts(25_143_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlistEntries` (r:1 w:0)
+	// Proof: `DdcClusters::NodeProviderAllowlistEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeAuthorizationRequests` (r:1 w:1)
+	// Proof: `DdcClusters::NodeAuthorizationRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_node_authorization() -> Weight {
+		Weight::from_parts(33_482_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeAuthorizationRequests` (r:1 w:1)
+	// Proof: `DdcClusters::NodeAuthorizationRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlistEntries` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlistEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn resolve_node_authorization() -> Weight {
+		Weight::from_parts(31_906_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:2 w:1)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:1)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn decommission_cluster() -> Weight {
+		Weight::from_parts(24_915_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::ClustersNodes` (r:101 w:100)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:100 w:100)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:1 w:1)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 100]`.
+	fn drain_cluster_nodes(n: u32, ) -> Weight {
+		Weight::from_parts(11_380_215_u64, 0)
+			// Standard Error: 12_114
+			.saturating_add(Weight::from_parts(30_102_847_u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n as u64)))
+	}
+	// Storage: `DdcClusters::NodeProviderAllowlistEntries` (r:100 w:100)
+	// Proof: `DdcClusters::NodeProviderAllowlistEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeAuthorizationRequests` (r:100 w:100)
+	// Proof: `DdcClusters::NodeAuthorizationRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:0 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PendingManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::PendingManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersGovParams` (r:0 w:1)
+	// Proof: `DdcClusters::ClustersGovParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:0 w:1)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::Clusters` (r:0 w:1)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:0 w:1)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesLimits` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 100]`.
+	fn remove_decommissioned_cluster(n: u32, ) -> Weight {
+		Weight::from_parts(29_436_112_u64, 0)
+			// Standard Error: 3_207
+			.saturating_add(Weight::from_parts(2_413_870_u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n as u64)))
+	}
+	// Storage: `DdcClusters::LeavingNodes` (r:1 w:1)
+	// Proof: `DdcClusters::LeavingNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:2 w:2)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:2)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersAggregators` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersAggregators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesLimits` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:1)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:1 w:0)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:1 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Storages` (r:1 w:0)
+	// Proof: `DdcStaking::Storages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:1 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:1 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_leaving_node() -> Weight {
+		Weight::from_parts(110_204_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesLimits` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:1)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:1 w:0)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:10 w:10)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:10 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Storages` (r:10 w:0)
+	// Proof: `DdcStaking::Storages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:10 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:10 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:0 w:10)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 10]`.
+	fn set_nodes_limit(n: u32, ) -> Weight {
+		Weight::from_parts(27_305_000_u64, 0)
+			// Standard Error: 14_276
+			.saturating_add(Weight::from_parts(38_716_402_u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n as u64)))
+	}
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:1)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn leave_admission_queue() -> Weight {
+		Weight::from_parts(19_843_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcCustomers::Buckets` (r:1 w:0)
+	// Proof: `DdcCustomers::Buckets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PricingOverrides` (r:0 w:1)
+	// Proof: `DdcClusters::PricingOverrides` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_pricing_override() -> Weight {
+		Weight::from_parts(21_642_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -172,10 +499,14 @@ impl WeightInfo for () {
 	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::ClustersNodes` (r:0 w:1)
 	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesLimits` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn add_node() -> Weight {
-		Weight::from_parts(82_055_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(86_412_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -211,23 +542,53 @@ impl WeightInfo for () {
 	// Proof: UNKNOWN KEY `0x11d2df4e979aa105cf552e9544ebd2b500000000` (r:1 w:0)
 	// Storage: UNKNOWN KEY `0xee61cd03857d4d6515cbe7367c56239d5b1f4a8e800000000000000000000000` (r:1 w:0)
 	// Proof: UNKNOWN KEY `0xee61cd03857d4d6515cbe7367c56239d5b1f4a8e800000000000000000000000` (r:1 w:0)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:1 w:0)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:0)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesLimits` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn join_cluster() -> Weight {
-		Weight::from_parts(656_290_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(17_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		Weight::from_parts(664_731_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcNodes::StorageNodes` (r:1 w:1)
+	// Storage: `DdcNodes::StorageNodes` (r:2 w:2)
 	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcClusters::ClustersNodes` (r:1 w:1)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:2)
 	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:1)
 	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersAggregators` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersAggregators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::LeavingNodes` (r:1 w:1)
+	// Proof: `DdcClusters::LeavingNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersGovParams` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersGovParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesLimits` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:1)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:1 w:0)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:1 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Storages` (r:1 w:0)
+	// Proof: `DdcStaking::Storages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:1 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:1 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn remove_node() -> Weight {
-		Weight::from_parts(55_664_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(118_532_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:1)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -247,4 +608,280 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersAggregators` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersAggregators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_aggregator() -> Weight {
+		Weight::from_parts(31_520_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersAggregators` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersAggregators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_aggregator() -> Weight {
+		Weight::from_parts(22_417_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PendingManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::PendingManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::GovManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::GovManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_manager_transfer() -> Weight {
+		Weight::from_parts(24_681_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:1)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PendingManagerTransfers` (r:1 w:1)
+	// Proof: `DdcClusters::PendingManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterBonded` (r:1 w:1)
+	// Proof: `DdcStaking::ClusterBonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::ClusterLedger` (r:2 w:2)
+	// Proof: `DdcStaking::ClusterLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::GovManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::GovManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_manager_transfer() -> Weight {
+		Weight::from_parts(43_916_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::GovManagerTransfers` (r:1 w:1)
+	// Proof: `DdcClusters::GovManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PendingManagerTransfers` (r:1 w:1)
+	// Proof: `DdcClusters::PendingManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_manager_transfer() -> Weight {
+		Weight::from_parts(24_305_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlistEntries` (r:0 w:100)
+	// Proof: `DdcClusters::NodeProviderAllowlistEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeAuthorizationRequests` (r:0 w:100)
+	// Proof: `DdcClusters::NodeAuthorizationRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 100]`.
+	fn set_node_provider_allowlist(n: u32, ) -> Weight {
+		Weight::from_parts(24_351_209_u64, 0)
+			// Standard Error: 2_114
+			.saturating_add(Weight::from_parts(1_806_542_u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n as u64)))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlistEntries` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlistEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_allowlist_entry() -> Weight {
+		Weight::from_parts(25_729_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlistEntries` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlistEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_allowlist_entry() -> Weight {
+		Weight::from_parts(25_143_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlistEntries` (r:1 w:0)
+	// Proof: `DdcClusters::NodeProviderAllowlistEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeAuthorizationRequests` (r:1 w:1)
+	// Proof: `DdcClusters::NodeAuthorizationRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_node_authorization() -> Weight {
+		Weight::from_parts(33_482_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeAuthorizationRequests` (r:1 w:1)
+	// Proof: `DdcClusters::NodeAuthorizationRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlistEntries` (r:1 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlistEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn resolve_node_authorization() -> Weight {
+		Weight::from_parts(31_906_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:2 w:1)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:1)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn decommission_cluster() -> Weight {
+		Weight::from_parts(24_915_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcClusters::ClustersNodes` (r:101 w:100)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:100 w:100)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:1 w:1)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 100]`.
+	fn drain_cluster_nodes(n: u32, ) -> Weight {
+		Weight::from_parts(11_380_215_u64, 0)
+			// Standard Error: 12_114
+			.saturating_add(Weight::from_parts(30_102_847_u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n as u64)))
+	}
+	// Storage: `DdcClusters::NodeProviderAllowlistEntries` (r:100 w:100)
+	// Proof: `DdcClusters::NodeProviderAllowlistEntries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeAuthorizationRequests` (r:100 w:100)
+	// Proof: `DdcClusters::NodeAuthorizationRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::NodeProviderAllowlists` (r:0 w:1)
+	// Proof: `DdcClusters::NodeProviderAllowlists` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PendingManagerTransfers` (r:0 w:1)
+	// Proof: `DdcClusters::PendingManagerTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersGovParams` (r:0 w:1)
+	// Proof: `DdcClusters::ClustersGovParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:0 w:1)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::Clusters` (r:0 w:1)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:0 w:1)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesLimits` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 100]`.
+	fn remove_decommissioned_cluster(n: u32, ) -> Weight {
+		Weight::from_parts(29_436_112_u64, 0)
+			// Standard Error: 3_207
+			.saturating_add(Weight::from_parts(2_413_870_u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n as u64)))
+	}
+	// Storage: `DdcClusters::LeavingNodes` (r:1 w:1)
+	// Proof: `DdcClusters::LeavingNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:2 w:2)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:2)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersAggregators` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersAggregators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesLimits` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodesLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:1)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:1 w:0)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:1 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Storages` (r:1 w:0)
+	// Proof: `DdcStaking::Storages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:1 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:1 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_leaving_node() -> Weight {
+		Weight::from_parts(110_204_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesLimits` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:1)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:1 w:0)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:10 w:10)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Nodes` (r:10 w:0)
+	// Proof: `DdcStaking::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Storages` (r:10 w:0)
+	// Proof: `DdcStaking::Storages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Bonded` (r:10 w:0)
+	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Ledger` (r:10 w:0)
+	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:0 w:10)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 10]`.
+	fn set_nodes_limit(n: u32, ) -> Weight {
+		Weight::from_parts(27_305_000_u64, 0)
+			// Standard Error: 14_276
+			.saturating_add(Weight::from_parts(38_716_402_u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n as u64)))
+	}
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:0)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:1)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn leave_admission_queue() -> Weight {
+		Weight::from_parts(19_843_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcCustomers::Buckets` (r:1 w:0)
+	// Proof: `DdcCustomers::Buckets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::PricingOverrides` (r:0 w:1)
+	// Proof: `DdcClusters::PricingOverrides` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_pricing_override() -> Weight {
+		Weight::from_parts(21_642_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
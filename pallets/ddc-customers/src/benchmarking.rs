@@ -28,10 +28,12 @@ benchmarks! {
 			storage_bond_size: 100u32.into(),
 			storage_chill_delay: 50u32.into(),
 			storage_unbonding_delay: 50u32.into(),
+			node_removal_notice_period: 50u32.into(),
 			unit_per_mb_stored: 10,
 			unit_per_mb_streamed: 10,
 			unit_per_put_request: 10,
 			unit_per_get_request: 10,
+			pricing_tiers: Default::default(),
 		};
 
 		let _ = <T as pallet::Config>::ClusterCreator::create_cluster(
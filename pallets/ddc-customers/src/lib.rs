@@ -16,7 +16,7 @@ use codec::{Decode, Encode};
 use ddc_primitives::{
 	traits::{
 		bucket::BucketManager,
-		cluster::{ClusterCreator, ClusterProtocol, ClusterQuery},
+		cluster::{ClusterCreator, ClusterDecommissionHandler, ClusterProtocol, ClusterQuery},
 		customer::{CustomerCharger, CustomerDepositor, CustomerVisitor},
 		payout::StorageUsageProvider,
 	},
@@ -135,7 +135,7 @@ pub mod pallet {
 
 	/// The current storage version.
 	const STORAGE_VERSION: frame_support::traits::StorageVersion =
-		frame_support::traits::StorageVersion::new(2);
+		frame_support::traits::StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -177,6 +177,11 @@ pub mod pallet {
 	#[pallet::getter(fn buckets)]
 	pub type Buckets<T: Config> = StorageMap<_, Twox64Concat, BucketId, Bucket<T>, OptionQuery>;
 
+	/// Index of buckets by the cluster they are created in or migrated to
+	#[pallet::storage]
+	pub type ClusterBuckets<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClusterId, Twox64Concat, BucketId, ()>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -217,6 +222,8 @@ pub mod pallet {
 		},
 		/// Bucket with specific id marked as removed
 		BucketRemoved { bucket_id: BucketId },
+		/// Bucket with specific id migrated from a decommissioned cluster
+		BucketMigrated { cluster_id: ClusterId, bucket_id: BucketId },
 	}
 
 	#[pallet::error]
@@ -251,6 +258,8 @@ pub mod pallet {
 		AlreadyRemoved,
 		/// Bucket belongs to another cluster
 		ClusterMismatch,
+		/// DDC Cluster with provided id is being decommissioned
+		ClusterIsDecommissioning,
 	}
 
 	#[pallet::genesis_config]
@@ -294,6 +303,7 @@ pub mod pallet {
 				<BucketsCount<T>>::set(cur_bucket_id);
 
 				<Buckets<T>>::insert(cur_bucket_id, bucket);
+				<ClusterBuckets<T>>::insert(bucket.cluster_id, cur_bucket_id, ());
 
 				let ledger = AccountsLedger::<T> {
 					owner: bucket.owner_id.clone(),
@@ -639,6 +649,10 @@ pub mod pallet {
 				<T::ClusterProtocol as ClusterQuery<T>>::cluster_exists(&cluster_id),
 				Error::<T>::ClusterDoesNotExist
 			);
+			ensure!(
+				!<T::ClusterProtocol as ClusterQuery<T>>::is_decommissioning(&cluster_id),
+				Error::<T>::ClusterIsDecommissioning
+			);
 
 			let bucket = Bucket {
 				bucket_id,
@@ -651,6 +665,7 @@ pub mod pallet {
 
 			<BucketsCount<T>>::set(bucket_id);
 			<Buckets<T>>::insert(bucket_id, bucket);
+			<ClusterBuckets<T>>::insert(cluster_id, bucket_id, ());
 
 			Self::deposit_event(Event::<T>::BucketCreated { cluster_id, bucket_id });
 
@@ -757,6 +772,51 @@ pub mod pallet {
 		}
 	}
 
+	/// Buckets of a decommissioned cluster are migrated to another cluster if it is provided and
+	/// marked as removed otherwise. Customer deposits are not bound to clusters, so they stay
+	/// available for other buckets and can be withdrawn with the regular unlocking.
+	impl<T: Config> ClusterDecommissionHandler for Pallet<T> {
+		fn clean_up_weight(limit: u32) -> Weight {
+			let limit: u64 = limit.into();
+			T::DbWeight::get().reads(1).saturating_add(
+				T::DbWeight::get().reads_writes(limit.saturating_mul(2), limit.saturating_mul(3)),
+			)
+		}
+
+		fn clean_up(
+			cluster_id: &ClusterId,
+			buckets_migrate_to: Option<ClusterId>,
+			limit: u32,
+		) -> Result<u32, DispatchError> {
+			let bucket_ids: Vec<BucketId> =
+				<ClusterBuckets<T>>::iter_key_prefix(cluster_id).take(limit as usize).collect();
+
+			for bucket_id in bucket_ids.iter() {
+				<ClusterBuckets<T>>::remove(cluster_id, bucket_id);
+				<Buckets<T>>::mutate(bucket_id, |maybe_bucket| match maybe_bucket {
+					Some(bucket) if bucket.cluster_id == *cluster_id && !bucket.is_removed =>
+						if let Some(target_cluster_id) = buckets_migrate_to {
+							bucket.cluster_id = target_cluster_id;
+							bucket.total_customers_usage = None;
+							<ClusterBuckets<T>>::insert(target_cluster_id, bucket_id, ());
+							Self::deposit_event(Event::<T>::BucketMigrated {
+								cluster_id: target_cluster_id,
+								bucket_id: *bucket_id,
+							});
+						} else {
+							bucket.is_removed = true;
+							Self::deposit_event(Event::<T>::BucketRemoved {
+								bucket_id: *bucket_id,
+							});
+						},
+					_ => {},
+				});
+			}
+
+			Ok(bucket_ids.len() as u32)
+		}
+	}
+
 	impl<T: Config> CustomerCharger<T> for Pallet<T> {
 		fn charge_bucket_owner(
 			bucket_owner: T::AccountId,
@@ -874,6 +934,11 @@ pub mod pallet {
 			let bucket = Self::buckets(bucket_id).ok_or(Error::<T>::NoBucketWithId)?;
 			Ok(bucket.owner_id)
 		}
+
+		fn get_bucket_cluster_id(bucket_id: &BucketId) -> Result<ClusterId, DispatchError> {
+			let bucket = Self::buckets(bucket_id).ok_or(Error::<T>::NoBucketWithId)?;
+			Ok(bucket.cluster_id)
+		}
 	}
 
 	impl<T: Config> StorageUsageProvider<BucketId, BucketStorageUsage<T::AccountId>> for Pallet<T> {
@@ -130,10 +130,10 @@ pub mod v1 {
 			let current_version = Pallet::<T>::current_storage_version();
 			let on_chain_version = Pallet::<T>::on_chain_storage_version();
 
-			frame_support::ensure!(current_version == 1, "must_upgrade");
+			frame_support::ensure!(on_chain_version >= 1, "must_upgrade");
 			ensure!(
-				current_version == on_chain_version,
-				"after migration, the current_version and on_chain_version should be the same"
+				current_version >= on_chain_version,
+				"after migration, the on_chain_version should not be ahead of the current_version"
 			);
 
 			Buckets::<T>::iter().try_for_each(|(_id, bucket)| -> Result<(), &'static str> {
@@ -251,10 +251,10 @@ pub mod v2 {
 			let current_version = Pallet::<T>::current_storage_version();
 			let on_chain_version = Pallet::<T>::on_chain_storage_version();
 
-			frame_support::ensure!(current_version == 2, "must_upgrade");
+			frame_support::ensure!(on_chain_version >= 2, "must_upgrade");
 			ensure!(
-				current_version == on_chain_version,
-				"after migration, the current_version and on_chain_version should be the same"
+				current_version >= on_chain_version,
+				"after migration, the on_chain_version should not be ahead of the current_version"
 			);
 
 			v2::Buckets::<T>::iter().try_for_each(|(_id, bucket)| -> Result<(), &'static str> {
@@ -269,3 +269,86 @@ pub mod v2 {
 		}
 	}
 }
+
+pub mod v3 {
+
+	use frame_support::pallet_prelude::*;
+
+	use super::*;
+
+	// New migration to index buckets by cluster
+	pub fn migrate_to_v3<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version == 2 {
+			info!(
+				target: LOG_TARGET,
+				" >>> Updating DDC Customers storage to v3. Indexing buckets by cluster..."
+			);
+
+			let mut count: u64 = 0;
+			crate::Buckets::<T>::iter().for_each(|(bucket_id, bucket)| {
+				crate::ClusterBuckets::<T>::insert(bucket.cluster_id, bucket_id, ());
+				count += 1;
+			});
+
+			// Update storage version.
+			StorageVersion::new(3).put::<Pallet<T>>();
+			info!(
+				target: LOG_TARGET,
+				" <<< DDC Customers storage updated to v3! Indexed {} buckets ✅", count
+			);
+
+			T::DbWeight::get().reads_writes(count + 1, count + 1)
+		} else {
+			info!(target: LOG_TARGET, " >>> Unused migration to v3!");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrate_to_v3::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+			let prev_count = crate::Buckets::<T>::iter().count();
+
+			Ok((prev_count as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_state: Vec<u8>) -> Result<(), DispatchError> {
+			let prev_count: u64 = Decode::decode(&mut &prev_state[..])
+				.expect("pre_upgrade provides a valid state; qed");
+
+			let post_count = crate::ClusterBuckets::<T>::iter().count() as u64;
+			ensure!(
+				prev_count == post_count,
+				"every bucket should be indexed by its cluster after the migration"
+			);
+
+			let current_version = Pallet::<T>::current_storage_version();
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+
+			frame_support::ensure!(on_chain_version >= 3, "must_upgrade");
+			ensure!(
+				current_version >= on_chain_version,
+				"after migration, the on_chain_version should not be ahead of the current_version"
+			);
+
+			crate::Buckets::<T>::iter().try_for_each(
+				|(bucket_id, bucket)| -> Result<(), &'static str> {
+					ensure!(
+						crate::ClusterBuckets::<T>::contains_key(bucket.cluster_id, bucket_id),
+						"At this point all the buckets should be indexed by their cluster"
+					);
+					Ok(())
+				},
+			)?;
+
+			Ok(())
+		}
+	}
+}
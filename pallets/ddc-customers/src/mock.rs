@@ -2,9 +2,10 @@
 
 use ddc_primitives::{
 	traits::cluster::{ClusterCreator, ClusterManager, ClusterProtocol, ClusterQuery},
-	ClusterBondingParams, ClusterFeesParams, ClusterId, ClusterNodeKind, ClusterNodeState,
-	ClusterNodeStatus, ClusterNodesStats, ClusterParams, ClusterPricingParams,
-	ClusterProtocolParams, ClusterStatus, NodePubKey, NodeType,
+	BucketId, ClusterBondingParams, ClusterFeesParams, ClusterId, ClusterNodeKind,
+	ClusterNodeState, ClusterNodeStatus, ClusterNodesStats, ClusterParams, ClusterPricingParams,
+	ClusterProtocolParams, ClusterStatus, NodePubKey, NodeType, PriceSchedule, PricingOverride,
+	PricingOverrideTarget, StorageNodeMode,
 };
 use frame_support::{
 	construct_runtime, parameter_types,
@@ -95,6 +96,7 @@ impl pallet_timestamp::Config for Test {
 parameter_types! {
 	pub const DdcCustomersPalletId: PalletId = PalletId(*b"accounts"); // DDC maintainer's stake
 	pub const UnlockingDelay: BlockNumber = 10u64; // 10 blocks for test
+	pub static DecommissioningClusters: Vec<ClusterId> = vec![];
 }
 
 impl crate::pallet::Config for Test {
@@ -122,6 +124,10 @@ impl<T: Config> ClusterQuery<T> for TestClusterProtocol {
 	) -> Result<(T::AccountId, T::AccountId), DispatchError> {
 		unimplemented!()
 	}
+
+	fn is_decommissioning(cluster_id: &ClusterId) -> bool {
+		DecommissioningClusters::get().contains(cluster_id)
+	}
 }
 
 impl<T: Config> ClusterProtocol<T, BalanceOf<T>> for TestClusterProtocol {
@@ -149,6 +155,7 @@ impl<T: Config> ClusterProtocol<T, BalanceOf<T>> for TestClusterProtocol {
 			unit_per_mb_streamed: 2,
 			unit_per_put_request: 3,
 			unit_per_get_request: 4,
+			pricing_tiers: Default::default(),
 		})
 	}
 
@@ -211,6 +218,15 @@ impl<T: Config> ClusterProtocol<T, BalanceOf<T>> for TestClusterProtocol {
 	fn end_unbond_cluster(_cluster_id: &ClusterId) -> DispatchResult {
 		unimplemented!()
 	}
+
+	fn get_price_schedule(
+		_cluster_id: &ClusterId,
+		_customer_id: &T::AccountId,
+		_bucket_id: BucketId,
+		_time: i64,
+	) -> PriceSchedule {
+		unimplemented!()
+	}
 }
 
 pub struct TestClusterManager;
@@ -228,6 +244,10 @@ impl<T: Config> ClusterQuery<T> for TestClusterManager {
 	) -> Result<(T::AccountId, T::AccountId), DispatchError> {
 		unimplemented!()
 	}
+
+	fn is_decommissioning(_cluster_id: &ClusterId) -> bool {
+		false
+	}
 }
 
 impl<T: Config> ClusterManager<T> for TestClusterManager {
@@ -262,6 +282,20 @@ impl<T: Config> ClusterManager<T> for TestClusterManager {
 		Ok(())
 	}
 
+	fn start_node_leaving(
+		_cluster_id: &ClusterId,
+		_node_pub_key: &NodePubKey,
+	) -> Result<(), DispatchError> {
+		Ok(())
+	}
+
+	fn cancel_node_leaving(
+		_cluster_id: &ClusterId,
+		_node_pub_key: &NodePubKey,
+	) -> Result<(), DispatchError> {
+		Ok(())
+	}
+
 	fn get_node_state(
 		_cluster_id: &ClusterId,
 		_node_pub_key: &NodePubKey,
@@ -284,6 +318,43 @@ impl<T: Config> ClusterManager<T> for TestClusterManager {
 	fn get_clusters(_status: ClusterStatus) -> Result<Vec<ClusterId>, DispatchError> {
 		unimplemented!()
 	}
+
+	fn get_aggregators(_cluster_id: &ClusterId) -> Result<Vec<NodePubKey>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn get_admission_queue(
+		_cluster_id: &ClusterId,
+		_mode: StorageNodeMode,
+	) -> Result<Vec<NodePubKey>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn propose_manager_transfer(
+		_cluster_id: &ClusterId,
+		_new_manager_id: &T::AccountId,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn cancel_manager_transfer(_cluster_id: &ClusterId) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn update_cluster_params(
+		_cluster_id: &ClusterId,
+		_cluster_params: ClusterParams<T::AccountId>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn set_pricing_override(
+		_cluster_id: &ClusterId,
+		_target: &PricingOverrideTarget<T::AccountId>,
+		_pricing_override: Option<PricingOverride>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
 }
 
 pub struct TestClusterCreator;
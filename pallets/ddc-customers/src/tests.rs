@@ -605,3 +605,64 @@ fn remove_bucket_checks_with_multiple_buckets_works() {
 		);
 	})
 }
+
+#[test]
+fn decommissioned_cluster_buckets_clean_up_works() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([1; 20]);
+		let cluster_id_2 = ClusterId::from([2; 20]);
+		let cluster_id_3 = ClusterId::from([3; 20]);
+		let account_1 = 1;
+		let bucket_params = BucketParams { is_public: false };
+
+		for id in [cluster_id, cluster_id_2, cluster_id, cluster_id] {
+			assert_ok!(DdcCustomers::create_bucket(
+				RuntimeOrigin::signed(account_1),
+				id,
+				bucket_params.clone()
+			));
+		}
+		assert_ok!(DdcCustomers::remove_bucket(RuntimeOrigin::signed(account_1), 3));
+
+		DecommissioningClusters::set(vec![cluster_id]);
+		assert_noop!(
+			DdcCustomers::create_bucket(
+				RuntimeOrigin::signed(account_1),
+				cluster_id,
+				bucket_params.clone()
+			),
+			Error::<Test>::ClusterIsDecommissioning
+		);
+
+		assert_eq!(ClusterBuckets::<Test>::iter_key_prefix(cluster_id).count(), 3);
+
+		// Buckets are processed in batches
+		assert_eq!(DdcCustomers::clean_up(&cluster_id, Some(cluster_id_3), 2), Ok(2));
+		assert_eq!(ClusterBuckets::<Test>::iter_key_prefix(cluster_id).count(), 1);
+		assert_eq!(DdcCustomers::clean_up(&cluster_id, Some(cluster_id_3), 2), Ok(1));
+		assert_eq!(ClusterBuckets::<Test>::iter_key_prefix(cluster_id).count(), 0);
+		assert_eq!(DdcCustomers::clean_up(&cluster_id, Some(cluster_id_3), 2), Ok(0));
+
+		// Buckets of other clusters are not visited
+		assert_eq!(DdcCustomers::buckets(2).unwrap().cluster_id, cluster_id_2);
+
+		// Removed buckets are not migrated
+		let bucket_3 = DdcCustomers::buckets(3).unwrap();
+		assert_eq!((bucket_3.cluster_id, bucket_3.is_removed), (cluster_id, true));
+		for bucket_id in [1, 4] {
+			assert_eq!(DdcCustomers::buckets(bucket_id).unwrap().cluster_id, cluster_id_3);
+			assert!(ClusterBuckets::<Test>::contains_key(cluster_id_3, bucket_id));
+		}
+		System::assert_has_event(
+			Event::BucketMigrated { cluster_id: cluster_id_3, bucket_id: 4 }.into(),
+		);
+
+		// Buckets are removed if there is no cluster to migrate them to
+		assert_eq!(DdcCustomers::clean_up(&cluster_id_2, None, 2), Ok(1));
+		System::assert_last_event(Event::BucketRemoved { bucket_id: 2 }.into());
+		let bucket_2 = DdcCustomers::buckets(2).unwrap();
+		assert_eq!((bucket_2.cluster_id, bucket_2.is_removed), (cluster_id_2, true));
+	})
+}
@@ -45,12 +45,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: `DdcCustomers::BucketsCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:1 w:0)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcCustomers::Buckets` (r:0 w:1)
 	// Proof: `DdcCustomers::Buckets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcCustomers::ClusterBuckets` (r:0 w:1)
+	// Proof: `DdcCustomers::ClusterBuckets` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn create_bucket() -> Weight {
-		Weight::from_parts(27_061_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(31_274_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: `DdcCustomers::Ledger` (r:1 w:1)
 	// Proof: `DdcCustomers::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -117,12 +121,16 @@ impl WeightInfo for () {
 	// Proof: `DdcCustomers::BucketsCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:1 w:0)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcCustomers::Buckets` (r:0 w:1)
 	// Proof: `DdcCustomers::Buckets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcCustomers::ClusterBuckets` (r:0 w:1)
+	// Proof: `DdcCustomers::ClusterBuckets` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn create_bucket() -> Weight {
-		Weight::from_parts(27_061_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(31_274_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	// Storage: `DdcCustomers::Ledger` (r:1 w:1)
 	// Proof: `DdcCustomers::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -41,7 +41,7 @@ mod node;
 mod storage_node;
 
 pub use crate::{
-	node::{Node, NodeError, NodeTrait},
+	node::{Node, NodeError, NodeProps, NodeTrait},
 	storage_node::StorageNode,
 };
 
@@ -161,6 +161,15 @@ pub mod pallet {
 			let caller_id = ensure_signed(origin)?;
 			let mut node = Self::get(node_pub_key.clone()).map_err(Into::<Error<T>>::into)?;
 			ensure!(node.get_provider_id() == &caller_id, Error::<T>::OnlyNodeProvider);
+			// Clusters limit the number of their nodes in each mode.
+			let mode_changed = match (node.get_props(), &node_params) {
+				(NodeProps::StorageProps(props), NodeParams::StorageParams(params)) =>
+					props.mode != params.mode,
+			};
+			ensure!(
+				node.get_cluster_id().is_none() || !mode_changed,
+				Error::<T>::NodeIsAssignedToCluster
+			);
 			node.set_params(node_params).map_err(Into::<Error<T>>::into)?;
 			Self::update(node).map_err(Into::<Error<T>>::into)?;
 			Self::deposit_event(Event::<T>::NodeParamsChanged { node_pub_key });
@@ -116,6 +116,16 @@ impl<T: Config> StakingVisitor<T> for TestStakingVisitor {
 	fn stash_by_ctrl(_controller: &T::AccountId) -> Result<T::AccountId, StakingVisitorError> {
 		todo!()
 	}
+
+	fn get_node_active_stake(_node_pub_key: &NodePubKey) -> Result<u128, StakingVisitorError> {
+		todo!()
+	}
+
+	fn get_cluster_active_stake(
+		_cluster_stash: &T::AccountId,
+	) -> Result<u128, StakingVisitorError> {
+		todo!()
+	}
 }
 
 pub(crate) type TestRuntimeCall = <Test as frame_system::Config>::RuntimeCall;
@@ -229,7 +229,7 @@ fn set_storage_node_params_works() {
 		// Storage domain length exceeds limit
 		assert_noop!(
 			DdcNodes::set_node_params(
-				RuntimeOrigin::signed(account_id1),
+				RuntimeOrigin::signed(account_id1.clone()),
 				NodePubKey::StoragePubKey(node_pub_key.clone()),
 				NodeParams::StorageParams(StorageNodeParams {
 					mode: StorageNodeMode::Storage,
@@ -244,6 +244,30 @@ fn set_storage_node_params_works() {
 			Error::<Test>::DomainLenExceedsLimit
 		);
 
+		// Mode of a node assigned to a cluster can not be changed
+		let mut node = <DdcNodes as NodeRepository<Test>>::get(NodePubKey::StoragePubKey(
+			node_pub_key.clone(),
+		))
+		.unwrap();
+		node.set_cluster_id(Some(ClusterId::from([1; 20])));
+		assert_ok!(<DdcNodes as NodeRepository<Test>>::update(node));
+		assert_noop!(
+			DdcNodes::set_node_params(
+				RuntimeOrigin::signed(account_id1),
+				NodePubKey::StoragePubKey(node_pub_key.clone()),
+				NodeParams::StorageParams(StorageNodeParams {
+					mode: StorageNodeMode::Storage,
+					host: vec![3u8; 255],
+					domain: vec![4u8; 255],
+					ssl: false,
+					http_port: 35000u16,
+					grpc_port: 25000u16,
+					p2p_port: 15000u16,
+				})
+			),
+			Error::<Test>::NodeIsAssignedToCluster
+		);
+
 		// Checking that event was emitted
 		assert_eq!(System::events().len(), 2);
 		System::assert_last_event(
@@ -23,9 +23,13 @@ pub mod migrations;
 
 use ddc_primitives::{
 	traits::{
-		bucket::BucketManager, cluster::ClusterProtocol as ClusterProtocolType,
-		customer::CustomerCharger as CustomerChargerType, node::NodeManager,
-		pallet::PalletVisitor as PalletVisitorType, payout::PayoutProcessor, ClusterValidator,
+		bucket::BucketManager,
+		cluster::{ClusterDecommissionHandler, ClusterProtocol as ClusterProtocolType},
+		customer::CustomerCharger as CustomerChargerType,
+		node::NodeManager,
+		pallet::PalletVisitor as PalletVisitorType,
+		payout::PayoutProcessor,
+		ClusterValidator,
 	},
 	BatchIndex, BillingFingerprintParams, BillingReportParams, BucketId, BucketUsage, ClusterId,
 	CustomerCharge, DdcEra, Fingerprint, MMRProof, MergeMMRHash, NodePubKey, NodeUsage,
@@ -79,10 +83,12 @@ parameter_types! {
 
 #[frame_support::pallet]
 pub mod pallet {
-	use ddc_primitives::{traits::ValidatorVisitor, ClusterPricingParams};
+	use ddc_primitives::{
+		traits::ValidatorVisitor, ClusterPricingParams, CustomerPricing, PriceSchedule, PricingTier,
+	};
 	use frame_support::PalletId;
 	use sp_io::hashing::blake2_128;
-	use sp_runtime::traits::{AccountIdConversion, Zero};
+	use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
 
 	use super::*;
 
@@ -114,6 +120,10 @@ pub mod pallet {
 		type ClusterValidator: ClusterValidator<Self>;
 		#[pallet::constant]
 		type ValidatorsQuorum: Get<Percent>;
+		/// Number of blocks an initialized billing report can be disputed for before the customers
+		/// charging begins.
+		#[pallet::constant]
+		type DisputePeriod: Get<BlockNumberFor<Self>>;
 	}
 
 	#[pallet::event]
@@ -134,6 +144,7 @@ pub mod pallet {
 			customer_id: T::AccountId,
 			bucket_id: BucketId,
 			amount: u128,
+			price_schedule: PriceSchedule,
 		},
 		ChargeFailed {
 			cluster_id: ClusterId,
@@ -228,6 +239,33 @@ pub mod pallet {
 			payers_merkle_root: PayableUsageHash,
 			payees_merkle_root: PayableUsageHash,
 		},
+		BillingReportDisputed {
+			cluster_id: ClusterId,
+			era: DdcEra,
+		},
+		BillingReportRolledBack {
+			cluster_id: ClusterId,
+			era: DdcEra,
+			from: PayoutState,
+			to: PayoutState,
+		},
+		BatchVoided {
+			cluster_id: ClusterId,
+			era: DdcEra,
+			state: PayoutState,
+			batch_index: BatchIndex,
+		},
+		BillingReportForceAdvanced {
+			cluster_id: ClusterId,
+			era: DdcEra,
+			from: PayoutState,
+			to: PayoutState,
+		},
+		VoidedRewardsReturned {
+			cluster_id: ClusterId,
+			era: DdcEra,
+			amount: u128,
+		},
 	}
 
 	#[pallet::error]
@@ -258,6 +296,10 @@ pub mod pallet {
 		NoQuorumOnBillingFingerprint,
 		FailedToCreateMerkleRoot,
 		FailedToVerifyMerkleProof,
+		DisputeWindowIsOpen,
+		DisputeWindowIsClosed,
+		RollbackNotAllowed,
+		BillingReportIsNotFinalized,
 	}
 
 	#[pallet::storage]
@@ -271,6 +313,43 @@ pub mod pallet {
 		BillingReport<T>,
 	>;
 
+	/// The block number the dispute window of an initialized billing report ends at.
+	#[pallet::storage]
+	#[pallet::getter(fn dispute_windows)]
+	pub type DisputeWindows<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClusterId,
+		Blake2_128Concat,
+		DdcEra,
+		BlockNumberFor<T>,
+	>;
+
+	/// Batches of billing reports voided by governance.
+	#[pallet::storage]
+	#[pallet::getter(fn voided_batches)]
+	pub type VoidedBatches<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClusterId,
+		Blake2_128Concat,
+		DdcEra,
+		VoidedBatchIndexes,
+	>;
+
+	/// Usage of a bucket charged within the current billing month. The pricing tiers apply to the
+	/// volume charged in the month the charged era starts in.
+	#[pallet::storage]
+	#[pallet::getter(fn monthly_bucket_usages)]
+	pub type MonthlyBucketUsages<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClusterId,
+		Blake2_128Concat,
+		BucketId,
+		MonthlyBucketUsage,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn debtor_customers)]
 	pub type DebtorCustomers<T: Config> =
@@ -315,6 +394,36 @@ pub mod pallet {
 		}
 	}
 
+	/// Batches of a billing report processed without charging customers or rewarding providers.
+	/// Customers of a voided charging batch are not charged for the era, the rewards of a voided
+	/// rewarding batch remain in the vault and are reported as not distributed.
+	#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Default)]
+	pub struct VoidedBatchIndexes {
+		pub charging: BoundedBTreeSet<BatchIndex, MaxBatchesCount>,
+		pub rewarding: BoundedBTreeSet<BatchIndex, MaxBatchesCount>,
+	}
+
+	/// Usage of a bucket charged within a billing month, where the month is the index of the
+	/// average month since the Unix epoch.
+	#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Default)]
+	pub struct MonthlyBucketUsage {
+		pub month: i64,
+		pub usage: BucketUsage,
+	}
+
+	impl MonthlyBucketUsage {
+		/// Usage charged in the billing month of the era starting at `start_era`, starting over
+		/// from zero once the month is over.
+		pub fn in_month_of(self, start_era: i64) -> Self {
+			let month = start_era / AVG_SECONDS_MONTH;
+			if self.month == month {
+				self
+			} else {
+				Self { month, usage: BucketUsage::default() }
+			}
+		}
+	}
+
 	#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Default)]
 	// don't remove or change numbers, if needed add a new state to the end with new number
 	// DAC uses the state value for integration!
@@ -327,6 +436,7 @@ pub mod pallet {
 		RewardingProviders = 5,
 		ProvidersRewarded = 6,
 		Finalized = 7,
+		Disputed = 8,
 	}
 
 	/// Billing fingerprint includes payment-sensitive data used to validate the payouts for a
@@ -480,19 +590,53 @@ pub mod pallet {
 		Some(total_reward)
 	}
 
+	/// Charges the volume added to the volume charged before by the price of the tier each unit of
+	/// the added volume falls into. The volume below the first tier is charged by the base price.
+	fn get_tiered_charge(
+		base_price: u128,
+		tiers: &[PricingTier],
+		charged_volume: u64,
+		volume: u64,
+	) -> Option<u128> {
+		let end = charged_volume.saturating_add(volume);
+		let mut start = charged_volume;
+		let mut price = base_price;
+		let mut charge: u128 = 0;
+
+		for tier in tiers {
+			if start >= end {
+				break;
+			}
+			if tier.threshold > start {
+				let tier_start = tier.threshold.min(end);
+				charge = charge.checked_add(u128::from(tier_start - start).checked_mul(price)?)?;
+				start = tier_start;
+			}
+			price = tier.unit_price;
+		}
+
+		charge.checked_add(u128::from(end - start).checked_mul(price)?)
+	}
+
 	#[allow(clippy::field_reassign_with_default)]
 	fn get_customer_charge<T: Config>(
 		pricing: &ClusterPricingParams,
 		payable_usage: &BucketUsage,
+		monthly_usage: &BucketUsage,
 		start_era: i64,
 		end_era: i64,
 	) -> Result<CustomerCharge, DispatchError> {
 		let mut total_charge = CustomerCharge::default();
+		let tiers = &pricing.pricing_tiers;
 
 		total_charge.transfer = (|| -> Option<u128> {
-			(payable_usage.transferred_bytes as u128)
-				.checked_mul(pricing.unit_per_mb_streamed)?
-				.checked_div(byte_unit::MEBIBYTE)
+			get_tiered_charge(
+				pricing.unit_per_mb_streamed,
+				&tiers.mb_streamed,
+				monthly_usage.transferred_bytes,
+				payable_usage.transferred_bytes,
+			)?
+			.checked_div(byte_unit::MEBIBYTE)
 		})()
 		.ok_or(Error::<T>::ArithmeticOverflow)?;
 
@@ -501,21 +645,34 @@ pub mod pallet {
 		let fraction_of_month =
 			Perquintill::from_rational(duration_seconds as u64, AVG_SECONDS_MONTH as u64);
 
+		// The payable storage usage already includes the data stored in the previous eras
 		total_charge.storage = fraction_of_month *
 			(|| -> Option<u128> {
-				(payable_usage.stored_bytes as u128)
-					.checked_mul(pricing.unit_per_mb_stored)?
-					.checked_div(byte_unit::MEBIBYTE)
+				get_tiered_charge(
+					pricing.unit_per_mb_stored,
+					&tiers.mb_stored,
+					0,
+					u64::try_from(payable_usage.stored_bytes).unwrap_or_default(),
+				)?
+				.checked_div(byte_unit::MEBIBYTE)
 			})()
 			.ok_or(Error::<T>::ArithmeticOverflow)?;
 
-		total_charge.gets = (payable_usage.number_of_gets as u128)
-			.checked_mul(pricing.unit_per_get_request)
-			.ok_or(Error::<T>::ArithmeticOverflow)?;
+		total_charge.gets = get_tiered_charge(
+			pricing.unit_per_get_request,
+			&tiers.get_requests,
+			monthly_usage.number_of_gets,
+			payable_usage.number_of_gets,
+		)
+		.ok_or(Error::<T>::ArithmeticOverflow)?;
 
-		total_charge.puts = (payable_usage.number_of_puts as u128)
-			.checked_mul(pricing.unit_per_put_request)
-			.ok_or(Error::<T>::ArithmeticOverflow)?;
+		total_charge.puts = get_tiered_charge(
+			pricing.unit_per_put_request,
+			&tiers.put_requests,
+			monthly_usage.number_of_puts,
+			payable_usage.number_of_puts,
+		)
+		.ok_or(Error::<T>::ArithmeticOverflow)?;
 
 		Ok(total_charge)
 	}
@@ -715,6 +872,48 @@ pub mod pallet {
 		}
 	}
 
+	/// Billing reports of a decommissioned cluster are removed once they are finalized, followed by
+	/// the monthly usages of its buckets. Debts of customers and providers are kept, so that they
+	/// can still be settled.
+	impl<T: Config> ClusterDecommissionHandler for Pallet<T> {
+		fn clean_up_weight(limit: u32) -> Weight {
+			T::DbWeight::get().reads_writes(limit.into(), (limit as u64).saturating_mul(4))
+		}
+
+		fn clean_up(
+			cluster_id: &ClusterId,
+			_buckets_migrate_to: Option<ClusterId>,
+			limit: u32,
+		) -> Result<u32, DispatchError> {
+			let billing_reports: Vec<(DdcEra, BillingReport<T>)> =
+				ActiveBillingReports::<T>::iter_prefix(cluster_id)
+					.take(limit as usize)
+					.collect();
+			ensure!(
+				billing_reports
+					.iter()
+					.all(|(_, billing_report)| billing_report.state == PayoutState::Finalized),
+				Error::<T>::BillingReportIsNotFinalized
+			);
+
+			for (era, billing_report) in billing_reports.iter() {
+				ActiveBillingReports::<T>::remove(cluster_id, era);
+				BillingFingerprints::<T>::remove(billing_report.fingerprint);
+				DisputeWindows::<T>::remove(cluster_id, era);
+				VoidedBatches::<T>::remove(cluster_id, era);
+			}
+
+			let mut processed = billing_reports.len() as u32;
+			if processed < limit {
+				processed +=
+					MonthlyBucketUsages::<T>::clear_prefix(cluster_id, limit - processed, None)
+						.unique;
+			}
+
+			Ok(processed)
+		}
+	}
+
 	impl<T: Config> PayoutProcessor<T> for Pallet<T> {
 		fn commit_billing_fingerprint(
 			validator: T::AccountId,
@@ -801,6 +1000,10 @@ pub mod pallet {
 			};
 			ActiveBillingReports::<T>::insert(cluster_id, era, billing_report);
 
+			let dispute_window_end =
+				<frame_system::Pallet<T>>::block_number().saturating_add(T::DisputePeriod::get());
+			DisputeWindows::<T>::insert(cluster_id, era, dispute_window_end);
+
 			Self::deposit_event(Event::<T>::BillingReportInitialized { cluster_id, era });
 
 			Ok(())
@@ -817,6 +1020,11 @@ pub mod pallet {
 				.map_err(|_| Error::<T>::BillingReportDoesNotExist)?;
 
 			ensure!(billing_report.state == PayoutState::Initialized, Error::<T>::NotExpectedState);
+			ensure!(
+				!Self::is_dispute_window_open(&cluster_id, era),
+				Error::<T>::DisputeWindowIsOpen
+			);
+			DisputeWindows::<T>::remove(cluster_id, era);
 
 			billing_report.charging_max_batch_index = max_batch_index;
 			billing_report.state = PayoutState::ChargingCustomers;
@@ -877,13 +1085,39 @@ pub mod pallet {
 			for (bucket_ref, payable_usage) in payers {
 				let bucket_id = *bucket_ref;
 				let customer_id = T::BucketManager::get_bucket_owner_id(bucket_id)?;
+				let mut monthly_usage = MonthlyBucketUsages::<T>::get(cluster_id, bucket_id)
+					.unwrap_or_default()
+					.in_month_of(billing_fingerprint.start_era);
+
+				let price_schedule = T::ClusterProtocol::get_price_schedule(
+					&cluster_id,
+					&customer_id,
+					bucket_id,
+					billing_fingerprint.start_era,
+				);
+				let (customer_pricing, discount) = match &price_schedule {
+					PriceSchedule::Cluster => (&pricing, None),
+					PriceSchedule::Customer(customer_pricing) |
+					PriceSchedule::Bucket(customer_pricing) => match customer_pricing {
+						CustomerPricing::Params(params) => (params, None),
+						CustomerPricing::Discount(discount) => (&pricing, Some(*discount)),
+					},
+				};
 
 				let mut customer_charge = get_customer_charge::<T>(
-					&pricing,
+					customer_pricing,
 					payable_usage,
+					&monthly_usage.usage,
 					billing_fingerprint.start_era,
 					billing_fingerprint.end_era,
 				)?;
+				if let Some(discount) = discount {
+					let ratio = discount.left_from_one();
+					customer_charge.storage = ratio * customer_charge.storage;
+					customer_charge.transfer = ratio * customer_charge.transfer;
+					customer_charge.gets = ratio * customer_charge.gets;
+					customer_charge.puts = ratio * customer_charge.puts;
+				}
 				let total_customer_charge = (|| -> Option<u128> {
 					customer_charge
 						.transfer
@@ -963,6 +1197,7 @@ pub mod pallet {
 						customer_id: customer_id.clone(),
 						bucket_id,
 						amount: total_customer_charge,
+						price_schedule,
 					});
 				}
 
@@ -990,6 +1225,16 @@ pub mod pallet {
 					.checked_add(customer_charge.gets)
 					.ok_or(Error::<T>::ArithmeticOverflow)?;
 
+				monthly_usage.usage.transferred_bytes = monthly_usage
+					.usage
+					.transferred_bytes
+					.saturating_add(payable_usage.transferred_bytes);
+				monthly_usage.usage.number_of_puts =
+					monthly_usage.usage.number_of_puts.saturating_add(payable_usage.number_of_puts);
+				monthly_usage.usage.number_of_gets =
+					monthly_usage.usage.number_of_gets.saturating_add(payable_usage.number_of_gets);
+				MonthlyBucketUsages::<T>::insert(cluster_id, bucket_id, monthly_usage);
+
 				T::BucketManager::update_total_bucket_usage(
 					&cluster_id,
 					bucket_id,
@@ -1283,6 +1528,32 @@ pub mod pallet {
 				});
 			}
 
+			// rewards of the voided batches are never distributed, they are returned to the
+			// treasury instead of being left in the vault. The vault is shared by the billing
+			// reports of all clusters and eras, so only the reward left by this report is returned.
+			let has_voided_rewarding = VoidedBatches::<T>::get(cluster_id, era)
+				.map_or(false, |voided| !voided.rewarding.is_empty());
+			if has_voided_rewarding {
+				let amount = expected_amount_to_reward
+					.saturating_sub(billing_report.total_distributed_reward)
+					.saturated_into::<BalanceOf<T>>()
+					.min(<T as pallet::Config>::Currency::free_balance(&billing_report.vault));
+				if !amount.is_zero() {
+					<T as pallet::Config>::Currency::transfer(
+						&billing_report.vault,
+						&T::TreasuryVisitor::get_account_id(),
+						amount,
+						ExistenceRequirement::AllowDeath,
+					)?;
+
+					Self::deposit_event(Event::<T>::VoidedRewardsReturned {
+						cluster_id,
+						era,
+						amount: amount.saturated_into::<u128>(),
+					});
+				}
+			}
+
 			billing_report.state = PayoutState::ProvidersRewarded;
 			ActiveBillingReports::<T>::insert(cluster_id, era, billing_report);
 
@@ -1344,6 +1615,24 @@ pub mod pallet {
 			.is_ok()
 		}
 
+		fn is_customers_batch_processed(
+			cluster_id: &ClusterId,
+			era_id: DdcEra,
+			batch_index: BatchIndex,
+		) -> bool {
+			ActiveBillingReports::<T>::get(cluster_id, era_id)
+				.is_some_and(|report| report.charging_processed_batches.contains(&batch_index))
+		}
+
+		fn is_providers_batch_processed(
+			cluster_id: &ClusterId,
+			era_id: DdcEra,
+			batch_index: BatchIndex,
+		) -> bool {
+			ActiveBillingReports::<T>::get(cluster_id, era_id)
+				.is_some_and(|report| report.rewarding_processed_batches.contains(&batch_index))
+		}
+
 		fn get_next_customer_batch_for_payment(
 			cluster_id: &ClusterId,
 			era_id: DdcEra,
@@ -1427,5 +1716,213 @@ pub mod pallet {
 
 			fingerprint
 		}
+
+		fn get_billing_fingerprint(
+			cluster_id: &ClusterId,
+			era_id: DdcEra,
+		) -> Result<BillingFingerprintParams<T::AccountId>, DispatchError> {
+			let billing_report = ActiveBillingReports::<T>::try_get(cluster_id, era_id)
+				.map_err(|_| Error::<T>::BillingReportDoesNotExist)?;
+
+			let billing_fingerprint = BillingFingerprints::<T>::try_get(billing_report.fingerprint)
+				.map_err(|_| Error::<T>::BillingFingerprintDoesNotExist)?;
+
+			Ok(BillingFingerprintParams {
+				cluster_id: billing_fingerprint.cluster_id,
+				era: billing_fingerprint.era_id,
+				start_era: billing_fingerprint.start_era,
+				end_era: billing_fingerprint.end_era,
+				payers_merkle_root: billing_fingerprint.payers_merkle_root,
+				payees_merkle_root: billing_fingerprint.payees_merkle_root,
+				cluster_usage: billing_fingerprint.cluster_usage,
+				validators: billing_fingerprint.validators,
+			})
+		}
+
+		fn is_dispute_window_open(cluster_id: &ClusterId, era_id: DdcEra) -> bool {
+			let dispute_window_end = match DisputeWindows::<T>::get(cluster_id, era_id) {
+				Some(dispute_window_end) => dispute_window_end,
+				None => return false,
+			};
+
+			Self::get_billing_report_status(cluster_id, era_id) == PayoutState::Initialized &&
+				<frame_system::Pallet<T>>::block_number() < dispute_window_end
+		}
+
+		fn dispute_billing_report(cluster_id: ClusterId, era: DdcEra) -> DispatchResult {
+			let mut billing_report = ActiveBillingReports::<T>::try_get(cluster_id, era)
+				.map_err(|_| Error::<T>::BillingReportDoesNotExist)?;
+
+			ensure!(billing_report.state == PayoutState::Initialized, Error::<T>::NotExpectedState);
+			ensure!(
+				Self::is_dispute_window_open(&cluster_id, era),
+				Error::<T>::DisputeWindowIsClosed
+			);
+
+			billing_report.state = PayoutState::Disputed;
+			ActiveBillingReports::<T>::insert(cluster_id, era, billing_report);
+			DisputeWindows::<T>::remove(cluster_id, era);
+
+			Self::deposit_event(Event::<T>::BillingReportDisputed { cluster_id, era });
+
+			Ok(())
+		}
+
+		fn rollback_billing_report(
+			cluster_id: ClusterId,
+			era: DdcEra,
+			state: PayoutState,
+		) -> DispatchResult {
+			let mut billing_report = ActiveBillingReports::<T>::try_get(cluster_id, era)
+				.map_err(|_| Error::<T>::BillingReportDoesNotExist)?;
+			let from = billing_report.state.clone();
+
+			// a disputed billing report is halted before charging customers
+			let from_stage = match from {
+				PayoutState::Disputed => PayoutState::Initialized as u8,
+				_ => from.clone() as u8,
+			};
+			let to_stage = state.clone() as u8;
+			ensure!(
+				state != PayoutState::Disputed &&
+					(to_stage < from_stage ||
+						(from == PayoutState::Disputed && state == PayoutState::Initialized)),
+				Error::<T>::NotExpectedState
+			);
+			ensure!(from != PayoutState::Finalized, Error::<T>::RollbackNotAllowed);
+
+			// fees are collected once customers are charged, so charging can't be undone anymore
+			ensure!(
+				from_stage < PayoutState::CustomersChargedWithFees as u8 ||
+					to_stage >= PayoutState::CustomersChargedWithFees as u8,
+				Error::<T>::RollbackNotAllowed
+			);
+
+			// only the stages with voided batches alone can be undone
+			let mut voided = VoidedBatches::<T>::get(cluster_id, era).unwrap_or_default();
+			if to_stage < PayoutState::ChargingCustomers as u8 {
+				ensure!(
+					billing_report.charging_processed_batches.is_subset(&voided.charging),
+					Error::<T>::RollbackNotAllowed
+				);
+				billing_report.charging_max_batch_index = Zero::zero();
+				billing_report.charging_processed_batches.clear();
+				voided.charging.clear();
+			}
+			if to_stage < PayoutState::RewardingProviders as u8 {
+				ensure!(
+					billing_report.rewarding_processed_batches.is_subset(&voided.rewarding),
+					Error::<T>::RollbackNotAllowed
+				);
+				billing_report.rewarding_max_batch_index = Zero::zero();
+				billing_report.rewarding_processed_batches.clear();
+				voided.rewarding.clear();
+			}
+
+			if state == PayoutState::NotInitialized {
+				ActiveBillingReports::<T>::remove(cluster_id, era);
+				DisputeWindows::<T>::remove(cluster_id, era);
+			} else {
+				billing_report.state = state.clone();
+				ActiveBillingReports::<T>::insert(cluster_id, era, billing_report);
+			}
+
+			if voided == Default::default() {
+				VoidedBatches::<T>::remove(cluster_id, era);
+			} else {
+				VoidedBatches::<T>::insert(cluster_id, era, voided);
+			}
+
+			Self::deposit_event(Event::<T>::BillingReportRolledBack {
+				cluster_id,
+				era,
+				from,
+				to: state,
+			});
+
+			Ok(())
+		}
+
+		fn void_billing_report_batch(
+			cluster_id: ClusterId,
+			era: DdcEra,
+			batch_index: BatchIndex,
+		) -> DispatchResult {
+			let mut billing_report = ActiveBillingReports::<T>::try_get(cluster_id, era)
+				.map_err(|_| Error::<T>::BillingReportDoesNotExist)?;
+			let mut voided = VoidedBatches::<T>::get(cluster_id, era).unwrap_or_default();
+
+			let state = billing_report.state.clone();
+			let (max_batch_index, processed_batches, voided_batches) = match state {
+				PayoutState::ChargingCustomers => (
+					billing_report.charging_max_batch_index,
+					&mut billing_report.charging_processed_batches,
+					&mut voided.charging,
+				),
+				PayoutState::RewardingProviders => (
+					billing_report.rewarding_max_batch_index,
+					&mut billing_report.rewarding_processed_batches,
+					&mut voided.rewarding,
+				),
+				_ => return Err(Error::<T>::NotExpectedState.into()),
+			};
+
+			ensure!(max_batch_index >= batch_index, Error::<T>::BatchIndexIsOutOfRange);
+			ensure!(
+				!processed_batches.contains(&batch_index),
+				Error::<T>::BatchIndexAlreadyProcessed
+			);
+
+			processed_batches
+				.try_insert(batch_index)
+				.map_err(|_| Error::<T>::BoundedVecOverflow)?;
+			voided_batches
+				.try_insert(batch_index)
+				.map_err(|_| Error::<T>::BoundedVecOverflow)?;
+
+			ActiveBillingReports::<T>::insert(cluster_id, era, billing_report);
+			VoidedBatches::<T>::insert(cluster_id, era, voided);
+
+			Self::deposit_event(Event::<T>::BatchVoided { cluster_id, era, state, batch_index });
+
+			Ok(())
+		}
+
+		fn force_advance_billing_report(cluster_id: ClusterId, era: DdcEra) -> DispatchResult {
+			let from = Self::get_billing_report_status(&cluster_id, era);
+
+			match from {
+				PayoutState::ChargingCustomers => {
+					while let Some(batch_index) =
+						Self::get_next_customer_batch_for_payment(&cluster_id, era)
+							.map_err(|_| Error::<T>::BillingReportDoesNotExist)?
+					{
+						Self::void_billing_report_batch(cluster_id, era, batch_index)?;
+					}
+					Self::end_charging_customers(cluster_id, era)?;
+				},
+				PayoutState::RewardingProviders => {
+					while let Some(batch_index) =
+						Self::get_next_provider_batch_for_payment(&cluster_id, era)
+							.map_err(|_| Error::<T>::BillingReportDoesNotExist)?
+					{
+						Self::void_billing_report_batch(cluster_id, era, batch_index)?;
+					}
+					Self::end_rewarding_providers(cluster_id, era)?;
+				},
+				PayoutState::NotInitialized =>
+					return Err(Error::<T>::BillingReportDoesNotExist.into()),
+				_ => return Err(Error::<T>::NotExpectedState.into()),
+			}
+
+			Self::deposit_event(Event::<T>::BillingReportForceAdvanced {
+				cluster_id,
+				era,
+				from,
+				to: Self::get_billing_report_status(&cluster_id, era),
+			});
+
+			Ok(())
+		}
 	}
 }
@@ -9,12 +9,13 @@ use ddc_primitives::{
 		bucket::BucketManager, cluster::ClusterProtocol, customer::CustomerCharger,
 		node::NodeManager, pallet::PalletVisitor, ClusterQuery, ValidatorVisitor,
 	},
-	ClusterBondingParams, ClusterFeesParams, ClusterPricingParams, ClusterProtocolParams,
-	ClusterStatus, NodeParams, NodePubKey, NodeType, DOLLARS,
+	ClusterBondingParams, ClusterFeesParams, ClusterPricingParams, ClusterPricingTiers,
+	ClusterProtocolParams, ClusterStatus, NodeParams, NodePubKey, NodeType, PriceSchedule,
+	PricingTier, DOLLARS,
 };
 use frame_election_provider_support::SortedListProvider;
 use frame_support::{
-	construct_runtime, parameter_types,
+	bounded_vec, construct_runtime, parameter_types,
 	traits::{ConstU32, ConstU64, Everything, ExistenceRequirement, Randomness},
 	weights::constants::RocksDbWeight,
 	PalletId,
@@ -120,6 +121,8 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	pub const PayoutsPalletId: PalletId = PalletId(*b"payouts_");
 	pub const MajorityOfValidators: Percent = Percent::from_percent(67);
+	pub static DisputePeriod: BlockNumber = 0;
+	pub static CustomerPriceSchedule: PriceSchedule = PriceSchedule::Cluster;
 }
 
 impl crate::pallet::Config for Test {
@@ -138,6 +141,7 @@ impl crate::pallet::Config for Test {
 	type Hasher = BlakeTwo256;
 	type ClusterValidator = MockClusterValidator;
 	type ValidatorsQuorum = MajorityOfValidators;
+	type DisputePeriod = DisputePeriod;
 }
 
 pub struct MockClusterValidator;
@@ -451,54 +455,100 @@ pub const STREAM_ZERO_CLUSTER_ID: ClusterId = ClusterId::repeat_byte(7u8);
 pub const PUT_ZERO_CLUSTER_ID: ClusterId = ClusterId::repeat_byte(8u8);
 pub const GET_ZERO_CLUSTER_ID: ClusterId = ClusterId::repeat_byte(9u8);
 pub const STORAGE_STREAM_ZERO_CLUSTER_ID: ClusterId = ClusterId::repeat_byte(11u8);
-pub const PRICING_PARAMS: ClusterPricingParams = ClusterPricingParams {
-	unit_per_mb_streamed: 2_000_000,
-	unit_per_mb_stored: 3_000_000,
-	unit_per_put_request: 4_000_000,
-	unit_per_get_request: 5_000_000,
-};
+pub const TIERED_CLUSTER_ID: ClusterId = ClusterId::repeat_byte(13u8);
+pub fn pricing_params() -> ClusterPricingParams {
+	ClusterPricingParams {
+		unit_per_mb_streamed: 2_000_000,
+		unit_per_mb_stored: 3_000_000,
+		unit_per_put_request: 4_000_000,
+		unit_per_get_request: 5_000_000,
+		pricing_tiers: Default::default(),
+	}
+}
 
-pub const PRICING_PARAMS_STREAM_ZERO: ClusterPricingParams = ClusterPricingParams {
-	unit_per_mb_streamed: 0,
-	unit_per_mb_stored: 3_000_000,
-	unit_per_put_request: 4_000_000,
-	unit_per_get_request: 5_000_000,
-};
+pub fn pricing_params_tiered() -> ClusterPricingParams {
+	ClusterPricingParams {
+		unit_per_mb_streamed: 2_000_000,
+		unit_per_mb_stored: 3_000_000,
+		unit_per_put_request: 4_000_000,
+		unit_per_get_request: 5_000_000,
+		pricing_tiers: ClusterPricingTiers {
+			mb_stored: bounded_vec![PricingTier {
+				threshold: 2 * 1024 * 1024,
+				unit_price: 1_000_000
+			}],
+			mb_streamed: bounded_vec![PricingTier {
+				threshold: 1024 * 1024,
+				unit_price: 1_000_000
+			}],
+			put_requests: bounded_vec![
+				PricingTier { threshold: 10, unit_price: 2_000_000 },
+				PricingTier { threshold: 100, unit_price: 1_000_000 }
+			],
+			get_requests: bounded_vec![PricingTier { threshold: 10, unit_price: 1_000_000 }],
+		},
+	}
+}
 
-pub const PRICING_PARAMS_STORAGE_ZERO: ClusterPricingParams = ClusterPricingParams {
-	unit_per_mb_streamed: 2_000_000,
-	unit_per_mb_stored: 0,
-	unit_per_put_request: 4_000_000,
-	unit_per_get_request: 5_000_000,
-};
+pub fn pricing_params_stream_zero() -> ClusterPricingParams {
+	ClusterPricingParams {
+		unit_per_mb_streamed: 0,
+		unit_per_mb_stored: 3_000_000,
+		unit_per_put_request: 4_000_000,
+		unit_per_get_request: 5_000_000,
+		pricing_tiers: Default::default(),
+	}
+}
 
-pub const PRICING_PARAMS_GET_ZERO: ClusterPricingParams = ClusterPricingParams {
-	unit_per_mb_streamed: 2_000_000,
-	unit_per_mb_stored: 3_000_000,
-	unit_per_put_request: 4_000_000,
-	unit_per_get_request: 0,
-};
+pub fn pricing_params_storage_zero() -> ClusterPricingParams {
+	ClusterPricingParams {
+		unit_per_mb_streamed: 2_000_000,
+		unit_per_mb_stored: 0,
+		unit_per_put_request: 4_000_000,
+		unit_per_get_request: 5_000_000,
+		pricing_tiers: Default::default(),
+	}
+}
 
-pub const PRICING_PARAMS_PUT_ZERO: ClusterPricingParams = ClusterPricingParams {
-	unit_per_mb_streamed: 2_000_000,
-	unit_per_mb_stored: 3_000_000,
-	unit_per_put_request: 0,
-	unit_per_get_request: 5_000_000,
-};
+pub fn pricing_params_get_zero() -> ClusterPricingParams {
+	ClusterPricingParams {
+		unit_per_mb_streamed: 2_000_000,
+		unit_per_mb_stored: 3_000_000,
+		unit_per_put_request: 4_000_000,
+		unit_per_get_request: 0,
+		pricing_tiers: Default::default(),
+	}
+}
 
-pub const PRICING_PARAMS_ONE: ClusterPricingParams = ClusterPricingParams {
-	unit_per_mb_streamed: 10_000_000_000,
-	unit_per_mb_stored: 10_000_000_000,
-	unit_per_put_request: 10_000_000_000,
-	unit_per_get_request: 10_000_000_000,
-};
+pub fn pricing_params_put_zero() -> ClusterPricingParams {
+	ClusterPricingParams {
+		unit_per_mb_streamed: 2_000_000,
+		unit_per_mb_stored: 3_000_000,
+		unit_per_put_request: 0,
+		unit_per_get_request: 5_000_000,
+		pricing_tiers: Default::default(),
+	}
+}
 
-pub const PRICING_PARAMS_CERE: ClusterPricingParams = ClusterPricingParams {
-	unit_per_mb_streamed: DOLLARS,
-	unit_per_mb_stored: DOLLARS,
-	unit_per_put_request: DOLLARS,
-	unit_per_get_request: DOLLARS,
-};
+pub fn pricing_params_one() -> ClusterPricingParams {
+	ClusterPricingParams {
+		unit_per_mb_streamed: 10_000_000_000,
+		unit_per_mb_stored: 10_000_000_000,
+		unit_per_put_request: 10_000_000_000,
+		unit_per_get_request: 10_000_000_000,
+		pricing_tiers: Default::default(),
+	}
+}
+
+pub fn pricing_params_cere() -> ClusterPricingParams {
+	ClusterPricingParams {
+		unit_per_mb_streamed: DOLLARS,
+		unit_per_mb_stored: DOLLARS,
+		unit_per_put_request: DOLLARS,
+		unit_per_get_request: DOLLARS,
+		pricing_tiers: Default::default(),
+	}
+}
 
 pub const PRICING_FEES: ClusterFeesParams = ClusterFeesParams {
 	treasury_share: Perquintill::from_percent(1),
@@ -619,19 +669,21 @@ pub fn get_fees(cluster_id: &ClusterId) -> ClusterFeesParams {
 
 pub fn get_pricing(cluster_id: &ClusterId) -> ClusterPricingParams {
 	if *cluster_id == ONE_CLUSTER_ID || *cluster_id == NO_FEE_CLUSTER_ID {
-		PRICING_PARAMS_ONE
+		pricing_params_one()
 	} else if *cluster_id == CERE_CLUSTER_ID {
-		PRICING_PARAMS_CERE
+		pricing_params_cere()
 	} else if *cluster_id == STORAGE_ZERO_CLUSTER_ID {
-		PRICING_PARAMS_STORAGE_ZERO
+		pricing_params_storage_zero()
 	} else if *cluster_id == STREAM_ZERO_CLUSTER_ID {
-		PRICING_PARAMS_STREAM_ZERO
+		pricing_params_stream_zero()
 	} else if *cluster_id == PUT_ZERO_CLUSTER_ID {
-		PRICING_PARAMS_PUT_ZERO
+		pricing_params_put_zero()
 	} else if *cluster_id == GET_ZERO_CLUSTER_ID {
-		PRICING_PARAMS_GET_ZERO
+		pricing_params_get_zero()
+	} else if *cluster_id == TIERED_CLUSTER_ID {
+		pricing_params_tiered()
 	} else {
-		PRICING_PARAMS
+		pricing_params()
 	}
 }
 
@@ -650,6 +702,10 @@ impl<T: Config> ClusterQuery<T> for TestClusterProtocol {
 	) -> Result<(T::AccountId, T::AccountId), DispatchError> {
 		unimplemented!()
 	}
+
+	fn is_decommissioning(_cluster_id: &ClusterId) -> bool {
+		false
+	}
 }
 
 impl<T: Config> ClusterProtocol<T, BalanceOf<T>> for TestClusterProtocol {
@@ -712,6 +768,15 @@ impl<T: Config> ClusterProtocol<T, BalanceOf<T>> for TestClusterProtocol {
 	fn end_unbond_cluster(_cluster_id: &ClusterId) -> DispatchResult {
 		unimplemented!()
 	}
+
+	fn get_price_schedule(
+		_cluster_id: &ClusterId,
+		_customer_id: &T::AccountId,
+		_bucket_id: BucketId,
+		_time: i64,
+	) -> PriceSchedule {
+		CustomerPriceSchedule::get()
+	}
 }
 
 pub(crate) type TestRuntimeCall = <Test as frame_system::Config>::RuntimeCall;
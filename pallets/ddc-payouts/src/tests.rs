@@ -2,7 +2,7 @@
 //! Tests for the module.
 
 use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
-use ddc_primitives::{ClusterId, Fingerprint, PayableUsageHash};
+use ddc_primitives::{ClusterId, CustomerPricing, Fingerprint, PayableUsageHash, PriceSchedule};
 use frame_support::{assert_noop, assert_ok, traits::Randomness};
 use polkadot_ckb_merkle_mountain_range::{
 	util::{MemMMR, MemStore},
@@ -10,7 +10,7 @@ use polkadot_ckb_merkle_mountain_range::{
 };
 use sp_core::H256;
 use sp_io::hashing::blake2_256;
-use sp_runtime::Perquintill;
+use sp_runtime::{Percent, Perquintill};
 
 use super::{mock::*, *};
 
@@ -252,6 +252,139 @@ fn begin_charging_customers_works() {
 	})
 }
 
+#[test]
+fn billing_report_can_be_disputed_during_dispute_window() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+		DisputePeriod::set(10);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let era = 100;
+		let max_charging_batch_index = 2;
+		let start_era: i64 = 1_000_000_000;
+		let end_era: i64 = start_era + 3600;
+		let cluster_usage = NodeUsage::default();
+		let fingerprint = get_fingerprint(
+			&cluster_id,
+			era,
+			start_era,
+			end_era,
+			DEFAULT_PAYERS_ROOT,
+			DEFAULT_PAYEES_ROOT,
+			&cluster_usage,
+		);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::commit_billing_fingerprint(
+			VALIDATOR1_ACCOUNT_ID.into(),
+			cluster_id,
+			era,
+			start_era,
+			end_era,
+			DEFAULT_PAYERS_ROOT,
+			DEFAULT_PAYEES_ROOT,
+			cluster_usage,
+		));
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_billing_report(
+			cluster_id,
+			era,
+			fingerprint
+		));
+		assert_eq!(DdcPayouts::dispute_windows(cluster_id, era), Some(11));
+		assert!(<DdcPayouts as PayoutProcessor<Test>>::is_dispute_window_open(&cluster_id, era));
+
+		let billing_fingerprint =
+			<DdcPayouts as PayoutProcessor<Test>>::get_billing_fingerprint(&cluster_id, era)
+				.unwrap();
+		assert_eq!(billing_fingerprint.payers_merkle_root, DEFAULT_PAYERS_ROOT);
+		assert_eq!(billing_fingerprint.payees_merkle_root, DEFAULT_PAYEES_ROOT);
+		assert!(billing_fingerprint.validators.contains(&VALIDATOR1_ACCOUNT_ID.into()));
+
+		// charging can't begin while the billing report can be disputed
+		assert_noop!(
+			<DdcPayouts as PayoutProcessor<Test>>::begin_charging_customers(
+				cluster_id,
+				era,
+				max_charging_batch_index,
+			),
+			Error::<Test>::DisputeWindowIsOpen
+		);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::dispute_billing_report(cluster_id, era));
+		System::assert_last_event(Event::BillingReportDisputed { cluster_id, era }.into());
+
+		let report = DdcPayouts::active_billing_reports(cluster_id, era).unwrap();
+		assert_eq!(report.state, PayoutState::Disputed);
+		assert_eq!(DdcPayouts::dispute_windows(cluster_id, era), None);
+
+		// disputed billing report is halted
+		System::set_block_number(11);
+		assert_noop!(
+			<DdcPayouts as PayoutProcessor<Test>>::begin_charging_customers(
+				cluster_id,
+				era,
+				max_charging_batch_index,
+			),
+			Error::<Test>::NotExpectedState
+		);
+	})
+}
+
+#[test]
+fn billing_report_can_not_be_disputed_after_dispute_window() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+		DisputePeriod::set(10);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let era = 100;
+		let max_charging_batch_index = 2;
+		let start_era: i64 = 1_000_000_000;
+		let end_era: i64 = start_era + 3600;
+		let cluster_usage = NodeUsage::default();
+		let fingerprint = get_fingerprint(
+			&cluster_id,
+			era,
+			start_era,
+			end_era,
+			DEFAULT_PAYERS_ROOT,
+			DEFAULT_PAYEES_ROOT,
+			&cluster_usage,
+		);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::commit_billing_fingerprint(
+			VALIDATOR1_ACCOUNT_ID.into(),
+			cluster_id,
+			era,
+			start_era,
+			end_era,
+			DEFAULT_PAYERS_ROOT,
+			DEFAULT_PAYEES_ROOT,
+			cluster_usage,
+		));
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_billing_report(
+			cluster_id,
+			era,
+			fingerprint
+		));
+
+		System::set_block_number(11);
+		assert!(!<DdcPayouts as PayoutProcessor<Test>>::is_dispute_window_open(&cluster_id, era));
+		assert_noop!(
+			<DdcPayouts as PayoutProcessor<Test>>::dispute_billing_report(cluster_id, era),
+			Error::<Test>::DisputeWindowIsClosed
+		);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_charging_customers(
+			cluster_id,
+			era,
+			max_charging_batch_index,
+		));
+		assert_eq!(DdcPayouts::dispute_windows(cluster_id, era), None);
+	})
+}
+
 #[test]
 fn send_charging_customers_batch_fails_uninitialised() {
 	ExtBuilder.build_and_execute(|| {
@@ -609,6 +742,7 @@ fn send_charging_customers_batch_works() {
 				bucket_id: bucket_id4,
 				batch_index,
 				amount: usage4_charge,
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -634,6 +768,7 @@ fn send_charging_customers_batch_works() {
 				bucket_id: bucket_id1,
 				customer_id: user1.clone(),
 				amount: calculate_charge_for_month(cluster_id, usage1.clone()),
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -829,6 +964,7 @@ fn end_charging_customers_works_small_usage_1_hour() {
 				bucket_id: bucket_id6,
 				batch_index: 0,
 				amount: usage6_charge,
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -841,6 +977,7 @@ fn end_charging_customers_works_small_usage_1_hour() {
 				bucket_id: bucket_id7,
 				batch_index: 0,
 				amount: usage7_charge,
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -1096,6 +1233,7 @@ fn send_charging_customers_batch_works_for_day() {
 				bucket_id: bucket_id4,
 				batch_index,
 				amount: usage4_charge,
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -1121,6 +1259,7 @@ fn send_charging_customers_batch_works_for_day() {
 				customer_id: user1.clone(),
 				bucket_id: bucket_id1,
 				amount: calculate_charge_for_day(cluster_id, usage1.clone()),
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -1373,6 +1512,7 @@ fn send_charging_customers_batch_works_for_day_free_storage() {
 				bucket_id: bucket_id4,
 				batch_index,
 				amount: usage4_charge,
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -1398,6 +1538,7 @@ fn send_charging_customers_batch_works_for_day_free_storage() {
 				customer_id: user1.clone(),
 				bucket_id: bucket_id1,
 				amount: calculate_charge_for_day(cluster_id, usage1.clone()),
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -1650,6 +1791,7 @@ fn send_charging_customers_batch_works_for_day_free_stream() {
 				bucket_id: bucket_id4,
 				batch_index,
 				amount: usage4_charge,
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -1675,6 +1817,7 @@ fn send_charging_customers_batch_works_for_day_free_stream() {
 				customer_id: user1.clone(),
 				bucket_id: bucket_id1,
 				amount: calculate_charge_for_day(cluster_id, usage1.clone()),
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -1929,6 +2072,7 @@ fn send_charging_customers_batch_works_for_day_free_get() {
 				bucket_id: bucket_id4,
 				batch_index,
 				amount: usage4_charge,
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -1954,6 +2098,7 @@ fn send_charging_customers_batch_works_for_day_free_get() {
 				customer_id: user1.clone(),
 				bucket_id: bucket_id1,
 				amount: calculate_charge_for_day(cluster_id, usage1.clone()),
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -2207,6 +2352,7 @@ fn send_charging_customers_batch_works_for_day_free_put() {
 				bucket_id: bucket_id4,
 				batch_index,
 				amount: usage4_charge,
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -2232,6 +2378,7 @@ fn send_charging_customers_batch_works_for_day_free_put() {
 				bucket_id: bucket_id1,
 				customer_id: user1.clone(),
 				amount: calculate_charge_for_day(cluster_id, usage1.clone()),
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -2484,6 +2631,7 @@ fn send_charging_customers_batch_works_for_day_free_storage_stream() {
 				bucket_id: bucket_id4,
 				batch_index,
 				amount: usage4_charge,
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -2509,6 +2657,7 @@ fn send_charging_customers_batch_works_for_day_free_storage_stream() {
 				bucket_id: bucket_id1,
 				customer_id: user1.clone(),
 				amount: calculate_charge_for_day(cluster_id, usage1.clone()),
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -2605,7 +2754,329 @@ fn send_charging_customers_batch_works_for_day_free_storage_stream() {
 }
 
 #[test]
-fn send_charging_customers_batch_works_zero_fees() {
+fn send_charging_customers_batch_works_zero_fees() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ONE_CLUSTER_ID;
+		let era = 100;
+		let max_charging_batch_index = 0;
+		let batch_index = 0;
+		let bucket_id5: BucketId = BUCKET_ID5;
+		let usage1 = BucketUsage {
+			// should pass without debt
+			transferred_bytes: 1024,
+			stored_bytes: 1024,
+			number_of_puts: 1,
+			number_of_gets: 1,
+		};
+		let payers1 = vec![(bucket_id5, usage1.clone())];
+		let start_date = NaiveDate::from_ymd_opt(2023, 4, 1).unwrap(); // April 1st
+		let time = NaiveTime::from_hms_opt(0, 0, 0).unwrap(); // Midnight
+		let start_era: i64 =
+			DateTime::<Utc>::from_naive_utc_and_offset(start_date.and_time(time), Utc).timestamp();
+		let end_era: i64 = start_era + (30.44 * 24.0 * 3600.0) as i64;
+		let cluster_usage = NodeUsage::default();
+		let (_, payers_batch_proof, payers_root) = hash_bucket_payable_usage_batch(payers1.clone());
+
+		let fingerprint = get_fingerprint(
+			&cluster_id,
+			era,
+			start_era,
+			end_era,
+			payers_root,
+			DEFAULT_PAYEES_ROOT,
+			&cluster_usage,
+		);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::commit_billing_fingerprint(
+			VALIDATOR1_ACCOUNT_ID.into(),
+			cluster_id,
+			era,
+			start_era,
+			end_era,
+			payers_root,
+			DEFAULT_PAYEES_ROOT,
+			cluster_usage,
+		));
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_billing_report(
+			cluster_id,
+			era,
+			fingerprint
+		));
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_charging_customers(
+			cluster_id,
+			era,
+			max_charging_batch_index,
+		));
+		assert_eq!(System::events().len(), 3);
+
+		// batch 1
+		let mut report = DdcPayouts::active_billing_reports(cluster_id, era).unwrap();
+		let before_total_customer_charge = report.total_customer_charge;
+		let balance_before = Balances::free_balance(DdcPayouts::account_id());
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::send_charging_customers_batch(
+			cluster_id,
+			era,
+			batch_index,
+			&payers1,
+			payers_batch_proof,
+		));
+
+		let usage5_charge = calculate_charge_for_month(cluster_id, usage1.clone());
+		let charge5 = calculate_charge_parts_for_month(cluster_id, usage1);
+		let balance = Balances::free_balance(DdcPayouts::account_id());
+		report = DdcPayouts::active_billing_reports(cluster_id, era).unwrap();
+		assert_eq!(balance, usage5_charge + balance_before);
+		assert_eq!(
+			charge5.puts + before_total_customer_charge.puts,
+			report.total_customer_charge.puts
+		);
+		assert_eq!(
+			charge5.gets + before_total_customer_charge.gets,
+			report.total_customer_charge.gets
+		);
+		assert_eq!(
+			charge5.storage + before_total_customer_charge.storage,
+			report.total_customer_charge.storage
+		);
+		assert_eq!(
+			charge5.transfer + before_total_customer_charge.transfer,
+			report.total_customer_charge.transfer
+		);
+	})
+}
+
+#[test]
+fn send_charging_customers_batch_works_with_pricing_tiers() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = TIERED_CLUSTER_ID;
+		let era = 100;
+		let max_charging_batch_index = 0;
+		let batch_index = 0;
+		let bucket_id5: BucketId = BUCKET_ID5;
+		let usage1 = BucketUsage {
+			transferred_bytes: 1024 * 1024,
+			stored_bytes: 3 * 1024 * 1024,
+			number_of_puts: 100,
+			number_of_gets: 10,
+		};
+		let payers1 = vec![(bucket_id5, usage1)];
+		let start_date = NaiveDate::from_ymd_opt(2023, 4, 1).unwrap(); // April 1st
+		let time = NaiveTime::from_hms_opt(0, 0, 0).unwrap(); // Midnight
+		let start_era: i64 =
+			DateTime::<Utc>::from_naive_utc_and_offset(start_date.and_time(time), Utc).timestamp();
+		let end_era: i64 = start_era + (30.44 * 24.0 * 3600.0) as i64;
+		// the bucket has already consumed part of the first transfer, put and get tiers this month
+		let month = start_era / AVG_SECONDS_MONTH;
+		let monthly_usage = BucketUsage {
+			transferred_bytes: 512 * 1024,
+			stored_bytes: 0,
+			number_of_puts: 5,
+			number_of_gets: 20,
+		};
+		MonthlyBucketUsages::<Test>::insert(
+			cluster_id,
+			bucket_id5,
+			MonthlyBucketUsage { month, usage: monthly_usage },
+		);
+		let cluster_usage = NodeUsage::default();
+		let (_, payers_batch_proof, payers_root) = hash_bucket_payable_usage_batch(payers1.clone());
+
+		let fingerprint = get_fingerprint(
+			&cluster_id,
+			era,
+			start_era,
+			end_era,
+			payers_root,
+			DEFAULT_PAYEES_ROOT,
+			&cluster_usage,
+		);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::commit_billing_fingerprint(
+			VALIDATOR1_ACCOUNT_ID.into(),
+			cluster_id,
+			era,
+			start_era,
+			end_era,
+			payers_root,
+			DEFAULT_PAYEES_ROOT,
+			cluster_usage,
+		));
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_billing_report(
+			cluster_id,
+			era,
+			fingerprint
+		));
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_charging_customers(
+			cluster_id,
+			era,
+			max_charging_batch_index,
+		));
+
+		let balance_before = Balances::free_balance(DdcPayouts::account_id());
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::send_charging_customers_batch(
+			cluster_id,
+			era,
+			batch_index,
+			&payers1,
+			payers_batch_proof,
+		));
+
+		// 0.5 MB at 2_000_000 and 0.5 MB at 1_000_000
+		let transfer = 1_500_000;
+		// 2 MB at 3_000_000 and 1 MB at 1_000_000
+		let storage = 7_000_000;
+		// 5 puts at 4_000_000, 90 puts at 2_000_000 and 5 puts at 1_000_000
+		let puts = 205_000_000;
+		// 10 gets at 1_000_000
+		let gets = 10_000_000;
+
+		let report = DdcPayouts::active_billing_reports(cluster_id, era).unwrap();
+		assert_eq!(report.total_customer_charge.transfer, transfer);
+		assert_eq!(report.total_customer_charge.storage, storage);
+		assert_eq!(report.total_customer_charge.puts, puts);
+		assert_eq!(report.total_customer_charge.gets, gets);
+		assert_eq!(
+			Balances::free_balance(DdcPayouts::account_id()),
+			balance_before + transfer + storage + puts + gets
+		);
+		assert_eq!(
+			DdcPayouts::monthly_bucket_usages(cluster_id, bucket_id5),
+			Some(MonthlyBucketUsage {
+				month,
+				usage: BucketUsage {
+					transferred_bytes: 512 * 1024 + 1024 * 1024,
+					stored_bytes: 0,
+					number_of_puts: 105,
+					number_of_gets: 30,
+				},
+			})
+		);
+	})
+}
+
+#[test]
+fn send_charging_customers_batch_applies_pricing_tiers_per_month() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = TIERED_CLUSTER_ID;
+		let era = 100;
+		let max_charging_batch_index = 0;
+		let batch_index = 0;
+		let bucket_id5: BucketId = BUCKET_ID5;
+		let usage1 = BucketUsage {
+			transferred_bytes: 1024 * 1024,
+			stored_bytes: 3 * 1024 * 1024,
+			number_of_puts: 100,
+			number_of_gets: 10,
+		};
+		let payers1 = vec![(bucket_id5, usage1)];
+		let start_date = NaiveDate::from_ymd_opt(2023, 4, 1).unwrap(); // April 1st
+		let time = NaiveTime::from_hms_opt(0, 0, 0).unwrap(); // Midnight
+		let start_era: i64 =
+			DateTime::<Utc>::from_naive_utc_and_offset(start_date.and_time(time), Utc).timestamp();
+		let end_era: i64 = start_era + (30.44 * 24.0 * 3600.0) as i64;
+		// the bucket has consumed the first transfer, put and get tiers in the previous month
+		let month = start_era / AVG_SECONDS_MONTH;
+		let monthly_usage = BucketUsage {
+			transferred_bytes: 2 * 1024 * 1024,
+			stored_bytes: 0,
+			number_of_puts: 200,
+			number_of_gets: 20,
+		};
+		MonthlyBucketUsages::<Test>::insert(
+			cluster_id,
+			bucket_id5,
+			MonthlyBucketUsage { month: month - 1, usage: monthly_usage },
+		);
+		let cluster_usage = NodeUsage::default();
+		let (_, payers_batch_proof, payers_root) = hash_bucket_payable_usage_batch(payers1.clone());
+
+		let fingerprint = get_fingerprint(
+			&cluster_id,
+			era,
+			start_era,
+			end_era,
+			payers_root,
+			DEFAULT_PAYEES_ROOT,
+			&cluster_usage,
+		);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::commit_billing_fingerprint(
+			VALIDATOR1_ACCOUNT_ID.into(),
+			cluster_id,
+			era,
+			start_era,
+			end_era,
+			payers_root,
+			DEFAULT_PAYEES_ROOT,
+			cluster_usage,
+		));
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_billing_report(
+			cluster_id,
+			era,
+			fingerprint
+		));
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_charging_customers(
+			cluster_id,
+			era,
+			max_charging_batch_index,
+		));
+
+		let balance_before = Balances::free_balance(DdcPayouts::account_id());
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::send_charging_customers_batch(
+			cluster_id,
+			era,
+			batch_index,
+			&payers1,
+			payers_batch_proof,
+		));
+
+		// 1 MB at 2_000_000
+		let transfer = 2_000_000;
+		// 2 MB at 3_000_000 and 1 MB at 1_000_000
+		let storage = 7_000_000;
+		// 10 puts at 4_000_000 and 90 puts at 2_000_000
+		let puts = 220_000_000;
+		// 10 gets at 5_000_000
+		let gets = 50_000_000;
+
+		let report = DdcPayouts::active_billing_reports(cluster_id, era).unwrap();
+		assert_eq!(report.total_customer_charge.transfer, transfer);
+		assert_eq!(report.total_customer_charge.storage, storage);
+		assert_eq!(report.total_customer_charge.puts, puts);
+		assert_eq!(report.total_customer_charge.gets, gets);
+		assert_eq!(
+			Balances::free_balance(DdcPayouts::account_id()),
+			balance_before + transfer + storage + puts + gets
+		);
+		assert_eq!(
+			DdcPayouts::monthly_bucket_usages(cluster_id, bucket_id5),
+			Some(MonthlyBucketUsage {
+				month,
+				usage: BucketUsage {
+					transferred_bytes: 1024 * 1024,
+					stored_bytes: 0,
+					number_of_puts: 100,
+					number_of_gets: 10,
+				},
+			})
+		);
+	})
+}
+
+#[test]
+fn send_charging_customers_batch_works_with_pricing_override() {
 	ExtBuilder.build_and_execute(|| {
 		System::set_block_number(1);
 
@@ -2614,12 +3085,15 @@ fn send_charging_customers_batch_works_zero_fees() {
 		let max_charging_batch_index = 0;
 		let batch_index = 0;
 		let bucket_id5: BucketId = BUCKET_ID5;
+		let customer5: AccountId = CUSTOMER5_KEY_32;
+		let price_schedule =
+			PriceSchedule::Bucket(CustomerPricing::Discount(Percent::from_percent(20)));
+		CustomerPriceSchedule::set(price_schedule.clone());
 		let usage1 = BucketUsage {
-			// should pass without debt
-			transferred_bytes: 1024,
-			stored_bytes: 1024,
-			number_of_puts: 1,
-			number_of_gets: 1,
+			transferred_bytes: 1024 * 1024,
+			stored_bytes: 1024 * 1024,
+			number_of_puts: 10,
+			number_of_gets: 10,
 		};
 		let payers1 = vec![(bucket_id5, usage1.clone())];
 		let start_date = NaiveDate::from_ymd_opt(2023, 4, 1).unwrap(); // April 1st
@@ -2662,11 +3136,7 @@ fn send_charging_customers_batch_works_zero_fees() {
 			era,
 			max_charging_batch_index,
 		));
-		assert_eq!(System::events().len(), 3);
 
-		// batch 1
-		let mut report = DdcPayouts::active_billing_reports(cluster_id, era).unwrap();
-		let before_total_customer_charge = report.total_customer_charge;
 		let balance_before = Balances::free_balance(DdcPayouts::account_id());
 		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::send_charging_customers_batch(
 			cluster_id,
@@ -2676,27 +3146,34 @@ fn send_charging_customers_batch_works_zero_fees() {
 			payers_batch_proof,
 		));
 
-		let usage5_charge = calculate_charge_for_month(cluster_id, usage1.clone());
+		// the customer is charged 80% of the cluster prices
 		let charge5 = calculate_charge_parts_for_month(cluster_id, usage1);
-		let balance = Balances::free_balance(DdcPayouts::account_id());
-		report = DdcPayouts::active_billing_reports(cluster_id, era).unwrap();
-		assert_eq!(balance, usage5_charge + balance_before);
-		assert_eq!(
-			charge5.puts + before_total_customer_charge.puts,
-			report.total_customer_charge.puts
-		);
-		assert_eq!(
-			charge5.gets + before_total_customer_charge.gets,
-			report.total_customer_charge.gets
-		);
-		assert_eq!(
-			charge5.storage + before_total_customer_charge.storage,
-			report.total_customer_charge.storage
-		);
-		assert_eq!(
-			charge5.transfer + before_total_customer_charge.transfer,
-			report.total_customer_charge.transfer
+		let ratio = Percent::from_percent(80);
+		let report = DdcPayouts::active_billing_reports(cluster_id, era).unwrap();
+		assert_eq!(report.total_customer_charge.transfer, ratio * charge5.transfer);
+		assert_eq!(report.total_customer_charge.storage, ratio * charge5.storage);
+		assert_eq!(report.total_customer_charge.puts, ratio * charge5.puts);
+		assert_eq!(report.total_customer_charge.gets, ratio * charge5.gets);
+
+		let amount = ratio * charge5.transfer +
+			ratio * charge5.storage +
+			ratio * charge5.puts +
+			ratio * charge5.gets;
+		assert_eq!(Balances::free_balance(DdcPayouts::account_id()), balance_before + amount);
+		System::assert_last_event(
+			Event::Charged {
+				cluster_id,
+				era,
+				batch_index,
+				customer_id: customer5,
+				bucket_id: bucket_id5,
+				amount,
+				price_schedule,
+			}
+			.into(),
 		);
+
+		CustomerPriceSchedule::set(PriceSchedule::Cluster);
 	})
 }
 
@@ -2880,6 +3357,7 @@ fn end_charging_customers_works() {
 				customer_id: user1,
 				amount: charge,
 				bucket_id: bucket_id1,
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -3076,6 +3554,7 @@ fn end_charging_customers_works_zero_fees() {
 				bucket_id: bucket_id1,
 				batch_index,
 				amount: charge,
+				price_schedule: PriceSchedule::Cluster,
 			}
 			.into(),
 		);
@@ -3983,6 +4462,7 @@ fn send_rewarding_providers_batch_100_nodes_small_usage_works() {
 						customer_id: customer_id.clone(),
 						batch_index: batch_user_index,
 						amount: charge,
+						price_schedule: PriceSchedule::Cluster,
 					}
 					.into(),
 				);
@@ -4305,6 +4785,7 @@ fn send_rewarding_providers_batch_100_nodes_large_usage_works() {
 						customer_id: customer_id.clone(),
 						batch_index: batch_user_index,
 						amount: charge,
+						price_schedule: PriceSchedule::Cluster,
 					}
 					.into(),
 				);
@@ -4590,6 +5071,7 @@ fn send_rewarding_providers_batch_100_nodes_small_large_usage_works() {
 						bucket_id: *bucket_id,
 						batch_index: batch_user_index,
 						amount: charge,
+						price_schedule: PriceSchedule::Cluster,
 					}
 					.into(),
 				);
@@ -4892,6 +5374,7 @@ fn send_rewarding_providers_batch_100_nodes_random_usage_works() {
 						customer_id: customer_id.clone(),
 						batch_index: batch_user_index,
 						amount: charge,
+						price_schedule: PriceSchedule::Cluster,
 					}
 					.into(),
 				);
@@ -5521,3 +6004,371 @@ fn end_billing_report_works() {
 		assert_eq!(report_end.state, PayoutState::Finalized);
 	})
 }
+
+fn begin_billing_report_for_recovery(
+	cluster_id: ClusterId,
+	era: DdcEra,
+	payers_root: PayableUsageHash,
+	payees_root: PayableUsageHash,
+) {
+	let start_era: i64 = 1_000_000_000;
+	let end_era: i64 = start_era + 3600;
+	let cluster_usage = NodeUsage::default();
+	let fingerprint = get_fingerprint(
+		&cluster_id,
+		era,
+		start_era,
+		end_era,
+		payers_root,
+		payees_root,
+		&cluster_usage,
+	);
+
+	assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::commit_billing_fingerprint(
+		VALIDATOR1_ACCOUNT_ID.into(),
+		cluster_id,
+		era,
+		start_era,
+		end_era,
+		payers_root,
+		payees_root,
+		cluster_usage,
+	));
+
+	assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_billing_report(
+		cluster_id,
+		era,
+		fingerprint
+	));
+}
+
+#[test]
+fn rollback_billing_report_works() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+		DisputePeriod::set(10);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let era = 100;
+		begin_billing_report_for_recovery(
+			cluster_id,
+			era,
+			DEFAULT_PAYERS_ROOT,
+			DEFAULT_PAYEES_ROOT,
+		);
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::dispute_billing_report(cluster_id, era));
+
+		// disputed billing report can't move forward
+		assert_noop!(
+			<DdcPayouts as PayoutProcessor<Test>>::rollback_billing_report(
+				cluster_id,
+				era,
+				PayoutState::ChargingCustomers,
+			),
+			Error::<Test>::NotExpectedState
+		);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::rollback_billing_report(
+			cluster_id,
+			era,
+			PayoutState::Initialized,
+		));
+		System::assert_last_event(
+			Event::BillingReportRolledBack {
+				cluster_id,
+				era,
+				from: PayoutState::Disputed,
+				to: PayoutState::Initialized,
+			}
+			.into(),
+		);
+		let report = DdcPayouts::active_billing_reports(cluster_id, era).unwrap();
+		assert_eq!(report.state, PayoutState::Initialized);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::rollback_billing_report(
+			cluster_id,
+			era,
+			PayoutState::NotInitialized,
+		));
+		assert_eq!(DdcPayouts::active_billing_reports(cluster_id, era), None);
+		assert_eq!(DdcPayouts::dispute_windows(cluster_id, era), None);
+		assert_noop!(
+			<DdcPayouts as PayoutProcessor<Test>>::rollback_billing_report(
+				cluster_id,
+				era,
+				PayoutState::NotInitialized,
+			),
+			Error::<Test>::BillingReportDoesNotExist
+		);
+	})
+}
+
+#[test]
+fn rollback_billing_report_fails_with_charged_batches() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let era = 100;
+		let payers = vec![(BUCKET_ID3, BucketUsage::default())];
+		let (_, payers_batch_proof, payers_root) = hash_bucket_payable_usage_batch(payers.clone());
+		begin_billing_report_for_recovery(cluster_id, era, payers_root, DEFAULT_PAYEES_ROOT);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_charging_customers(
+			cluster_id, era, 1,
+		));
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::send_charging_customers_batch(
+			cluster_id,
+			era,
+			0,
+			&payers,
+			payers_batch_proof,
+		));
+
+		assert_noop!(
+			<DdcPayouts as PayoutProcessor<Test>>::rollback_billing_report(
+				cluster_id,
+				era,
+				PayoutState::Initialized,
+			),
+			Error::<Test>::RollbackNotAllowed
+		);
+
+		// voiding the remaining batch doesn't undo the charged one
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::void_billing_report_batch(
+			cluster_id, era, 1
+		));
+		assert_noop!(
+			<DdcPayouts as PayoutProcessor<Test>>::rollback_billing_report(
+				cluster_id,
+				era,
+				PayoutState::Initialized,
+			),
+			Error::<Test>::RollbackNotAllowed
+		);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::end_charging_customers(cluster_id, era));
+		assert_eq!(
+			DdcPayouts::get_billing_report_status(&cluster_id, era),
+			PayoutState::CustomersChargedWithFees
+		);
+
+		// fees are already collected
+		assert_noop!(
+			<DdcPayouts as PayoutProcessor<Test>>::rollback_billing_report(
+				cluster_id,
+				era,
+				PayoutState::ChargingCustomers,
+			),
+			Error::<Test>::RollbackNotAllowed
+		);
+	})
+}
+
+#[test]
+fn void_billing_report_batch_works() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let era = 100;
+		begin_billing_report_for_recovery(
+			cluster_id,
+			era,
+			DEFAULT_PAYERS_ROOT,
+			DEFAULT_PAYEES_ROOT,
+		);
+
+		assert_noop!(
+			<DdcPayouts as PayoutProcessor<Test>>::void_billing_report_batch(cluster_id, era, 0),
+			Error::<Test>::NotExpectedState
+		);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_charging_customers(
+			cluster_id, era, 1,
+		));
+		assert_noop!(
+			<DdcPayouts as PayoutProcessor<Test>>::void_billing_report_batch(cluster_id, era, 2),
+			Error::<Test>::BatchIndexIsOutOfRange
+		);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::void_billing_report_batch(
+			cluster_id, era, 1
+		));
+		System::assert_last_event(
+			Event::BatchVoided {
+				cluster_id,
+				era,
+				state: PayoutState::ChargingCustomers,
+				batch_index: 1,
+			}
+			.into(),
+		);
+		assert_noop!(
+			<DdcPayouts as PayoutProcessor<Test>>::void_billing_report_batch(cluster_id, era, 1),
+			Error::<Test>::BatchIndexAlreadyProcessed
+		);
+
+		let report = DdcPayouts::active_billing_reports(cluster_id, era).unwrap();
+		assert!(report.charging_processed_batches.contains(&1));
+		let voided = DdcPayouts::voided_batches(cluster_id, era).unwrap();
+		assert!(voided.charging.contains(&1));
+		assert!(voided.rewarding.is_empty());
+
+		// a billing report with voided batches only can be rolled back
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::rollback_billing_report(
+			cluster_id,
+			era,
+			PayoutState::Initialized,
+		));
+		let report = DdcPayouts::active_billing_reports(cluster_id, era).unwrap();
+		assert_eq!(report.state, PayoutState::Initialized);
+		assert_eq!(report.charging_max_batch_index, 0);
+		assert!(report.charging_processed_batches.is_empty());
+		assert_eq!(DdcPayouts::voided_batches(cluster_id, era), None);
+	})
+}
+
+#[test]
+fn force_advance_billing_report_works() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let era = 100;
+
+		assert_noop!(
+			<DdcPayouts as PayoutProcessor<Test>>::force_advance_billing_report(cluster_id, era),
+			Error::<Test>::BillingReportDoesNotExist
+		);
+
+		begin_billing_report_for_recovery(
+			cluster_id,
+			era,
+			DEFAULT_PAYERS_ROOT,
+			DEFAULT_PAYEES_ROOT,
+		);
+		assert_noop!(
+			<DdcPayouts as PayoutProcessor<Test>>::force_advance_billing_report(cluster_id, era),
+			Error::<Test>::NotExpectedState
+		);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_charging_customers(
+			cluster_id, era, 2,
+		));
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::force_advance_billing_report(
+			cluster_id, era
+		));
+		System::assert_last_event(
+			Event::BillingReportForceAdvanced {
+				cluster_id,
+				era,
+				from: PayoutState::ChargingCustomers,
+				to: PayoutState::CustomersChargedWithFees,
+			}
+			.into(),
+		);
+
+		let voided = DdcPayouts::voided_batches(cluster_id, era).unwrap();
+		assert_eq!(voided.charging.into_iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_rewarding_providers(
+			cluster_id, era, 1,
+		));
+
+		// the vault holds the funds of other billing reports only, as no customer was charged
+		let vault = DdcPayouts::active_billing_reports(cluster_id, era).unwrap().vault;
+		let _ = Balances::deposit_creating(&vault, 1_000);
+		let vault_balance = Balances::free_balance(&vault);
+		let treasury_balance = Balances::free_balance(AccountId::from(TREASURY_ACCOUNT_ID));
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::force_advance_billing_report(
+			cluster_id, era
+		));
+		assert_eq!(
+			DdcPayouts::get_billing_report_status(&cluster_id, era),
+			PayoutState::ProvidersRewarded
+		);
+		let voided = DdcPayouts::voided_batches(cluster_id, era).unwrap();
+		assert_eq!(voided.rewarding.into_iter().collect::<Vec<_>>(), vec![0, 1]);
+
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::DdcPayouts(Event::VoidedRewardsReturned { .. })
+		)));
+		assert_eq!(Balances::free_balance(&vault), vault_balance);
+		assert_eq!(Balances::free_balance(AccountId::from(TREASURY_ACCOUNT_ID)), treasury_balance);
+	})
+}
+
+#[test]
+fn voided_rewards_of_billing_report_are_returned_without_funds_of_other_reports() {
+	ExtBuilder.build_and_execute(|| {
+		System::set_block_number(1);
+
+		let era = 100;
+		let payers = vec![(
+			BUCKET_ID1,
+			BucketUsage {
+				transferred_bytes: 0,
+				stored_bytes: 0,
+				number_of_puts: 1,
+				number_of_gets: 0,
+			},
+		)];
+		let (_, payers_batch_proof, payers_root) = hash_bucket_payable_usage_batch(payers.clone());
+
+		// the billing reports of two clusters are in progress at the same time
+		let voided_cluster_id = NO_FEE_CLUSTER_ID;
+		let other_cluster_id = ONE_CLUSTER_ID;
+		for cluster_id in [voided_cluster_id, other_cluster_id] {
+			begin_billing_report_for_recovery(cluster_id, era, payers_root, DEFAULT_PAYEES_ROOT);
+			assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_charging_customers(
+				cluster_id, era, 0,
+			));
+			assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::send_charging_customers_batch(
+				cluster_id,
+				era,
+				0,
+				&payers,
+				payers_batch_proof.clone(),
+			));
+			assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::end_charging_customers(
+				cluster_id, era
+			));
+			assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::begin_rewarding_providers(
+				cluster_id, era, 0,
+			));
+		}
+
+		let report = DdcPayouts::active_billing_reports(voided_cluster_id, era).unwrap();
+		let rewards = report.total_customer_charge.transfer +
+			report.total_customer_charge.storage +
+			report.total_customer_charge.puts +
+			report.total_customer_charge.gets;
+		assert_eq!(rewards, 10_000_000_000);
+		let vault_balance = Balances::free_balance(&report.vault);
+		let treasury_balance = Balances::free_balance(AccountId::from(TREASURY_ACCOUNT_ID));
+
+		assert_ok!(<DdcPayouts as PayoutProcessor<Test>>::force_advance_billing_report(
+			voided_cluster_id,
+			era
+		));
+		System::assert_has_event(
+			Event::VoidedRewardsReturned { cluster_id: voided_cluster_id, era, amount: rewards }
+				.into(),
+		);
+		assert_eq!(
+			Balances::free_balance(AccountId::from(TREASURY_ACCOUNT_ID)),
+			treasury_balance + rewards
+		);
+
+		// the rewards of the other billing report stay in the vault
+		assert_eq!(Balances::free_balance(&report.vault), vault_balance - rewards);
+		assert!(Balances::free_balance(&report.vault) >= rewards);
+		assert_eq!(
+			DdcPayouts::get_billing_report_status(&other_cluster_id, era),
+			PayoutState::RewardingProviders
+		);
+	})
+}
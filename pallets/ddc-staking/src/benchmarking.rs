@@ -96,32 +96,42 @@ benchmarks! {
 	}
 
 	store {
+		let cluster_id = ClusterId::from([1; 20]);
 		let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([0; 32]));
-		let (stash, controller, _) = create_stash_controller_node_with_balance::<T>(0, T::ClusterProtocol::get_bond_size(&ClusterId::from([1; 20]), NodeType::Storage).unwrap_or(100u128), node_pub_key)?;
+		let (stash, controller, _) = create_stash_controller_node_with_balance::<T>(0, T::ClusterProtocol::get_bond_size(&cluster_id, NodeType::Storage).unwrap_or(100u128), node_pub_key.clone())?;
+
+		// The worst case is cancelling the chill of the node leaving the cluster.
+		DdcStaking::<T>::store(RawOrigin::Signed(controller.clone()).into(), cluster_id)?;
+		add_node_to_cluster::<T>(&cluster_id, &node_pub_key)?;
+		DdcStaking::<T>::chill(RawOrigin::Signed(controller.clone()).into())?;
 
 		whitelist_account!(controller);
-	}: _(RawOrigin::Signed(controller), ClusterId::from([1; 20]))
+	}: _(RawOrigin::Signed(controller.clone()), cluster_id)
 	verify {
 		assert!(Storages::<T>::contains_key(&stash));
+		let ledger = Ledger::<T>::get(&controller).ok_or("ledger not found")?;
+		assert!(ledger.chilling.is_none());
 	}
 
-
 	chill {
 		// clean up any existing state.
 		clear_activated_nodes::<T>();
 
+		let cluster_id = ClusterId::from([1; 20]);
 		let node_pub_key = NodePubKey::StoragePubKey(StorageNodePubKey::new([0; 32]));
-		let (storage_stash, storage_controller, _) = create_stash_controller_node_with_balance::<T>(0, T::ClusterProtocol::get_bond_size(&ClusterId::from([1; 20]), NodeType::Storage).unwrap_or(10u128), node_pub_key)?;
-		DdcStaking::<T>::store(RawOrigin::Signed(storage_controller.clone()).into(), ClusterId::from([1; 20]))?;
+		let (storage_stash, storage_controller, _) = create_stash_controller_node_with_balance::<T>(0, T::ClusterProtocol::get_bond_size(&cluster_id, NodeType::Storage).unwrap_or(10u128), node_pub_key.clone())?;
+		DdcStaking::<T>::store(RawOrigin::Signed(storage_controller.clone()).into(), cluster_id)?;
 		assert!(Storages::<T>::contains_key(&storage_stash));
-		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(1u32));
-		DdcStaking::<T>::chill(RawOrigin::Signed(storage_controller.clone()).into())?;
-		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(1u32) + T::ClusterProtocol::get_chill_delay(&ClusterId::from([1; 20]), NodeType::Storage).unwrap_or_else(|_| BlockNumberFor::<T>::from(10u32)));
+
+		// The worst case is the node chilled and removed from the cluster right away.
+		add_node_to_cluster::<T>(&cluster_id, &node_pub_key)?;
+		remove_chill_delays::<T>(&cluster_id)?;
 
 		whitelist_account!(storage_controller);
 	}: _(RawOrigin::Signed(storage_controller))
 	verify {
 		assert!(!Storages::<T>::contains_key(&storage_stash));
+		assert!(!T::ClusterManager::contains_node(&cluster_id, &node_pub_key, None));
 	}
 
 	set_controller {
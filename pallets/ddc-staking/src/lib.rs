@@ -488,14 +488,16 @@ pub mod pallet {
 					ledger.active = Zero::zero();
 				}
 
-				let min_active_bond = if let Some(cluster_id) = Self::storages(&ledger.stash) {
+				let min_active_bond = if let Some(cluster_id) =
+					Self::storages(&ledger.stash).filter(Self::cluster_exists)
+				{
 					let bond_size =
 						T::ClusterProtocol::get_bond_size(&cluster_id, NodeType::Storage)
 							.map_err(|_| Error::<T>::NoClusterGovParams)?;
 					bond_size.saturated_into::<BalanceOf<T>>()
 				} else {
-					// If node is not assigned to a cluster or node is chilling, allow to unbond
-					// any available amount.
+					// If node is not assigned to a cluster, node is chilling or the cluster is
+					// removed after decommissioning, allow to unbond any available amount.
 					Zero::zero()
 				};
 
@@ -510,7 +512,7 @@ pub mod pallet {
 					let node_cluster_id = T::NodeManager::get_cluster_id(&node_pub_key)
 						.map_err(|_| Error::<T>::NoCluster)?;
 
-					if let Some(cluster_id) = node_cluster_id {
+					if let Some(cluster_id) = node_cluster_id.filter(Self::cluster_exists) {
 						let bonding_params = T::ClusterProtocol::get_bonding_params(&cluster_id)
 							.map_err(|_| Error::<T>::NoClusterGovParams)?;
 
@@ -533,7 +535,8 @@ pub mod pallet {
 							NodePubKey::StoragePubKey(_) => bonding_params.storage_unbonding_delay,
 						}
 					} else {
-						// If node is not a member of any cluster, allow immediate unbonding.
+						// If node is not a member of any existing cluster, allow immediate
+						// unbonding.
 						BlockNumberFor::<T>::from(0u32)
 					}
 				} else {
@@ -660,6 +663,9 @@ pub mod pallet {
 				ensure!(current_cluster == cluster_id, Error::<T>::AlreadyInRole);
 				// Cancel previous "chill" attempts
 				Self::reset_chilling(&controller);
+				// The node could leave the cluster or be removed by the cluster manager by this
+				// moment already, so it is ok to ignore result.
+				let _ = T::ClusterManager::cancel_node_leaving(&cluster_id, &node_pub_key);
 				return Ok(());
 			} else {
 				// Can't participate in new Storage network if provider hasn't left the previous
@@ -684,7 +690,8 @@ pub mod pallet {
 		/// network participants. If the cluster settings updated significantly decreasing
 		/// the delay, one may invoke it again to decrease the block at with the participant may
 		/// "chill". But it never increases the block at which the participant may "chill" even when
-		/// the cluster settings updated increasing the delay.
+		/// the cluster settings updated increasing the delay. The participant of a cluster removed
+		/// after decommissioning is removed immediately.
 		///
 		/// The dispatch origin for this call must be _Signed_ by the controller, not the stash.
 		///
@@ -698,6 +705,13 @@ pub mod pallet {
 
 			// Extract delay from the cluster settings.
 			let (cluster, delay) = if let Some(cluster) = Self::storages(&ledger.stash) {
+				if !Self::cluster_exists(&cluster) {
+					// The cluster is removed after decommissioning, so there is nothing to leave.
+					Self::chill_stash(&ledger.stash);
+					Self::reset_chilling(&controller);
+					return Ok(());
+				}
+
 				let chill_delay = T::ClusterProtocol::get_chill_delay(&cluster, NodeType::Storage)
 					.map_err(|_| Error::<T>::NoClusterGovParams)?;
 				(cluster, chill_delay)
@@ -708,6 +722,7 @@ pub mod pallet {
 			if delay == BlockNumberFor::<T>::from(0u32) {
 				// No delay is set, so we can chill right away.
 				Self::chill_stash(&ledger.stash);
+				Self::start_node_leaving(&ledger.stash, &cluster);
 				return Ok(());
 			}
 
@@ -715,6 +730,7 @@ pub mod pallet {
 			match ledger.chilling {
 				None => {
 					// No previous declarations of desire to chill. Note it to allow chilling soon.
+					Self::start_node_leaving(&ledger.stash, &cluster);
 					Self::chill_stash_soon(&ledger.stash, &controller, cluster, can_chill_from);
 					return Ok(());
 				},
@@ -984,6 +1000,11 @@ pub mod pallet {
 			<ClusterLedger<T>>::insert(controller, ledger);
 		}
 
+		/// Whether the cluster exists, i.e. it is not removed after decommissioning.
+		fn cluster_exists(cluster_id: &ClusterId) -> bool {
+			<T::ClusterProtocol as ClusterQuery<T>>::cluster_exists(cluster_id)
+		}
+
 		/// Chill a stash account.
 		fn chill_stash(stash: &T::AccountId) {
 			let chilled_as_storage = Self::do_remove_storage(stash);
@@ -1007,6 +1028,18 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::ChillSoon(stash.clone(), cluster, can_chill_from));
 		}
 
+		/// Start the removal of the node of a chilling stash account from the cluster, so that the
+		/// data stored by the node is migrated during the notice period.
+		fn start_node_leaving(stash: &T::AccountId, cluster_id: &ClusterId) {
+			if let Some(node_pub_key) = <Providers<T>>::get(stash) {
+				if T::ClusterManager::contains_node(cluster_id, &node_pub_key, None) {
+					// Cluster manager could remove the node from cluster by this moment already, so
+					// it is ok to ignore result.
+					let _ = T::ClusterManager::start_node_leaving(cluster_id, &node_pub_key);
+				}
+			}
+		}
+
 		/// Remove all associated data of a stash account from the staking system.
 		///
 		/// Assumes storage is upgraded before calling.
@@ -1118,6 +1151,31 @@ pub mod pallet {
 			T::ClusterProtocol::bond_cluster(&cluster_id)?;
 			Ok(())
 		}
+
+		fn set_cluster_controller(
+			cluster_stash: &T::AccountId,
+			cluster_controller: &T::AccountId,
+		) -> DispatchResult {
+			let controller = match <ClusterBonded<T>>::get(cluster_stash) {
+				Some(controller) => controller,
+				None => return Ok(()),
+			};
+
+			if &controller == cluster_controller {
+				return Ok(());
+			}
+
+			if <ClusterLedger<T>>::contains_key(cluster_controller) {
+				Err(Error::<T>::AlreadyPaired)?
+			}
+
+			if let Some(ledger) = <ClusterLedger<T>>::take(&controller) {
+				<ClusterLedger<T>>::insert(cluster_controller, ledger);
+			}
+			<ClusterBonded<T>>::insert(cluster_stash, cluster_controller);
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> StakingVisitor<T> for Pallet<T> {
@@ -1158,5 +1216,27 @@ pub mod pallet {
 				.map(|l| l.stash)
 				.ok_or(StakingVisitorError::ControllerDoesNotExist)
 		}
+
+		fn get_node_active_stake(node_pub_key: &NodePubKey) -> Result<u128, StakingVisitorError> {
+			let stash =
+				<Nodes<T>>::get(node_pub_key).ok_or(StakingVisitorError::NodeStakeDoesNotExist)?;
+			let controller =
+				<Bonded<T>>::get(&stash).ok_or(StakingVisitorError::NodeStakeIsInBadState)?;
+			let ledger =
+				<Ledger<T>>::get(&controller).ok_or(StakingVisitorError::NodeStakeIsInBadState)?;
+
+			Ok(ledger.active.saturated_into::<u128>())
+		}
+
+		fn get_cluster_active_stake(
+			cluster_stash: &T::AccountId,
+		) -> Result<u128, StakingVisitorError> {
+			let controller = <ClusterBonded<T>>::get(cluster_stash)
+				.ok_or(StakingVisitorError::ControllerDoesNotExist)?;
+			let ledger = <ClusterLedger<T>>::get(&controller)
+				.ok_or(StakingVisitorError::ControllerDoesNotExist)?;
+
+			Ok(ledger.active.saturated_into::<u128>())
+		}
 	}
 }
@@ -3,8 +3,9 @@
 #![allow(dead_code)]
 
 use ddc_primitives::{
-	ClusterNodeKind, ClusterNodeStatus, ClusterParams, ClusterProtocolParams, ClusterStatus,
-	NodeParams, NodePubKey, StorageNodeParams, StorageNodePubKey,
+	traits::customer::CustomerVisitor, BucketId, ClusterId, ClusterNodeKind, ClusterNodeStatus,
+	ClusterParams, ClusterProtocolParams, ClusterStatus, NodeParams, NodePubKey, StorageNodeParams,
+	StorageNodePubKey,
 };
 use frame_support::{
 	construct_runtime,
@@ -18,7 +19,7 @@ use sp_core::H256;
 use sp_io::TestExternalities;
 use sp_runtime::{
 	traits::{BlakeTwo256, Convert, IdentifyAccount, IdentityLookup, Verify},
-	BuildStorage, MultiSignature, Perbill, Perquintill,
+	BuildStorage, DispatchError, MultiSignature, Perbill, Perquintill,
 };
 
 use crate::{self as pallet_ddc_staking, *};
@@ -178,6 +179,15 @@ impl pallet_ddc_clusters::Config for Test {
 	type MinErasureCodingRequiredLimit = ConstU32<0>;
 	type MinErasureCodingTotalLimit = ConstU32<0>;
 	type MinReplicationTotalLimit = ConstU32<0>;
+	type ManagerTransferRequiresGov = ConstBool<true>;
+	type MaxAllowlistLen = ConstU32<10>;
+	type IdentityVerifier = ();
+	type DecommissionNoticePeriod = ConstU64<10>;
+	type DecommissionBatchSize = ConstU32<10>;
+	type ClusterDecommissionHandler = ();
+	type MaxAdmissionQueueLen = ConstU32<10>;
+	type PricingOverrideRequiresGov = ConstBool<true>;
+	type CustomerVisitor = TestCustomerVisitor;
 }
 
 impl crate::pallet::Config for Test {
@@ -259,10 +269,12 @@ pub(crate) fn build_default_setup(
 				storage_bond_size: 10,
 				storage_chill_delay: 10u32.into(),
 				storage_unbonding_delay: 10u32.into(),
+				node_removal_notice_period: 20u32.into(),
 				unit_per_mb_stored: 2,
 				unit_per_mb_streamed: 3,
 				unit_per_put_request: 4,
 				unit_per_get_request: 5,
+				pricing_tiers: Default::default(),
 			},
 			ClusterStatus::Activated,
 		)],
@@ -384,6 +396,18 @@ pub(crate) fn build_node_bond(
 	)
 }
 
+pub struct TestCustomerVisitor;
+
+impl<T: frame_system::Config> CustomerVisitor<T> for TestCustomerVisitor {
+	fn get_bucket_owner(_bucket_id: &BucketId) -> Result<T::AccountId, DispatchError> {
+		unimplemented!()
+	}
+
+	fn get_bucket_cluster_id(_bucket_id: &BucketId) -> Result<ClusterId, DispatchError> {
+		unimplemented!()
+	}
+}
+
 fn insert_unique_balance(
 	account_vec: &mut Vec<(AccountId, Balance)>,
 	account_id: AccountId,
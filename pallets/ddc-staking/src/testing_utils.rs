@@ -2,7 +2,7 @@
 
 #[cfg(feature = "runtime-benchmarks")]
 use ddc_primitives::{
-	ClusterId, ClusterParams, ClusterProtocolParams, NodeParams, StorageNodeMode,
+	ClusterId, ClusterNodeKind, ClusterParams, ClusterProtocolParams, NodeParams, StorageNodeMode,
 	StorageNodeParams, StorageNodePubKey,
 };
 use frame_benchmarking::account;
@@ -134,10 +134,12 @@ pub fn create_stash_controller_node_with_balance<T: Config>(
 			storage_bond_size: 10u32.into(),
 			storage_chill_delay: 50u32.into(),
 			storage_unbonding_delay: 50u32.into(),
+			node_removal_notice_period: 50u32.into(),
 			unit_per_mb_stored: 10,
 			unit_per_mb_streamed: 10,
 			unit_per_put_request: 10,
 			unit_per_get_request: 10,
+			pricing_tiers: Default::default(),
 		};
 	T::ClusterCreator::create_cluster(
 		cluster_id,
@@ -156,3 +158,30 @@ pub fn create_stash_controller_node_with_balance<T: Config>(
 
 	Ok((stash, controller, node_pub))
 }
+
+/// Bonds the cluster and adds the node to it, so that chilling starts the removal of the node from
+/// the cluster.
+#[cfg(feature = "runtime-benchmarks")]
+pub fn add_node_to_cluster<T: Config>(
+	cluster_id: &ClusterId,
+	node_pub_key: &NodePubKey,
+) -> Result<(), &'static str> {
+	T::ClusterProtocol::bond_cluster(cluster_id)?;
+	T::ClusterManager::add_node(cluster_id, node_pub_key, &ClusterNodeKind::Genesis)?;
+	Ok(())
+}
+
+/// Drops the chill delay and the node removal notice period of the cluster, so that chilling
+/// removes the node from the cluster right away.
+#[cfg(feature = "runtime-benchmarks")]
+pub fn remove_chill_delays<T: Config>(cluster_id: &ClusterId) -> Result<(), &'static str> {
+	T::ClusterProtocol::update_cluster_protocol(
+		cluster_id,
+		ClusterProtocolParams {
+			storage_bond_size: 10u32.into(),
+			storage_unbonding_delay: 50u32.into(),
+			..Default::default()
+		},
+	)?;
+	Ok(())
+}
@@ -546,6 +546,7 @@ fn storage_full_unbonding_works() {
 		let storage_bond_size = 10_u128;
 		let storage_chill_delay = 10_u64;
 		let storage_unbond_delay = 10_u64;
+		let node_removal_notice_period = 20_u64;
 
 		// Put some money in account that we'll use.
 		let _ = Balances::make_free_balance_be(&provider_controller.clone(), 2000);
@@ -570,7 +571,7 @@ fn storage_full_unbonding_works() {
 		System::assert_last_event(Event::Activated(provider_stash.clone()).into());
 
 		assert_eq!(DdcStaking::storages(provider_stash.clone()), Some(cluster_id));
-		assert_eq!(DdcStaking::nodes(node_pub_key), Some(provider_stash.clone()));
+		assert_eq!(DdcStaking::nodes(node_pub_key.clone()), Some(provider_stash.clone()));
 
 		// Set block timestamp.
 		Timestamp::set_timestamp(System::block_number() * BLOCK_TIME + INIT_TIMESTAMP);
@@ -582,6 +583,15 @@ fn storage_full_unbonding_works() {
 			Event::ChillSoon(provider_stash.clone(), cluster_id, chilling).into(),
 		);
 
+		// The node is leaving the cluster after the notice period of the cluster.
+		assert_eq!(
+			DdcClusters::leaving_nodes(cluster_id, node_pub_key),
+			Some(pallet_ddc_clusters::cluster::NodeLeaving {
+				leave_at: System::block_number() + node_removal_notice_period,
+				by_provider: true
+			})
+		);
+
 		// Set the block number that allows us to chill.
 		while System::block_number() < chilling {
 			System::set_block_number(System::block_number() + 1);
@@ -624,6 +634,38 @@ fn storage_full_unbonding_works() {
 	});
 }
 
+#[test]
+fn storage_unbonding_from_removed_cluster_works() {
+	let (clusters, nodes, clusters_bonds, nodes_bondes) = build_default_setup();
+	ExtBuilder.build_and_execute(clusters, nodes, clusters_bonds, nodes_bondes, || {
+		System::set_block_number(1);
+
+		let provider_stash = AccountId::from(NODE_STASH_1);
+		let provider_controller = AccountId::from(NODE_CONTROLLER_1);
+		let cluster_id = ClusterId::from(CLUSTER_ID);
+
+		// The cluster is removed once it is decommissioned.
+		pallet_ddc_clusters::Clusters::<Test>::remove(cluster_id);
+		pallet_ddc_clusters::ClustersGovParams::<Test>::remove(cluster_id);
+
+		// The participant is chilled right away as there is no cluster to leave.
+		assert_ok!(DdcStaking::chill(RuntimeOrigin::signed(provider_controller.clone())));
+		System::assert_last_event(Event::Chilled(provider_stash.clone()).into());
+		assert_eq!(DdcStaking::storages(provider_stash.clone()), None);
+
+		// All tokens are unbonded immediately.
+		assert_ok!(DdcStaking::unbond(
+			RuntimeOrigin::signed(provider_controller.clone()),
+			ENDOWMENT
+		));
+		System::assert_last_event(Event::Unbonded(provider_stash.clone(), ENDOWMENT).into());
+		assert_eq!(DdcStaking::leaving_storages(provider_stash.clone()), None);
+
+		assert_ok!(DdcStaking::withdraw_unbonded(RuntimeOrigin::signed(provider_controller)));
+		System::assert_last_event(Event::Withdrawn(provider_stash, ENDOWMENT).into());
+	});
+}
+
 #[test]
 fn staking_creator_works() {
 	let (clusters, nodes, clusters_bonds, nodes_bondes) = build_default_setup();
@@ -855,6 +897,87 @@ fn bond_cluster_works_only_for_unbonded_cluster() {
 		},
 	);
 }
+#[test]
+fn cluster_manager_transfer_moves_cluster_controller() {
+	const BONDED_CLUSTER_ID: [u8; 20] = [10; 20];
+	const PAIRED_CLUSTER_ID: [u8; 20] = [11; 20];
+	let cluster_1 = build_cluster(
+		BONDED_CLUSTER_ID,
+		CLUSTER_CONTROLLER,
+		CLUSTER_STASH,
+		ClusterParams::default(),
+		ClusterProtocolParams::default(),
+		ClusterStatus::Bonded,
+	);
+	let bond_1 = build_cluster_bond(CLUSTER_STASH, CLUSTER_CONTROLLER, BONDED_CLUSTER_ID);
+	let cluster_2 = build_cluster(
+		PAIRED_CLUSTER_ID,
+		USER_KEY_2,
+		USER_KEY_2,
+		ClusterParams::default(),
+		ClusterProtocolParams::default(),
+		ClusterStatus::Bonded,
+	);
+	let bond_2 = build_cluster_bond(USER_KEY_2, USER_KEY_2, PAIRED_CLUSTER_ID);
+
+	ExtBuilder.build_and_execute(
+		vec![cluster_1, cluster_2],
+		vec![],
+		vec![bond_1, bond_2],
+		vec![],
+		|| {
+			System::set_block_number(1);
+
+			let cluster_id = ClusterId::from(BONDED_CLUSTER_ID);
+			let ledger = DdcStaking::cluster_ledger(AccountId::from(CLUSTER_CONTROLLER)).unwrap();
+
+			// A controller of another cluster can't take over the cluster stake
+			assert_ok!(DdcClusters::propose_manager_transfer(
+				RuntimeOrigin::signed(AccountId::from(CLUSTER_CONTROLLER)),
+				cluster_id,
+				AccountId::from(USER_KEY_2)
+			));
+			assert_noop!(
+				DdcClusters::accept_manager_transfer(
+					RuntimeOrigin::signed(AccountId::from(USER_KEY_2)),
+					cluster_id
+				),
+				Error::<Test>::AlreadyPaired
+			);
+
+			assert_ok!(DdcClusters::propose_manager_transfer(
+				RuntimeOrigin::signed(AccountId::from(CLUSTER_CONTROLLER)),
+				cluster_id,
+				AccountId::from(USER_KEY_1)
+			));
+			assert_ok!(DdcClusters::accept_manager_transfer(
+				RuntimeOrigin::signed(AccountId::from(USER_KEY_1)),
+				cluster_id
+			));
+
+			assert_eq!(
+				DdcStaking::cluster_bonded(AccountId::from(CLUSTER_STASH)),
+				Some(AccountId::from(USER_KEY_1))
+			);
+			assert_eq!(DdcStaking::cluster_ledger(AccountId::from(USER_KEY_1)), Some(ledger));
+			assert_eq!(DdcStaking::cluster_ledger(AccountId::from(CLUSTER_CONTROLLER)), None);
+
+			// The cluster stake is controlled by the new cluster manager
+			assert_noop!(
+				DdcStaking::unbond_cluster(
+					RuntimeOrigin::signed(AccountId::from(CLUSTER_CONTROLLER)),
+					cluster_id
+				),
+				Error::<Test>::NotController
+			);
+			assert_ok!(DdcStaking::unbond_cluster(
+				RuntimeOrigin::signed(AccountId::from(USER_KEY_1)),
+				cluster_id
+			));
+		},
+	);
+}
+
 #[test]
 fn unbond_bonded_cluster_works() {
 	const BONDED_CLUSTER_ID: [u8; 20] = [10; 20];
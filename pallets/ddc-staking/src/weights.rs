@@ -101,31 +101,51 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcStaking::Ledger` (r:1 w:0)
+	// Storage: `DdcStaking::Ledger` (r:1 w:1)
 	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::ClustersGovParams` (r:1 w:0)
 	// Proof: `DdcClusters::ClustersGovParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcStaking::Providers` (r:1 w:0)
 	// Proof: `DdcStaking::Providers` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcStaking::Storages` (r:1 w:1)
+	// Storage: `DdcStaking::Storages` (r:1 w:0)
 	// Proof: `DdcStaking::Storages` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcStaking::LeavingStorages` (r:1 w:0)
-	// Proof: `DdcStaking::LeavingStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::LeavingNodes` (r:1 w:1)
+	// Proof: `DdcClusters::LeavingNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn store() -> Weight {
-		Weight::from_parts(48_231_000_u64, 0)
+		Weight::from_parts(49_538_000_u64, 0)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	// Storage: `DdcStaking::Ledger` (r:1 w:1)
+	// Storage: `DdcStaking::Ledger` (r:1 w:0)
 	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcStaking::Storages` (r:1 w:1)
 	// Proof: `DdcStaking::Storages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::ClustersGovParams` (r:1 w:0)
 	// Proof: `DdcClusters::ClustersGovParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Providers` (r:1 w:0)
+	// Proof: `DdcStaking::Providers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:1)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::LeavingNodes` (r:1 w:1)
+	// Proof: `DdcClusters::LeavingNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersAggregators` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersAggregators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:1 w:0)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:0)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn chill() -> Weight {
-		Weight::from_parts(41_127_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(112_384_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	// Storage: `DdcStaking::Bonded` (r:1 w:1)
 	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -272,31 +292,51 @@ impl WeightInfo for () {
 	}
 	// Storage: `DdcClusters::Clusters` (r:1 w:0)
 	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcStaking::Ledger` (r:1 w:0)
+	// Storage: `DdcStaking::Ledger` (r:1 w:1)
 	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::ClustersGovParams` (r:1 w:0)
 	// Proof: `DdcClusters::ClustersGovParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcStaking::Providers` (r:1 w:0)
 	// Proof: `DdcStaking::Providers` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcStaking::Storages` (r:1 w:1)
+	// Storage: `DdcStaking::Storages` (r:1 w:0)
 	// Proof: `DdcStaking::Storages` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	// Storage: `DdcStaking::LeavingStorages` (r:1 w:0)
-	// Proof: `DdcStaking::LeavingStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::LeavingNodes` (r:1 w:1)
+	// Proof: `DdcClusters::LeavingNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn store() -> Weight {
-		Weight::from_parts(48_231_000_u64, 0)
+		Weight::from_parts(49_538_000_u64, 0)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-	// Storage: `DdcStaking::Ledger` (r:1 w:1)
+	// Storage: `DdcStaking::Ledger` (r:1 w:0)
 	// Proof: `DdcStaking::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcStaking::Storages` (r:1 w:1)
 	// Proof: `DdcStaking::Storages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcClusters::ClustersGovParams` (r:1 w:0)
 	// Proof: `DdcClusters::ClustersGovParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcStaking::Providers` (r:1 w:0)
+	// Proof: `DdcStaking::Providers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcNodes::StorageNodes` (r:1 w:1)
+	// Proof: `DdcNodes::StorageNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::LeavingNodes` (r:1 w:1)
+	// Proof: `DdcClusters::LeavingNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodesCounts` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersNodesCounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersAggregators` (r:1 w:1)
+	// Proof: `DdcClusters::ClustersAggregators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClusterDecommissions` (r:1 w:0)
+	// Proof: `DdcClusters::ClusterDecommissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::AdmissionQueues` (r:1 w:0)
+	// Proof: `DdcClusters::AdmissionQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn chill() -> Weight {
-		Weight::from_parts(41_127_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(112_384_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	// Storage: `DdcStaking::Bonded` (r:1 w:1)
 	// Proof: `DdcStaking::Bonded` (`max_values`: None, `max_size`: None, mode: `Measured`)
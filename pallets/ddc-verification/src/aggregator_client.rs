@@ -1,10 +1,12 @@
 #![allow(dead_code)]
 
-use ddc_primitives::{AggregatorInfo, BucketId, DdcEra};
+use base64ct::Encoding;
+use ddc_primitives::{AggregatorInfo, BucketId, DdcEra, NodePubKey};
 use prost::Message;
+use serde::de::DeserializeOwned;
 use serde_with::{base64::Base64, serde_as};
 use sp_io::offchain::timestamp;
-use sp_runtime::offchain::{http, Duration};
+use sp_runtime::offchain::{http, Duration, Timestamp};
 
 use super::*;
 use crate::signature::Verify;
@@ -13,12 +15,36 @@ pub struct AggregatorClient<'a> {
 	pub base_url: &'a str,
 	timeout: Duration,
 	retries: u32,
-	verify_sig: bool,
+	/// Key of the aggregator, its responses are signed with and its metrics are recorded for.
+	node_pub_key: &'a NodePubKey,
+	/// Whether the aggregator responses must be signed.
+	verify_signature: bool,
+}
+
+/// Aggregator client error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AggregatorClientError {
+	/// Request failed or the response can not be parsed.
+	Http(http::Error),
+	/// Response is unsigned, wrongly signed or signed by a key other than the aggregator key.
+	InvalidSignature,
+}
+
+impl From<http::Error> for AggregatorClientError {
+	fn from(error: http::Error) -> Self {
+		AggregatorClientError::Http(error)
+	}
 }
 
 impl<'a> AggregatorClient<'a> {
-	pub fn new(base_url: &'a str, timeout: Duration, retries: u32, verify_sig: bool) -> Self {
-		Self { base_url, timeout, retries, verify_sig }
+	pub fn new(
+		base_url: &'a str,
+		timeout: Duration,
+		retries: u32,
+		node_pub_key: &'a NodePubKey,
+		verify_signature: bool,
+	) -> Self {
+		Self { base_url, timeout, retries, node_pub_key, verify_signature }
 	}
 
 	pub fn buckets_aggregates(
@@ -26,73 +52,128 @@ impl<'a> AggregatorClient<'a> {
 		era_id: DdcEra,
 		limit: Option<u32>,
 		prev_token: Option<BucketId>,
-	) -> Result<Vec<json::BucketAggregateResponse>, http::Error> {
-		let mut url = format!("{}/activity/buckets?eraId={}", self.base_url, era_id);
-		if let Some(limit) = limit {
-			url = format!("{}&limit={}", url, limit);
-		}
-		if let Some(prev_token) = prev_token {
-			url = format!("{}&prevToken={}", url, prev_token);
-		}
-		if self.verify_sig {
-			url = format!("{}&sign=true", url);
-		}
-		let response = self.get(&url, Accept::Any)?;
+	) -> Result<Vec<json::BucketAggregateResponse>, AggregatorClientError> {
+		self.get_negotiated(self.aggregates_url::<json::BucketAggregateResponse>(
+			era_id,
+			limit,
+			prev_token.map(|token| token.to_string()),
+		))
+	}
 
-		let body = response.body().collect::<Vec<u8>>();
-		if self.verify_sig {
-			let json_response: json::SignedJsonResponse<Vec<json::BucketAggregateResponse>> =
-				serde_json::from_slice(&body).map_err(|_| http::Error::Unknown)?;
+	pub fn nodes_aggregates(
+		&self,
+		era_id: DdcEra,
+		limit: Option<u32>,
+		prev_token: Option<String>, // node_id hex string
+	) -> Result<Vec<json::NodeAggregateResponse>, AggregatorClientError> {
+		self.get_negotiated(
+			self.aggregates_url::<json::NodeAggregateResponse>(era_id, limit, prev_token),
+		)
+	}
+
+	/// Fetch all pages of era aggregates from several aggregators concurrently.
+	///
+	/// Every round requests the next page from all aggregators having more pages at once, so a
+	/// round lasts as long as the slowest response instead of the sum of them. Aggregators which
+	/// fail to return all their pages by the `deadline` are reported with an error, the
+	/// aggregates of the others are returned anyway. The results are in the order of `clients`.
+	pub fn fetch_aggregates<R: AggregatesPage>(
+		clients: &[AggregatorClient<'_>],
+		era_id: DdcEra,
+		batch_size: u32,
+		deadline: Timestamp,
+	) -> Vec<Result<Vec<R>, AggregatorClientError>>
+	where
+		Vec<R>: ProtoResponse,
+	{
+		let mut fetches: Vec<PagesFetch<R>> = clients.iter().map(|_| PagesFetch::new()).collect();
+
+		loop {
+			let now = timestamp();
+			let mut requests = Vec::new();
+			let mut pending_requests = Vec::new();
 
-			if !json_response.verify() {
-				log::debug!("bad signature, req: {:?}, resp: {:?}", url, json_response);
-				return Err(http::Error::Unknown); // TODO (khssnv): more specific error.
+			for (index, (client, fetch)) in clients.iter().zip(fetches.iter_mut()).enumerate() {
+				if fetch.result.is_some() {
+					continue;
+				}
+
+				if now >= deadline {
+					fetch.result = Some(Err(http::Error::DeadlineReached.into()));
+					continue;
+				}
+
+				let url = client.signed_url(client.aggregates_url::<R>(
+					era_id,
+					Some(batch_size),
+					fetch.prev_token.clone(),
+				));
+				let request_deadline = core::cmp::min(deadline, now.add(client.timeout));
+
+				match Accept::ProtobufOrJson.request(&url).deadline(request_deadline).send() {
+					Ok(pending) => {
+						requests.push((index, url));
+						pending_requests.push(pending);
+					},
+					Err(_) => fetch.fail(http::Error::IoError.into(), client.retries),
+				}
 			}
 
-			Ok(json_response.payload)
-		} else {
-			let json_response: Vec<json::BucketAggregateResponse> =
-				serde_json::from_slice(&body).map_err(|_| http::Error::Unknown)?;
+			if fetches.iter().all(|fetch| fetch.result.is_some()) {
+				break;
+			}
+
+			let responses = http::PendingRequest::try_wait_all(pending_requests, deadline);
+			// the responses are awaited together, so the round duration is recorded as latency
+			let latency = timestamp().diff(&now).millis();
 
-			Ok(json_response)
+			for ((index, url), response) in requests.into_iter().zip(responses) {
+				let client = &clients[index];
+				let fetch = &mut fetches[index];
+
+				let page = match response {
+					Ok(Ok(response)) if response.code == 200 =>
+						client.parse_response::<Vec<R>>(&url, response),
+					Ok(_) => Err(http::Error::Unknown.into()),
+					Err(_) => Err(http::Error::DeadlineReached.into()),
+				};
+				metrics::record_aggregator_request(client.node_pub_key, latency, page.is_err());
+
+				match page {
+					Ok(page) => fetch.extend(page, batch_size),
+					// a wrongly signed response is not retried
+					Err(AggregatorClientError::InvalidSignature) =>
+						fetch.result = Some(Err(AggregatorClientError::InvalidSignature)),
+					Err(error) => fetch.fail(error, client.retries),
+				}
+			}
 		}
+
+		fetches
+			.into_iter()
+			.map(|fetch| match fetch.result {
+				Some(Ok(())) => Ok(fetch.aggregates),
+				Some(Err(error)) => Err(error),
+				None => Err(http::Error::Unknown.into()),
+			})
+			.collect()
 	}
 
-	pub fn nodes_aggregates(
+	fn aggregates_url<R: AggregatesPage>(
 		&self,
 		era_id: DdcEra,
 		limit: Option<u32>,
-		prev_token: Option<String>, // node_id hex string
-	) -> Result<Vec<json::NodeAggregateResponse>, http::Error> {
-		let mut url = format!("{}/activity/nodes?eraId={}", self.base_url, era_id);
+		prev_token: Option<String>,
+	) -> String {
+		let mut url = format!("{}/activity/{}?eraId={}", self.base_url, R::PATH, era_id);
 		if let Some(limit) = limit {
 			url = format!("{}&limit={}", url, limit);
 		}
 		if let Some(prev_token) = prev_token {
 			url = format!("{}&prevToken={}", url, prev_token);
 		}
-		if self.verify_sig {
-			url = format!("{}&sign=true", url);
-		}
-		let response = self.get(&url, Accept::Any)?;
 
-		let body = response.body().collect::<Vec<u8>>();
-		if self.verify_sig {
-			let json_response: json::SignedJsonResponse<Vec<json::NodeAggregateResponse>> =
-				serde_json::from_slice(&body).map_err(|_| http::Error::Unknown)?;
-
-			if !json_response.verify() {
-				log::debug!("bad signature, req: {:?}, resp: {:?}", url, json_response);
-				return Err(http::Error::Unknown); // TODO (khssnv): more specific error.
-			}
-
-			Ok(json_response.payload)
-		} else {
-			let json_response: Vec<json::NodeAggregateResponse> =
-				serde_json::from_slice(&body).map_err(|_| http::Error::Unknown)?;
-
-			Ok(json_response)
-		}
+		url
 	}
 
 	pub fn challenge_bucket_sub_aggregate(
@@ -101,7 +182,7 @@ impl<'a> AggregatorClient<'a> {
 		bucket_id: BucketId,
 		node_id: &str,
 		merkle_tree_node_id: Vec<u32>,
-	) -> Result<proto::ChallengeResponse, http::Error> {
+	) -> Result<proto::ChallengeResponse, AggregatorClientError> {
 		let url = format!(
 			"{}/activity/buckets/{}/challenge?eraId={}&nodeId={}&merkleTreeNodeId={}",
 			self.base_url,
@@ -110,12 +191,8 @@ impl<'a> AggregatorClient<'a> {
 			node_id,
 			Self::merkle_tree_node_id_param(merkle_tree_node_id.as_slice()),
 		);
-		let response = self.get(&url, Accept::Protobuf)?;
-		let body = response.body().collect::<Vec<u8>>();
-		let proto_response =
-			proto::ChallengeResponse::decode(body.as_slice()).map_err(|_| http::Error::Unknown)?;
 
-		Ok(proto_response)
+		self.get_proto(url)
 	}
 
 	pub fn challenge_node_aggregate(
@@ -123,7 +200,7 @@ impl<'a> AggregatorClient<'a> {
 		era_id: DdcEra,
 		node_id: &str,
 		merkle_tree_node_id: Vec<u32>,
-	) -> Result<proto::ChallengeResponse, http::Error> {
+	) -> Result<proto::ChallengeResponse, AggregatorClientError> {
 		let url = format!(
 			"{}/activity/nodes/{}/challenge?eraId={}&merkleTreeNodeId={}",
 			self.base_url,
@@ -131,38 +208,50 @@ impl<'a> AggregatorClient<'a> {
 			era_id,
 			Self::merkle_tree_node_id_param(merkle_tree_node_id.as_slice()),
 		);
-		let response = self.get(&url, Accept::Protobuf)?;
-		let body = response.body().collect::<Vec<u8>>();
-		let proto_response =
-			proto::ChallengeResponse::decode(body.as_slice()).map_err(|_| http::Error::Unknown)?;
 
-		Ok(proto_response)
+		self.get_proto(url)
 	}
 
-	pub fn eras(&self) -> Result<Vec<json::AggregationEraResponse>, http::Error> {
-		let mut url = format!("{}/activity/eras", self.base_url);
-		if self.verify_sig {
-			url = format!("{}&sign=true", url);
-		}
-		let response = self.get(&url, Accept::Any)?;
+	pub fn challenge_bucket_sub_aggregate_json(
+		&self,
+		era_id: DdcEra,
+		bucket_id: BucketId,
+		node_id: &str,
+		merkle_tree_node_id: Vec<u64>,
+	) -> Result<json::ChallengeAggregateResponse, AggregatorClientError> {
+		let url = format!(
+			"{}/activity/buckets/{}/challenge?eraId={}&nodeId={}&merkleTreeNodeId={}",
+			self.base_url,
+			bucket_id,
+			era_id,
+			node_id,
+			Self::merkle_tree_node_id_param(merkle_tree_node_id.as_slice()),
+		);
 
-		let body = response.body().collect::<Vec<u8>>();
-		if self.verify_sig {
-			let json_response: json::SignedJsonResponse<Vec<json::AggregationEraResponse>> =
-				serde_json::from_slice(&body).map_err(|_| http::Error::Unknown)?;
+		self.get_json(url)
+	}
 
-			if !json_response.verify() {
-				log::debug!("bad signature, req: {:?}, resp: {:?}", url, json_response);
-				return Err(http::Error::Unknown); // TODO (khssnv): more specific error.
-			}
+	pub fn challenge_node_aggregate_json(
+		&self,
+		era_id: DdcEra,
+		node_id: &str,
+		merkle_tree_node_id: Vec<u64>,
+	) -> Result<json::ChallengeAggregateResponse, AggregatorClientError> {
+		let url = format!(
+			"{}/activity/nodes/{}/challenge?eraId={}&merkleTreeNodeId={}",
+			self.base_url,
+			node_id,
+			era_id,
+			Self::merkle_tree_node_id_param(merkle_tree_node_id.as_slice()),
+		);
 
-			Ok(json_response.payload)
-		} else {
-			let json_response: Vec<json::AggregationEraResponse> =
-				serde_json::from_slice(&body).map_err(|_| http::Error::Unknown)?;
+		self.get_json(url)
+	}
 
-			Ok(json_response)
-		}
+	pub fn eras(&self) -> Result<Vec<json::AggregationEraResponse>, AggregatorClientError> {
+		let url = format!("{}/activity/eras", self.base_url);
+
+		self.get_negotiated(url)
 	}
 
 	pub fn traverse_bucket_sub_aggregate(
@@ -172,34 +261,13 @@ impl<'a> AggregatorClient<'a> {
 		node_id: &str,
 		merkle_tree_node_id: u32,
 		levels: u16,
-	) -> Result<json::MerkleTreeNodeResponse, http::Error> {
-		let mut url = format!(
+	) -> Result<json::MerkleTreeNodeResponse, AggregatorClientError> {
+		let url = format!(
 			"{}/activity/buckets/{}/traverse?eraId={}&nodeId={}&merkleTreeNodeId={}&levels={}",
 			self.base_url, bucket_id, era_id, node_id, merkle_tree_node_id, levels,
 		);
-		if self.verify_sig {
-			url = format!("{}&sign=true", url);
-		}
-
-		let response = self.get(&url, Accept::Any)?;
-
-		let body = response.body().collect::<Vec<u8>>();
-		if self.verify_sig {
-			let json_response: json::SignedJsonResponse<json::MerkleTreeNodeResponse> =
-				serde_json::from_slice(&body).map_err(|_| http::Error::Unknown)?;
 
-			if !json_response.verify() {
-				log::debug!("bad signature, req: {:?}, resp: {:?}", url, json_response);
-				return Err(http::Error::Unknown); // TODO (khssnv): more specific error.
-			}
-
-			Ok(json_response.payload)
-		} else {
-			let json_response: json::MerkleTreeNodeResponse =
-				serde_json::from_slice(&body).map_err(|_| http::Error::Unknown)?;
-
-			Ok(json_response)
-		}
+		self.get_negotiated(url)
 	}
 
 	pub fn traverse_node_aggregate(
@@ -208,57 +276,306 @@ impl<'a> AggregatorClient<'a> {
 		node_id: &str,
 		merkle_tree_node_id: u32,
 		levels: u16,
-	) -> Result<json::MerkleTreeNodeResponse, http::Error> {
-		let mut url = format!(
+	) -> Result<json::MerkleTreeNodeResponse, AggregatorClientError> {
+		let url = format!(
 			"{}/activity/nodes/{}/traverse?eraId={}&merkleTreeNodeId={}&levels={}",
 			self.base_url, node_id, era_id, merkle_tree_node_id, levels,
 		);
-		if self.verify_sig {
-			url = format!("{}&sign=true", url);
+
+		self.get_negotiated(url)
+	}
+
+	/// Whether the endpoint responds at all, regardless of the response status.
+	pub fn is_reachable(&self) -> bool {
+		let deadline = timestamp().add(self.timeout);
+
+		(0..self.retries).any(|_| {
+			http::Request::get(self.base_url)
+				.deadline(deadline)
+				.send()
+				.map(|pending| matches!(pending.try_wait(deadline), Ok(Ok(_))))
+				.unwrap_or(false)
+		})
+	}
+
+	/// Whether the gRPC endpoint reports serving to the gRPC health check. Offchain workers can
+	/// only send HTTP/1.1 requests, so the check is sent as a gRPC-Web call.
+	pub fn is_grpc_serving(&self) -> bool {
+		let url = format!("{}/grpc.health.v1.Health/Check", self.base_url);
+		let body = Self::grpc_web_frame(&proto::health::HealthCheckRequest::default());
+		let deadline = timestamp().add(self.timeout);
+
+		for _ in 0..self.retries {
+			let pending = match http::Request::post(&url, vec![body.clone()])
+				.add_header("Content-Type", "application/grpc-web+proto")
+				.add_header("X-Grpc-Web", "1")
+				.deadline(deadline)
+				.send()
+			{
+				Ok(pending) => pending,
+				Err(_) => continue,
+			};
+
+			// the endpoint responded, its answer is final
+			if let Ok(Ok(response)) = pending.try_wait(deadline) {
+				if response.code != 200 {
+					return false;
+				}
+
+				let body = response.body().collect::<Vec<u8>>();
+				return Self::grpc_web_message(&body)
+					.and_then(|message| proto::health::HealthCheckResponse::decode(message).ok())
+					.map_or(false, |health| {
+						health.status() ==
+							proto::health::health_check_response::ServingStatus::Serving
+					});
+			}
+		}
+
+		false
+	}
+
+	/// Ask the node to sign the nonce with its node key, the response is accepted only if the
+	/// nonce is signed by the node key. Returns `None` if the node does not serve the handshake.
+	pub fn handshake(
+		&self,
+		nonce: &[u8],
+	) -> Result<Option<json::NodeHandshakeResponse>, AggregatorClientError> {
+		let url = format!("{}/handshake?nonce={}", self.base_url, hex::encode(nonce));
+		let response = self.get_any(&url, Accept::Any)?;
+		// the handshake is not a part of the DAC API, nodes not serving it are not required to
+		// sign the nonce
+		if response.code == 404 {
+			return Ok(None);
+		}
+		if response.code != 200 {
+			return Err(http::Error::Unknown.into());
 		}
-		let response = self.get(&url, Accept::Any)?;
 
 		let body = response.body().collect::<Vec<u8>>();
-		if self.verify_sig {
-			let json_response: json::SignedJsonResponse<json::MerkleTreeNodeResponse> =
-				serde_json::from_slice(&body).map_err(|_| http::Error::Unknown)?;
 
-			if !json_response.verify() {
-				log::debug!("bad signature, req: {:?}, resp: {:?}", url, json_response);
-				return Err(http::Error::Unknown); // TODO (khssnv): more specific error.
-			}
+		let handshake: json::NodeHandshakeResponse =
+			serde_json::from_slice(&body).map_err(|_| http::Error::Unknown)?;
 
-			Ok(json_response.payload)
-		} else {
-			let json_response: json::MerkleTreeNodeResponse =
-				serde_json::from_slice(&body).map_err(|_| http::Error::Unknown)?;
+		if handshake.nonce != nonce ||
+			!Self::is_signed_by(self.node_pub_key, &handshake.signer) ||
+			!handshake.verify()
+		{
+			log::debug!("bad handshake signature, req: {:?}", url);
+			return Err(AggregatorClientError::InvalidSignature);
+		}
+
+		Ok(Some(handshake))
+	}
+
+	/// Length-prefixed gRPC-Web data frame of the message.
+	fn grpc_web_frame<M: Message>(message: &M) -> Vec<u8> {
+		let payload = message.encode_to_vec();
+		let mut frame = Vec::with_capacity(5 + payload.len());
+		frame.push(0);
+		frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+		frame.extend_from_slice(&payload);
+
+		frame
+	}
 
-			Ok(json_response)
+	/// Message of the first gRPC-Web frame of the response, if it is a data frame.
+	fn grpc_web_message(body: &[u8]) -> Option<&[u8]> {
+		let (header, rest) = (body.get(..5)?, body.get(5..)?);
+		if header[0] != 0 {
+			return None;
 		}
+		let len = u32::from_be_bytes([header[1], header[2], header[3], header[4]]) as usize;
+
+		rest.get(..len)
 	}
 
-	fn merkle_tree_node_id_param(merkle_tree_node_id: &[u32]) -> String {
+	fn merkle_tree_node_id_param<I: core::fmt::Display>(merkle_tree_node_id: &[I]) -> String {
 		merkle_tree_node_id
 			.iter()
-			.map(|x| format!("{}", x.clone()))
+			.map(|x| format!("{}", x))
 			.collect::<Vec<_>>()
 			.join(",")
 	}
 
+	/// Fetch a JSON response. When the signature verification is enabled, the response is
+	/// requested signed and accepted only if it is signed by the aggregator key.
+	fn get_json<R: Serialize + DeserializeOwned>(
+		&self,
+		url: String,
+	) -> Result<R, AggregatorClientError> {
+		let url = self.signed_url(url);
+		let response = self.get(&url, Accept::Any)?;
+		let body = response.body().collect::<Vec<u8>>();
+
+		self.parse_json(&url, &body)
+	}
+
+	/// Fetch a response preferably protobuf encoded, aggregators not serving protobuf yet respond
+	/// with JSON. When the signature verification is enabled, the response is requested signed
+	/// and accepted only if it is signed by the aggregator key.
+	fn get_negotiated<R: ProtoResponse>(&self, url: String) -> Result<R, AggregatorClientError> {
+		let url = self.signed_url(url);
+		let response = self.get(&url, Accept::ProtobufOrJson)?;
+
+		self.parse_response(&url, response)
+	}
+
+	/// Fetch a protobuf response of signed activity records. Aggregators serve it unwrapped, so
+	/// it is not requested signed, the signatures of the records are verified by the caller.
+	fn get_proto<M: Message + Default>(&self, url: String) -> Result<M, AggregatorClientError> {
+		let response = self.get(&url, Accept::Protobuf)?;
+		let body = response.body().collect::<Vec<u8>>();
+
+		Ok(M::decode(body.as_slice()).map_err(|_| http::Error::Unknown)?)
+	}
+
+	/// URL of a request, the response is requested signed when the signature verification is
+	/// enabled.
+	fn signed_url(&self, url: String) -> String {
+		if !self.verify_signature {
+			return url;
+		}
+
+		let separator = if url.contains('?') { '&' } else { '?' };
+		format!("{}{}sign=true", url, separator)
+	}
+
+	/// Parse a response in the format given by its content type, JSON unless stated otherwise.
+	fn parse_response<R: ProtoResponse>(
+		&self,
+		url: &str,
+		mut response: http::Response,
+	) -> Result<R, AggregatorClientError> {
+		let is_protobuf = Self::is_protobuf(&mut response);
+		let body = response.body().collect::<Vec<u8>>();
+
+		if is_protobuf {
+			self.parse_proto(url, &body)
+		} else {
+			self.parse_json(url, &body)
+		}
+	}
+
+	fn is_protobuf(response: &mut http::Response) -> bool {
+		let mut headers = response.headers().into_iter();
+		while headers.next() {
+			if let Some((name, value)) = headers.current() {
+				if name.eq_ignore_ascii_case("Content-Type") {
+					let media_type = value.split(';').next().unwrap_or_default().trim();
+					return PROTOBUF_MEDIA_TYPES
+						.iter()
+						.any(|protobuf| media_type.eq_ignore_ascii_case(protobuf));
+				}
+			}
+		}
+
+		false
+	}
+
+	/// Parse a protobuf response, a signed one is accepted only if it is signed by the
+	/// aggregator key.
+	fn parse_proto<R: ProtoResponse>(
+		&self,
+		url: &str,
+		body: &[u8],
+	) -> Result<R, AggregatorClientError> {
+		let payload = self.proto_payload(url, body)?;
+		let message = R::Proto::decode(payload.as_slice()).map_err(|_| http::Error::Unknown)?;
+
+		Ok(R::from_proto(message)?)
+	}
+
+	/// Encoded message of a protobuf response, a signed one is accepted only if it is signed by
+	/// the aggregator key.
+	fn proto_payload(&self, url: &str, body: &[u8]) -> Result<Vec<u8>, AggregatorClientError> {
+		if !self.verify_signature {
+			return Ok(body.to_vec());
+		}
+
+		let signed_response =
+			proto::SignedResponse::decode(body).map_err(|_| http::Error::Unknown)?;
+		let signer =
+			signed_response.signature.as_ref().map(|s| s.signer.clone()).unwrap_or_default();
+
+		if !Self::is_signed_by(self.node_pub_key, &signer) || !signed_response.verify() {
+			log::debug!("bad signature, req: {:?}, signer: {:?}", url, signer);
+			return Err(AggregatorClientError::InvalidSignature);
+		}
+
+		Ok(signed_response.payload)
+	}
+
+	/// Parse a JSON response, a signed one is accepted only if it is signed by the aggregator
+	/// key.
+	fn parse_json<R: Serialize + DeserializeOwned>(
+		&self,
+		url: &str,
+		body: &[u8],
+	) -> Result<R, AggregatorClientError> {
+		if !self.verify_signature {
+			return serde_json::from_slice(body)
+				.map_err(|_| AggregatorClientError::Http(http::Error::Unknown));
+		}
+
+		let json_response: json::SignedJsonResponse<R> = match serde_json::from_slice(body) {
+			Ok(json_response) => json_response,
+			// a well-formed but unsigned payload is rejected as not signed
+			Err(_) if serde_json::from_slice::<R>(body).is_ok() => {
+				log::debug!("unsigned response, req: {:?}", url);
+				return Err(AggregatorClientError::InvalidSignature);
+			},
+			Err(_) => return Err(AggregatorClientError::Http(http::Error::Unknown)),
+		};
+
+		if !Self::is_signed_by(self.node_pub_key, &json_response.signer) || !json_response.verify()
+		{
+			log::debug!("bad signature, req: {:?}, signer: {:?}", url, json_response.signer);
+			return Err(AggregatorClientError::InvalidSignature);
+		}
+
+		Ok(json_response.payload)
+	}
+
+	fn is_signed_by(node_pub_key: &NodePubKey, signer: &[u8]) -> bool {
+		match node_pub_key {
+			NodePubKey::StoragePubKey(pub_key) => {
+				let pub_key: &[u8] = pub_key.as_ref();
+				pub_key == signer
+			},
+		}
+	}
+
 	fn get(&self, url: &str, accept: Accept) -> Result<http::Response, http::Error> {
+		let response = self.get_any(url, accept)?;
+		if response.code != 200 {
+			return Err(http::Error::Unknown);
+		}
+
+		Ok(response)
+	}
+
+	/// Same as `get`, but returns the response regardless of its status code.
+	fn get_any(&self, url: &str, accept: Accept) -> Result<http::Response, http::Error> {
+		let started_at = timestamp();
+		let result = self.try_get(url, accept);
+		metrics::record_aggregator_request(
+			self.node_pub_key,
+			timestamp().diff(&started_at).millis(),
+			result.as_ref().map_or(true, |response| response.code != 200),
+		);
+
+		result
+	}
+
+	fn try_get(&self, url: &str, accept: Accept) -> Result<http::Response, http::Error> {
 		let mut maybe_response = None;
 
 		let deadline = timestamp().add(self.timeout);
 		let mut error = None;
 
 		for _ in 0..self.retries {
-			let mut request = http::Request::get(url).deadline(deadline);
-			request = match accept {
-				Accept::Any => request,
-				Accept::Protobuf => request.add_header("Accept", "application/protobuf"),
-			};
-
-			let pending = match request.send() {
+			let pending = match accept.request(url).deadline(deadline).send() {
 				Ok(p) => p,
 				Err(_) => {
 					error = Some(http::Error::IoError);
@@ -283,25 +600,195 @@ impl<'a> AggregatorClient<'a> {
 			return Err(e);
 		}
 
-		let response = match maybe_response {
-			Some(r) => r,
-			None => return Err(http::Error::Unknown),
-		};
-
-		if response.code != 200 {
-			return Err(http::Error::Unknown);
+		match maybe_response {
+			Some(r) => Ok(r),
+			None => Err(http::Error::Unknown),
 		}
-
-		Ok(response)
 	}
 }
 
+/// Media types of protobuf encoded responses.
+const PROTOBUF_MEDIA_TYPES: [&str; 2] = ["application/protobuf", "application/x-protobuf"];
+
 enum Accept {
 	Any,
 	Protobuf,
+	/// Protobuf is preferred, JSON is accepted from aggregators not serving protobuf yet.
+	ProtobufOrJson,
+}
+
+impl Accept {
+	fn request<'a>(&self, url: &'a str) -> http::Request<'a> {
+		let request = http::Request::get(url);
+		match self {
+			Accept::Any => request,
+			Accept::Protobuf => request.add_header("Accept", "application/protobuf"),
+			Accept::ProtobufOrJson =>
+				request.add_header("Accept", "application/protobuf, application/json;q=0.9"),
+		}
+	}
+}
+
+/// Aggregator response which can be served protobuf encoded as well as JSON. The protobuf
+/// message is converted into the JSON one, so the callers are agnostic of the format.
+pub trait ProtoResponse: Serialize + DeserializeOwned {
+	/// Protobuf message of the response.
+	type Proto: Message + Default;
+
+	fn from_proto(message: Self::Proto) -> Result<Self, http::Error>;
+}
+
+impl ProtoResponse for Vec<json::BucketAggregateResponse> {
+	type Proto = proto::BucketAggregatesResponse;
+
+	fn from_proto(message: Self::Proto) -> Result<Self, http::Error> {
+		Ok(message
+			.aggregates
+			.into_iter()
+			.map(|aggregate| json::BucketAggregateResponse {
+				bucket_id: aggregate.bucket_id,
+				stored_bytes: aggregate.stored_bytes,
+				transferred_bytes: aggregate.transferred_bytes,
+				number_of_puts: aggregate.number_of_puts,
+				number_of_gets: aggregate.number_of_gets,
+				sub_aggregates: aggregate
+					.sub_aggregates
+					.into_iter()
+					.map(|sub_aggregate| json::BucketSubAggregateResponse {
+						NodeID: sub_aggregate.node_id,
+						stored_bytes: sub_aggregate.stored_bytes,
+						transferred_bytes: sub_aggregate.transferred_bytes,
+						number_of_puts: sub_aggregate.number_of_puts,
+						number_of_gets: sub_aggregate.number_of_gets,
+					})
+					.collect(),
+			})
+			.collect())
+	}
+}
+
+impl ProtoResponse for Vec<json::NodeAggregateResponse> {
+	type Proto = proto::NodeAggregatesResponse;
+
+	fn from_proto(message: Self::Proto) -> Result<Self, http::Error> {
+		Ok(message
+			.aggregates
+			.into_iter()
+			.map(|aggregate| json::NodeAggregateResponse {
+				node_id: aggregate.node_id,
+				stored_bytes: aggregate.stored_bytes,
+				transferred_bytes: aggregate.transferred_bytes,
+				number_of_puts: aggregate.number_of_puts,
+				number_of_gets: aggregate.number_of_gets,
+			})
+			.collect())
+	}
+}
+
+impl ProtoResponse for Vec<json::AggregationEraResponse> {
+	type Proto = proto::ErasResponse;
+
+	fn from_proto(message: Self::Proto) -> Result<Self, http::Error> {
+		Ok(message
+			.eras
+			.into_iter()
+			.map(|era| json::AggregationEraResponse {
+				id: era.id,
+				status: era.status,
+				start: era.start,
+				end: era.end,
+				processing_time: era.processing_time,
+				nodes_total: era.nodes_total,
+				nodes_processed: era.nodes_processed,
+				records_processed: era.records_processed,
+				records_applied: era.records_applied,
+				records_discarded: era.records_discarded,
+				attempt: era.attempt,
+			})
+			.collect())
+	}
+}
+
+impl ProtoResponse for json::MerkleTreeNodeResponse {
+	type Proto = proto::MerkleTreeNode;
+
+	fn from_proto(message: Self::Proto) -> Result<Self, http::Error> {
+		// the JSON response carries the hash base64 encoded
+		let mut hash = vec![0u8; base64ct::Base64::encoded_len(&message.hash)];
+		let hash = base64ct::Base64::encode(&message.hash, &mut hash)
+			.map_err(|_| http::Error::Unknown)?
+			.to_string();
+
+		Ok(json::MerkleTreeNodeResponse {
+			merkle_tree_node_id: message.merkle_tree_node_id,
+			hash,
+			stored_bytes: message.stored_bytes,
+			transferred_bytes: message.transferred_bytes,
+			number_of_puts: message.number_of_puts,
+			number_of_gets: message.number_of_gets,
+		})
+	}
+}
+
+/// Paginated era aggregates served by aggregators.
+pub trait AggregatesPage: Serialize + DeserializeOwned {
+	/// Path of the aggregates under `/activity`.
+	const PATH: &'static str;
+
+	/// Token the next page starts after.
+	fn prev_token(&self) -> String;
+}
+
+impl AggregatesPage for json::BucketAggregateResponse {
+	const PATH: &'static str = "buckets";
+
+	fn prev_token(&self) -> String {
+		self.bucket_id.to_string()
+	}
+}
+
+impl AggregatesPage for json::NodeAggregateResponse {
+	const PATH: &'static str = "nodes";
+
+	fn prev_token(&self) -> String {
+		self.node_id.clone()
+	}
+}
+
+/// Pages fetched from an aggregator by [`AggregatorClient::fetch_aggregates`].
+struct PagesFetch<R> {
+	aggregates: Vec<R>,
+	prev_token: Option<String>,
+	/// Failed attempts to fetch the current page.
+	failures: u32,
+	/// Set once all pages are fetched or the fetch failed.
+	result: Option<Result<(), AggregatorClientError>>,
+}
+
+impl<R: AggregatesPage> PagesFetch<R> {
+	fn new() -> Self {
+		Self { aggregates: Vec::new(), prev_token: None, failures: 0, result: None }
+	}
+
+	fn extend(&mut self, page: Vec<R>, batch_size: u32) {
+		if page.len() < batch_size as usize {
+			self.result = Some(Ok(()));
+		}
+
+		self.prev_token = page.last().map(|aggregate| aggregate.prev_token());
+		self.aggregates.extend(page);
+		self.failures = 0;
+	}
+
+	fn fail(&mut self, error: AggregatorClientError, retries: u32) {
+		self.failures += 1;
+		if self.failures >= retries {
+			self.result = Some(Err(error));
+		}
+	}
 }
 
-pub(crate) mod json {
+pub mod json {
 	use super::*;
 
 	/// Node aggregate response from aggregator.
@@ -518,6 +1005,20 @@ pub(crate) mod json {
 		pub number_of_gets: u64,
 	}
 
+	/// Nonce signed by a node with its node key.
+	#[serde_as]
+	#[derive(
+		Debug, Serialize, Deserialize, Clone, Hash, Ord, PartialOrd, PartialEq, Eq, Encode, Decode,
+	)]
+	pub struct NodeHandshakeResponse {
+		#[serde_as(as = "Base64")]
+		pub nonce: Vec<u8>,
+		#[serde_as(as = "Base64")]
+		pub signer: Vec<u8>,
+		#[serde_as(as = "Base64")]
+		pub signature: Vec<u8>,
+	}
+
 	/// Json response wrapped with a signature.
 	#[serde_as]
 	#[derive(
@@ -2,9 +2,10 @@
 
 use ddc_primitives::{
 	BillingFingerprintParams, BillingReportParams, BucketId, BucketParams, ClusterId,
-	ClusterParams, ClusterProtocolParams, CustomerCharge, DeltaUsageHash, EraValidation,
-	EraValidationStatus, MergeMMRHash, NodeParams, NodePubKey, NodeUsage, PayoutState,
-	StorageNodeMode, StorageNodeParams, AVG_SECONDS_MONTH, DOLLARS as CERE, MAX_PAYOUT_BATCH_SIZE,
+	ClusterNodeKind, ClusterParams, ClusterProtocolParams, CustomerCharge, DeltaUsageHash,
+	EraValidation, EraValidationStatus, MergeMMRHash, NodeParams, NodePubKey, NodeUsage,
+	PayoutState, StorageNodeMode, StorageNodeParams, AVG_SECONDS_MONTH, DOLLARS as CERE,
+	MAX_PAYOUT_BATCH_COUNT, MAX_PAYOUT_BATCH_SIZE,
 };
 use frame_benchmarking::{account, v2::*, whitelist_account};
 use frame_system::RawOrigin;
@@ -89,6 +90,7 @@ mod benchmarks {
 				unit_per_mb_streamed: CERE,
 				unit_per_put_request: CERE,
 				unit_per_get_request: CERE,
+				pricing_tiers: Default::default(),
 				..Default::default()
 			};
 
@@ -109,17 +111,17 @@ mod benchmarks {
 		payees_merkle_root_hash: DeltaUsageHash,
 		status: EraValidationStatus,
 	) {
+		let start_era: i64 = 1_000_000_000;
+		let end_era: i64 = start_era + AVG_SECONDS_MONTH;
+
 		let mut validations_map = BTreeMap::new();
 		for validator in validators {
 			validations_map.insert(
-				(payers_merkle_root_hash, payees_merkle_root_hash),
+				(payers_merkle_root_hash, payees_merkle_root_hash, start_era, end_era),
 				vec![validator.clone()],
 			);
 		}
 
-		let start_era: i64 = 1_000_000_000;
-		let end_era: i64 = start_era + AVG_SECONDS_MONTH;
-
 		let era_validation = EraValidation::<T> {
 			validators: validations_map,
 			start_era,
@@ -787,10 +789,7 @@ mod benchmarks {
 			gets: 10 * CERE,      // price for 10 gets
 			puts: 5 * CERE,       // price for 5 puts
 		};
-		let total_distributed_reward: u128 = total_customer_charge.transfer +
-			total_customer_charge.storage +
-			total_customer_charge.gets +
-			total_customer_charge.puts;
+		let total_distributed_reward: u128 = 0;
 		let cluster_usage = NodeUsage {
 			transferred_bytes: 200000000, // 200 mb
 			stored_bytes: 100000000,      // 100 mb
@@ -799,7 +798,7 @@ mod benchmarks {
 		};
 		let charging_max_batch_index = 0;
 		let charging_processed_batches = vec![0];
-		let rewarding_max_batch_index = 0;
+		let rewarding_max_batch_index = 1;
 		let rewarding_processed_batches = vec![0];
 		let payers_merkle_root = H256(blake2_256(&3.encode()));
 		let payees_merkle_root = H256(blake2_256(&4.encode()));
@@ -833,6 +832,9 @@ mod benchmarks {
 			payees_merkle_root,
 			vec![validator.clone()],
 		);
+		// rewards of the voided batch are returned to the treasury
+		T::PayoutProcessor::void_billing_report_batch(cluster_id, era_id, 1)
+			.expect("Batch to be voided");
 
 		#[extrinsic_call]
 		end_rewarding_providers(RawOrigin::Signed(validator), cluster_id, era_id);
@@ -909,20 +911,25 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn emit_consensus_errors(b: Linear<1, 5>) {
+	fn emit_consensus_errors(b: Linear<1, MAX_CONSENSUS_ERRORS>) {
+		let cluster_id = ClusterId::from([1; 20]);
+		create_default_cluster::<T>(cluster_id);
 		let validator = create_validator_account::<T>();
 		let mut errros = vec![];
 
 		for _i in 0..b {
 			errros.push(OCWError::SendChargingCustomersBatchTransactionError {
-				cluster_id: ClusterId::from([1; 20]),
+				cluster_id,
 				era_id: 1,
 				batch_index: 0,
 			});
 		}
+		let errors = BoundedVec::truncate_from(errros);
 
 		#[extrinsic_call]
-		emit_consensus_errors(RawOrigin::Signed(validator.clone()), errros);
+		emit_consensus_errors(RawOrigin::Signed(validator.clone()), cluster_id, errors);
+
+		assert!(ConsensusErrorsReports::<T>::contains_key(cluster_id, validator));
 	}
 
 	#[benchmark]
@@ -935,4 +942,326 @@ mod benchmarks {
 
 		<EraValidations<T>>::contains_key(cluster_id, era_id);
 	}
+
+	#[benchmark]
+	fn submit_fraud_proof(b: Linear<4_000, 400_000>) {
+		let cluster_id = ClusterId::from([1; 20]);
+		let era_id: DdcEra = 1;
+		// activity records of the fixture are created at 1730197103
+		let start_era: i64 = 1_730_197_000;
+		let end_era: i64 = 1_730_198_000;
+
+		create_default_cluster::<T>(cluster_id);
+		let validator = create_validator_account::<T>();
+
+		// records of the fixture are signed by this node
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::from(
+			array_bytes::hex2array_unchecked::<_, 32>(
+				"0ac7cb9c53594e9f538d9950c6bcf28f0c0c7b8385deea2ebe24062bc640e7be",
+			),
+		));
+		T::NodeManager::create_node(
+			node_pub_key.clone(),
+			create_account::<T>("provider", 0, 0),
+			NodeParams::StorageParams(StorageNodeParams {
+				mode: StorageNodeMode::Storage,
+				host: vec![1u8; 255],
+				domain: vec![2u8; 255],
+				ssl: true,
+				http_port: 35000u16,
+				grpc_port: 25000u16,
+				p2p_port: 15000u16,
+			}),
+		)
+		.expect("Node to be created");
+		T::ClusterManager::add_node(&cluster_id, &node_pub_key, &ClusterNodeKind::External)
+			.expect("Node to be added to the cluster");
+
+		// requests of the fixture are authorized by an auth token of the bucket owner
+		let bucket_owner = T::AccountId::decode(
+			&mut array_bytes::hex2array_unchecked::<_, 32>(
+				"09cf069b8ef5d3f509eea39766d7c73a068c9e6de5c4995733a6962405499d45",
+			)
+			.as_slice(),
+		)
+		.expect("Bucket owner to be decoded");
+		T::BucketManager::create_bucket(
+			&cluster_id,
+			1,
+			bucket_owner,
+			BucketParams { is_public: true },
+		)
+		.expect("Bucket to be created");
+
+		let payees = vec![(node_pub_key.clone(), NodeUsage::default())];
+		let leaves = payees
+			.iter()
+			.map(|(node_key, usage)| NodePayableUsage(node_key.clone(), usage.clone()).hash::<T>())
+			.collect::<Vec<_>>();
+		let batch_root = DdcVerification::<T>::create_merkle_root(&cluster_id, era_id, &leaves)
+			.expect("Batch root to be created");
+		let payees_merkle_root =
+			DdcVerification::<T>::create_merkle_root(&cluster_id, era_id, &[batch_root])
+				.expect("Payees root to be created");
+
+		let fingerprint =
+			T::PayoutProcessor::create_billing_fingerprint(BillingFingerprintParams {
+				cluster_id,
+				era: era_id,
+				start_era,
+				end_era,
+				payers_merkle_root: H256(blake2_256(&3.encode())),
+				payees_merkle_root,
+				cluster_usage: NodeUsage::default(),
+				validators: BTreeSet::from([validator.clone()]),
+			});
+		T::PayoutProcessor::begin_billing_report(cluster_id, era_id, fingerprint)
+			.expect("Billing report to be initialized");
+
+		endow_account::<T>(&T::PalletId::get().into_account_truncating(), 1_000 * CERE);
+
+		// every copy of the fixture records is verified, but accounted only once
+		let fixture = proto::ChallengeResponse::decode(
+			include_bytes!("./test_data/challenge_response.pb").as_slice(),
+		)
+		.expect("Fixture to be decoded");
+		let mut challenge_response = proto::ChallengeResponse::default();
+		while (challenge_response.encoded_len() as u32) < b {
+			challenge_response.proofs.extend(fixture.proofs.iter().cloned());
+		}
+
+		let fraud_proof = FraudProof {
+			usage: DisputedUsage::Payees(payees),
+			leaf_index: 0,
+			batch_index: 0,
+			max_batch_index: 0,
+			batch_proof: MMRProof::default(),
+			activity_proof: challenge_response.encode_to_vec(),
+			node_key: node_pub_key,
+		};
+		let challenger = create_account::<T>("challenger", 0, 0);
+
+		#[extrinsic_call]
+		submit_fraud_proof(RawOrigin::Signed(challenger), cluster_id, era_id, fraud_proof);
+
+		let status = T::PayoutProcessor::get_billing_report_status(&cluster_id, era_id);
+		assert_eq!(status, PayoutState::Disputed);
+		assert!(<FlaggedValidators<T>>::contains_key(validator, (cluster_id, era_id)));
+	}
+
+	#[benchmark]
+	fn rollback_billing_report() {
+		let cluster_id = ClusterId::from([1; 20]);
+		let era_id: DdcEra = 1;
+		let start_era: i64 = 1_000_000_000;
+		let end_era: i64 = start_era + AVG_SECONDS_MONTH;
+		let max_batch_index: BatchIndex = MAX_PAYOUT_BATCH_COUNT - 1;
+
+		create_default_cluster::<T>(cluster_id);
+		let validator = create_validator_account::<T>();
+
+		setup_validation_era::<T>(
+			cluster_id,
+			era_id,
+			vec![validator.clone()],
+			H256(blake2_256(&1.encode())),
+			H256(blake2_256(&2.encode())),
+			EraValidationStatus::PayoutInProgress,
+		);
+		create_billing_report::<T>(
+			cluster_id,
+			era_id,
+			start_era,
+			end_era,
+			PayoutState::ChargingCustomers,
+			CustomerCharge::default(),
+			0,
+			NodeUsage::default(),
+			max_batch_index,
+			vec![],
+			0,
+			vec![],
+			H256(blake2_256(&3.encode())),
+			H256(blake2_256(&4.encode())),
+			vec![validator],
+		);
+		// the charging stage is rolled back with all its batches voided
+		for batch_index in 0..=max_batch_index {
+			T::PayoutProcessor::void_billing_report_batch(cluster_id, era_id, batch_index)
+				.expect("Batch to be voided");
+		}
+
+		#[extrinsic_call]
+		rollback_billing_report(RawOrigin::Root, cluster_id, era_id, PayoutState::NotInitialized);
+
+		let status = T::PayoutProcessor::get_billing_report_status(&cluster_id, era_id);
+		assert_eq!(status, PayoutState::NotInitialized);
+		assert_eq!(
+			<EraValidations<T>>::get(cluster_id, era_id).map(|validation| validation.status),
+			Some(EraValidationStatus::ReadyForPayout)
+		);
+	}
+
+	#[benchmark]
+	fn void_billing_report_batch() {
+		let cluster_id = ClusterId::from([1; 20]);
+		let era_id: DdcEra = 1;
+		let start_era: i64 = 1_000_000_000;
+		let end_era: i64 = start_era + AVG_SECONDS_MONTH;
+		let max_batch_index: BatchIndex = MAX_PAYOUT_BATCH_COUNT - 1;
+
+		create_default_cluster::<T>(cluster_id);
+		let validator = create_validator_account::<T>();
+
+		// all the batches but the voided one are processed
+		create_billing_report::<T>(
+			cluster_id,
+			era_id,
+			start_era,
+			end_era,
+			PayoutState::ChargingCustomers,
+			CustomerCharge::default(),
+			0,
+			NodeUsage::default(),
+			max_batch_index,
+			(0..max_batch_index).collect(),
+			0,
+			vec![],
+			H256(blake2_256(&3.encode())),
+			H256(blake2_256(&4.encode())),
+			vec![validator],
+		);
+
+		#[extrinsic_call]
+		void_billing_report_batch(RawOrigin::Root, cluster_id, era_id, max_batch_index);
+
+		assert!(T::PayoutProcessor::all_customer_batches_processed(&cluster_id, era_id));
+	}
+
+	#[benchmark]
+	fn force_advance_billing_report() {
+		let cluster_id = ClusterId::from([1; 20]);
+		let era_id: DdcEra = 1;
+		let start_era: i64 = 1_000_000_000;
+		let end_era: i64 = start_era + AVG_SECONDS_MONTH;
+		let total_customer_charge = CustomerCharge {
+			transfer: 200 * CERE, // price for 200 mb
+			storage: 100 * CERE,  // price for 100 mb
+			gets: 10 * CERE,      // price for 10 gets
+			puts: 5 * CERE,       // price for 5 puts
+		};
+
+		create_default_cluster::<T>(cluster_id);
+		let validator = create_validator_account::<T>();
+
+		setup_validation_era::<T>(
+			cluster_id,
+			era_id,
+			vec![validator.clone()],
+			H256(blake2_256(&1.encode())),
+			H256(blake2_256(&2.encode())),
+			EraValidationStatus::PayoutInProgress,
+		);
+		// none of the charging batches are processed, so all of them are voided before the
+		// fees are collected
+		create_billing_report::<T>(
+			cluster_id,
+			era_id,
+			start_era,
+			end_era,
+			PayoutState::ChargingCustomers,
+			total_customer_charge,
+			0,
+			NodeUsage::default(),
+			MAX_PAYOUT_BATCH_COUNT - 1,
+			vec![],
+			0,
+			vec![],
+			H256(blake2_256(&3.encode())),
+			H256(blake2_256(&4.encode())),
+			vec![validator],
+		);
+
+		#[extrinsic_call]
+		force_advance_billing_report(RawOrigin::Root, cluster_id, era_id);
+
+		let status = T::PayoutProcessor::get_billing_report_status(&cluster_id, era_id);
+		assert_eq!(status, PayoutState::CustomersChargedWithFees);
+		assert!(T::PayoutProcessor::all_customer_batches_processed(&cluster_id, era_id));
+	}
+
+	#[benchmark]
+	fn requeue_era_validation() {
+		let cluster_id = ClusterId::from([1; 20]);
+		let era_id: DdcEra = 1;
+
+		create_default_cluster::<T>(cluster_id);
+		let validator = create_validator_account::<T>();
+
+		setup_validation_era::<T>(
+			cluster_id,
+			era_id,
+			vec![validator],
+			H256(blake2_256(&1.encode())),
+			H256(blake2_256(&2.encode())),
+			EraValidationStatus::ReadyForPayout,
+		);
+
+		#[extrinsic_call]
+		requeue_era_validation(RawOrigin::Root, cluster_id, era_id);
+
+		assert_eq!(
+			<EraValidations<T>>::get(cluster_id, era_id).map(|validation| validation.status),
+			Some(EraValidationStatus::ValidatingData)
+		);
+	}
+
+	#[benchmark]
+	fn submit_node_validation() {
+		let cluster_id = ClusterId::from([1; 20]);
+		create_default_cluster::<T>(cluster_id);
+		T::StakerCreator::bond_cluster(
+			create_account::<T>("cr", 0, 0),
+			create_account::<T>("cm", 0, 0),
+			cluster_id,
+		)
+		.expect("Cluster is not bonded");
+
+		let provider = create_account::<T>("provider", 0, 0);
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::from([1; 32]));
+		T::NodeManager::create_node(
+			node_pub_key.clone(),
+			provider,
+			NodeParams::StorageParams(StorageNodeParams {
+				mode: StorageNodeMode::Storage,
+				host: vec![1u8; 255],
+				domain: vec![2u8; 255],
+				ssl: true,
+				http_port: 35000u16,
+				grpc_port: 25000u16,
+				p2p_port: 15000u16,
+			}),
+		)
+		.expect("Node to be created");
+		T::ClusterManager::add_node(&cluster_id, &node_pub_key, &ClusterNodeKind::External)
+			.expect("Node to be added to the cluster");
+
+		let validator = create_validator_account::<T>();
+		let result =
+			NodeProbeResult { http_reachable: true, grpc_reachable: true, handshake_signed: true };
+
+		#[extrinsic_call]
+		submit_node_validation(
+			RawOrigin::Signed(validator),
+			cluster_id,
+			node_pub_key.clone(),
+			result,
+		);
+
+		assert!(T::ClusterManager::contains_node(
+			&cluster_id,
+			&node_pub_key,
+			Some(ClusterNodeStatus::ValidationSucceeded)
+		));
+	}
 }
@@ -13,22 +13,26 @@ use core::str;
 
 use base64ct::{Base64, Encoding};
 #[cfg(feature = "runtime-benchmarks")]
-use ddc_primitives::traits::{BucketManager, ClusterCreator, CustomerDepositor};
+use ddc_primitives::traits::{BucketManager, ClusterCreator, CustomerDepositor, StakerCreator};
 use ddc_primitives::{
 	traits::{
-		ClusterManager, ClusterValidator, CustomerVisitor, NodeManager, PayoutProcessor,
-		StorageUsageProvider, ValidatorVisitor,
+		ClusterDecommissionHandler, ClusterManager, ClusterValidator, CustomerVisitor, NodeManager,
+		PayoutProcessor, StorageUsageProvider, ValidatorVisitor,
 	},
-	BatchIndex, BillingReportParams, BucketStorageUsage, BucketUsage, ClusterId, ClusterStatus,
-	DdcEra, EraValidation, EraValidationStatus, MMRProof, NodeParams, NodePubKey, NodeStorageUsage,
-	NodeUsage, PayableUsageHash, PayoutState, StorageNodeParams, StorageNodePubKey,
+	BatchIndex, BillingFingerprintParams, BillingReportParams, BucketStorageUsage, BucketUsage,
+	ClusterId, ClusterNodeStatus, ClusterStatus, DdcEra, EraValidation, EraValidationStatus,
+	MMRProof, NodeParams, NodePubKey, NodeStorageUsage, NodeUsage, OcwSettings, PayableUsageHash,
+	PayoutState, StorageNodeMode, StorageNodeParams, StorageNodePubKey, OCW_SETTINGS_KEY,
 };
 use frame_support::{
+	dispatch::GetDispatchInfo,
 	pallet_prelude::*,
-	traits::{Currency, Get, OneSessionHandler},
+	traits::{Currency, ExistenceRequirement, Get, OneSessionHandler, UnfilteredDispatchable},
 };
 use frame_system::{
-	offchain::{Account, AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer},
+	offchain::{
+		Account, AppCrypto, CreateSignedTransaction, SendUnsignedTransaction, SignedPayload, Signer,
+	},
 	pallet_prelude::*,
 };
 use itertools::Itertools;
@@ -38,6 +42,7 @@ use polkadot_ckb_merkle_mountain_range::{
 	util::{MemMMR, MemStore},
 	MerkleProof, MMR,
 };
+use prost::Message;
 use rand::{prelude::*, rngs::SmallRng, SeedableRng};
 use scale_info::prelude::{format, string::String};
 use serde::{Deserialize, Serialize};
@@ -51,7 +56,7 @@ pub use sp_io::{
 };
 use sp_runtime::{
 	offchain::{http, Duration, StorageKind},
-	traits::{Hash, IdentifyAccount},
+	traits::{AccountIdConversion, Hash, IdentifyAccount, Zero},
 	Percent,
 };
 use sp_staking::StakingInterface;
@@ -75,14 +80,25 @@ mod tests;
 
 pub mod migrations;
 
-mod aggregator_client;
+pub mod aggregator_client;
+
+mod metrics;
+
+pub mod runtime_api;
 
 pub mod proto {
 	include!(concat!(env!("OUT_DIR"), "/activity.rs"));
+
+	pub mod health {
+		include!(concat!(env!("OUT_DIR"), "/grpc.health.v1.rs"));
+	}
 }
 
 mod signature;
 
+pub mod signed_extension;
+pub use signed_extension::CheckPayoutStep;
+
 pub(crate) type BalanceOf<T> =
 	<<T as pallet::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -101,15 +117,28 @@ pub mod pallet {
 
 	/// The current storage version.
 	const STORAGE_VERSION: frame_support::traits::StorageVersion =
-		frame_support::traits::StorageVersion::new(1);
+		frame_support::traits::StorageVersion::new(2);
 
 	const _SUCCESS_CODE: u16 = 200;
 	const _BUF_SIZE: usize = 128;
 	const RESPONSE_TIMEOUT: u64 = 20000;
+	const RESPONSE_RETRIES: u32 = 3;
+	/// Milliseconds all DAC nodes have to return the aggregates of an era, at least
+	/// `RESPONSE_TIMEOUT`.
+	const AGGREGATES_FETCH_DEADLINE: u64 = 60000;
 	pub const BUCKETS_AGGREGATES_FETCH_BATCH_SIZE: usize = 100;
 	pub const NODES_AGGREGATES_FETCH_BATCH_SIZE: usize = 10;
 	pub const IS_RUNNING_KEY: &[u8] = b"offchain::validator::is_running";
 	pub const IS_RUNNING_VALUE: &[u8] = &[1];
+	/// Number of blocks validators' unsigned transactions are valid for.
+	pub const UNSIGNED_TRANSACTIONS_LONGEVITY: u32 = 64;
+	/// Number of blocks a payout step may remain pending before the next validator submits it.
+	pub const PAYOUT_STEP_LEADER_TIMEOUT: u32 = 20;
+	/// Maximum number of consensus errors a validator reports for a cluster at once, the rest are
+	/// not reported.
+	pub const MAX_CONSENSUS_ERRORS: u32 = 64;
+	/// Maximum number of probe results submitted for a node awaiting validation in a cluster.
+	pub const MAX_NODE_PROBE_RESULTS: u32 = 128;
 
 	/// Delta usage of a bucket includes only the delta usage for the processing era reported by
 	/// collectors. This usage can be verified of unverified by inspectors.
@@ -123,13 +152,13 @@ pub mod pallet {
 	/// delta usage verified by inspectors. This is overall amount of bytes that the bucket owner
 	/// will be charged for.
 	#[derive(Clone, PartialOrd, Ord, Eq, PartialEq, Encode, Decode)]
-	pub(crate) struct BucketPayableUsage(BucketId, BucketUsage);
+	pub(crate) struct BucketPayableUsage(pub(crate) BucketId, pub(crate) BucketUsage);
 
 	/// Payable usage of a node includes the current storage usage this node provides and the delta
 	/// usage verified by inspectors. This is overall amount of bytes that the node owner will be
 	/// rewarded for.
 	#[derive(Clone, PartialOrd, Ord, Eq, PartialEq, Encode, Decode)]
-	pub(crate) struct NodePayableUsage(NodePubKey, NodeUsage);
+	pub(crate) struct NodePayableUsage(pub(crate) NodePubKey, pub(crate) NodeUsage);
 
 	/// Payable usage of an Era includes all the batches of customers and providers that will be
 	/// processed during the payout process along with merkle root hashes and proofs. To calculate
@@ -223,12 +252,22 @@ pub mod pallet {
 		>;
 		type Currency: Currency<Self::AccountId>;
 		const VERIFY_AGGREGATOR_RESPONSE_SIGNATURE: bool;
+		/// Amount paid from the pallet account to a challenger who proved a committed billing
+		/// fingerprint wrong.
+		#[pallet::constant]
+		type FraudProofReward: Get<BalanceOf<Self>>;
+		/// Priority of the unsigned transactions submitted by validators on behalf of their
+		/// verification keys.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
 		#[cfg(feature = "runtime-benchmarks")]
 		type CustomerDepositor: CustomerDepositor<Self>;
 		#[cfg(feature = "runtime-benchmarks")]
 		type ClusterCreator: ClusterCreator<Self, BalanceOf<Self>>;
 		#[cfg(feature = "runtime-benchmarks")]
 		type BucketManager: BucketManager<Self>;
+		#[cfg(feature = "runtime-benchmarks")]
+		type StakerCreator: StakerCreator<Self, BalanceOf<Self>>;
 	}
 
 	/// The event type.
@@ -261,6 +300,11 @@ pub mod pallet {
 			cluster_id: ClusterId,
 			era_id: DdcEra,
 		},
+		/// An era was re-queued for validation, the votes of validators for it are discarded.
+		EraValidationRequeued {
+			cluster_id: ClusterId,
+			era_id: DdcEra,
+		},
 		/// Node Usage Retrieval Error.
 		NodeUsageRetrievalError {
 			cluster_id: ClusterId,
@@ -380,6 +424,21 @@ pub mod pallet {
 		ValidatorKeySet {
 			validator: T::AccountId,
 		},
+		/// The verification key of a validator stash was replaced.
+		ValidatorKeyRotated {
+			stash: T::AccountId,
+			previous: T::AccountId,
+			validator: T::AccountId,
+		},
+		/// A billing report was disputed with a fraud proof. Validators that committed the
+		/// disputed billing fingerprint are flagged and the challenger is rewarded.
+		BillingReportDisputed {
+			cluster_id: ClusterId,
+			era_id: DdcEra,
+			challenger: T::AccountId,
+			validators: Vec<T::AccountId>,
+			reward: BalanceOf<T>,
+		},
 		FailedToFetchClusterNodes {
 			validator: T::AccountId,
 		},
@@ -421,9 +480,33 @@ pub mod pallet {
 			aggregator: NodePubKey,
 			validator: T::AccountId,
 		},
+		AggregatorResponseSignatureError {
+			cluster_id: ClusterId,
+			aggregator: NodePubKey,
+			failures: u32,
+			validator: T::AccountId,
+		},
 		EmptyConsistentGroup,
 		FailedToFetchVerifiedDeltaUsage,
 		FailedToFetchVerifiedPayableUsage,
+		SubmitNodeValidationTransactionError {
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+			validator: T::AccountId,
+		},
+		/// A validator submitted the result of probing a node awaiting validation.
+		NodeProbeResultSubmitted {
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+			validator: T::AccountId,
+			result: NodeProbeResult,
+		},
+		/// The quorum of validators agreed on the outcome of the node validation.
+		NodeValidationCompleted {
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+			succeeded: bool,
+		},
 	}
 
 	/// Consensus Errors
@@ -466,6 +549,13 @@ pub mod pallet {
 			aggregate_key: AggregateKey,
 			aggregator: NodePubKey,
 		},
+		/// Aggregator response is unsigned or not signed with the aggregator node key.
+		/// `failures` is the number of such responses received from the aggregator so far.
+		AggregatorResponseSignatureError {
+			cluster_id: ClusterId,
+			aggregator: NodePubKey,
+			failures: u32,
+		},
 		PrepareEraTransactionError {
 			cluster_id: ClusterId,
 			era_id: DdcEra,
@@ -548,6 +638,10 @@ pub mod pallet {
 		EmptyConsistentGroup,
 		FailedToFetchVerifiedDeltaUsage,
 		FailedToFetchVerifiedPayableUsage,
+		SubmitNodeValidationTransactionError {
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+		},
 	}
 
 	#[pallet::error]
@@ -590,6 +684,35 @@ pub mod pallet {
 		NoEraValidation,
 		/// Given era is already validated and paid.
 		EraAlreadyPaid,
+		/// Billing report of the era can not be disputed anymore.
+		DisputeWindowIsClosed,
+		/// Disputed batch is not committed to the billing fingerprint.
+		FraudProofBatchIsNotCommitted,
+		/// Disputed leaf is not in the batch or activity proof can not be decoded.
+		BadFraudProof,
+		/// Activity records of the fraud proof are not properly signed.
+		FraudProofSignatureInvalid,
+		/// Activity records of the fraud proof do not contradict the committed usage.
+		FraudProofDoesNotContradict,
+		/// The call can not be submitted by a validator as an unsigned transaction.
+		NotValidatorCall,
+		/// Billing report of the era must be rolled back before the era is validated again.
+		BillingReportNotRolledBack,
+		/// Era is validated, but its payout is not completed.
+		EraPayoutNotCompleted,
+		/// Node is not awaiting validation in the cluster.
+		NodeIsNotAwaitingValidation,
+		/// Validator has already submitted the probe result for the node.
+		AlreadySubmittedNodeProbeResult,
+		/// The node of the fraud proof is not in the cluster, or the requests its activity records
+		/// fulfilled are signed by neither the bucket owner nor a holder of its auth token.
+		FraudProofSignerIsNotAuthorized,
+		/// The signed validator call has already been applied.
+		ValidatorCallAlreadyApplied,
+		/// Validator has already reported the consensus errors of the cluster in the era.
+		ConsensusErrorsAlreadyReported,
+		/// The node has received the maximum number of probe results.
+		TooManyNodeProbeResults,
 	}
 
 	/// Era validations
@@ -614,6 +737,51 @@ pub mod pallet {
 	#[pallet::getter(fn get_stash_for_ddc_validator)]
 	pub type ValidatorToStashKey<T: Config> = StorageMap<_, Identity, T::AccountId, T::AccountId>;
 
+	/// Latest verification key bound to a validator stash. Keys replaced by a rotation remain in
+	/// `ValidatorToStashKey`, so that the signatures of eras in flight are still attributed to
+	/// the stash.
+	#[pallet::storage]
+	#[pallet::getter(fn get_ddc_validator_for_stash)]
+	pub type StashToValidatorKey<T: Config> = StorageMap<_, Identity, T::AccountId, T::AccountId>;
+
+	/// Validators that committed a billing fingerprint disputed with a fraud proof.
+	#[pallet::storage]
+	#[pallet::getter(fn flagged_validators)]
+	pub type FlaggedValidators<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		(ClusterId, DdcEra),
+		(),
+	>;
+
+	/// Validations of nodes joined clusters, kept as an audit trail of the probe results
+	/// submitted by validators.
+	#[pallet::storage]
+	#[pallet::getter(fn node_validations)]
+	pub type NodeValidations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClusterId,
+		Blake2_128Concat,
+		NodePubKey,
+		NodeValidation<T::AccountId, BlockNumberFor<T>>,
+	>;
+
+	/// Hashes of the validator calls applied with `submit_validator_call`, by the block their
+	/// payloads were signed at. They are kept until the payloads expire, so that a signed call
+	/// can not be replayed.
+	#[pallet::storage]
+	pub type AppliedValidatorCalls<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Identity, T::Hash, ()>;
+
+	/// Last paid era of the cluster when the validator reported its consensus errors, they are
+	/// reported once until the next era is paid.
+	#[pallet::storage]
+	pub type ConsensusErrorsReports<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClusterId, Blake2_128Concat, T::AccountId, DdcEra>;
+
 	/// Era activity of a node.
 	#[derive(
 		Debug,
@@ -683,6 +851,139 @@ pub mod pallet {
 		pub(crate) others: Vec<ConsolidatedAggregate<A>>,
 	}
 
+	/// Keys, hashes and numbers of aggregators of the aggregates in consistency groups.
+	#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Default)]
+	pub struct ConsistencyGroupsSummary {
+		pub consensus: Vec<(AggregateKey, H256, u16)>,
+		pub quorum: Vec<(AggregateKey, H256, u16)>,
+		pub others: Vec<(AggregateKey, H256, u16)>,
+	}
+
+	impl ConsistencyGroupsSummary {
+		pub(crate) fn from_groups<T: Config, A: Aggregate>(groups: &ConsistencyGroups<A>) -> Self {
+			let summary = |group: &Vec<ConsolidatedAggregate<A>>| {
+				group
+					.iter()
+					.map(|ca| (ca.aggregate.get_key(), ca.aggregate.hash::<T>(), ca.count))
+					.collect()
+			};
+
+			Self {
+				consensus: summary(&groups.consensus),
+				quorum: summary(&groups.quorum),
+				others: summary(&groups.others),
+			}
+		}
+	}
+
+	/// Status of a DAC verification key.
+	#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq)]
+	pub struct VerificationKeyStatus<AccountId> {
+		/// The key belongs to a validator of the current session.
+		pub in_validator_set: bool,
+		/// Stash the key is bound to.
+		pub stash: Option<AccountId>,
+		/// The key is the latest key bound to its stash.
+		pub is_latest: bool,
+	}
+
+	/// Result of probing the endpoints a node declared on joining a cluster.
+	#[derive(Debug, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, Default)]
+	pub struct NodeProbeResult {
+		/// The HTTP endpoint responded.
+		pub http_reachable: bool,
+		/// The gRPC endpoint reported serving to the gRPC health check.
+		pub grpc_reachable: bool,
+		/// The node signed the handshake nonce with its node key, or does not serve the
+		/// handshake.
+		pub handshake_signed: bool,
+	}
+
+	impl NodeProbeResult {
+		/// The node passes the validation only if all its endpoints are operational.
+		pub fn succeeded(&self) -> bool {
+			self.http_reachable && self.grpc_reachable && self.handshake_signed
+		}
+	}
+
+	/// Probe results submitted by validators for a node awaiting validation in a cluster.
+	#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq)]
+	pub struct NodeValidation<AccountId, BlockNumber> {
+		/// Block the node was added to the cluster at, the results submitted for an earlier
+		/// membership of the node are discarded.
+		pub added_at: BlockNumber,
+		/// Probe results in the order they were submitted.
+		pub results: BoundedVec<(AccountId, NodeProbeResult), ConstU32<MAX_NODE_PROBE_RESULTS>>,
+		/// Outcome of the validation once the quorum of validators agrees on it.
+		pub succeeded: Option<bool>,
+	}
+
+	/// Call of a validator submitted as an unsigned transaction, signed with its verification key.
+	#[derive(
+		CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, Encode, Decode, TypeInfo,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ValidatorCallPayload<T: Config> {
+		/// Verification key of the validator.
+		pub public: T::Public,
+		/// Block the payload was signed at, the transaction expires after
+		/// `UNSIGNED_TRANSACTIONS_LONGEVITY` blocks.
+		pub block_number: BlockNumberFor<T>,
+		/// The call dispatched on behalf of the validator.
+		pub call: Box<Call<T>>,
+	}
+
+	impl<T: Config> SignedPayload<T> for ValidatorCallPayload<T> {
+		fn public(&self) -> T::Public {
+			self.public.clone()
+		}
+	}
+
+	/// Outcome of a DAC validation replayed for an era without submitting transactions.
+	#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq)]
+	pub struct ValidationDryRun {
+		pub era: EraActivity,
+		pub buckets_groups: ConsistencyGroupsSummary,
+		pub nodes_groups: ConsistencyGroupsSummary,
+		pub payers_delta_root: DeltaUsageHash,
+		pub payees_delta_root: DeltaUsageHash,
+		pub payers_delta_batch_roots: Vec<DeltaUsageHash>,
+		pub payees_delta_batch_roots: Vec<DeltaUsageHash>,
+		pub payers_root: PayableUsageHash,
+		pub payees_root: PayableUsageHash,
+		pub payers_batch_roots: Vec<PayableUsageHash>,
+		pub payees_batch_roots: Vec<PayableUsageHash>,
+		pub cluster_usage: NodeUsage,
+		pub fingerprint: Fingerprint,
+	}
+
+	/// Usage of a batch committed to a billing fingerprint that is disputed by a fraud proof.
+	#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq)]
+	pub enum DisputedUsage {
+		Payers(Vec<(BucketId, BucketUsage)>),
+		Payees(Vec<(NodePubKey, NodeUsage)>),
+	}
+
+	/// Proof that a leaf under the payers or payees merkle root of a billing fingerprint
+	/// contradicts signed activity records.
+	#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq)]
+	pub struct FraudProof {
+		/// Usage of the whole batch the disputed leaf belongs to.
+		pub usage: DisputedUsage,
+		/// Index of the disputed leaf in the batch.
+		pub leaf_index: u32,
+		/// Index of the batch under the merkle root.
+		pub batch_index: BatchIndex,
+		pub max_batch_index: BatchIndex,
+		/// MMR proof of the batch root under the merkle root.
+		pub batch_proof: MMRProof,
+		/// Protobuf encoded `ChallengeResponse` with signed activity records of the era.
+		pub activity_proof: Vec<u8>,
+		/// Node of the cluster whose activity records prove the usage. It must be the disputed
+		/// node for a batch of payees.
+		pub node_key: NodePubKey,
+	}
+
 	#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq)]
 	pub enum AggregateKey {
 		NodeAggregateKey(String),
@@ -827,6 +1128,18 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+			// payloads signed at this block have expired, their calls can not be replayed anymore
+			let longevity: BlockNumberFor<T> = (UNSIGNED_TRANSACTIONS_LONGEVITY + 1).into();
+			if block_number < longevity {
+				return Weight::zero();
+			}
+
+			let removed =
+				AppliedValidatorCalls::<T>::clear_prefix(block_number - longevity, u32::MAX, None);
+			T::DbWeight::get().reads_writes(removed.loops.into(), removed.unique.into())
+		}
+
 		fn offchain_worker(block_number: BlockNumberFor<T>) {
 			if block_number.saturated_into::<u32>() % T::BLOCK_TO_START as u32 != 0 {
 				return;
@@ -867,25 +1180,64 @@ pub mod pallet {
 			);
 			log::info!("🎡 {:?} of 'Activated' clusters found", clusters_ids.len());
 
-			for cluster_id in clusters_ids {
-				let mut errors: Vec<OCWError> = Vec::new();
+			let settings = Self::ocw_settings();
 
-				let validation_result =
-					Self::start_validation_phase(&cluster_id, &verification_account, &signer);
+			// metrics of the run are accumulated in memory and persisted once the run is completed
+			metrics::collect(|| {
+				for cluster_id in clusters_ids {
+					if !settings.is_cluster_enabled(&cluster_id) {
+						log::info!(
+							"⏭️ Skipping cluster {:?} disabled by the node operator",
+							cluster_id
+						);
+						continue;
+					}
 
-				if let Err(errs) = validation_result {
-					errors.extend(errs);
-				}
+					let mut errors: Vec<OCWError> = Vec::new();
+					metrics::record_run(&cluster_id);
 
-				let payouts_result =
-					Self::start_payouts_phase(&cluster_id, &verification_account, &signer);
+					let started_at = sp_io::offchain::timestamp();
+					let validation_result = Self::start_validation_phase(&cluster_id, &signer);
+					metrics::record_phase(
+						&cluster_id,
+						"validation",
+						sp_io::offchain::timestamp().diff(&started_at).millis(),
+					);
 
-				if let Err(errs) = payouts_result {
-					errors.extend(errs);
-				}
+					if let Err(errs) = validation_result {
+						errors.extend(errs);
+					}
 
-				Self::submit_errors(&errors, &verification_account, &signer);
-			}
+					let started_at = sp_io::offchain::timestamp();
+					let payouts_result = Self::start_payouts_phase(&cluster_id, &signer);
+					metrics::record_phase(
+						&cluster_id,
+						"payouts",
+						sp_io::offchain::timestamp().diff(&started_at).millis(),
+					);
+
+					if let Err(errs) = payouts_result {
+						errors.extend(errs);
+					}
+
+					let started_at = sp_io::offchain::timestamp();
+					let nodes_validation_result =
+						Self::start_nodes_validation_phase(&cluster_id, &signer);
+					metrics::record_phase(
+						&cluster_id,
+						"nodes_validation",
+						sp_io::offchain::timestamp().diff(&started_at).millis(),
+					);
+
+					if let Err(errs) = nodes_validation_result {
+						errors.extend(errs);
+					}
+
+					errors.extend(Self::take_aggregator_signature_errors(&cluster_id));
+
+					Self::submit_errors(&cluster_id, &errors, &signer);
+				}
+			});
 
 			// Allow the next invocation of the offchain worker hook to run.
 			local_storage_clear(StorageKind::PERSISTENT, IS_RUNNING_KEY);
@@ -904,7 +1256,6 @@ pub mod pallet {
 			#[allow(clippy::redundant_closure_call)]
 			pub(crate) fn $func_name(
 				cluster_id: &ClusterId,
-				account: &Account<T>,
 				signer: &Signer<T, T::OffchainIdentifierId>,
 			) -> Result<Option<DdcEra>, Vec<OCWError>> {
 				match Self::$prepare_fn(&cluster_id) {
@@ -920,10 +1271,21 @@ pub mod pallet {
 						);
 
 						let call = $call_variant(cluster_id, prepared_data.clone());
-						let result = signer.send_single_signed_transaction(account, call);
+
+						if !Self::is_payout_step_turn(&call) {
+							log::info!(
+								concat!($log_prefix, " Skipping '{}' call for cluster_id: {:?}, era_id: {:?} as another validator is elected to send it"),
+								stringify!($func_name),
+								cluster_id,
+								era_id,
+							);
+							return Ok(Some(era_id));
+						}
+
+						let result = Self::send_validator_call(signer, call);
 
 						match result {
-							Some(Ok(_)) => {
+							Ok(_) => {
 								log::info!(
 									concat!($log_prefix, " Successfully sent '{}' call for cluster_id: {:?}, era_id: {:?}"),
 									stringify!($func_name),
@@ -932,7 +1294,7 @@ pub mod pallet {
 								);
 								Ok(Some(era_id))
 							}
-							_ => {
+							Err(_) => {
 								log::error!(
 									concat!($log_prefix, " Failed to send '{}' call for cluster_id: {:?}, era_id: {:?}"),
 									stringify!($func_name),
@@ -972,7 +1334,12 @@ pub mod pallet {
 
 				let signed_validators = era_validation
 					.validators
-					.entry((DeltaUsageHash::default(), DeltaUsageHash::default()))
+					.entry((
+						DeltaUsageHash::default(),
+						DeltaUsageHash::default(),
+						Default::default(),
+						Default::default(),
+					))
 					.or_insert_with(Vec::new);
 
 				let validators = <ValidatorSet<T>>::get();
@@ -998,6 +1365,30 @@ pub mod pallet {
 				Vec<DeltaUsageHash>,
 			)>,
 			Vec<OCWError>,
+		> {
+			Ok(Self::process_dac_era_with_groups(cluster_id, era_id_to_process)?
+				.map(|(processed_era, _, _)| processed_era))
+		}
+
+		/// Same as [`Self::process_dac_era`], additionally returns the buckets sub-aggregates and
+		/// nodes aggregates consistency groups the era usage is built of.
+		#[allow(clippy::type_complexity)]
+		pub(crate) fn process_dac_era_with_groups(
+			cluster_id: &ClusterId,
+			era_id_to_process: Option<EraActivity>,
+		) -> Result<
+			Option<(
+				(
+					EraActivity,
+					DeltaUsageHash,
+					DeltaUsageHash,
+					Vec<DeltaUsageHash>,
+					Vec<DeltaUsageHash>,
+				),
+				ConsistencyGroupsSummary,
+				ConsistencyGroupsSummary,
+			)>,
+			Vec<OCWError>,
 		> {
 			let batch_size = T::MAX_PAYOUT_BATCH_SIZE;
 
@@ -1038,6 +1429,14 @@ pub mod pallet {
 				Self::fetch_buckets_aggregates_for_era(cluster_id, era_activity.id, &dac_nodes)
 					.map_err(|err| vec![err])?;
 
+			let buckets_aggregates_by_aggregator = Self::filter_buckets_aggregates_by_assignment(
+				cluster_id,
+				era_activity.id,
+				buckets_aggregates_by_aggregator,
+				&dac_nodes,
+				dac_redundancy_factor,
+			);
+
 			let buckets_sub_aggregates_groups = Self::group_buckets_sub_aggregates_by_consistency(
 				cluster_id,
 				era_activity.id,
@@ -1045,6 +1444,13 @@ pub mod pallet {
 				dac_redundancy_factor,
 				aggregators_quorum,
 			);
+			metrics::record_consistency_groups(
+				cluster_id,
+				"buckets",
+				&buckets_sub_aggregates_groups,
+			);
+			let buckets_groups_summary =
+				ConsistencyGroupsSummary::from_groups::<T, _>(&buckets_sub_aggregates_groups);
 
 			let total_buckets_usage = Self::get_total_usage(
 				cluster_id,
@@ -1108,6 +1514,9 @@ pub mod pallet {
 				dac_redundancy_factor,
 				aggregators_quorum,
 			);
+			metrics::record_consistency_groups(cluster_id, "nodes", &nodes_aggregates_groups);
+			let nodes_groups_summary =
+				ConsistencyGroupsSummary::from_groups::<T, _>(&nodes_aggregates_groups);
 
 			let total_nodes_usage =
 				Self::get_total_usage(cluster_id, era_activity.id, nodes_aggregates_groups, true)?;
@@ -1174,17 +1583,86 @@ pub mod pallet {
 				era_activity.id
 			);
 			Ok(Some((
-				era_activity,
-				customers_activity_root,
-				nodes_activity_root,
-				customers_activity_batch_roots,
-				nodes_activity_batch_roots,
+				(
+					era_activity,
+					customers_activity_root,
+					nodes_activity_root,
+					customers_activity_batch_roots,
+					nodes_activity_batch_roots,
+				),
+				buckets_groups_summary,
+				nodes_groups_summary,
 			)))
 		}
 
+		/// Replay the DAC validation of an era without submitting any transaction.
+		///
+		/// The era usage is fetched from the cluster DAC aggregators, the verified delta usage and
+		/// the payable usage are stored in the offchain local storage as during the regular
+		/// validation, so the caller is expected to provide a disposable one.
+		pub fn dry_run_era(
+			cluster_id: &ClusterId,
+			era_id: DdcEra,
+		) -> Result<ValidationDryRun, Vec<OCWError>> {
+			let dac_nodes = Self::get_dac_nodes(cluster_id)
+				.map_err(|_| vec![OCWError::FailedToFetchDacNodes])?;
+
+			// the era boundaries reported by the majority of aggregators are replayed
+			let mut eras_counts: BTreeMap<EraActivity, u32> = BTreeMap::new();
+			for era in Self::fetch_processed_era_for_nodes(cluster_id, &dac_nodes)
+				.map_err(|err| vec![err])?
+				.into_iter()
+				.flatten()
+				.filter(|era| era.id == era_id)
+			{
+				*eras_counts.entry(era).or_default() += 1;
+			}
+
+			let era = match eras_counts.into_iter().max_by_key(|(_, count)| *count) {
+				Some((era, _)) => era,
+				None =>
+					return Err(vec![OCWError::EraRetrievalError {
+						cluster_id: *cluster_id,
+						node_pub_key: None,
+					}]),
+			};
+
+			let (
+				(
+					_,
+					payers_delta_root,
+					payees_delta_root,
+					payers_delta_batch_roots,
+					payees_delta_batch_roots,
+				),
+				buckets_groups,
+				nodes_groups,
+			) = Self::process_dac_era_with_groups(cluster_id, Some(era))?
+				.ok_or_else(|| vec![OCWError::FailedToFetchVerifiedDeltaUsage])?;
+
+			Self::build_and_store_payable_usage(cluster_id, era)?;
+			let payable_usage = Self::fetch_payable_usage(cluster_id, era.id)
+				.ok_or_else(|| vec![OCWError::FailedToFetchVerifiedPayableUsage])?;
+
+			Ok(ValidationDryRun {
+				era,
+				buckets_groups,
+				nodes_groups,
+				payers_delta_root,
+				payees_delta_root,
+				payers_delta_batch_roots,
+				payees_delta_batch_roots,
+				fingerprint: payable_usage.fingerprint(),
+				payers_root: payable_usage.payers_root,
+				payees_root: payable_usage.payees_root,
+				payers_batch_roots: payable_usage.payers_batch_roots,
+				payees_batch_roots: payable_usage.payees_batch_roots,
+				cluster_usage: payable_usage.cluster_usage,
+			})
+		}
+
 		pub(crate) fn start_validation_phase(
 			cluster_id: &ClusterId,
-			verification_account: &Account<T>,
 			signer: &Signer<T, T::OffchainIdentifierId>,
 		) -> Result<(), Vec<OCWError>> {
 			let validation_output = Self::process_dac_era(cluster_id, None)?;
@@ -1206,10 +1684,10 @@ pub mod pallet {
 						payees_batch_merkle_root_hashes: payees_batch_merkle_root_hashes.clone(),
 					};
 
-					let result = signer.send_single_signed_transaction(verification_account, call);
+					let result = Self::send_validator_call(signer, call);
 
 					match result {
-						Some(Ok(_)) => {
+						Ok(_) => {
 							log::info!(
 								"👁️‍🗨️  DAC Validation merkle roots posted on-chain for cluster_id: {:?}, era: {:?}",
 								cluster_id,
@@ -1217,7 +1695,7 @@ pub mod pallet {
 							);
 							Ok(())
 						},
-						_ => Err(vec![OCWError::PrepareEraTransactionError {
+						Err(_) => Err(vec![OCWError::PrepareEraTransactionError {
 							cluster_id: *cluster_id,
 							era_id: era_activity.id,
 							payers_merkle_root_hash,
@@ -1232,46 +1710,147 @@ pub mod pallet {
 			}
 		}
 
+		/// Probe the nodes awaiting validation in the cluster and submit the results, a node is
+		/// validated once the quorum of validators agrees on the outcome.
+		pub(crate) fn start_nodes_validation_phase(
+			cluster_id: &ClusterId,
+			signer: &Signer<T, T::OffchainIdentifierId>,
+		) -> Result<(), Vec<OCWError>> {
+			let validator = Self::fetch_verification_account_id().map_err(|e| vec![e])?;
+			let nodes = T::ClusterManager::get_nodes(cluster_id)
+				.map_err(|_| vec![OCWError::FailedToFetchClusterNodes])?;
+
+			let mut errors = Vec::new();
+			for node_pub_key in nodes {
+				let node_state = match T::ClusterManager::get_node_state(cluster_id, &node_pub_key)
+				{
+					Ok(node_state) => node_state,
+					Err(_) => continue,
+				};
+
+				if node_state.status != ClusterNodeStatus::AwaitsValidation ||
+					Self::has_submitted_node_probe_result(
+						cluster_id,
+						&node_pub_key,
+						node_state.added_at,
+						&validator,
+					) {
+					continue;
+				}
+
+				let node_params = match T::NodeManager::get_node_params(&node_pub_key) {
+					Ok(NodeParams::StorageParams(node_params)) => node_params,
+					Err(_) => continue,
+				};
+
+				let result = Self::probe_node(&node_pub_key, &node_params);
+				log::info!(
+					"🩺 Probed node {:?} awaiting validation in cluster_id: {:?}, result: {:?}",
+					node_pub_key.get_hex(),
+					cluster_id,
+					result
+				);
+
+				let call = Call::submit_node_validation {
+					cluster_id: *cluster_id,
+					node_pub_key: node_pub_key.clone(),
+					result,
+				};
+				if Self::send_validator_call(signer, call).is_err() {
+					errors.push(OCWError::SubmitNodeValidationTransactionError {
+						cluster_id: *cluster_id,
+						node_pub_key,
+					});
+				}
+			}
+
+			if errors.is_empty() {
+				Ok(())
+			} else {
+				Err(errors)
+			}
+		}
+
+		/// Whether the validator has already submitted the probe result for the current
+		/// membership of the node in the cluster.
+		pub(crate) fn has_submitted_node_probe_result(
+			cluster_id: &ClusterId,
+			node_pub_key: &NodePubKey,
+			added_at: BlockNumberFor<T>,
+			validator: &T::AccountId,
+		) -> bool {
+			<NodeValidations<T>>::get(cluster_id, node_pub_key).is_some_and(|node_validation| {
+				node_validation.added_at == added_at &&
+					node_validation.results.iter().any(|(submitted, _)| submitted == validator)
+			})
+		}
+
+		/// Probe the HTTP endpoint declared by the node, check the gRPC endpoint with the gRPC
+		/// health check and ask the node to sign a random nonce with its node key, if it serves
+		/// the handshake.
+		pub(crate) fn probe_node(
+			node_pub_key: &NodePubKey,
+			node_params: &StorageNodeParams,
+		) -> NodeProbeResult {
+			let host = match str::from_utf8(&node_params.host) {
+				Ok(host) => host,
+				Err(_) => return NodeProbeResult::default(),
+			};
+
+			let settings = Self::ocw_settings();
+			let http_url = format!("http://{}:{}", host, node_params.http_port);
+			let grpc_url = format!("http://{}:{}", host, node_params.grpc_port);
+			let http_client = Self::aggregator_client(&http_url, node_pub_key, &settings);
+			let grpc_client = Self::aggregator_client(&grpc_url, node_pub_key, &settings);
+
+			let nonce = sp_io::offchain::random_seed();
+
+			NodeProbeResult {
+				http_reachable: http_client.is_reachable(),
+				grpc_reachable: grpc_client.is_grpc_serving(),
+				handshake_signed: http_client.handshake(&nonce).is_ok(),
+			}
+		}
+
 		pub(crate) fn start_payouts_phase(
 			cluster_id: &ClusterId,
-			account: &Account<T>,
 			signer: &Signer<T, T::OffchainIdentifierId>,
 		) -> Result<(), Vec<OCWError>> {
 			let mut errors: Vec<OCWError> = Vec::new();
 
-			if let Err(errs) = Self::step_commit_billing_fingerprint(cluster_id, account, signer) {
+			if let Err(errs) = Self::step_commit_billing_fingerprint(cluster_id, signer) {
 				errors.extend(errs);
 			}
 
-			if let Err(errs) = Self::step_begin_billing_report(cluster_id, account, signer) {
+			if let Err(errs) = Self::step_begin_billing_report(cluster_id, signer) {
 				errors.extend(errs);
 			}
 
-			if let Err(errs) = Self::step_begin_charging_customers(cluster_id, account, signer) {
+			if let Err(errs) = Self::step_begin_charging_customers(cluster_id, signer) {
 				errors.extend(errs);
 			}
 
-			if let Err(errs) = Self::step_send_charging_customers(cluster_id, account, signer) {
+			if let Err(errs) = Self::step_send_charging_customers(cluster_id, signer) {
 				errors.extend(errs);
 			}
 
-			if let Err(errs) = Self::step_end_charging_customers(cluster_id, account, signer) {
+			if let Err(errs) = Self::step_end_charging_customers(cluster_id, signer) {
 				errors.extend(errs);
 			}
 
-			if let Err(errs) = Self::step_begin_rewarding_providers(cluster_id, account, signer) {
+			if let Err(errs) = Self::step_begin_rewarding_providers(cluster_id, signer) {
 				errors.extend(errs);
 			}
 
-			if let Err(errs) = Self::step_send_rewarding_providers(cluster_id, account, signer) {
+			if let Err(errs) = Self::step_send_rewarding_providers(cluster_id, signer) {
 				errors.extend(errs);
 			}
 
-			if let Err(errs) = Self::step_end_rewarding_providers(cluster_id, account, signer) {
+			if let Err(errs) = Self::step_end_rewarding_providers(cluster_id, signer) {
 				errors.extend(errs);
 			}
 
-			match Self::step_end_billing_report(cluster_id, account, signer) {
+			match Self::step_end_billing_report(cluster_id, signer) {
 				Ok(Some(era_id)) => {
 					Self::clear_verified_delta_usage(cluster_id, era_id);
 				},
@@ -1451,15 +2030,22 @@ pub mod pallet {
 		);
 
 		pub(crate) fn submit_errors(
+			cluster_id: &ClusterId,
 			errors: &Vec<OCWError>,
-			verification_account: &Account<T>,
 			signer: &Signer<T, T::OffchainIdentifierId>,
 		) {
-			if !errors.is_empty() {
-				let call = Call::emit_consensus_errors { errors: errors.to_owned() };
-				let result = signer.send_single_signed_transaction(verification_account, call);
-
-				if let Some(Ok(_)) = result {
+			// the errors are reported once per era, the transaction would be rejected
+			let is_reported = Self::fetch_verification_account_id().map_or(true, |validator| {
+				!Self::can_report_consensus_errors(cluster_id, &validator)
+			});
+			if !errors.is_empty() && !is_reported {
+				let errors = BoundedVec::truncate_from(errors.to_owned());
+				let call =
+					Call::emit_consensus_errors { cluster_id: *cluster_id, errors: errors.clone() };
+				let result = Self::send_validator_call(signer, call);
+
+				if result.is_ok() {
+					metrics::record_submitted_errors(&errors);
 					log::info!("✔️ Successfully sent 'emit_consensus_errors' call");
 				} else {
 					log::error!("❌ Failed to send 'emit_consensus_errors' call");
@@ -1467,6 +2053,58 @@ pub mod pallet {
 			}
 		}
 
+		/// Submit a call of the validator as an unsigned transaction with the payload signed by
+		/// its verification key, so validators do not pay fees for the DAC validation.
+		pub(crate) fn send_validator_call(
+			signer: &Signer<T, T::OffchainIdentifierId>,
+			call: Call<T>,
+		) -> Result<(), ()> {
+			let block_number = <frame_system::Pallet<T>>::block_number();
+
+			signer
+				.send_unsigned_transaction(
+					|account| ValidatorCallPayload {
+						public: account.public.clone(),
+						block_number,
+						call: Box::new(call.clone()),
+					},
+					|payload, signature| Call::submit_validator_call { payload, signature },
+				)
+				.ok_or(())
+				.and_then(|(_, result)| result)
+		}
+
+		/// Whether this validator sends the call now. Every validator sends its votes, while a
+		/// payout step is sent by the elected validator, or by the next ones if the step remains
+		/// pending.
+		pub(crate) fn is_payout_step_turn(call: &Call<T>) -> bool {
+			let step = match Self::payout_step(call) {
+				Some(step) => step,
+				None => return true,
+			};
+
+			let validator = match Self::fetch_verification_account_id() {
+				Ok(validator) => validator,
+				Err(_) => return false,
+			};
+
+			// the block the validator has seen the step pending first at
+			let key = Self::derive_pending_payout_step_key(&step.cluster_id);
+			let step_id = step.encode();
+			let now = <frame_system::Pallet<T>>::block_number();
+			let pending_since = match local_storage_get(StorageKind::PERSISTENT, &key)
+				.and_then(|data| <(Vec<u8>, BlockNumberFor<T>)>::decode(&mut &data[..]).ok())
+			{
+				Some((pending_step_id, since)) if pending_step_id == step_id => since,
+				_ => {
+					local_storage_set(StorageKind::PERSISTENT, &key, &(step_id, now).encode());
+					now
+				},
+			};
+
+			Self::is_payout_step_leader(&step, &validator, now.saturating_sub(pending_since))
+		}
+
 		pub(crate) fn get_total_usage<A: Aggregate>(
 			cluster_id: &ClusterId,
 			era_id: DdcEra,
@@ -1572,11 +2210,13 @@ pub mod pallet {
 					// let is_passed = Self::_challenge_aggregate(_cluster_id, _era_id,
 					// &defective_aggregate)?;
 					if should_challenge {
-						is_passed = Self::challenge_aggregate_proto(
+						let result = Self::challenge_aggregate_proto(
 							_cluster_id,
 							_era_id,
 							&defective_aggregate,
-						)?;
+						);
+						metrics::record_challenge(_cluster_id, matches!(result, Ok(true)));
+						is_passed = result?;
 					}
 					if is_passed {
 						// we assume all aggregates are valid at the moment, so we just take the
@@ -1649,15 +2289,19 @@ pub mod pallet {
 				aggregate_key.clone(),
 				1,
 				1,
+				&aggregator.node_pub_key,
 				&aggregator.node_params,
 			)
-			.map_err(|_| {
-				vec![OCWError::TraverseResponseRetrievalError {
-					cluster_id: *cluster_id,
-					era_id,
-					aggregate_key: aggregate_key.clone(),
-					aggregator: aggregator.node_pub_key,
-				}]
+			.map_err(|err| match err {
+				aggregator_client::AggregatorClientError::InvalidSignature =>
+					vec![Self::aggregator_signature_error(cluster_id, &aggregator.node_pub_key)],
+				aggregator_client::AggregatorClientError::Http(_) =>
+					vec![OCWError::TraverseResponseRetrievalError {
+						cluster_id: *cluster_id,
+						era_id,
+						aggregate_key: aggregate_key.clone(),
+						aggregator: aggregator.node_pub_key.clone(),
+					}],
 			})?;
 
 			let mut merkle_root_buf = [0u8; _BUF_SIZE];
@@ -2166,7 +2810,8 @@ pub mod pallet {
 				Self::get_era_for_payout(cluster_id, EraValidationStatus::PayoutInProgress)
 			{
 				if T::PayoutProcessor::get_billing_report_status(cluster_id, era.id) ==
-					PayoutState::Initialized
+					PayoutState::Initialized &&
+					!T::PayoutProcessor::is_dispute_window_open(cluster_id, era.id)
 				{
 					let era_payable_usage = Self::fetch_payable_usage_or_retry(cluster_id, era)?;
 					Self::fetch_charging_loop_input(
@@ -2488,10 +3133,83 @@ pub mod pallet {
 			format!("offchain::activities::{:?}::{:?}", cluster_id, era_id).into_bytes()
 		}
 
+		pub(crate) fn derive_pending_payout_step_key(cluster_id: &ClusterId) -> Vec<u8> {
+			format!("offchain::pending_payout_step::{:?}", cluster_id).into_bytes()
+		}
+
 		pub(crate) fn derive_paybale_usage_key(cluster_id: &ClusterId, era_id: DdcEra) -> Vec<u8> {
 			format!("offchain::paybale_usage::{:?}::{:?}", cluster_id, era_id).into_bytes()
 		}
 
+		pub(crate) fn derive_aggregator_signature_failures_key(aggregator: &NodePubKey) -> Vec<u8> {
+			format!("offchain::aggregator_signature_failures::{}", aggregator.get_hex())
+				.into_bytes()
+		}
+
+		pub(crate) fn derive_aggregator_signature_errors_key(cluster_id: &ClusterId) -> Vec<u8> {
+			format!("offchain::aggregator_signature_errors::{:?}", cluster_id).into_bytes()
+		}
+
+		/// Number of responses with a missing or invalid signature received from the aggregator.
+		pub(crate) fn get_aggregator_signature_failures(aggregator: &NodePubKey) -> u32 {
+			let key = Self::derive_aggregator_signature_failures_key(aggregator);
+			local_storage_get(StorageKind::PERSISTENT, &key)
+				.and_then(|data| u32::decode(&mut &data[..]).ok())
+				.unwrap_or_default()
+		}
+
+		/// Counts a response with a missing or invalid signature against the aggregator and
+		/// returns the error to report.
+		pub(crate) fn aggregator_signature_error(
+			cluster_id: &ClusterId,
+			aggregator: &NodePubKey,
+		) -> OCWError {
+			let failures = Self::get_aggregator_signature_failures(aggregator).saturating_add(1);
+			let key = Self::derive_aggregator_signature_failures_key(aggregator);
+			local_storage_set(StorageKind::PERSISTENT, &key, &failures.encode());
+
+			log::warn!(
+				"🚨 Aggregator {:?} from cluster {:?} responded with invalid signature, failures: {:?}",
+				aggregator.get_hex(),
+				cluster_id,
+				failures,
+			);
+
+			OCWError::AggregatorResponseSignatureError {
+				cluster_id: *cluster_id,
+				aggregator: aggregator.clone(),
+				failures,
+			}
+		}
+
+		/// Counts a response with a missing or invalid signature against the aggregator and
+		/// queues the error to be submitted with other errors of the cluster. Used where an
+		/// aggregator failure is skipped instead of failing the whole phase.
+		pub(crate) fn report_aggregator_signature_error(
+			cluster_id: &ClusterId,
+			aggregator: &NodePubKey,
+		) {
+			let error = Self::aggregator_signature_error(cluster_id, aggregator);
+
+			let key = Self::derive_aggregator_signature_errors_key(cluster_id);
+			let mut errors: Vec<OCWError> = local_storage_get(StorageKind::PERSISTENT, &key)
+				.and_then(|data| Decode::decode(&mut &data[..]).ok())
+				.unwrap_or_default();
+			errors.push(error);
+			local_storage_set(StorageKind::PERSISTENT, &key, &errors.encode());
+		}
+
+		/// Takes the queued aggregator signature errors of the cluster.
+		pub(crate) fn take_aggregator_signature_errors(cluster_id: &ClusterId) -> Vec<OCWError> {
+			let key = Self::derive_aggregator_signature_errors_key(cluster_id);
+			let errors = local_storage_get(StorageKind::PERSISTENT, &key)
+				.and_then(|data| Decode::decode(&mut &data[..]).ok())
+				.unwrap_or_default();
+			local_storage_clear(StorageKind::PERSISTENT, &key);
+
+			errors
+		}
+
 		pub(crate) fn collect_verification_pub_key() -> Result<Account<T>, OCWError> {
 			let session_verification_keys = <T::OffchainIdentifierId as AppCrypto<
 				T::Public,
@@ -2518,7 +3236,7 @@ pub mod pallet {
 
 			if session_verification_keys.len() != 1 {
 				log::error!(
-					"🚨 Unexpected number of session verification keys is found. Expected: 1, Actual: {:?}",
+					"🚨 Unexpected number of session verification keys is found. Expected: 1, Actual: {:?}. Check the keys with the `ddcVerification_keyStatus` RPC",
 					session_verification_keys.len()
 				);
 				return Err(OCWError::FailedToCollectVerificationKey);
@@ -2530,13 +3248,62 @@ pub mod pallet {
 				.ok_or(OCWError::FailedToCollectVerificationKey)
 		}
 
-		pub(crate) fn store_verification_account_id(account_id: T::AccountId) {
-			let validator: Vec<u8> = account_id.encode();
-			let key = format!("offchain::validator::{:?}", DAC_VERIFICATION_KEY_TYPE).into_bytes();
-			local_storage_set(StorageKind::PERSISTENT, &key, &validator);
+		/// Binds the verification key set with the session keys of a validator to its stash.
+		fn bind_session_key(stash: &T::AccountId, key: T::AuthorityId) -> T::AccountId {
+			let key = T::AccountId::decode(&mut &key.into().encode()[..]).unwrap();
+			if Self::bind_validator_key(stash, &key) {
+				Self::deposit_event(Event::<T>::ValidatorKeySet { validator: key.clone() });
+			}
+
+			key
 		}
 
-		pub(crate) fn fetch_verification_account_id() -> Result<T::AccountId, OCWError> {
+		/// Binds a verification key to a validator stash, returns `false` if the key is already
+		/// bound to the stash. The replaced key of the stash stays bound to it.
+		pub(crate) fn bind_validator_key(stash: &T::AccountId, key: &T::AccountId) -> bool {
+			let previous = StashToValidatorKey::<T>::get(stash);
+			if previous.as_ref() == Some(key) &&
+				ValidatorToStashKey::<T>::get(key).as_ref() == Some(stash)
+			{
+				return false;
+			}
+
+			ValidatorToStashKey::<T>::insert(key, stash);
+			StashToValidatorKey::<T>::insert(stash, key);
+
+			if let Some(previous) = previous.filter(|previous| previous != key) {
+				Self::deposit_event(Event::<T>::ValidatorKeyRotated {
+					stash: stash.clone(),
+					previous,
+					validator: key.clone(),
+				});
+			}
+
+			true
+		}
+
+		/// Status of a verification key, used by the node to report the readiness of its
+		/// offchain worker.
+		pub fn verification_key_status(key: &T::AccountId) -> VerificationKeyStatus<T::AccountId> {
+			let stash = ValidatorToStashKey::<T>::get(key);
+			let is_latest = stash
+				.as_ref()
+				.is_some_and(|stash| StashToValidatorKey::<T>::get(stash).as_ref() == Some(key));
+
+			VerificationKeyStatus {
+				in_validator_set: <ValidatorSet<T>>::get().contains(key),
+				stash,
+				is_latest,
+			}
+		}
+
+		pub(crate) fn store_verification_account_id(account_id: T::AccountId) {
+			let validator: Vec<u8> = account_id.encode();
+			let key = format!("offchain::validator::{:?}", DAC_VERIFICATION_KEY_TYPE).into_bytes();
+			local_storage_set(StorageKind::PERSISTENT, &key, &validator);
+		}
+
+		pub(crate) fn fetch_verification_account_id() -> Result<T::AccountId, OCWError> {
 			let key = format!("offchain::validator::{:?}", DAC_VERIFICATION_KEY_TYPE).into_bytes();
 
 			match local_storage_get(StorageKind::PERSISTENT, &key) {
@@ -2902,6 +3669,21 @@ pub mod pallet {
 			Ok(max_era)
 		}
 
+		/// Whether an era is being validated and the validator has not voted for it yet.
+		pub(crate) fn is_era_pending_validation(
+			cluster_id: &ClusterId,
+			era_id: DdcEra,
+			validator: &T::AccountId,
+		) -> bool {
+			<EraValidations<T>>::get(cluster_id, era_id).is_some_and(|validation| {
+				validation.status == EraValidationStatus::ValidatingData &&
+					!validation
+						.validators
+						.values()
+						.any(|validators| validators.contains(validator))
+			})
+		}
+
 		/// Fetch current era across all DAC nodes to validate.
 		///
 		/// Parameters:
@@ -2914,7 +3696,7 @@ pub mod pallet {
 			let this_validator = Self::fetch_verification_account_id()?;
 
 			let last_validated_era_by_this_validator =
-				Self::get_last_paid_era(cluster_id, this_validator)?
+				Self::get_last_paid_era(cluster_id, this_validator.clone())?
 					.unwrap_or_else(DdcEra::default);
 
 			let last_paid_era_for_cluster =
@@ -2934,14 +3716,19 @@ pub mod pallet {
 				Self::fetch_processed_era_for_nodes(cluster_id, dac_nodes)?;
 
 			// we want to let the current validator to validate available processed/completed eras
-			// that are greater than the last validated era in the cluster
+			// that are greater than the last validated era in the cluster, and the eras re-queued
+			// for validation it has not voted for yet
 			let processed_eras_to_validate: Vec<EraActivity> = available_processed_eras
 				.iter()
 				.flat_map(|eras| {
 					eras.iter()
 						.filter(|&ids| {
-							ids.id > last_validated_era_by_this_validator &&
-								ids.id > last_paid_era_for_cluster
+							(ids.id > last_validated_era_by_this_validator ||
+								Self::is_era_pending_validation(
+									cluster_id,
+									ids.id,
+									&this_validator,
+								)) && ids.id > last_paid_era_for_cluster
 						})
 						.cloned()
 				})
@@ -3105,13 +3892,19 @@ pub mod pallet {
 				era_id,
 				aggregate_key.clone(),
 				merkle_node_identifiers.clone(),
+				&aggregator.node_pub_key,
 				&aggregator.node_params,
 			)
-			.map_err(|_| OCWError::ChallengeResponseRetrievalError {
-				cluster_id: *cluster_id,
-				era_id,
-				aggregate_key,
-				aggregator: aggregator.node_pub_key,
+			.map_err(|err| match err {
+				aggregator_client::AggregatorClientError::InvalidSignature =>
+					Self::aggregator_signature_error(cluster_id, &aggregator.node_pub_key),
+				aggregator_client::AggregatorClientError::Http(_) =>
+					OCWError::ChallengeResponseRetrievalError {
+						cluster_id: *cluster_id,
+						era_id,
+						aggregate_key,
+						aggregator: aggregator.node_pub_key.clone(),
+					},
 			})?;
 
 			Ok(response)
@@ -3129,13 +3922,19 @@ pub mod pallet {
 				era_id,
 				aggregate_key.clone(),
 				merkle_tree_node_id.clone(),
+				&aggregator.node_pub_key,
 				&aggregator.node_params,
 			)
-			.map_err(|_| OCWError::ChallengeResponseRetrievalError {
-				cluster_id: *cluster_id,
-				era_id,
-				aggregate_key,
-				aggregator: aggregator.node_pub_key,
+			.map_err(|err| match err {
+				aggregator_client::AggregatorClientError::InvalidSignature =>
+					Self::aggregator_signature_error(cluster_id, &aggregator.node_pub_key),
+				aggregator_client::AggregatorClientError::Http(_) =>
+					OCWError::ChallengeResponseRetrievalError {
+						cluster_id: *cluster_id,
+						era_id,
+						aggregate_key,
+						aggregator: aggregator.node_pub_key.clone(),
+					},
 			})?;
 
 			Ok(response)
@@ -3147,46 +3946,33 @@ pub mod pallet {
 		/// - `era_id`: era id
 		/// - `aggregate_key`: key of the aggregate to challenge
 		/// - `merkle_node_identifiers`: set of merkle node identifiers to challenge
+		/// - `node_pub_key`: aggregator node key the response must be signed with
 		/// - `node_params`: aggregator node parameters
 		pub(crate) fn _fetch_challenge_response(
 			era_id: DdcEra,
 			aggregate_key: AggregateKey,
 			merkle_node_identifiers: Vec<u64>,
+			node_pub_key: &NodePubKey,
 			node_params: &StorageNodeParams,
-		) -> Result<aggregator_client::json::ChallengeAggregateResponse, http::Error> {
-			let scheme = "http";
-			let host = str::from_utf8(&node_params.host).map_err(|_| http::Error::Unknown)?;
+		) -> Result<
+			aggregator_client::json::ChallengeAggregateResponse,
+			aggregator_client::AggregatorClientError,
+		> {
+			let settings = Self::ocw_settings();
+			let base_url = Self::aggregator_base_url(node_pub_key, node_params, &settings)?;
+			let client = Self::aggregator_client(&base_url, node_pub_key, &settings);
 
-			let ids = merkle_node_identifiers
-				.iter()
-				.map(|x| format!("{}", x.clone()))
-				.collect::<Vec<_>>()
-				.join(",");
-
-			let url = match aggregate_key {
-                AggregateKey::NodeAggregateKey(node_id) => format!(
-                    "{}://{}:{}/activity/nodes/{}/challenge?eraId={}&merkleTreeNodeId={}",
-                    scheme, host, node_params.http_port, node_id, era_id, ids
-                ),
-                AggregateKey::BucketSubAggregateKey(bucket_id, node_id) => format!(
-                    "{}://{}:{}/activity/buckets/{}/challenge?eraId={}&nodeId={}&merkleTreeNodeId={}",
-                    scheme, host, node_params.http_port, bucket_id, era_id, node_id, ids
-                ),
-            };
-
-			let request = http::Request::get(&url);
-			let timeout = sp_io::offchain::timestamp()
-				.add(sp_runtime::offchain::Duration::from_millis(RESPONSE_TIMEOUT));
-			let pending = request.deadline(timeout).send().map_err(|_| http::Error::IoError)?;
-
-			let response =
-				pending.try_wait(timeout).map_err(|_| http::Error::DeadlineReached)??;
-			if response.code != _SUCCESS_CODE {
-				return Err(http::Error::Unknown);
+			match aggregate_key {
+				AggregateKey::BucketSubAggregateKey(bucket_id, node_id) => client
+					.challenge_bucket_sub_aggregate_json(
+						era_id,
+						bucket_id,
+						&node_id,
+						merkle_node_identifiers,
+					),
+				AggregateKey::NodeAggregateKey(node_id) =>
+					client.challenge_node_aggregate_json(era_id, &node_id, merkle_node_identifiers),
 			}
-
-			let body = response.body().collect::<Vec<u8>>();
-			serde_json::from_slice(&body).map_err(|_| http::Error::Unknown)
 		}
 
 		/// Fetch protobuf challenge response.
@@ -3194,16 +3980,12 @@ pub mod pallet {
 			era_id: DdcEra,
 			aggregate_key: AggregateKey,
 			merkle_tree_node_id: Vec<u32>,
+			node_pub_key: &NodePubKey,
 			node_params: &StorageNodeParams,
-		) -> Result<proto::ChallengeResponse, http::Error> {
-			let host = str::from_utf8(&node_params.host).map_err(|_| http::Error::Unknown)?;
-			let base_url = format!("http://{}:{}", host, node_params.http_port);
-			let client = aggregator_client::AggregatorClient::new(
-				&base_url,
-				Duration::from_millis(RESPONSE_TIMEOUT),
-				3,
-				T::VERIFY_AGGREGATOR_RESPONSE_SIGNATURE,
-			);
+		) -> Result<proto::ChallengeResponse, aggregator_client::AggregatorClientError> {
+			let settings = Self::ocw_settings();
+			let base_url = Self::aggregator_base_url(node_pub_key, node_params, &settings)?;
+			let client = Self::aggregator_client(&base_url, node_pub_key, &settings);
 
 			match aggregate_key {
 				AggregateKey::BucketSubAggregateKey(bucket_id, node_id) => client
@@ -3225,22 +4007,22 @@ pub mod pallet {
 		/// - `aggregate_key`: key of the aggregate to challenge
 		/// - `merkle_node_identifiers`: set of merkle node identifiers to challenge
 		/// - `levels`: a number of levels to raverse
+		/// - `node_pub_key`: aggregator node key the response must be signed with
 		/// - `node_params`: aggregator node parameters
 		pub(crate) fn _fetch_traverse_response(
 			era_id: DdcEra,
 			aggregate_key: AggregateKey,
 			merkle_tree_node_id: u32,
 			levels: u16,
+			node_pub_key: &NodePubKey,
 			node_params: &StorageNodeParams,
-		) -> Result<aggregator_client::json::MerkleTreeNodeResponse, http::Error> {
-			let host = str::from_utf8(&node_params.host).map_err(|_| http::Error::Unknown)?;
-			let base_url = format!("http://{}:{}", host, node_params.http_port);
-			let client = aggregator_client::AggregatorClient::new(
-				&base_url,
-				Duration::from_millis(RESPONSE_TIMEOUT),
-				3,
-				T::VERIFY_AGGREGATOR_RESPONSE_SIGNATURE,
-			);
+		) -> Result<
+			aggregator_client::json::MerkleTreeNodeResponse,
+			aggregator_client::AggregatorClientError,
+		> {
+			let settings = Self::ocw_settings();
+			let base_url = Self::aggregator_base_url(node_pub_key, node_params, &settings)?;
+			let client = Self::aggregator_client(&base_url, node_pub_key, &settings);
 
 			let response = match aggregate_key {
 				AggregateKey::BucketSubAggregateKey(bucket_id, node_id) => client
@@ -3261,108 +4043,58 @@ pub mod pallet {
 		/// Fetch processed era.
 		///
 		/// Parameters:
+		/// - `node_pub_key`: DAC node key the response must be signed with
 		/// - `node_params`: DAC node parameters
 		#[allow(dead_code)]
 		pub(crate) fn fetch_processed_eras(
+			node_pub_key: &NodePubKey,
 			node_params: &StorageNodeParams,
-		) -> Result<Vec<aggregator_client::json::AggregationEraResponse>, http::Error> {
-			let host = str::from_utf8(&node_params.host).map_err(|_| http::Error::Unknown)?;
-			let base_url = format!("http://{}:{}", host, node_params.http_port);
-			let client = aggregator_client::AggregatorClient::new(
-				&base_url,
-				Duration::from_millis(RESPONSE_TIMEOUT),
-				3,
-				T::VERIFY_AGGREGATOR_RESPONSE_SIGNATURE,
-			);
+		) -> Result<
+			Vec<aggregator_client::json::AggregationEraResponse>,
+			aggregator_client::AggregatorClientError,
+		> {
+			let settings = Self::ocw_settings();
+			let base_url = Self::aggregator_base_url(node_pub_key, node_params, &settings)?;
+			let client = Self::aggregator_client(&base_url, node_pub_key, &settings);
 
 			let response = client.eras()?;
 
 			Ok(response.into_iter().filter(|e| e.status == "PROCESSED").collect::<Vec<_>>())
 		}
-		/// Fetch customer usage.
-		///
-		/// Parameters:
-		/// - `cluster_id`: cluster id of a cluster
-		/// - `era_id`: era id
-		/// - `node_params`: DAC node parameters
-		pub(crate) fn fetch_bucket_aggregates(
-			_cluster_id: &ClusterId,
-			era_id: DdcEra,
-			node_params: &StorageNodeParams,
-		) -> Result<Vec<aggregator_client::json::BucketAggregateResponse>, http::Error> {
-			let host = str::from_utf8(&node_params.host).map_err(|_| http::Error::Unknown)?;
-			let base_url = format!("http://{}:{}", host, node_params.http_port);
-			let client = aggregator_client::AggregatorClient::new(
-				&base_url,
-				Duration::from_millis(RESPONSE_TIMEOUT),
-				3,
-				T::VERIFY_AGGREGATOR_RESPONSE_SIGNATURE,
-			);
-
-			let mut buckets_aggregates = Vec::new();
-			let mut prev_token = None;
-
-			loop {
-				let response = client.buckets_aggregates(
-					era_id,
-					Some(BUCKETS_AGGREGATES_FETCH_BATCH_SIZE as u32),
-					prev_token,
-				)?;
-
-				let response_len = response.len();
-				prev_token = response.last().map(|a| a.bucket_id);
-
-				buckets_aggregates.extend(response);
+		/// Offchain worker settings written by the node operator to the offchain local storage.
+		pub(crate) fn ocw_settings() -> OcwSettings {
+			local_storage_get(StorageKind::PERSISTENT, OCW_SETTINGS_KEY)
+				.and_then(|encoded| OcwSettings::decode(&mut &encoded[..]).ok())
+				.unwrap_or_default()
+		}
 
-				if response_len < BUCKETS_AGGREGATES_FETCH_BATCH_SIZE {
-					break;
-				}
+		/// Base URL of a DAC node, either overridden by the node operator or the registered one.
+		fn aggregator_base_url(
+			node_pub_key: &NodePubKey,
+			node_params: &StorageNodeParams,
+			settings: &OcwSettings,
+		) -> Result<String, http::Error> {
+			if let Some(url) = settings.endpoint_override(node_pub_key) {
+				let url = str::from_utf8(url).map_err(|_| http::Error::Unknown)?;
+				return Ok(url.trim_end_matches('/').into());
 			}
 
-			Ok(buckets_aggregates)
+			let host = str::from_utf8(&node_params.host).map_err(|_| http::Error::Unknown)?;
+			Ok(format!("http://{}:{}", host, node_params.http_port))
 		}
 
-		/// Fetch node usage.
-		///
-		/// Parameters:
-		/// - `cluster_id`: cluster id of a cluster
-		/// - `era_id`: era id
-		/// - `node_params`: DAC node parameters
-		pub(crate) fn fetch_node_aggregates(
-			_cluster_id: &ClusterId,
-			era_id: DdcEra,
-			node_params: &StorageNodeParams,
-		) -> Result<Vec<aggregator_client::json::NodeAggregateResponse>, http::Error> {
-			let host = str::from_utf8(&node_params.host).map_err(|_| http::Error::Unknown)?;
-			let base_url = format!("http://{}:{}", host, node_params.http_port);
-			let client = aggregator_client::AggregatorClient::new(
-				&base_url,
-				Duration::from_millis(RESPONSE_TIMEOUT),
-				3,
+		fn aggregator_client<'a>(
+			base_url: &'a str,
+			node_pub_key: &'a NodePubKey,
+			settings: &OcwSettings,
+		) -> aggregator_client::AggregatorClient<'a> {
+			aggregator_client::AggregatorClient::new(
+				base_url,
+				Duration::from_millis(settings.response_timeout_ms.unwrap_or(RESPONSE_TIMEOUT)),
+				settings.retries.unwrap_or(RESPONSE_RETRIES),
+				node_pub_key,
 				T::VERIFY_AGGREGATOR_RESPONSE_SIGNATURE,
-			);
-
-			let mut nodes_aggregates = Vec::new();
-			let mut prev_token = None;
-
-			loop {
-				let response = client.nodes_aggregates(
-					era_id,
-					Some(NODES_AGGREGATES_FETCH_BATCH_SIZE as u32),
-					prev_token,
-				)?;
-
-				let response_len = response.len();
-				prev_token = response.last().map(|a| a.node_id.clone());
-
-				nodes_aggregates.extend(response);
-
-				if response_len < NODES_AGGREGATES_FETCH_BATCH_SIZE {
-					break;
-				}
-			}
-
-			Ok(nodes_aggregates)
+			)
 		}
 
 		/// Fetch DAC nodes of a cluster.
@@ -3376,19 +4108,46 @@ pub mod pallet {
 			let nodes = T::ClusterManager::get_nodes(cluster_id)
 				.map_err(|_| Error::<T>::NodeRetrievalError)?;
 
+			// Dedicated aggregators registered by the cluster manager, if any, restrict the
+			// selection further
+			let aggregators = T::ClusterManager::get_aggregators(cluster_id)
+				.map_err(|_| Error::<T>::NodeRetrievalError)?;
+
 			// Iterate over each node
 			for node_pub_key in nodes {
+				if !aggregators.is_empty() && !aggregators.contains(&node_pub_key) {
+					continue;
+				}
+
+				// Only nodes that passed validation in the cluster can act as aggregators
+				if !T::ClusterManager::contains_node(
+					cluster_id,
+					&node_pub_key,
+					Some(ClusterNodeStatus::ValidationSucceeded),
+				) {
+					log::info!(
+						"🏭 Skipping not validated node for cluster_id: {:?} and with key: {:?}",
+						cluster_id,
+						node_pub_key.get_hex()
+					);
+					continue;
+				}
+
 				// Get the node parameters
 				if let Ok(NodeParams::StorageParams(storage_params)) =
 					T::NodeManager::get_node_params(&node_pub_key)
 				{
+					// Add to the results if the mode matches
+					if storage_params.mode != StorageNodeMode::DAC {
+						continue;
+					}
+
 					log::info!(
 						"🏭 Obtained DAC Node for cluster_id: {:?} and with key: {:?}",
 						cluster_id,
 						node_pub_key.get_hex()
 					);
 
-					// Add to the results if the mode matches
 					dac_nodes.push((node_pub_key, storage_params));
 				}
 			}
@@ -3396,6 +4155,93 @@ pub mod pallet {
 			Ok(dac_nodes)
 		}
 
+		/// Deterministically assign aggregators to a bucket.
+		///
+		/// Every DAC node is ranked by the hash of `(cluster_id, bucket_id, node_pub_key)` and the
+		/// first `redundancy_factor` nodes are responsible for the bucket, so any validator can
+		/// recompute the assignment from the cluster state alone.
+		///
+		/// Parameters:
+		/// - `cluster_id`: cluster id of a cluster
+		/// - `bucket_id`: bucket id
+		/// - `dac_nodes`: DAC nodes of the cluster
+		/// - `redundancy_factor`: number of aggregators per bucket
+		pub(crate) fn get_bucket_aggregators(
+			cluster_id: &ClusterId,
+			bucket_id: BucketId,
+			dac_nodes: &[(NodePubKey, StorageNodeParams)],
+			redundancy_factor: u16,
+		) -> Vec<NodePubKey> {
+			let mut ranked_nodes: Vec<([u8; 32], NodePubKey)> = dac_nodes
+				.iter()
+				.map(|(node_pub_key, _)| {
+					(
+						blake2_256(&(cluster_id, bucket_id, node_pub_key).encode()),
+						node_pub_key.clone(),
+					)
+				})
+				.collect();
+			ranked_nodes.sort();
+
+			ranked_nodes
+				.into_iter()
+				.take(redundancy_factor.into())
+				.map(|(_, node_pub_key)| node_pub_key)
+				.collect()
+		}
+
+		/// Drop bucket aggregates reported by aggregators that are not assigned to the bucket.
+		///
+		/// Parameters:
+		/// - `cluster_id`: cluster id of a cluster
+		/// - `era_id`: era id
+		/// - `buckets_aggregates_by_aggregator`: bucket aggregates fetched from DAC nodes
+		/// - `dac_nodes`: DAC nodes of the cluster
+		/// - `redundancy_factor`: number of aggregators per bucket
+		pub(crate) fn filter_buckets_aggregates_by_assignment(
+			cluster_id: &ClusterId,
+			era_id: DdcEra,
+			buckets_aggregates_by_aggregator: Vec<(
+				AggregatorInfo,
+				Vec<aggregator_client::json::BucketAggregateResponse>,
+			)>,
+			dac_nodes: &[(NodePubKey, StorageNodeParams)],
+			redundancy_factor: u16,
+		) -> Vec<(AggregatorInfo, Vec<aggregator_client::json::BucketAggregateResponse>)> {
+			let mut assignments: BTreeMap<BucketId, Vec<NodePubKey>> = BTreeMap::new();
+			let mut result = Vec::new();
+
+			for (aggregator, buckets_aggregates) in buckets_aggregates_by_aggregator {
+				let mut assigned_buckets_aggregates = Vec::new();
+				for bucket_aggregate in buckets_aggregates {
+					let bucket_aggregators =
+						assignments.entry(bucket_aggregate.bucket_id).or_insert_with(|| {
+							Self::get_bucket_aggregators(
+								cluster_id,
+								bucket_aggregate.bucket_id,
+								dac_nodes,
+								redundancy_factor,
+							)
+						});
+
+					if bucket_aggregators.contains(&aggregator.node_pub_key) {
+						assigned_buckets_aggregates.push(bucket_aggregate);
+					} else {
+						log::warn!(
+							"🏭 Ignoring aggregate of bucket_id: {:?} for cluster_id: {:?} era_id: {:?} from not assigned aggregator {:?}",
+							bucket_aggregate.bucket_id,
+							cluster_id,
+							era_id,
+							aggregator.node_pub_key.get_hex()
+						);
+					}
+				}
+				result.push((aggregator, assigned_buckets_aggregates));
+			}
+
+			result
+		}
+
 		/// Fetch node usage of an era.
 		///
 		/// Parameters:
@@ -3410,33 +4256,12 @@ pub mod pallet {
 			Vec<(AggregatorInfo, Vec<aggregator_client::json::NodeAggregateResponse>)>,
 			OCWError,
 		> {
-			let mut nodes_aggregates = Vec::new();
-
-			for (node_key, node_params) in dac_nodes {
-				let aggregates_res = Self::fetch_node_aggregates(cluster_id, era_id, node_params);
-				if aggregates_res.is_err() {
-					log::warn!(
-						"Aggregator from cluster {:?} is unavailable while fetching nodes aggregates. Key: {:?} Host: {:?}",
-						cluster_id,
-						node_key.get_hex(),
-						String::from_utf8(node_params.host.clone())
-					);
-					// skip unavailable aggregators and continue with available ones
-					continue;
-				}
-
-				let aggregates = aggregates_res.expect("Nodes Aggregates Response to be available");
+			let batch_size = Self::ocw_settings()
+				.nodes_aggregates_batch_size
+				.filter(|size| *size > 0)
+				.unwrap_or(NODES_AGGREGATES_FETCH_BATCH_SIZE as u32);
 
-				nodes_aggregates.push((
-					AggregatorInfo {
-						node_pub_key: node_key.clone(),
-						node_params: node_params.clone(),
-					},
-					aggregates,
-				));
-			}
-
-			Ok(nodes_aggregates)
+			Ok(Self::fetch_aggregates_for_era(cluster_id, era_id, dac_nodes, batch_size))
 		}
 
 		/// Fetch customer usage for an era.
@@ -3453,37 +4278,87 @@ pub mod pallet {
 			Vec<(AggregatorInfo, Vec<aggregator_client::json::BucketAggregateResponse>)>,
 			OCWError,
 		> {
-			let mut bucket_aggregates: Vec<(
-				AggregatorInfo,
-				Vec<aggregator_client::json::BucketAggregateResponse>,
-			)> = Vec::new();
+			let batch_size = Self::ocw_settings()
+				.buckets_aggregates_batch_size
+				.filter(|size| *size > 0)
+				.unwrap_or(BUCKETS_AGGREGATES_FETCH_BATCH_SIZE as u32);
+
+			Ok(Self::fetch_aggregates_for_era(cluster_id, era_id, dac_nodes, batch_size))
+		}
+
+		/// Fetch aggregates of an era from all DAC nodes concurrently, unavailable aggregators
+		/// and the ones not responding within `AGGREGATES_FETCH_DEADLINE` are skipped.
+		fn fetch_aggregates_for_era<R: aggregator_client::AggregatesPage>(
+			cluster_id: &ClusterId,
+			era_id: DdcEra,
+			dac_nodes: &[(NodePubKey, StorageNodeParams)],
+			batch_size: u32,
+		) -> Vec<(AggregatorInfo, Vec<R>)>
+		where
+			Vec<R>: aggregator_client::ProtoResponse,
+		{
+			let settings = Self::ocw_settings();
+			let response_timeout = settings.response_timeout_ms.unwrap_or(RESPONSE_TIMEOUT);
+			let deadline = sp_io::offchain::timestamp()
+				.add(Duration::from_millis(response_timeout.max(AGGREGATES_FETCH_DEADLINE)));
 
+			let mut available_nodes = Vec::new();
+			let mut base_urls = Vec::new();
 			for (node_key, node_params) in dac_nodes {
-				let aggregates_res = Self::fetch_bucket_aggregates(cluster_id, era_id, node_params);
-				if aggregates_res.is_err() {
-					log::warn!(
-						"Aggregator from cluster {:?} is unavailable while fetching buckets aggregates. Key: {:?} Host: {:?}",
+				match Self::aggregator_base_url(node_key, node_params, &settings) {
+					Ok(base_url) => {
+						available_nodes.push((node_key, node_params));
+						base_urls.push(base_url);
+					},
+					Err(_) => log::warn!(
+						"Aggregator from cluster {:?} has invalid host. Key: {:?} Host: {:?}",
 						cluster_id,
 						node_key.get_hex(),
 						String::from_utf8(node_params.host.clone())
-					);
-					// skip unavailable aggregators and continue with available ones
-					continue;
+					),
 				}
+			}
 
-				let aggregates =
-					aggregates_res.expect("Buckets Aggregates Response to be available");
+			let clients = available_nodes
+				.iter()
+				.zip(base_urls.iter())
+				.map(|((node_key, _), base_url)| {
+					Self::aggregator_client(base_url, *node_key, &settings)
+				})
+				.collect::<Vec<_>>();
+
+			let responses = aggregator_client::AggregatorClient::fetch_aggregates::<R>(
+				&clients, era_id, batch_size, deadline,
+			);
 
-				bucket_aggregates.push((
-					AggregatorInfo {
-						node_pub_key: node_key.clone(),
-						node_params: node_params.clone(),
+			let mut aggregates = Vec::new();
+			for ((node_key, node_params), response) in available_nodes.into_iter().zip(responses) {
+				match response {
+					Ok(response) => aggregates.push((
+						AggregatorInfo {
+							node_pub_key: node_key.clone(),
+							node_params: node_params.clone(),
+						},
+						response,
+					)),
+					Err(error) => {
+						if error == aggregator_client::AggregatorClientError::InvalidSignature {
+							Self::report_aggregator_signature_error(cluster_id, node_key);
+						}
+						// skip unavailable aggregators and continue with available ones
+						log::warn!(
+							"Aggregator from cluster {:?} is unavailable while fetching {} aggregates. Key: {:?} Host: {:?} Error: {:?}",
+							cluster_id,
+							R::PATH,
+							node_key.get_hex(),
+							String::from_utf8(node_params.host.clone()),
+							error
+						);
 					},
-					aggregates,
-				));
+				}
 			}
 
-			Ok(bucket_aggregates)
+			aggregates
 		}
 
 		/// Fetch processed era for across all nodes.
@@ -3498,7 +4373,12 @@ pub mod pallet {
 			let mut processed_eras_by_nodes: Vec<Vec<EraActivity>> = Vec::new();
 
 			for (node_key, node_params) in dac_nodes {
-				let processed_eras_by_node = Self::fetch_processed_eras(node_params);
+				let processed_eras_by_node = Self::fetch_processed_eras(node_key, node_params);
+				if let Err(aggregator_client::AggregatorClientError::InvalidSignature) =
+					processed_eras_by_node
+				{
+					Self::report_aggregator_signature_error(cluster_id, node_key);
+				}
 				if processed_eras_by_node.is_err() {
 					log::warn!(
 						"Aggregator from cluster {:?} is unavailable while fetching processed eras. Key: {:?} Host: {:?}",
@@ -3556,66 +4436,347 @@ pub mod pallet {
 				.verify(root_hash, vec![(batch_position, batch_hash)])
 				.map_err(|_| Error::<T>::FailedToVerifyMerkleProof)
 		}
-	}
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Create billing reports from a public origin.
+		/// Verify that a fraud proof contradicts a committed billing fingerprint.
 		///
-		/// The origin must be Signed.
+		/// The disputed batch must be committed under the payers or payees merkle root of the
+		/// fingerprint. Signed activity records of the era have to prove more transferred bytes,
+		/// puts or gets for the disputed bucket or node than the committed leaf contains. Only the
+		/// records signed by the node the proof is for are taken into account, and the requests
+		/// they fulfill must be issued by the bucket owner or by a holder of an auth token issued
+		/// by the owner.
 		///
 		/// Parameters:
-		/// - `cluster_id`: Cluster id of a cluster.
-		/// - `era`: Era id.
-		/// - `payers_merkle_root_hash`: Merkle root hash of payers
-		/// - `payees_merkle_root_hash`: Merkle root hash of payees
-		///
-		/// Emits `BillingReportCreated` event when successful.
-		#[pallet::call_index(0)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_prepare_era_for_payout(payers_batch_merkle_root_hashes.len() as u32 + payees_batch_merkle_root_hashes.len() as u32))]
-		pub fn set_prepare_era_for_payout(
-			origin: OriginFor<T>,
-			cluster_id: ClusterId,
-			era_activity: EraActivity,
-			payers_merkle_root_hash: DeltaUsageHash,
-			payees_merkle_root_hash: DeltaUsageHash,
-			payers_batch_merkle_root_hashes: Vec<DeltaUsageHash>,
-			payees_batch_merkle_root_hashes: Vec<DeltaUsageHash>,
-		) -> DispatchResult {
-			let caller = ensure_signed(origin)?;
-
-			ensure!(Self::is_ocw_validator(caller.clone()), Error::<T>::Unauthorized);
-			let mut era_validation = {
-				let era_validations = <EraValidations<T>>::get(cluster_id, era_activity.id);
-
-				if era_validations.is_none() {
-					EraValidation {
-						payers_merkle_root_hash: DeltaUsageHash::default(),
-						payees_merkle_root_hash: DeltaUsageHash::default(),
-						start_era: Default::default(),
-						end_era: Default::default(),
-						validators: Default::default(),
-						status: EraValidationStatus::ValidatingData,
-					}
-				} else {
-					era_validations.unwrap()
-				}
+		/// - `fingerprint`: committed billing fingerprint
+		/// - `fraud_proof`: disputed batch with the activity proof
+		pub(crate) fn verify_fraud_proof(
+			fingerprint: &BillingFingerprintParams<T::AccountId>,
+			fraud_proof: &FraudProof,
+		) -> Result<(), Error<T>> {
+			let (root_hash, leaves) = match &fraud_proof.usage {
+				DisputedUsage::Payers(payers) => (
+					fingerprint.payers_merkle_root,
+					payers
+						.iter()
+						.map(|(bucket_id, usage)| {
+							BucketPayableUsage(*bucket_id, usage.clone()).hash::<T>()
+						})
+						.collect::<Vec<_>>(),
+				),
+				DisputedUsage::Payees(payees) => (
+					fingerprint.payees_merkle_root,
+					payees
+						.iter()
+						.map(|(node_key, usage)| {
+							NodePayableUsage(node_key.clone(), usage.clone()).hash::<T>()
+						})
+						.collect::<Vec<_>>(),
+				),
 			};
 
-			// disallow signatures after era status change
+			ensure!((fraud_proof.leaf_index as usize) < leaves.len(), Error::<T>::BadFraudProof);
+
+			let batch_root =
+				Self::create_merkle_root(&fingerprint.cluster_id, fingerprint.era, &leaves)
+					.map_err(|_| Error::<T>::BadFraudProof)?;
 			ensure!(
-				era_validation.status == EraValidationStatus::ValidatingData,
+				Self::_proof_merkle_leaf(
+					root_hash,
+					batch_root,
+					fraud_proof.batch_index,
+					fraud_proof.max_batch_index,
+					&fraud_proof.batch_proof,
+				)?,
+				Error::<T>::FraudProofBatchIsNotCommitted
+			);
+
+			let challenge_response =
+				proto::ChallengeResponse::decode(fraud_proof.activity_proof.as_slice())
+					.map_err(|_| Error::<T>::BadFraudProof)?;
+			ensure!(
+				signature::Verify::verify(&challenge_response),
+				Error::<T>::FraudProofSignatureInvalid
+			);
+
+			ensure!(
+				T::ClusterManager::contains_node(
+					&fingerprint.cluster_id,
+					&fraud_proof.node_key,
+					None
+				),
+				Error::<T>::FraudProofSignerIsNotAuthorized
+			);
+			let node_key: &[u8] = match &fraud_proof.node_key {
+				NodePubKey::StoragePubKey(pub_key) => pub_key.as_ref(),
+			};
+
+			let leaf_index = fraud_proof.leaf_index as usize;
+			let (committed, proven) = match &fraud_proof.usage {
+				DisputedUsage::Payers(payers) => {
+					let (bucket_id, usage) = &payers[leaf_index];
+					let proven = Self::proven_usage(
+						&fingerprint.cluster_id,
+						node_key,
+						&challenge_response,
+						fingerprint.start_era,
+						fingerprint.end_era,
+						|record_bucket_id| record_bucket_id == *bucket_id,
+					)?;
+					((usage.transferred_bytes, usage.number_of_puts, usage.number_of_gets), proven)
+				},
+				DisputedUsage::Payees(payees) => {
+					let (payee_key, usage) = &payees[leaf_index];
+					ensure!(*payee_key == fraud_proof.node_key, Error::<T>::BadFraudProof);
+					let proven = Self::proven_usage(
+						&fingerprint.cluster_id,
+						node_key,
+						&challenge_response,
+						fingerprint.start_era,
+						fingerprint.end_era,
+						|_| true,
+					)?;
+					((usage.transferred_bytes, usage.number_of_puts, usage.number_of_gets), proven)
+				},
+			};
+
+			ensure!(
+				proven.0 > committed.0 || proven.1 > committed.1 || proven.2 > committed.2,
+				Error::<T>::FraudProofDoesNotContradict
+			);
+
+			Ok(())
+		}
+
+		/// Sum up transferred bytes, puts and gets proven by distinct activity records of an era
+		/// signed by a node that belong to a bucket.
+		///
+		/// Only signed fields are taken into account, so the transferred bytes are the bytes
+		/// acknowledged for the upstream request rather than the bytes the aggregator reported as
+		/// delivered. Record timestamps are in milliseconds while era boundaries are in seconds.
+		fn proven_usage(
+			cluster_id: &ClusterId,
+			node_key: &[u8],
+			challenge_response: &proto::ChallengeResponse,
+			start_era: i64,
+			end_era: i64,
+			belongs_to: impl Fn(BucketId) -> bool,
+		) -> Result<(u64, u64, u64), Error<T>> {
+			let mut record_ids = BTreeSet::new();
+			let mut cluster_nodes = BTreeMap::new();
+			let mut bucket_owners = BTreeMap::new();
+			let (mut transferred_bytes, mut number_of_puts, mut number_of_gets) =
+				(0u64, 0u64, 0u64);
+
+			for leaf in challenge_response.proofs.iter().flat_map(|proof| proof.leaves.iter()) {
+				let record = match &leaf.leaf_variant {
+					Some(proto::challenge_response::proof::leaf::LeafVariant::Record(record)) =>
+						record,
+					_ => continue,
+				};
+				let activity = match &record.record {
+					Some(activity) => activity,
+					None => continue,
+				};
+				let is_signed_by_node = activity
+					.signature
+					.as_ref()
+					.is_some_and(|signature| signature.signer == node_key);
+				if !is_signed_by_node {
+					continue;
+				}
+
+				let upstream_request =
+					activity.upstream.as_ref().and_then(|upstream| upstream.request.as_ref());
+				let bucket_id = upstream_request.map(Self::request_bucket_id).unwrap_or_default();
+
+				let timestamp = (activity.timestamp / 1000) as i64;
+				if timestamp < start_era || timestamp > end_era || !belongs_to(bucket_id) {
+					continue;
+				}
+
+				if !record_ids.insert(activity.id.clone()) {
+					continue;
+				}
+
+				let bucket_owner = bucket_owners
+					.entry(bucket_id)
+					.or_insert_with(|| {
+						T::CustomerVisitor::get_bucket_owner(&bucket_id)
+							.ok()
+							.map(|owner| owner.encode())
+					})
+					.clone();
+				ensure!(
+					Self::is_authorized_record(
+						cluster_id,
+						activity,
+						bucket_id,
+						bucket_owner.as_deref(),
+						&mut cluster_nodes,
+					),
+					Error::<T>::FraudProofSignerIsNotAuthorized
+				);
+
+				let acknowledged_bytes = activity
+					.upstream
+					.as_ref()
+					.and_then(|upstream| upstream.ack.as_ref())
+					.map(|ack| ack.bytes_stored_or_delivered)
+					.unwrap_or_default();
+				transferred_bytes = transferred_bytes.saturating_add(acknowledged_bytes);
+
+				let request_type = upstream_request.map(|request| request.request_type);
+				if request_type == Some(proto::activity_request::RequestType::Put as i32) {
+					number_of_puts = number_of_puts.saturating_add(1);
+				} else if request_type == Some(proto::activity_request::RequestType::Get as i32) {
+					number_of_gets = number_of_gets.saturating_add(1);
+				}
+			}
+
+			Ok((transferred_bytes, number_of_puts, number_of_gets))
+		}
+
+		/// Bucket of a request, a forwarded request takes it from the chain of its parent
+		/// requests.
+		fn request_bucket_id(request: &proto::ActivityRequest) -> BucketId {
+			let mut next = Some(request);
+			while let Some(request) = next {
+				if request.bucket_id != 0 {
+					return request.bucket_id;
+				}
+				next = request.parent_request.as_deref();
+			}
+
+			0
+		}
+
+		/// Check that the client request an activity record fulfilled is signed by the bucket
+		/// owner or a holder of an auth token issued by the bucket owner, and that the requests
+		/// forwarding it and the acknowledgments are signed by the client or by nodes of the
+		/// cluster.
+		fn is_authorized_record(
+			cluster_id: &ClusterId,
+			record: &proto::ActivityRecord,
+			bucket_id: BucketId,
+			bucket_owner: Option<&[u8]>,
+			cluster_nodes: &mut BTreeMap<Vec<u8>, bool>,
+		) -> bool {
+			let mut is_cluster_node = |signer: &[u8]| {
+				*cluster_nodes.entry(signer.to_vec()).or_insert_with(|| {
+					AccountId32::try_from(signer).is_ok_and(|pub_key| {
+						T::ClusterManager::contains_node(
+							cluster_id,
+							&NodePubKey::StoragePubKey(pub_key),
+							None,
+						)
+					})
+				})
+			};
+
+			let is_client = |signer: &[u8]| {
+				bucket_owner == Some(signer) ||
+					match (&record.auth_token, bucket_owner) {
+						(Some(token), Some(owner)) =>
+							signature::auth_token_authorizes(token, owner, bucket_id, signer),
+						_ => false,
+					}
+			};
+
+			let is_client_request = match &record.upstream {
+				Some(upstream) => signature::client_request(upstream)
+					.and_then(|request| request.signature.as_ref())
+					.is_some_and(|signature| is_client(signature.signer.as_slice())),
+				None => true,
+			};
+			if !is_client_request {
+				return false;
+			}
+
+			record
+				.upstream
+				.iter()
+				.chain(record.downstream.iter())
+				.flat_map(signature::fulfillment_signers)
+				.all(|signer| is_client(signer) || is_cluster_node(signer))
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create billing reports from a public origin.
+		///
+		/// The origin must be Signed.
+		///
+		/// Parameters:
+		/// - `cluster_id`: Cluster id of a cluster.
+		/// - `era_activity`: Era id with its start and end timestamps.
+		/// - `payers_merkle_root_hash`: Merkle root hash of payers
+		/// - `payees_merkle_root_hash`: Merkle root hash of payees
+		///
+		/// Validators have to agree on both merkle roots and era boundaries to reach the quorum.
+		///
+		/// Emits `BillingReportCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_prepare_era_for_payout(payers_batch_merkle_root_hashes.len() as u32 + payees_batch_merkle_root_hashes.len() as u32))]
+		pub fn set_prepare_era_for_payout(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			era_activity: EraActivity,
+			payers_merkle_root_hash: DeltaUsageHash,
+			payees_merkle_root_hash: DeltaUsageHash,
+			payers_batch_merkle_root_hashes: Vec<DeltaUsageHash>,
+			payees_batch_merkle_root_hashes: Vec<DeltaUsageHash>,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			ensure!(Self::is_ocw_validator(caller.clone()), Error::<T>::Unauthorized);
+			let mut era_validation = {
+				let era_validations = <EraValidations<T>>::get(cluster_id, era_activity.id);
+
+				if era_validations.is_none() {
+					EraValidation {
+						payers_merkle_root_hash: DeltaUsageHash::default(),
+						payees_merkle_root_hash: DeltaUsageHash::default(),
+						start_era: Default::default(),
+						end_era: Default::default(),
+						validators: Default::default(),
+						status: EraValidationStatus::ValidatingData,
+					}
+				} else {
+					era_validations.unwrap()
+				}
+			};
+
+			// disallow signatures after era status change
+			ensure!(
+				era_validation.status == EraValidationStatus::ValidatingData,
 				Error::<T>::NotExpectedState
 			);
 
+			// A validator can vote only once per era, regardless of the roots and era boundaries
+			// it has voted for and of the verification key it has voted with
+			let caller_stash = ValidatorToStashKey::<T>::get(&caller);
+			ensure!(
+				!era_validation.validators.values().flatten().any(|signed| {
+					*signed == caller ||
+						caller_stash.is_some() &&
+							ValidatorToStashKey::<T>::get(signed) == caller_stash
+				}),
+				Error::<T>::AlreadySignedEra
+			);
+
 			// Ensure the validators entry exists for the specified (payers_merkle_root_hash,
-			// payees_merkle_root_hash)
+			// payees_merkle_root_hash, start_era, end_era)
 			let signed_validators = era_validation
 				.validators
-				.entry((payers_merkle_root_hash, payees_merkle_root_hash))
+				.entry((
+					payers_merkle_root_hash,
+					payees_merkle_root_hash,
+					era_activity.start,
+					era_activity.end,
+				))
 				.or_insert_with(Vec::new);
 
-			ensure!(!signed_validators.contains(&caller.clone()), Error::<T>::AlreadySignedEra);
 			signed_validators.push(caller.clone());
 
 			let validators_quorum = T::ValidatorsQuorum::get();
@@ -3627,7 +4788,7 @@ pub mod pallet {
 				// threshold
 				era_validation.payers_merkle_root_hash = payers_merkle_root_hash;
 				era_validation.payees_merkle_root_hash = payees_merkle_root_hash;
-				era_validation.start_era = era_activity.start; // todo! start/end is set by the last validator and is not in consensus
+				era_validation.start_era = era_activity.start;
 				era_validation.end_era = era_activity.end;
 
 				if payers_merkle_root_hash == DeltaUsageHash::default() &&
@@ -3691,7 +4852,7 @@ pub mod pallet {
 				Error::<T>::NotValidatorStash
 			);
 
-			ValidatorToStashKey::<T>::insert(&ddc_validator_pub, &stash);
+			Self::bind_validator_key(&stash, &ddc_validator_pub);
 			Self::deposit_event(Event::<T>::ValidatorKeySet { validator: ddc_validator_pub });
 			Ok(())
 		}
@@ -3862,10 +5023,12 @@ pub mod pallet {
 
 		/// Emit consensus errors.
 		///
-		/// The origin must be a validator.
+		/// The origin must be a validator. A validator reports the errors of a cluster once per
+		/// era, until the next era of the cluster is paid.
 		///
 		/// Parameters:
-		/// - errors`: List of consensus errors
+		/// - `cluster_id`: Cluster id of a cluster the errors occurred in.
+		/// - `errors`: List of consensus errors
 		///
 		/// Emits `NotEnoughNodesForConsensus`  OR `ActivityNotInConsensus` event depend of error
 		/// type, when successful.
@@ -3873,11 +5036,19 @@ pub mod pallet {
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::emit_consensus_errors(errors.len() as u32))]
 		pub fn emit_consensus_errors(
 			origin: OriginFor<T>,
-			errors: Vec<OCWError>,
+			cluster_id: ClusterId,
+			errors: BoundedVec<OCWError, ConstU32<MAX_CONSENSUS_ERRORS>>,
 		) -> DispatchResult {
 			let caller = ensure_signed(origin)?;
 			ensure!(Self::is_ocw_validator(caller.clone()), Error::<T>::Unauthorized);
 
+			ensure!(
+				Self::can_report_consensus_errors(&cluster_id, &caller),
+				Error::<T>::ConsensusErrorsAlreadyReported
+			);
+			let era_id = T::ClusterValidator::get_last_paid_era(&cluster_id)?;
+			ConsensusErrorsReports::<T>::insert(cluster_id, &caller, era_id);
+
 			for error in errors {
 				match error {
 					OCWError::NodeUsageRetrievalError { cluster_id, era_id, node_pub_key } => {
@@ -4109,6 +5280,18 @@ pub mod pallet {
 							validator: caller.clone(),
 						});
 					},
+					OCWError::AggregatorResponseSignatureError {
+						cluster_id,
+						aggregator,
+						failures,
+					} => {
+						Self::deposit_event(Event::AggregatorResponseSignatureError {
+							cluster_id,
+							aggregator,
+							failures,
+							validator: caller.clone(),
+						});
+					},
 					OCWError::FailedToFetchClusterNodes => {
 						Self::deposit_event(Event::FailedToFetchClusterNodes {
 							validator: caller.clone(),
@@ -4128,6 +5311,13 @@ pub mod pallet {
 					OCWError::FailedToFetchVerifiedPayableUsage => {
 						Self::deposit_event(Event::FailedToFetchVerifiedPayableUsage);
 					},
+					OCWError::SubmitNodeValidationTransactionError { cluster_id, node_pub_key } => {
+						Self::deposit_event(Event::SubmitNodeValidationTransactionError {
+							cluster_id,
+							node_pub_key,
+							validator: caller.clone(),
+						});
+					},
 				}
 			}
 
@@ -4187,6 +5377,541 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Dispute a billing report with a fraud proof.
+		///
+		/// The origin can be any signed account. The billing report must be in its dispute
+		/// window, i.e. initialized and not charging customers yet.
+		///
+		/// Parameters:
+		/// - `cluster_id`: Cluster id of a cluster.
+		/// - `era_id`: Era id of the disputed billing report.
+		/// - `fraud_proof`: Committed batch with signed activity records contradicting it.
+		///
+		/// Halts the billing report, flags validators that committed its billing fingerprint and
+		/// rewards the challenger with `FraudProofReward` if the pallet account can afford it.
+		///
+		/// Emits `BillingReportDisputed` event when successful.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_fraud_proof(fraud_proof.activity_proof.len() as u32))]
+		pub fn submit_fraud_proof(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			era_id: DdcEra,
+			fraud_proof: FraudProof,
+		) -> DispatchResult {
+			let challenger = ensure_signed(origin)?;
+			ensure!(
+				T::PayoutProcessor::is_dispute_window_open(&cluster_id, era_id),
+				Error::<T>::DisputeWindowIsClosed
+			);
+
+			let fingerprint = T::PayoutProcessor::get_billing_fingerprint(&cluster_id, era_id)?;
+			Self::verify_fraud_proof(&fingerprint, &fraud_proof)?;
+
+			T::PayoutProcessor::dispute_billing_report(cluster_id, era_id)?;
+
+			let validators: Vec<T::AccountId> = fingerprint.validators.into_iter().collect();
+			for validator in &validators {
+				FlaggedValidators::<T>::insert(validator, (cluster_id, era_id), ());
+			}
+
+			let reward = T::Currency::transfer(
+				&T::PalletId::get().into_account_truncating(),
+				&challenger,
+				T::FraudProofReward::get(),
+				ExistenceRequirement::AllowDeath,
+			)
+			.map(|_| T::FraudProofReward::get())
+			.unwrap_or_else(|_| Zero::zero());
+
+			Self::deposit_event(Event::<T>::BillingReportDisputed {
+				cluster_id,
+				era_id,
+				challenger,
+				validators,
+				reward,
+			});
+
+			Ok(())
+		}
+
+		/// Dispatch a call of a validator submitted as an unsigned transaction.
+		///
+		/// The origin must be None, the payload must be signed by the verification key of a
+		/// validator of the current session.
+		///
+		/// Parameters:
+		/// - `payload`: The call of the validator with its verification key.
+		/// - `signature`: Signature of the payload by the verification key.
+		///
+		/// The call is dispatched with the signed origin of the verification key, so validators
+		/// are authorized as with signed transactions but do not pay fees. A signed call is applied
+		/// once, it can not be replayed until its payload expires.
+		#[pallet::call_index(15)]
+		#[pallet::weight({
+			let dispatch_info = payload.call.get_dispatch_info();
+			(
+				dispatch_info.weight.saturating_add(T::DbWeight::get().reads_writes(3, 1)),
+				dispatch_info.class,
+			)
+		})]
+		pub fn submit_validator_call(
+			origin: OriginFor<T>,
+			payload: ValidatorCallPayload<T>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let validator = payload.public.clone().into_account();
+			ensure!(
+				Self::validator_call_tag(&payload.call, &validator, payload.block_number).is_some(),
+				Error::<T>::NotValidatorCall
+			);
+
+			let call_hash = T::Hashing::hash_of(&payload);
+			ensure!(
+				!AppliedValidatorCalls::<T>::contains_key(payload.block_number, call_hash),
+				Error::<T>::ValidatorCallAlreadyApplied
+			);
+			AppliedValidatorCalls::<T>::insert(payload.block_number, call_hash, ());
+
+			payload
+				.call
+				.dispatch_bypass_filter(frame_system::RawOrigin::Signed(validator).into())
+				.map(|_| ())
+				.map_err(|e| e.error)
+		}
+
+		/// Roll the billing report of an era back to an earlier state.
+		///
+		/// The origin must be root. When rolled back to `NotInitialized`, the billing report is
+		/// removed and the payout of the era starts over. The stages which have already charged
+		/// customers or rewarded providers can not be rolled back.
+		///
+		/// Parameters:
+		/// - `cluster_id`: Cluster id of a cluster.
+		/// - `era_id`: Era id of the billing report.
+		/// - `state`: State to roll the billing report back to.
+		///
+		/// Emits `BillingReportRolledBack` event of the payouts pallet.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::rollback_billing_report())]
+		pub fn rollback_billing_report(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			era_id: DdcEra,
+			state: PayoutState,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			T::PayoutProcessor::rollback_billing_report(cluster_id, era_id, state.clone())?;
+
+			if state == PayoutState::NotInitialized {
+				<EraValidations<T>>::mutate(cluster_id, era_id, |era_validation| {
+					if let Some(era_validation) = era_validation {
+						if era_validation.status == EraValidationStatus::PayoutInProgress {
+							era_validation.status = EraValidationStatus::ReadyForPayout;
+						}
+					}
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Void a batch of the billing report of an era which can never be proven.
+		///
+		/// The origin must be root. The batch of the current charging or rewarding stage is
+		/// processed without charging its customers or rewarding its providers, the rewards of
+		/// voided batches are returned to the treasury once the rewarding stage ends.
+		///
+		/// Parameters:
+		/// - `cluster_id`: Cluster id of a cluster.
+		/// - `era_id`: Era id of the billing report.
+		/// - `batch_index`: Index of the batch to void.
+		///
+		/// Emits `BatchVoided` event of the payouts pallet.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::void_billing_report_batch())]
+		pub fn void_billing_report_batch(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			era_id: DdcEra,
+			batch_index: BatchIndex,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			T::PayoutProcessor::void_billing_report_batch(cluster_id, era_id, batch_index)
+		}
+
+		/// Complete the current charging or rewarding stage of the billing report of an era.
+		///
+		/// The origin must be root. The unprocessed batches of the stage are voided and the
+		/// stage is ended as usual, i.e. fees are collected from the charged customers.
+		///
+		/// Parameters:
+		/// - `cluster_id`: Cluster id of a cluster.
+		/// - `era_id`: Era id of the billing report.
+		///
+		/// Emits `BillingReportForceAdvanced` event of the payouts pallet.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::force_advance_billing_report())]
+		pub fn force_advance_billing_report(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			era_id: DdcEra,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			T::PayoutProcessor::force_advance_billing_report(cluster_id, era_id)
+		}
+
+		/// Re-queue an era for validation, e.g. after its payout has failed.
+		///
+		/// The origin must be root. The era must not be paid yet and its billing report must be
+		/// rolled back to `NotInitialized` beforehand. The votes of validators for the era are
+		/// discarded, so the era is validated again.
+		///
+		/// Parameters:
+		/// - `cluster_id`: Cluster id of a cluster.
+		/// - `era_id`: Era id to validate again.
+		///
+		/// Emits `EraValidationRequeued`.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::requeue_era_validation())]
+		pub fn requeue_era_validation(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			era_id: DdcEra,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				era_id > T::ClusterValidator::get_last_paid_era(&cluster_id)?,
+				Error::<T>::EraAlreadyPaid
+			);
+			ensure!(
+				T::PayoutProcessor::get_billing_report_status(&cluster_id, era_id) ==
+					PayoutState::NotInitialized,
+				Error::<T>::BillingReportNotRolledBack
+			);
+			ensure!(
+				<EraValidations<T>>::contains_key(cluster_id, era_id),
+				Error::<T>::NoEraValidation
+			);
+
+			let era_validation =
+				EraValidation { status: EraValidationStatus::ValidatingData, ..Default::default() };
+			<EraValidations<T>>::insert(cluster_id, era_id, era_validation);
+
+			Self::deposit_event(Event::<T>::EraValidationRequeued { cluster_id, era_id });
+
+			Ok(())
+		}
+
+		/// Submit the result of probing a node awaiting validation in a cluster.
+		///
+		/// The origin must be a validator. Each validator submits the result once per membership
+		/// of the node in the cluster. The node is validated once the quorum of validators agrees
+		/// on the outcome, i.e. whether all the endpoints declared by the node are operational.
+		///
+		/// Parameters:
+		/// - `cluster_id`: Cluster id of a cluster.
+		/// - `node_pub_key`: Key of the node awaiting validation.
+		/// - `result`: Result of probing the node endpoints.
+		///
+		/// Emits `NodeProbeResultSubmitted` and `NodeValidationCompleted` once the quorum is
+		/// reached.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_node_validation())]
+		pub fn submit_node_validation(
+			origin: OriginFor<T>,
+			cluster_id: ClusterId,
+			node_pub_key: NodePubKey,
+			result: NodeProbeResult,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			ensure!(Self::is_ocw_validator(caller.clone()), Error::<T>::Unauthorized);
+
+			let node_state = T::ClusterManager::get_node_state(&cluster_id, &node_pub_key)?;
+			ensure!(
+				node_state.status == ClusterNodeStatus::AwaitsValidation,
+				Error::<T>::NodeIsNotAwaitingValidation
+			);
+
+			let mut node_validation = match <NodeValidations<T>>::get(cluster_id, &node_pub_key) {
+				Some(node_validation) if node_validation.added_at == node_state.added_at =>
+					node_validation,
+				_ => NodeValidation {
+					added_at: node_state.added_at,
+					results: Default::default(),
+					succeeded: None,
+				},
+			};
+
+			// A validator submits the result once, regardless of the verification key it submits
+			// the result with
+			let caller_stash = ValidatorToStashKey::<T>::get(&caller);
+			ensure!(
+				!node_validation.results.iter().any(|(submitted, _)| {
+					*submitted == caller ||
+						caller_stash.is_some() &&
+							ValidatorToStashKey::<T>::get(submitted) == caller_stash
+				}),
+				Error::<T>::AlreadySubmittedNodeProbeResult
+			);
+
+			node_validation
+				.results
+				.try_push((caller.clone(), result))
+				.map_err(|_| Error::<T>::TooManyNodeProbeResults)?;
+
+			let succeeded = result.succeeded();
+			let agreed = node_validation
+				.results
+				.iter()
+				.filter(|(_, submitted)| submitted.succeeded() == succeeded)
+				.count();
+			if Self::is_quorum_reached(T::ValidatorsQuorum::get(), agreed) {
+				T::ClusterManager::validate_node(&cluster_id, &node_pub_key, succeeded)?;
+				node_validation.succeeded = Some(succeeded);
+			}
+
+			let completed = node_validation.succeeded.is_some();
+			<NodeValidations<T>>::insert(cluster_id, &node_pub_key, node_validation);
+			Self::deposit_event(Event::<T>::NodeProbeResultSubmitted {
+				cluster_id,
+				node_pub_key: node_pub_key.clone(),
+				validator: caller,
+				result,
+			});
+			if completed {
+				Self::deposit_event(Event::<T>::NodeValidationCompleted {
+					cluster_id,
+					node_pub_key,
+					succeeded,
+				});
+			}
+
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let (payload, signature) = match call {
+				Call::submit_validator_call { payload, signature } => (payload, signature),
+				_ => return InvalidTransaction::Call.into(),
+			};
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let expires_at =
+				payload.block_number.saturating_add(UNSIGNED_TRANSACTIONS_LONGEVITY.into());
+			if payload.block_number > now {
+				return InvalidTransaction::Future.into();
+			}
+			if expires_at < now {
+				return InvalidTransaction::Stale.into();
+			}
+
+			let validator = payload.public.clone().into_account();
+			if !Self::is_ocw_validator(validator.clone()) {
+				return InvalidTransaction::BadSigner.into();
+			}
+
+			let tag =
+				match Self::validator_call_tag(&payload.call, &validator, payload.block_number) {
+					Some(tag) => tag,
+					None => return InvalidTransaction::Call.into(),
+				};
+
+			if !SignedPayload::<T>::verify::<T::OffchainIdentifierId>(payload, signature.clone()) {
+				return InvalidTransaction::BadProof.into();
+			}
+
+			if AppliedValidatorCalls::<T>::contains_key(
+				payload.block_number,
+				T::Hashing::hash_of(payload),
+			) {
+				return InvalidTransaction::Stale.into();
+			}
+
+			if let Call::emit_consensus_errors { cluster_id, .. } = &payload.call {
+				if !Self::can_report_consensus_errors(cluster_id, &validator) {
+					return InvalidTransaction::Stale.into();
+				}
+			}
+
+			ValidTransaction::with_tag_prefix("DdcVerification")
+				.priority(T::UnsignedPriority::get())
+				.and_provides(tag)
+				.longevity((expires_at - now).saturated_into::<u64>().max(1))
+				.propagate(true)
+				.build()
+		}
+	}
+
+	/// Step of an era payout executed once by any validator.
+	#[derive(Debug, Clone, PartialEq, Encode)]
+	pub(crate) struct PayoutStep {
+		/// Name of the call executing the step.
+		pub(crate) name: &'static str,
+		pub(crate) cluster_id: ClusterId,
+		pub(crate) era_id: DdcEra,
+		/// Batch of customers or providers processed by the step.
+		pub(crate) batch_index: Option<BatchIndex>,
+		/// State of the billing report the step is executed at.
+		pub(crate) state: PayoutState,
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Deduplication tag of a call validators can submit as an unsigned transaction, `None`
+		/// if the call can not be submitted this way.
+		///
+		/// Every validator votes for an era and for a node awaiting validation once, while payout
+		/// steps are executed once per era by any validator.
+		pub(crate) fn validator_call_tag(
+			call: &Call<T>,
+			validator: &T::AccountId,
+			block_number: BlockNumberFor<T>,
+		) -> Option<Vec<u8>> {
+			let tag = match call {
+				Call::set_prepare_era_for_payout { cluster_id, era_activity, .. } =>
+					(b"set_prepare_era_for_payout", validator, cluster_id, era_activity.id).encode(),
+				Call::commit_billing_fingerprint { cluster_id, era_id, .. } =>
+					(b"commit_billing_fingerprint", validator, cluster_id, era_id).encode(),
+				Call::emit_consensus_errors { cluster_id, .. } => (
+					b"emit_consensus_errors",
+					validator,
+					cluster_id,
+					T::ClusterValidator::get_last_paid_era(cluster_id).ok()?,
+				)
+					.encode(),
+				Call::submit_node_validation { cluster_id, node_pub_key, .. } =>
+					(b"submit_node_validation", validator, cluster_id, node_pub_key).encode(),
+				_ => return Self::payout_step(call).map(|step| step.encode()),
+			};
+
+			Some(tag)
+		}
+
+		/// Whether the validator has not reported the consensus errors of the cluster since its
+		/// last era was paid.
+		pub(crate) fn can_report_consensus_errors(
+			cluster_id: &ClusterId,
+			validator: &T::AccountId,
+		) -> bool {
+			match T::ClusterValidator::get_last_paid_era(cluster_id) {
+				Ok(era_id) =>
+					ConsensusErrorsReports::<T>::get(cluster_id, validator) != Some(era_id),
+				Err(_) => false,
+			}
+		}
+
+		/// Payout step executed by the call, `None` if the call is not a payout step.
+		pub(crate) fn payout_step(call: &Call<T>) -> Option<PayoutStep> {
+			let (name, cluster_id, era_id, batch_index, state) = match call {
+				Call::begin_billing_report { cluster_id, era_id, .. } =>
+					("begin_billing_report", cluster_id, era_id, None, PayoutState::NotInitialized),
+				Call::begin_charging_customers { cluster_id, era_id, .. } =>
+					("begin_charging_customers", cluster_id, era_id, None, PayoutState::Initialized),
+				Call::send_charging_customers_batch { cluster_id, era_id, batch_index, .. } => (
+					"send_charging_customers_batch",
+					cluster_id,
+					era_id,
+					Some(*batch_index),
+					PayoutState::ChargingCustomers,
+				),
+				Call::end_charging_customers { cluster_id, era_id } => (
+					"end_charging_customers",
+					cluster_id,
+					era_id,
+					None,
+					PayoutState::ChargingCustomers,
+				),
+				Call::begin_rewarding_providers { cluster_id, era_id, .. } => (
+					"begin_rewarding_providers",
+					cluster_id,
+					era_id,
+					None,
+					PayoutState::CustomersChargedWithFees,
+				),
+				Call::send_rewarding_providers_batch {
+					cluster_id, era_id, batch_index, ..
+				} => (
+					"send_rewarding_providers_batch",
+					cluster_id,
+					era_id,
+					Some(*batch_index),
+					PayoutState::RewardingProviders,
+				),
+				Call::end_rewarding_providers { cluster_id, era_id } => (
+					"end_rewarding_providers",
+					cluster_id,
+					era_id,
+					None,
+					PayoutState::RewardingProviders,
+				),
+				Call::end_billing_report { cluster_id, era_id } =>
+					("end_billing_report", cluster_id, era_id, None, PayoutState::ProvidersRewarded),
+				_ => return None,
+			};
+
+			Some(PayoutStep { name, cluster_id: *cluster_id, era_id: *era_id, batch_index, state })
+		}
+
+		/// Whether the billing report is already past the payout step or the batch of the step
+		/// is already processed.
+		pub(crate) fn is_payout_step_executed(step: &PayoutStep) -> bool {
+			let state =
+				T::PayoutProcessor::get_billing_report_status(&step.cluster_id, step.era_id);
+			if state as u8 > step.state.clone() as u8 {
+				return true;
+			}
+
+			match step.batch_index {
+				Some(batch_index) if step.state == PayoutState::ChargingCustomers =>
+					T::PayoutProcessor::is_customers_batch_processed(
+						&step.cluster_id,
+						step.era_id,
+						batch_index,
+					),
+				Some(batch_index) => T::PayoutProcessor::is_providers_batch_processed(
+					&step.cluster_id,
+					step.era_id,
+					batch_index,
+				),
+				None => false,
+			}
+		}
+
+		/// Whether the validator is elected to submit the payout step pending for the given
+		/// number of blocks.
+		///
+		/// The leader of a step is picked deterministically among validators of the current
+		/// session, the next validators take over one after another every
+		/// `PAYOUT_STEP_LEADER_TIMEOUT` blocks the step remains pending.
+		pub(crate) fn is_payout_step_leader(
+			step: &PayoutStep,
+			validator: &T::AccountId,
+			pending_for: BlockNumberFor<T>,
+		) -> bool {
+			let mut validators = <ValidatorSet<T>>::get();
+			validators.sort();
+
+			let position = match validators.iter().position(|v| v == validator) {
+				Some(position) => position,
+				None => return false,
+			};
+
+			let mut seed = [0u8; 8];
+			seed.copy_from_slice(&blake2_256(&step.encode())[..8]);
+			let leader = (u64::from_le_bytes(seed) % validators.len() as u64) as usize;
+			let rank = (position + validators.len() - leader) % validators.len();
+			let fallbacks = pending_for.saturated_into::<u32>() / PAYOUT_STEP_LEADER_TIMEOUT;
+
+			rank as u32 <= fallbacks
+		}
 	}
 
 	impl<T: Config> ValidatorVisitor<T> for Pallet<T> {
@@ -4204,6 +5929,49 @@ pub mod pallet {
 		}
 	}
 
+	/// Era validations of a decommissioned cluster are removed once the payouts of the validated
+	/// eras are completed. Eras which are not validated yet are dropped, as the cluster has no
+	/// nodes to collect the activity from anymore.
+	impl<T: Config> ClusterDecommissionHandler for Pallet<T> {
+		fn clean_up_weight(limit: u32) -> Weight {
+			T::DbWeight::get().reads_writes(limit.into(), limit.into())
+		}
+
+		fn clean_up(
+			cluster_id: &ClusterId,
+			_buckets_migrate_to: Option<ClusterId>,
+			limit: u32,
+		) -> Result<u32, DispatchError> {
+			let eras: Vec<(DdcEra, EraValidationStatus)> =
+				EraValidations::<T>::iter_prefix(cluster_id)
+					.take(limit as usize)
+					.map(|(era, era_validation)| (era, era_validation.status))
+					.collect();
+			ensure!(
+				eras.iter().all(|(_, status)| matches!(
+					status,
+					EraValidationStatus::ValidatingData |
+						EraValidationStatus::PayoutSuccess |
+						EraValidationStatus::PayoutSkipped
+				)),
+				Error::<T>::EraPayoutNotCompleted
+			);
+
+			for (era, _) in eras.iter() {
+				EraValidations::<T>::remove(cluster_id, era);
+			}
+
+			let mut processed = eras.len() as u32;
+			if processed < limit {
+				processed +=
+					ConsensusErrorsReports::<T>::clear_prefix(cluster_id, limit - processed, None)
+						.unique;
+			}
+
+			Ok(processed)
+		}
+	}
+
 	impl<T: Config> sp_application_crypto::BoundToRuntimeAppPublic for Pallet<T> {
 		type Public = T::AuthorityId;
 	}
@@ -4217,7 +5985,7 @@ pub mod pallet {
 		{
 			log::info!("🙌Adding Validator from genesis session.");
 			let validators = validators
-				.map(|(_, k)| T::AccountId::decode(&mut &k.into().encode()[..]).unwrap())
+				.map(|(stash, k)| Self::bind_session_key(stash, k))
 				.collect::<Vec<_>>();
 
 			ValidatorSet::<T>::put(validators); // only active validators in session - this is NOT all the
@@ -4230,7 +5998,7 @@ pub mod pallet {
 		{
 			log::info!("🙌Adding Validator from new session.");
 			let validators = validators
-				.map(|(_, k)| T::AccountId::decode(&mut &k.into().encode()[..]).unwrap())
+				.map(|(stash, k)| Self::bind_session_key(stash, k))
 				.collect::<Vec<_>>();
 			log::info!("🙌Total validator from new session. {:?}", validators.len());
 			ValidatorSet::<T>::put(validators);
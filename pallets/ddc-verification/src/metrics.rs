@@ -0,0 +1,102 @@
+//! Offchain worker metrics. They are accumulated in the persistent offchain local storage, the
+//! node exports them to Prometheus. Within an offchain worker run the metrics are kept in memory
+//! and persisted once the run is completed, see [`collect`].
+
+use ddc_primitives::{OcwMetrics, OCW_METRICS_KEY};
+
+use super::*;
+
+environmental::environmental!(run_metrics: OcwMetrics);
+
+/// Runs `f` accumulating all the recorded metrics in memory, then persists them at once.
+pub(crate) fn collect<R>(f: impl FnOnce() -> R) -> R {
+	let mut metrics = load();
+	let result = run_metrics::using(&mut metrics, f);
+	store(&metrics);
+	result
+}
+
+pub(crate) fn record_run(cluster_id: &ClusterId) {
+	update(|metrics| *metrics.runs.entry(*cluster_id).or_default() += 1);
+}
+
+pub(crate) fn record_phase(cluster_id: &ClusterId, phase: &str, duration_ms: u64) {
+	update(|metrics| {
+		let phase = metrics.phases.entry((*cluster_id, phase.as_bytes().to_vec())).or_default();
+		phase.runs += 1;
+		phase.total_duration_ms = phase.total_duration_ms.saturating_add(duration_ms);
+		phase.last_duration_ms = duration_ms;
+	});
+}
+
+pub(crate) fn record_aggregator_request(node_pub_key: &NodePubKey, latency_ms: u64, failed: bool) {
+	update(|metrics| {
+		let requests = metrics.aggregator_requests.entry(node_pub_key.clone()).or_default();
+		requests.requests += 1;
+		requests.errors += u64::from(failed);
+		requests.total_latency_ms = requests.total_latency_ms.saturating_add(latency_ms);
+	});
+}
+
+pub(crate) fn record_consistency_groups<A: Aggregate>(
+	cluster_id: &ClusterId,
+	kind: &str,
+	groups: &ConsistencyGroups<A>,
+) {
+	update(|metrics| {
+		let sizes = metrics
+			.consistency_groups
+			.entry((*cluster_id, kind.as_bytes().to_vec()))
+			.or_default();
+		sizes.consensus = groups.consensus.len() as u32;
+		sizes.quorum = groups.quorum.len() as u32;
+		sizes.others = groups.others.len() as u32;
+	});
+}
+
+pub(crate) fn record_challenge(cluster_id: &ClusterId, passed: bool) {
+	update(|metrics| {
+		let challenges = metrics.challenges.entry(*cluster_id).or_default();
+		challenges.runs += 1;
+		challenges.failures += u64::from(!passed);
+	});
+}
+
+pub(crate) fn record_submitted_errors(errors: &[OCWError]) {
+	update(|metrics| {
+		for error in errors {
+			*metrics.submitted_errors.entry(error_category(error)).or_default() += 1;
+		}
+	});
+}
+
+/// Name of the error variant, e.g. `NodeUsageRetrievalError`.
+fn error_category(error: &OCWError) -> Vec<u8> {
+	format!("{:?}", error)
+		.split(|c: char| !c.is_alphanumeric())
+		.next()
+		.unwrap_or_default()
+		.as_bytes()
+		.to_vec()
+}
+
+fn update(f: impl FnOnce(&mut OcwMetrics)) {
+	let mut f = Some(f);
+	run_metrics::with(|metrics| f.take().map(|f| f(metrics)));
+	// recorded outside of a run, persist right away
+	if let Some(f) = f {
+		let mut metrics = load();
+		f(&mut metrics);
+		store(&metrics);
+	}
+}
+
+fn load() -> OcwMetrics {
+	local_storage_get(StorageKind::PERSISTENT, OCW_METRICS_KEY)
+		.and_then(|encoded| OcwMetrics::decode(&mut &encoded[..]).ok())
+		.unwrap_or_default()
+}
+
+fn store(metrics: &OcwMetrics) {
+	local_storage_set(StorageKind::PERSISTENT, OCW_METRICS_KEY, &metrics.encode());
+}
@@ -67,3 +67,92 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use ddc_primitives::DeltaUsageHash;
+	use frame_support::pallet_prelude::*;
+	use sp_runtime::Saturating;
+
+	use super::*;
+
+	#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq)]
+	#[scale_info(skip_type_params(T))]
+	pub struct OldEraValidation<T: Config> {
+		pub validators: BTreeMap<(DeltaUsageHash, DeltaUsageHash), Vec<T::AccountId>>,
+		pub start_era: i64,
+		pub end_era: i64,
+		pub payers_merkle_root_hash: DeltaUsageHash,
+		pub payees_merkle_root_hash: DeltaUsageHash,
+		pub status: EraValidationStatus,
+	}
+
+	pub fn migrate_to_v2<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		let current_version = Pallet::<T>::current_storage_version();
+
+		log::info!(
+			target: LOG_TARGET,
+			"Running migration with current storage version {:?} / onchain {:?}",
+			current_version,
+			on_chain_version
+		);
+
+		if on_chain_version == 1 && current_version == 2 {
+			let mut translated = 0u64;
+
+			EraValidations::<T>::translate::<OldEraValidation<T>, _>(
+				|cluster_id: ClusterId, era_id: DdcEra, era_validation: OldEraValidation<T>| {
+					log::info!(target: LOG_TARGET, "Migrating Era Validation for cluster_id {:?} era_id {:?}", cluster_id, era_id);
+					translated.saturating_inc();
+
+					// Votes did not include era boundaries before, so the stored ones are
+					// attributed to all of them.
+					let validators = era_validation
+						.validators
+						.into_iter()
+						.map(|((payers_root, payees_root), validators)| {
+							(
+								(
+									payers_root,
+									payees_root,
+									era_validation.start_era,
+									era_validation.end_era,
+								),
+								validators,
+							)
+						})
+						.collect();
+
+					Some(EraValidation {
+						validators,
+						start_era: era_validation.start_era,
+						end_era: era_validation.end_era,
+						payers_merkle_root_hash: era_validation.payers_merkle_root_hash,
+						payees_merkle_root_hash: era_validation.payees_merkle_root_hash,
+						status: era_validation.status,
+					})
+				},
+			);
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			log::info!(
+				target: LOG_TARGET,
+				"Upgraded {} records, storage to version {:?}",
+				translated,
+				current_version
+			);
+
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		} else {
+			log::info!(target: LOG_TARGET, " >>> Unused migration!");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrate_to_v2::<T>()
+		}
+	}
+}
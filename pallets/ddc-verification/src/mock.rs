@@ -8,8 +8,9 @@ use ddc_primitives::{
 };
 #[cfg(feature = "runtime-benchmarks")]
 use ddc_primitives::{
-	traits::{BucketManager, ClusterCreator, CustomerDepositor},
+	traits::{BucketManager, ClusterCreator, CustomerDepositor, StakerCreator},
 	BillingReportParams, BucketParams, ClusterId, ClusterParams, ClusterProtocolParams,
+	PricingOverride, PricingOverrideTarget,
 };
 use frame_election_provider_support::{
 	bounds::{ElectionBounds, ElectionBoundsBuilder},
@@ -222,6 +223,8 @@ parameter_types! {
 	pub const MajorityOfAggregators: Percent = Percent::from_percent(67);
 	pub const VerifyAggregatorResponseSignature: bool = false;
 	pub const MajorityOfValidators: Percent = Percent::from_percent(67);
+	pub const FraudProofReward: Balance = 100;
+	pub const UnsignedPriority: u64 = 1 << 20;
 }
 
 impl crate::Config for Test {
@@ -247,6 +250,8 @@ impl crate::Config for Test {
 	const MAX_MERKLE_NODE_IDENTIFIER: u16 = 4;
 	type Currency = Balances;
 	const VERIFY_AGGREGATOR_RESPONSE_SIGNATURE: bool = false;
+	type FraudProofReward = FraudProofReward;
+	type UnsignedPriority = UnsignedPriority;
 	type BucketsStorageUsageProvider = MockBucketValidator;
 	type NodesStorageUsageProvider = MockNodeValidator;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -255,6 +260,8 @@ impl crate::Config for Test {
 	type ClusterCreator = MockClusterCreator;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BucketManager = MockBucketManager;
+	#[cfg(feature = "runtime-benchmarks")]
+	type StakerCreator = MockStakerCreator;
 }
 
 pub struct MockBucketValidator;
@@ -293,14 +300,22 @@ impl StorageUsageProvider<StorageNodePubKey, NodeStorageUsage<AccountId>> for Mo
 	}
 }
 
+parameter_types! {
+	pub static BucketOwner: [u8; 32] = [0xa; 32];
+}
+
 pub struct MockCustomerVisitor;
 impl<T: Config> CustomerVisitor<T> for MockCustomerVisitor {
 	fn get_bucket_owner(_bucket_id: &BucketId) -> Result<T::AccountId, DispatchError> {
-		let temp: AccountId = AccountId::from([0xa; 32]);
+		let temp: AccountId = AccountId::from(BucketOwner::get());
 		let account_1 = T::AccountId::decode(&mut &temp.as_slice()[..]).unwrap();
 
 		Ok(account_1)
 	}
+
+	fn get_bucket_cluster_id(_bucket_id: &BucketId) -> Result<ClusterId, DispatchError> {
+		unimplemented!()
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -330,6 +345,36 @@ impl<T: Config> ClusterCreator<T, Balance> for MockClusterCreator {
 	}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockStakerCreator;
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: Config> StakerCreator<T, Balance> for MockStakerCreator {
+	fn bond_stake_and_participate(
+		_stash: T::AccountId,
+		_controller: T::AccountId,
+		_node: NodePubKey,
+		_value: Balance,
+		_cluster_id: ClusterId,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn bond_cluster(
+		_cluster_stash: T::AccountId,
+		_cluster_controller: T::AccountId,
+		_cluster_id: ClusterId,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn set_cluster_controller(
+		_cluster_stash: &T::AccountId,
+		_cluster_controller: &T::AccountId,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub struct MockBucketManager;
 #[cfg(feature = "runtime-benchmarks")]
@@ -463,10 +508,29 @@ impl<T: Config> ClusterValidator<T> for MockClusterValidator {
 	}
 
 	fn get_last_paid_era(_cluster_id: &ClusterId) -> Result<DdcEra, DispatchError> {
-		Ok(Default::default())
+		Ok(LastPaidEra::get())
 	}
 }
 
+parameter_types! {
+	pub static LastPaidEra: DdcEra = 0;
+}
+
+parameter_types! {
+	pub static NodeValidationStatus: ClusterNodeStatus = ClusterNodeStatus::ValidationSucceeded;
+	pub static NodeAddedAt: u32 = 1;
+	// any node is in the cluster unless the nodes are given
+	pub static ClusterNodeKeys: Option<Vec<NodePubKey>> = None;
+}
+
+parameter_types! {
+	pub static BillingReportState: PayoutState = PayoutState::NotInitialized;
+	pub static ProcessedBatches: Vec<BatchIndex> = vec![];
+	pub static DisputeWindowOpen: bool = false;
+	// start and end of the era, payers and payees merkle roots and validators of the fingerprint
+	pub static BillingFingerprint: Option<(i64, i64, H256, H256, Vec<AccountId>)> = None;
+}
+
 pub struct MockPayoutProcessor;
 impl<T: Config> PayoutProcessor<T> for MockPayoutProcessor {
 	fn commit_billing_fingerprint(
@@ -560,8 +624,24 @@ impl<T: Config> PayoutProcessor<T> for MockPayoutProcessor {
 		true
 	}
 
+	fn is_customers_batch_processed(
+		_cluster_id: &ClusterId,
+		_era_id: DdcEra,
+		batch_index: BatchIndex,
+	) -> bool {
+		ProcessedBatches::get().contains(&batch_index)
+	}
+
+	fn is_providers_batch_processed(
+		_cluster_id: &ClusterId,
+		_era_id: DdcEra,
+		batch_index: BatchIndex,
+	) -> bool {
+		ProcessedBatches::get().contains(&batch_index)
+	}
+
 	fn get_billing_report_status(_cluster_id: &ClusterId, _era_id: DdcEra) -> PayoutState {
-		PayoutState::NotInitialized
+		BillingReportState::get()
 	}
 
 	fn create_billing_report(_vault: T::AccountId, _params: BillingReportParams) {
@@ -571,6 +651,60 @@ impl<T: Config> PayoutProcessor<T> for MockPayoutProcessor {
 	fn create_billing_fingerprint(_params: BillingFingerprintParams<T::AccountId>) -> Fingerprint {
 		unimplemented!()
 	}
+
+	fn get_billing_fingerprint(
+		cluster_id: &ClusterId,
+		era_id: DdcEra,
+	) -> Result<BillingFingerprintParams<T::AccountId>, DispatchError> {
+		let (start_era, end_era, payers_merkle_root, payees_merkle_root, validators) =
+			BillingFingerprint::get().ok_or(DispatchError::Other("No billing fingerprint"))?;
+
+		Ok(BillingFingerprintParams {
+			cluster_id: *cluster_id,
+			era: era_id,
+			start_era,
+			end_era,
+			payers_merkle_root,
+			payees_merkle_root,
+			cluster_usage: Default::default(),
+			validators: validators
+				.into_iter()
+				.map(|validator| T::AccountId::decode(&mut &validator.as_slice()[..]).unwrap())
+				.collect(),
+		})
+	}
+
+	fn is_dispute_window_open(_cluster_id: &ClusterId, _era_id: DdcEra) -> bool {
+		DisputeWindowOpen::get()
+	}
+
+	fn dispute_billing_report(_cluster_id: ClusterId, _era_id: DdcEra) -> DispatchResult {
+		BillingReportState::set(PayoutState::Disputed);
+		DisputeWindowOpen::set(false);
+
+		Ok(())
+	}
+
+	fn rollback_billing_report(
+		_cluster_id: ClusterId,
+		_era_id: DdcEra,
+		state: PayoutState,
+	) -> DispatchResult {
+		BillingReportState::set(state);
+		Ok(())
+	}
+
+	fn void_billing_report_batch(
+		_cluster_id: ClusterId,
+		_era_id: DdcEra,
+		_batch_index: BatchIndex,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn force_advance_billing_report(_cluster_id: ClusterId, _era_id: DdcEra) -> DispatchResult {
+		unimplemented!()
+	}
 }
 
 pub struct MockNodeManager;
@@ -755,15 +889,18 @@ impl<T: Config> ClusterQuery<T> for TestClusterManager {
 	) -> Result<(T::AccountId, T::AccountId), DispatchError> {
 		unimplemented!()
 	}
+	fn is_decommissioning(_cluster_id: &ClusterId) -> bool {
+		unimplemented!()
+	}
 }
 
 impl<T: Config> ClusterManager<T> for TestClusterManager {
 	fn contains_node(
 		_cluster_id: &ClusterId,
-		_node_pub_key: &NodePubKey,
+		node_pub_key: &NodePubKey,
 		_validation_status: Option<ClusterNodeStatus>,
 	) -> bool {
-		unimplemented!()
+		ClusterNodeKeys::get().map_or(true, |keys| keys.contains(node_pub_key))
 	}
 
 	fn get_nodes(_cluster_id: &ClusterId) -> Result<Vec<NodePubKey>, DispatchError> {
@@ -813,6 +950,20 @@ impl<T: Config> ClusterManager<T> for TestClusterManager {
 		unimplemented!()
 	}
 
+	fn start_node_leaving(
+		_cluster_id: &ClusterId,
+		_node_pub_key: &NodePubKey,
+	) -> Result<(), DispatchError> {
+		unimplemented!()
+	}
+
+	fn cancel_node_leaving(
+		_cluster_id: &ClusterId,
+		_node_pub_key: &NodePubKey,
+	) -> Result<(), DispatchError> {
+		unimplemented!()
+	}
+
 	fn get_manager_account_id(_cluster_id: &ClusterId) -> Result<T::AccountId, DispatchError> {
 		unimplemented!()
 	}
@@ -821,7 +972,11 @@ impl<T: Config> ClusterManager<T> for TestClusterManager {
 		_cluster_id: &ClusterId,
 		_node_pub_key: &NodePubKey,
 	) -> Result<ClusterNodeState<BlockNumberFor<T>>, DispatchError> {
-		unimplemented!()
+		Ok(ClusterNodeState {
+			kind: ClusterNodeKind::External,
+			status: NodeValidationStatus::get(),
+			added_at: NodeAddedAt::get().into(),
+		})
 	}
 
 	fn get_nodes_stats(_cluster_id: &ClusterId) -> Result<ClusterNodesStats, DispatchError> {
@@ -831,14 +986,56 @@ impl<T: Config> ClusterManager<T> for TestClusterManager {
 	fn validate_node(
 		_cluster_id: &ClusterId,
 		_node_pub_key: &NodePubKey,
-		_succeeded: bool,
+		succeeded: bool,
 	) -> Result<(), DispatchError> {
-		unimplemented!()
+		NodeValidationStatus::set(if succeeded {
+			ClusterNodeStatus::ValidationSucceeded
+		} else {
+			ClusterNodeStatus::ValidationFailed
+		});
+		Ok(())
 	}
 
 	fn get_clusters(_status: ClusterStatus) -> Result<Vec<ClusterId>, DispatchError> {
 		Ok(vec![ClusterId::from([12; 20])])
 	}
+
+	fn get_aggregators(_cluster_id: &ClusterId) -> Result<Vec<NodePubKey>, DispatchError> {
+		Ok(vec![])
+	}
+
+	fn get_admission_queue(
+		_cluster_id: &ClusterId,
+		_mode: StorageNodeMode,
+	) -> Result<Vec<NodePubKey>, DispatchError> {
+		Ok(vec![])
+	}
+
+	fn propose_manager_transfer(
+		_cluster_id: &ClusterId,
+		_new_manager_id: &T::AccountId,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn cancel_manager_transfer(_cluster_id: &ClusterId) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn update_cluster_params(
+		_cluster_id: &ClusterId,
+		_cluster_params: ClusterParams<T::AccountId>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn set_pricing_override(
+		_cluster_id: &ClusterId,
+		_target: &PricingOverrideTarget<T::AccountId>,
+		_pricing_override: Option<PricingOverride>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
 }
 
 impl frame_system::offchain::SigningTypes for Test {
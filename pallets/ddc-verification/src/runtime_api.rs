@@ -0,0 +1,18 @@
+//! Runtime API of the DDC verification pallet.
+
+use codec::Codec;
+use ddc_primitives::{ClusterId, DdcEra};
+use sp_std::prelude::*;
+
+use crate::{OCWError, ValidationDryRun, VerificationKeyStatus};
+
+sp_api::decl_runtime_apis! {
+	pub trait DdcVerificationApi<AccountId: Codec> {
+		/// Replay the DAC validation of a cluster era without submitting transactions. Must be
+		/// called with the offchain worker extensions registered.
+		fn dry_run_era(cluster_id: ClusterId, era_id: DdcEra) -> Result<ValidationDryRun, Vec<OCWError>>;
+
+		/// Status of a DAC verification key.
+		fn verification_key_status(key: AccountId) -> VerificationKeyStatus<AccountId>;
+	}
+}
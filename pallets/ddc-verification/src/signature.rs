@@ -5,6 +5,10 @@ use sp_io::crypto::ed25519_verify;
 
 use super::*;
 
+/// Maximum number of parent requests in a forwarded request chain and of previous tokens in a
+/// delegated auth token chain.
+const MAX_CHAIN_DEPTH: usize = 5;
+
 pub trait Verify {
 	fn verify(&self) -> bool;
 }
@@ -33,6 +37,19 @@ impl Verify for proto::ActivityRecord {
 			}
 		}
 
+		if let Some(auth_token) = &self.auth_token {
+			if !auth_token.verify() {
+				return false;
+			}
+
+			// record timestamp is in milliseconds, token expiration is in seconds
+			if let Some(expires_at) = auth_token.payload.as_ref().and_then(|p| p.expires_at) {
+				if self.timestamp / 1000 > expires_at.max(0) as u64 {
+					return false;
+				}
+			}
+		}
+
 		true
 	}
 }
@@ -43,15 +60,46 @@ impl Verify for proto::ActivityRequest {
 			return false;
 		}
 
-		// TODO(khssnv): parent requests are expected to have an invalid signature.
-		// if let Some(ref parent_request) = self.parent_request {
-		// 	if !parent_request.verify() {
-		// 		return false;
-		// 	}
-		// }
+		verify_parent_requests(self, 0)
+	}
+}
 
-		true
+/// Verifies the chain of parent requests of a forwarded request.
+///
+/// A node forwarding a request embeds the parent request as is, together with the parent
+/// signature, so the forwarding node signature covers the whole chain. Each parent is signed by
+/// its own requester and must not be issued later than the request it was forwarded as. A client
+/// signs a `PUT` request before the piece CID is known, the CID is filled in by the node the piece
+/// was uploaded to, so the parent signature of a forwarded `PUT` request may cover the request
+/// without the CID. It is accepted only if the CID of the forwarded request, signed by that node,
+/// refers to the piece the CID was filled in with, a segment or a merkle tree CID being equal to
+/// the piece CID and a metadata CID ending with it. Otherwise a CID signed by the client could be
+/// replaced.
+fn verify_parent_requests(request: &proto::ActivityRequest, depth: usize) -> bool {
+	let parent = match &request.parent_request {
+		Some(parent) => parent.as_ref(),
+		None => return true,
+	};
+
+	if depth >= MAX_CHAIN_DEPTH || parent.timestamp > request.timestamp {
+		return false;
 	}
+
+	if !verify_signature(parent.clone()) {
+		let is_put = parent.request_type == proto::activity_request::RequestType::Put as i32;
+		if !is_put || parent.piece_cid.is_empty() || !request.piece_cid.ends_with(&parent.piece_cid)
+		{
+			return false;
+		}
+
+		let mut parent_without_cid = parent.clone();
+		parent_without_cid.piece_cid = Vec::new();
+		if !verify_signature(parent_without_cid) {
+			return false;
+		}
+	}
+
+	verify_parent_requests(parent, depth + 1)
 }
 
 impl Verify for proto::ActivityFulfillment {
@@ -72,6 +120,156 @@ impl Verify for proto::ActivityFulfillment {
 	}
 }
 
+impl Verify for proto::AuthToken {
+	fn verify(&self) -> bool {
+		verify_auth_token(self, 0)
+	}
+}
+
+/// Verifies a delegated auth token together with the chain of its previous tokens.
+///
+/// The first token in the chain is signed by the issuer. Each next token is signed by the subject
+/// of the previous one and may omit the signer. A token can't widen the access of the previous
+/// one: it can't be issued if delegation is prohibited, can't allow other operations, can't
+/// expire later, and can't set the bucket or the piece CID once they are set earlier in the chain.
+fn verify_auth_token(token: &proto::AuthToken, depth: usize) -> bool {
+	let (signature, payload) = match (&token.signature, &token.payload) {
+		(Some(signature), Some(payload)) => (signature, payload),
+		_ => return false,
+	};
+
+	let prev = match &payload.prev {
+		Some(prev) => prev.as_ref(),
+		None => return verify_signature(token.clone()),
+	};
+
+	if depth >= MAX_CHAIN_DEPTH {
+		return false;
+	}
+
+	let prev_payload = match &prev.payload {
+		Some(prev_payload) => prev_payload,
+		None => return false,
+	};
+
+	let signer = match &prev_payload.subject {
+		Some(subject) if !subject.is_empty() => subject,
+		_ => return false,
+	};
+
+	if !signature.signer.is_empty() && &signature.signer != signer {
+		return false;
+	}
+
+	if prev_payload.can_delegate == Some(false) {
+		return false;
+	}
+
+	if !prev_payload.operations.is_empty() &&
+		payload.operations.iter().any(|op| !prev_payload.operations.contains(op))
+	{
+		return false;
+	}
+
+	if let Some(prev_expires_at) = prev_payload.expires_at {
+		if payload.expires_at.map_or(true, |expires_at| expires_at > prev_expires_at) {
+			return false;
+		}
+	}
+
+	if payload.bucket_id.is_some() && auth_token_chain_any(prev, |p| p.bucket_id.is_some()) {
+		return false;
+	}
+
+	if payload.piece_cid.is_some() && auth_token_chain_any(prev, |p| p.piece_cid.is_some()) {
+		return false;
+	}
+
+	verify_signature_by(token.clone(), signer) && verify_auth_token(prev, depth + 1)
+}
+
+fn auth_token_chain_any(token: &proto::AuthToken, f: impl Fn(&proto::Payload) -> bool) -> bool {
+	let mut next = Some(token);
+	while let Some(token) = next {
+		let payload = match &token.payload {
+			Some(payload) => payload,
+			None => return false,
+		};
+		if f(payload) {
+			return true;
+		}
+		next = payload.prev.as_deref();
+	}
+
+	false
+}
+
+/// Checks that a delegated auth token grants the access to a bucket to a signer.
+///
+/// The first token in the chain has to be issued by the given issuer and no token in the chain can
+/// restrict the access to another bucket. The access is granted to the subject of the last token,
+/// or to any holder if the last token has no subject.
+pub(crate) fn auth_token_authorizes(
+	token: &proto::AuthToken,
+	issuer: &[u8],
+	bucket_id: u64,
+	signer: &[u8],
+) -> bool {
+	if auth_token_chain_any(token, |p| p.bucket_id.is_some_and(|id| id != bucket_id)) {
+		return false;
+	}
+
+	let is_holder = match token.payload.as_ref().and_then(|p| p.subject.as_ref()) {
+		Some(subject) if !subject.is_empty() => subject.as_slice() == signer,
+		_ => true,
+	};
+
+	is_holder && auth_token_issuer(token) == Some(issuer)
+}
+
+/// Signer of the first token in the chain of a delegated auth token.
+fn auth_token_issuer(token: &proto::AuthToken) -> Option<&[u8]> {
+	let mut token = token;
+	for _ in 0..=MAX_CHAIN_DEPTH {
+		match token.payload.as_ref().and_then(|p| p.prev.as_deref()) {
+			Some(prev) => token = prev,
+			None => return token.signature.as_ref().map(|s| s.signer.as_slice()),
+		}
+	}
+
+	None
+}
+
+/// Request a client issued, i.e. the first request in the chain of parent requests of a fulfilled
+/// request.
+pub(crate) fn client_request(
+	fulfillment: &proto::ActivityFulfillment,
+) -> Option<&proto::ActivityRequest> {
+	let mut request = fulfillment.request.as_ref()?;
+	while let Some(parent) = request.parent_request.as_deref() {
+		request = parent;
+	}
+
+	Some(request)
+}
+
+/// Signers of a fulfilled request, its parent requests and its acknowledgment.
+pub(crate) fn fulfillment_signers(fulfillment: &proto::ActivityFulfillment) -> Vec<&[u8]> {
+	let mut signers = Vec::new();
+
+	let mut next = fulfillment.request.as_ref();
+	while let Some(request) = next {
+		signers.extend(request.signature.as_ref().map(|s| s.signer.as_slice()));
+		next = request.parent_request.as_deref();
+	}
+
+	if let Some(ack) = &fulfillment.ack {
+		signers.extend(ack.signature.as_ref().map(|s| s.signer.as_slice()));
+	}
+
+	signers
+}
+
 impl Verify for proto::challenge_response::proof::Record {
 	fn verify(&self) -> bool {
 		if let Some(record) = &self.record {
@@ -100,6 +298,27 @@ impl Verify for proto::ChallengeResponse {
 	}
 }
 
+impl Verify for proto::SignedResponse {
+	fn verify(&self) -> bool {
+		let signature = match &self.signature {
+			Some(s) => s,
+			None => return false,
+		};
+
+		let sig = match Signature::try_from(signature.value.as_slice()) {
+			Ok(s) => s,
+			Err(_) => return false,
+		};
+
+		let pub_key = match Public::try_from(signature.signer.as_slice()) {
+			Ok(p) => p,
+			Err(_) => return false,
+		};
+
+		ed25519_verify(&sig, self.payload.as_slice(), &pub_key)
+	}
+}
+
 impl<T: Serialize> Verify for json::SignedJsonResponse<T> {
 	fn verify(&self) -> bool {
 		let sig = match Signature::try_from(self.signature.as_slice()) {
@@ -121,6 +340,22 @@ impl<T: Serialize> Verify for json::SignedJsonResponse<T> {
 	}
 }
 
+impl Verify for json::NodeHandshakeResponse {
+	fn verify(&self) -> bool {
+		let sig = match Signature::try_from(self.signature.as_slice()) {
+			Ok(s) => s,
+			Err(_) => return false,
+		};
+
+		let pub_key = match Public::try_from(self.signer.as_slice()) {
+			Ok(p) => p,
+			Err(_) => return false,
+		};
+
+		ed25519_verify(&sig, self.nonce.as_slice(), &pub_key)
+	}
+}
+
 trait Signed {
 	fn get_signature(&self) -> Option<&proto::Signature>;
 	fn reset_signature(&mut self);
@@ -141,9 +376,24 @@ macro_rules! impl_signed {
 	}
 }
 
-impl_signed!(for proto::ActivityAcknowledgment, proto::ActivityRecord, proto::ActivityRequest);
+impl_signed!(
+	for proto::ActivityAcknowledgment,
+	proto::ActivityRecord,
+	proto::ActivityRequest,
+	proto::AuthToken
+);
+
+fn verify_signature(signed: impl Clone + Message + Signed) -> bool {
+	let signer = match signed.get_signature() {
+		Some(s) => s.signer.clone(),
+		None => return false,
+	};
 
-fn verify_signature(mut signed: impl Clone + Message + Signed) -> bool {
+	verify_signature_by(signed, &signer)
+}
+
+/// Verifies the message signature against the given signer instead of the one in the signature.
+fn verify_signature_by(mut signed: impl Clone + Message + Signed, signer: &[u8]) -> bool {
 	let signature = match signed.get_signature() {
 		Some(s) => s.clone(),
 		None => return false,
@@ -156,7 +406,7 @@ fn verify_signature(mut signed: impl Clone + Message + Signed) -> bool {
 	signed.reset_signature();
 	let payload = signed.encode_to_vec();
 
-	let pub_key = match Public::try_from(signature.signer.as_slice()) {
+	let pub_key = match Public::try_from(signer) {
 		Ok(p) => p,
 		Err(_) => return false,
 	};
@@ -221,4 +471,201 @@ mod tests {
 			.expect("protobuf fixture decoding failed, fix the test data");
 		assert!(challenge_response.verify());
 	}
+
+	fn sign_request(request: &mut proto::ActivityRequest, signer: &sp_core::ed25519::Pair) {
+		request.signature = None;
+		let signature = signer.sign(request.encode_to_vec().as_slice());
+		request.signature = Some(proto::Signature {
+			algorithm: proto::signature::Algorithm::Ed25519 as i32,
+			value: signature.0.to_vec(),
+			signer: signer.public().0.to_vec(),
+		});
+	}
+
+	fn sign_auth_token(
+		token: &mut proto::AuthToken,
+		signer: &sp_core::ed25519::Pair,
+		with_signer: bool,
+	) {
+		token.signature = None;
+		let signature = signer.sign(token.encode_to_vec().as_slice());
+		token.signature = Some(proto::Signature {
+			algorithm: proto::signature::Algorithm::Ed25519 as i32,
+			value: signature.0.to_vec(),
+			signer: if with_signer { signer.public().0.to_vec() } else { Vec::new() },
+		});
+	}
+
+	fn forwarded_put_request(
+		client: &sp_core::ed25519::Pair,
+		node: &sp_core::ed25519::Pair,
+		parent_timestamp: u64,
+	) -> proto::ActivityRequest {
+		// the client signs the request before the piece CID is known
+		let mut parent = proto::ActivityRequest {
+			request_id: "parent".to_string(),
+			request_type: proto::activity_request::RequestType::Put as i32,
+			bucket_id: 1,
+			size: 1024,
+			timestamp: parent_timestamp,
+			..Default::default()
+		};
+		sign_request(&mut parent, client);
+		parent.piece_cid = vec![1, 2, 3];
+
+		let mut request = proto::ActivityRequest {
+			parent_request: Some(Box::new(parent)),
+			request_id: "child".to_string(),
+			request_type: proto::activity_request::RequestType::Put as i32,
+			content_type: proto::activity_request::ContentType::Segment as i32,
+			piece_cid: vec![1, 2, 3],
+			size: 1024,
+			timestamp: 2,
+			..Default::default()
+		};
+		sign_request(&mut request, node);
+
+		request
+	}
+
+	#[test]
+	fn verify_parent_requests_works() {
+		let client = sp_core::ed25519::Pair::generate().0;
+		let node = sp_core::ed25519::Pair::generate().0;
+
+		assert!(forwarded_put_request(&client, &node, 1).verify());
+
+		// parent issued after the forwarded request
+		assert!(!forwarded_put_request(&client, &node, 3).verify());
+
+		// parent signed by someone else than the signer in its signature
+		let mut request = forwarded_put_request(&client, &node, 1);
+		let mut parent = request.parent_request.take().unwrap();
+		parent.signature.as_mut().unwrap().signer = node.public().0.to_vec();
+		request.parent_request = Some(parent);
+		sign_request(&mut request, &node);
+		assert!(!request.verify());
+
+		// only the CID of a `PUT` request can be filled in after signing
+		let mut request = forwarded_put_request(&client, &node, 1);
+		let mut parent = request.parent_request.take().unwrap();
+		parent.request_type = proto::activity_request::RequestType::Get as i32;
+		sign_request(&mut parent, &client);
+		parent.piece_cid = vec![4, 5, 6];
+		request.parent_request = Some(parent);
+		sign_request(&mut request, &node);
+		assert!(!request.verify());
+	}
+
+	#[test]
+	fn verify_parent_requests_with_tampered_cid_fails() {
+		let client = sp_core::ed25519::Pair::generate().0;
+		let node = sp_core::ed25519::Pair::generate().0;
+
+		// the CID filled in does not refer to the piece the request was forwarded for
+		let mut request = forwarded_put_request(&client, &node, 1);
+		request.parent_request.as_mut().unwrap().piece_cid = vec![4, 5, 6];
+		sign_request(&mut request, &node);
+		assert!(!request.verify());
+
+		// the CID signed by the client is replaced
+		let mut request = forwarded_put_request(&client, &node, 1);
+		let mut parent = request.parent_request.take().unwrap();
+		sign_request(&mut parent, &client);
+		parent.piece_cid = vec![4, 5, 6];
+		request.parent_request = Some(parent);
+		request.piece_cid = vec![4, 5, 6];
+		sign_request(&mut request, &node);
+		assert!(!request.verify());
+	}
+
+	#[test]
+	fn verify_challenge_response_with_tampered_parent_request_fails() {
+		let challenge_response_serialized =
+			include_bytes!("./test_data/challenge_response.pb").as_slice();
+		let mut challenge_response =
+			proto::ChallengeResponse::decode(challenge_response_serialized)
+				.expect("protobuf fixture decoding failed, fix the test data");
+
+		let leaf = &mut challenge_response.proofs[0].leaves[0];
+		let record = match &mut leaf.leaf_variant {
+			Some(proto::challenge_response::proof::leaf::LeafVariant::Record(record)) =>
+				record.record.as_mut().unwrap(),
+			_ => panic!("record leaf is expected, fix the test data"),
+		};
+		let request = record.downstream[0].request.as_mut().unwrap();
+		request.parent_request.as_mut().unwrap().bucket_id += 1;
+
+		assert!(!challenge_response.verify());
+	}
+
+	fn delegated_auth_token(
+		issuer: &sp_core::ed25519::Pair,
+		delegate: &sp_core::ed25519::Pair,
+		can_delegate: bool,
+		operations: Vec<proto::Operation>,
+	) -> proto::AuthToken {
+		let mut prev = proto::AuthToken {
+			signature: None,
+			payload: Some(proto::Payload {
+				subject: Some(delegate.public().0.to_vec()),
+				can_delegate: Some(can_delegate),
+				bucket_id: Some(1),
+				operations: vec![proto::Operation::Get as i32, proto::Operation::Put as i32],
+				expires_at: Some(100),
+				..Default::default()
+			}),
+		};
+		sign_auth_token(&mut prev, issuer, true);
+
+		let mut token = proto::AuthToken {
+			signature: None,
+			payload: Some(proto::Payload {
+				prev: Some(Box::new(prev)),
+				operations: operations.into_iter().map(|op| op as i32).collect(),
+				expires_at: Some(50),
+				..Default::default()
+			}),
+		};
+		sign_auth_token(&mut token, delegate, false);
+
+		token
+	}
+
+	#[test]
+	fn verify_delegated_auth_token_works() {
+		let issuer = sp_core::ed25519::Pair::generate().0;
+		let delegate = sp_core::ed25519::Pair::generate().0;
+		let other = sp_core::ed25519::Pair::generate().0;
+
+		assert!(
+			delegated_auth_token(&issuer, &delegate, true, vec![proto::Operation::Get]).verify()
+		);
+
+		// delegation is prohibited by the issuer
+		assert!(
+			!delegated_auth_token(&issuer, &delegate, false, vec![proto::Operation::Get]).verify()
+		);
+
+		// delegated token allows more operations than the previous one
+		assert!(!delegated_auth_token(&issuer, &delegate, true, vec![proto::Operation::Delete])
+			.verify());
+
+		// delegated token is not signed by the subject of the previous one
+		let mut token = delegated_auth_token(&issuer, &delegate, true, vec![]);
+		sign_auth_token(&mut token, &other, false);
+		assert!(!token.verify());
+
+		// delegated token expires later than the previous one
+		let mut token = delegated_auth_token(&issuer, &delegate, true, vec![]);
+		token.payload.as_mut().unwrap().expires_at = Some(200);
+		sign_auth_token(&mut token, &delegate, false);
+		assert!(!token.verify());
+
+		// bucket is already set earlier in the chain
+		let mut token = delegated_auth_token(&issuer, &delegate, true, vec![]);
+		token.payload.as_mut().unwrap().bucket_id = Some(2);
+		sign_auth_token(&mut token, &delegate, false);
+		assert!(!token.verify());
+	}
 }
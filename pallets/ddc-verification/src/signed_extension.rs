@@ -0,0 +1,104 @@
+//! Transaction pool checks of the era payout steps submitted by validators.
+
+use codec::{Decode, Encode};
+use frame_support::traits::IsSubType;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+use sp_std::{fmt, marker::PhantomData};
+
+use crate::{Call, Config, Pallet};
+
+/// Rejects a payout step of an era billing report that is already executed, e.g. a batch of
+/// customers charged by another validator, before it is included in a block.
+///
+/// Both signed payout step calls and the ones submitted by validators as unsigned transactions
+/// are checked.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckPayoutStep<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckPayoutStep<T> {
+	/// Create new `SignedExtension` to check payout steps.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckPayoutStep<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> fmt::Debug for CheckPayoutStep<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CheckPayoutStep")
+	}
+}
+
+impl<T: Config + Send + Sync> CheckPayoutStep<T>
+where
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+	fn check(call: &<T as frame_system::Config>::RuntimeCall) -> TransactionValidity {
+		let call = match call.is_sub_type() {
+			Some(Call::submit_validator_call { payload, .. }) => payload.call.as_ref(),
+			Some(call) => call,
+			None => return Ok(ValidTransaction::default()),
+		};
+
+		match Pallet::<T>::payout_step(call) {
+			Some(step) if Pallet::<T>::is_payout_step_executed(&step) =>
+				InvalidTransaction::Stale.into(),
+			_ => Ok(ValidTransaction::default()),
+		}
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckPayoutStep<T>
+where
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "CheckPayoutStep";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		Self::check(call)
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<(), TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+
+	fn validate_unsigned(
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		Self::check(call)
+	}
+}
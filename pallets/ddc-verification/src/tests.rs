@@ -1,8 +1,14 @@
 use ddc_primitives::{
-	AggregatorInfo, ClusterId, DeltaUsageHash, MergeMMRHash, StorageNodeMode, StorageNodeParams,
-	StorageNodePubKey, DAC_VERIFICATION_KEY_TYPE,
+	AggregatorInfo, BucketId, ClusterId, DeltaUsageHash, MergeMMRHash, OcwChallengeMetrics,
+	OcwMetrics, OcwSettings, StorageNodeMode, StorageNodeParams, StorageNodePubKey,
+	DAC_VERIFICATION_KEY_TYPE, OCW_METRICS_KEY, OCW_SETTINGS_KEY,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchInfo,
+	pallet_prelude::{InvalidTransaction, TransactionSource, TransactionValidity},
+	unsigned::ValidateUnsigned,
 };
-use frame_support::{assert_noop, assert_ok};
 use prost::Message;
 use sp_core::{
 	offchain::{
@@ -13,7 +19,7 @@ use sp_core::{
 };
 use sp_io::TestExternalities;
 use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
-use sp_runtime::{offchain::Duration, AccountId32};
+use sp_runtime::{offchain::Duration, traits::SignedExtension, AccountId32};
 
 use crate::{mock::*, Error, *};
 
@@ -42,6 +48,11 @@ fn get_validators() -> Vec<AccountId32> {
 	vec![validator1, validator2, validator3, validator4, validator5]
 }
 
+/// Headers of a request to an aggregator serving the response either protobuf encoded or as JSON.
+fn accept_protobuf_or_json() -> Vec<(String, String)> {
+	vec![("Accept".into(), "application/protobuf, application/json;q=0.9".into())]
+}
+
 fn get_node_activities() -> Vec<aggregator_client::json::NodeAggregate> {
 	let aggregator = AggregatorInfo {
 		node_pub_key: NodePubKey::StoragePubKey(AccountId32::new([1; 32])),
@@ -137,6 +148,7 @@ fn fetch_node_aggregates_works() {
 		// Mock HTTP request and response
 		let pending_request = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!(
 				"http://{}:{}/activity/nodes?eraId={}&limit={}",
 				host,
@@ -163,9 +175,15 @@ fn fetch_node_aggregates_works() {
 			domain: b"example2.com".to_vec(),
 		};
 
-		let result = Pallet::<Test>::fetch_node_aggregates(&cluster_id, era_id, &node_params);
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::new([1; 32]));
+
+		let result = Pallet::<Test>::fetch_nodes_aggregates_for_era(
+			&cluster_id,
+			era_id,
+			&[(node_pub_key, node_params)],
+		);
 		assert!(result.is_ok());
-		let activities = result.unwrap();
+		let (_, activities) = result.unwrap().remove(0);
 		assert_eq!(activities[0].number_of_gets, node_activity1.number_of_gets);
 		assert_eq!(activities[0].number_of_puts, node_activity1.number_of_puts);
 		assert_eq!(activities[0].transferred_bytes, node_activity1.transferred_bytes);
@@ -178,6 +196,228 @@ fn fetch_node_aggregates_works() {
 	});
 }
 
+#[test]
+fn fetch_node_aggregates_uses_operator_settings() {
+	let mut ext = TestExternalities::default();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, _) = TestTransactionPoolExt::new();
+
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(Box::new(offchain)));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::new([1; 32]));
+		let settings = OcwSettings {
+			nodes_aggregates_batch_size: Some(1),
+			endpoint_overrides: vec![(
+				node_pub_key.clone(),
+				b"http://dac-1.internal:8080/".to_vec(),
+			)],
+			..Default::default()
+		};
+		local_storage_set(StorageKind::PERSISTENT, OCW_SETTINGS_KEY, &settings.encode());
+
+		let node_activity = |node_id: &str| aggregator_client::json::NodeAggregateResponse {
+			node_id: node_id.to_string(),
+			stored_bytes: 100,
+			transferred_bytes: 50,
+			number_of_puts: 10,
+			number_of_gets: 20,
+		};
+
+		// Nodes aggregates are paged by one and fetched from the overridden endpoint
+		let mut offchain_state = offchain_state.write();
+		offchain_state.timestamp = Timestamp::from_unix_millis(0);
+		for (query, response) in [
+			("", vec![node_activity("1")]),
+			("&prevToken=1", vec![node_activity("2")]),
+			("&prevToken=2", vec![]),
+		] {
+			offchain_state.expect_request(PendingRequest {
+				method: "GET".to_string(),
+				headers: accept_protobuf_or_json(),
+				uri: format!("http://dac-1.internal:8080/activity/nodes?eraId=1&limit=1{}", query),
+				response: Some(serde_json::to_vec(&response).unwrap()),
+				sent: true,
+				..Default::default()
+			});
+		}
+		drop(offchain_state);
+
+		let node_params = StorageNodeParams {
+			ssl: false,
+			host: b"example.com".to_vec(),
+			http_port: 80,
+			mode: StorageNodeMode::DAC,
+			p2p_port: 5555,
+			grpc_port: 4444,
+			domain: b"example2.com".to_vec(),
+		};
+
+		let (_, activities) = Pallet::<Test>::fetch_nodes_aggregates_for_era(
+			&ClusterId::from([1; 20]),
+			1,
+			&[(node_pub_key, node_params)],
+		)
+		.unwrap()
+		.remove(0);
+		assert_eq!(
+			activities.iter().map(|a| a.node_id.as_str()).collect::<Vec<_>>(),
+			vec!["1", "2"]
+		);
+	});
+}
+
+#[test]
+fn nodes_aggregates_are_fetched_concurrently_skipping_failed_aggregators() {
+	let mut ext = TestExternalities::default();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, _) = TestTransactionPoolExt::new();
+
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(Box::new(offchain)));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		let settings = OcwSettings { nodes_aggregates_batch_size: Some(1), ..Default::default() };
+		local_storage_set(StorageKind::PERSISTENT, OCW_SETTINGS_KEY, &settings.encode());
+
+		let node_activity = |node_id: &str| aggregator_client::json::NodeAggregateResponse {
+			node_id: node_id.to_string(),
+			stored_bytes: 100,
+			transferred_bytes: 50,
+			number_of_puts: 10,
+			number_of_gets: 20,
+		};
+
+		let mut offchain_state = offchain_state.write();
+		offchain_state.timestamp = Timestamp::from_unix_millis(0);
+		// the first aggregator is paged by one, the second one responds with garbage
+		for (host, query, response) in [
+			("dac-1.com", "", serde_json::to_vec(&vec![node_activity("1")]).unwrap()),
+			("dac-2.com", "", b"garbage".to_vec()),
+			("dac-1.com", "&prevToken=1", serde_json::to_vec(&vec![node_activity("2")]).unwrap()),
+			("dac-2.com", "", b"garbage".to_vec()),
+			("dac-1.com", "&prevToken=2", b"[]".to_vec()),
+			("dac-2.com", "", b"garbage".to_vec()),
+		] {
+			offchain_state.expect_request(PendingRequest {
+				method: "GET".to_string(),
+				headers: accept_protobuf_or_json(),
+				uri: format!("http://{}:8080/activity/nodes?eraId=1&limit=1{}", host, query),
+				response: Some(response),
+				sent: true,
+				..Default::default()
+			});
+		}
+		drop(offchain_state);
+
+		let dac_nodes = get_dac_nodes_params(&["dac-1.com", "dac-2.com"]);
+		let aggregates = Pallet::<Test>::fetch_nodes_aggregates_for_era(
+			&ClusterId::from([1; 20]),
+			1,
+			&dac_nodes,
+		)
+		.unwrap();
+
+		assert_eq!(aggregates.len(), 1);
+		assert_eq!(aggregates[0].0.node_pub_key, dac_nodes[0].0);
+		assert_eq!(
+			aggregates[0].1.iter().map(|a| a.node_id.as_str()).collect::<Vec<_>>(),
+			vec!["1", "2"]
+		);
+	});
+}
+
+#[test]
+fn ocw_settings_select_clusters_to_validate() {
+	let mut ext = TestExternalities::default();
+	let (offchain, _) = TestOffchainExt::new();
+	ext.register_extension(OffchainDbExt::new(Box::new(offchain)));
+
+	ext.execute_with(|| {
+		let cluster_1 = ClusterId::from([1; 20]);
+		let cluster_2 = ClusterId::from([2; 20]);
+		let cluster_3 = ClusterId::from([3; 20]);
+
+		// All clusters are validated by default
+		let settings = Pallet::<Test>::ocw_settings();
+		assert_eq!(settings, OcwSettings::default());
+		assert!(settings.is_cluster_enabled(&cluster_1));
+
+		let settings = OcwSettings {
+			clusters: vec![cluster_1, cluster_2],
+			excluded_clusters: vec![cluster_2],
+			..Default::default()
+		};
+		local_storage_set(StorageKind::PERSISTENT, OCW_SETTINGS_KEY, &settings.encode());
+
+		let settings = Pallet::<Test>::ocw_settings();
+		assert!(settings.is_cluster_enabled(&cluster_1));
+		assert!(!settings.is_cluster_enabled(&cluster_2));
+		assert!(!settings.is_cluster_enabled(&cluster_3));
+	});
+}
+
+#[test]
+fn ocw_metrics_are_accumulated_in_offchain_storage() {
+	let mut ext = TestExternalities::default();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(Box::new(offchain)));
+
+	ext.execute_with(|| {
+		let base_url = "http://example.com:8080";
+		let aggregator = NodePubKey::StoragePubKey(AccountId32::new([1; 32]));
+		let cluster_id = ClusterId::from([1; 20]);
+
+		let mut offchain_state = offchain_state.write();
+		for _ in 0..2 {
+			offchain_state.expect_request(PendingRequest {
+				method: "GET".to_string(),
+				headers: accept_protobuf_or_json(),
+				uri: format!("{}/activity/eras", base_url),
+				response: Some(b"[]".to_vec()),
+				sent: true,
+				..Default::default()
+			});
+		}
+		drop(offchain_state);
+
+		let client =
+			AggregatorClient::new(base_url, Duration::from_millis(1_000), 1, &aggregator, false);
+		assert_ok!(client.eras());
+		assert_ok!(client.eras());
+
+		metrics::record_run(&cluster_id);
+		metrics::record_challenge(&cluster_id, true);
+		metrics::record_challenge(&cluster_id, false);
+		metrics::record_submitted_errors(&[
+			OCWError::FailedToFetchDacNodes,
+			OCWError::EraRetrievalError { cluster_id, node_pub_key: Some(aggregator.clone()) },
+			OCWError::FailedToFetchDacNodes,
+		]);
+
+		let metrics = local_storage_get(StorageKind::PERSISTENT, OCW_METRICS_KEY)
+			.and_then(|encoded| OcwMetrics::decode(&mut &encoded[..]).ok())
+			.unwrap();
+
+		let requests = &metrics.aggregator_requests[&aggregator];
+		assert_eq!((requests.requests, requests.errors), (2, 0));
+		assert_eq!(metrics.runs[&cluster_id], 1);
+		assert_eq!(metrics.challenges[&cluster_id], OcwChallengeMetrics { runs: 2, failures: 1 });
+		assert_eq!(
+			metrics.submitted_errors,
+			BTreeMap::from([
+				(b"EraRetrievalError".to_vec(), 1),
+				(b"FailedToFetchDacNodes".to_vec(), 2)
+			])
+		);
+	});
+}
+
 #[test]
 fn fetch_bucket_aggregates_works() {
 	let mut ext = TestExternalities::default();
@@ -231,6 +471,7 @@ fn fetch_bucket_aggregates_works() {
 		// Mock HTTP request and response
 		let pending_request = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!(
 				"http://{}:{}/activity/buckets?eraId={}&limit={}",
 				host,
@@ -257,9 +498,15 @@ fn fetch_bucket_aggregates_works() {
 			domain: b"example2.com".to_vec(),
 		};
 
-		let result = Pallet::<Test>::fetch_bucket_aggregates(&cluster_id, era_id, &node_params);
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::new([1; 32]));
+
+		let result = Pallet::<Test>::fetch_buckets_aggregates_for_era(
+			&cluster_id,
+			era_id,
+			&[(node_pub_key, node_params)],
+		);
 		assert!(result.is_ok());
-		let activities = result.unwrap();
+		let (_, activities) = result.unwrap().remove(0);
 		assert_eq!(
 			activities[0].sub_aggregates[0].number_of_gets,
 			bucket_aggregate1.sub_aggregates[0].number_of_gets
@@ -1607,6 +1854,7 @@ fn bucket_sub_aggregates_are_fetched_and_grouped() {
 
 		let pending_request1 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=476817&limit={}", host1, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"bucket_id":90235,"stored_bytes":0,"transferred_bytes":38,"number_of_puts":0,"number_of_gets":1,"sub_aggregates":[{"NodeID":"0xb6186f80dce7190294665ab53860de2841383bb202c562bb8b81a624351fa318","stored_bytes":578,"transferred_bytes":578,"number_of_puts":2,"number_of_gets":0}]},{"bucket_id":90235,"stored_bytes":0,"transferred_bytes":38,"number_of_puts":0,"number_of_gets":1,"sub_aggregates":[{"NodeID":"0xb6186f80dce7190294665ab53860de2841383bb202c562bb8b81a624351fa319","stored_bytes":0,"transferred_bytes":505,"number_of_puts":0,"number_of_gets":1}]}]"#.to_vec()),
 			sent: true,
@@ -1615,6 +1863,7 @@ fn bucket_sub_aggregates_are_fetched_and_grouped() {
 
 		let pending_request2 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=476817&limit={}", host2, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"bucket_id":90235,"stored_bytes":0,"transferred_bytes":38,"number_of_puts":0,"number_of_gets":1,"sub_aggregates":[{"NodeID":"0xb6186f80dce7190294665ab53860de2841383bb202c562bb8b81a624351fa318","stored_bytes":578,"transferred_bytes":578,"number_of_puts":2,"number_of_gets":0}]},{"bucket_id":90235,"stored_bytes":0,"transferred_bytes":38,"number_of_puts":0,"number_of_gets":1,"sub_aggregates":[{"NodeID":"0xb6186f80dce7190294665ab53860de2841383bb202c562bb8b81a624351fa319","stored_bytes":0,"transferred_bytes":506,"number_of_puts":0,"number_of_gets":1}]}]"#.to_vec()),
 			sent: true,
@@ -1623,6 +1872,7 @@ fn bucket_sub_aggregates_are_fetched_and_grouped() {
 
 		let pending_request3 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=476817&limit={}", host3, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"bucket_id":90235,"stored_bytes":0,"transferred_bytes":38,"number_of_puts":0,"number_of_gets":1,"sub_aggregates":[{"NodeID":"0xb6186f80dce7190294665ab53860de2841383bb202c562bb8b81a624351fa318","stored_bytes":578,"transferred_bytes":578,"number_of_puts":2,"number_of_gets":0}]},{"bucket_id":90235,"stored_bytes":0,"transferred_bytes":38,"number_of_puts":0,"number_of_gets":1,"sub_aggregates":[{"NodeID":"0xb6186f80dce7190294665ab53860de2841383bb202c562bb8b81a624351fa319","stored_bytes":0,"transferred_bytes":505,"number_of_puts":0,"number_of_gets":1}]}]"#.to_vec()),
 			sent: true,
@@ -1631,6 +1881,7 @@ fn bucket_sub_aggregates_are_fetched_and_grouped() {
 
 		let pending_request4 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=476817&limit={}", host4, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"bucket_id":90235,"stored_bytes":0,"transferred_bytes":38,"number_of_puts":0,"number_of_gets":1,"sub_aggregates":[]}]"#.to_vec()),
 			sent: true,
@@ -1639,6 +1890,7 @@ fn bucket_sub_aggregates_are_fetched_and_grouped() {
 
 		let pending_request5 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=476817&limit={}", host5, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"bucket_id":90235,"stored_bytes":0,"transferred_bytes":38,"number_of_puts":0,"number_of_gets":1,"sub_aggregates":[{"NodeID":"0xb6186f80dce7190294665ab53860de2841383bb202c562bb8b81a624351fa320","stored_bytes":578,"transferred_bytes":578,"number_of_puts":2,"number_of_gets":0}]}]"#.to_vec()),
 			sent: true,
@@ -1872,6 +2124,7 @@ fn node_aggregates_are_fetched_and_grouped() {
 
 		let pending_request1 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=476817&limit={}", host1, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0x48594f1fd4f05135914c42b03e63b61f6a3e4c537ccee3dbac555ef6df371b7e","stored_bytes": 675613289,"transferred_bytes": 1097091579,"number_of_puts": 889,"number_of_gets": 97},{"node_id": "0x9ef98ad9c3626ba725e78d76cfcfc4b4d07e84f0388465bc7eb992e3e117234a","stored_bytes": 0, "transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]"#.to_vec()),
 			sent: true,
@@ -1880,6 +2133,7 @@ fn node_aggregates_are_fetched_and_grouped() {
 
 		let pending_request2 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=476817&limit={}", host2, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0x48594f1fd4f05135914c42b03e63b61f6a3e4c537ccee3dbac555ef6df371b7e","stored_bytes": 675613289,"transferred_bytes": 1097091579,"number_of_puts": 889,"number_of_gets": 97},{"node_id": "0x9ef98ad9c3626ba725e78d76cfcfc4b4d07e84f0388465bc7eb992e3e117234a","stored_bytes": 0, "transferred_bytes": 48,"number_of_puts": 0,"number_of_gets": 1}]"#.to_vec()),
 			sent: true,
@@ -1888,6 +2142,7 @@ fn node_aggregates_are_fetched_and_grouped() {
 
 		let pending_request3 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=476817&limit={}", host3, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0x48594f1fd4f05135914c42b03e63b61f6a3e4c537ccee3dbac555ef6df371b7e","stored_bytes": 675613289,"transferred_bytes": 1097091579,"number_of_puts": 889,"number_of_gets": 97}]"#.to_vec()),
 			sent: true,
@@ -1896,6 +2151,7 @@ fn node_aggregates_are_fetched_and_grouped() {
 
 		let pending_request4 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=476817&limit={}", host4, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0x9ef98ad9c3626ba725e78d76cfcfc4b4d07e84f0388465bc7eb992e3e117234a","stored_bytes": 0, "transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]"#.to_vec()),
 			sent: true,
@@ -1904,6 +2160,7 @@ fn node_aggregates_are_fetched_and_grouped() {
 
 		let pending_request5 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=476817&limit={}", host5, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0xfc28d5f5bb10212077a8654f62c4f8f0b5ab985fc322a51f5a3c75943b29194b","stored_bytes": 675613289,"transferred_bytes": 1097091579,"number_of_puts": 889,"number_of_gets": 97}]"#.to_vec()),
 			sent: true,
@@ -2198,6 +2455,7 @@ fn fetch_processed_era_works() {
         // Mock HTTP request and response
         let pending_request = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host, port),
             response: Some(br#"[{"id":17,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":18,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":19,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2216,7 +2474,9 @@ fn fetch_processed_era_works() {
             domain: b"example2.com".to_vec(),
         };
 
-        let result = Pallet::<Test>::fetch_processed_eras(&node_params);
+        let node_pub_key = NodePubKey::StoragePubKey(AccountId32::new([1; 32]));
+
+        let result = Pallet::<Test>::fetch_processed_eras(&node_pub_key, &node_params);
         assert!(result.is_ok());
         let activities = result.unwrap();
 
@@ -2257,6 +2517,7 @@ fn get_era_for_validation_works() {
 
         let pending_request1 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host1, port),
             response: Some(br#"[{"id":16,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":17,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":18,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":19,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2264,6 +2525,7 @@ fn get_era_for_validation_works() {
         };
         let pending_request2 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host2, port),
             response: Some(br#"[{"id":16,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":17,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":18,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2271,6 +2533,7 @@ fn get_era_for_validation_works() {
         };
         let pending_request3 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host3, port),
             response: Some(br#"[{"id":16,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":17,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":18,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2278,6 +2541,7 @@ fn get_era_for_validation_works() {
         };
         let pending_request4 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host4, port),
             response: Some(br#"[{"id":16,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":17,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":18,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2363,7 +2627,7 @@ fn test_get_last_validated_era() {
 
 		let mut validators_map_1 = BTreeMap::new();
 		validators_map_1.insert(
-			(payers_root, payees_root),
+			(payers_root, payees_root, 1, 2),
 			vec![validators[1].clone(), validators[2].clone(), validators[3].clone()],
 		);
 
@@ -2393,8 +2657,10 @@ fn test_get_last_validated_era() {
 		));
 
 		let mut validators_map_2 = BTreeMap::new();
-		validators_map_2
-			.insert((payers_root, payees_root), vec![validators[2].clone(), validators[3].clone()]);
+		validators_map_2.insert(
+			(payers_root, payees_root, 1, 2),
+			vec![validators[2].clone(), validators[3].clone()],
+		);
 
 		let validation_2 = EraValidation {
 			validators: validators_map_2,
@@ -2597,6 +2863,7 @@ fn test_single_ocw_pallet_integration() {
 
         let pending_request1 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host1, port),
             response: Some(br#"[{"id":5738616,"status":"PROCESSED","start":1721584800000,"end":1721585099999,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":5738617,"status":"PROCESSED","start":1721585100000,"end":1721585399999,"processing_time":1818,"nodes_total":9,"nodes_processed":9,"records_processed":16,"records_applied":16,"records_discarded":0,"attempt":0},{"id":5738618,"status":"PROCESSED","start":1721585400000,"end":1721585699999,"processing_time":1997,"nodes_total":9,"nodes_processed":9,"records_processed":622,"records_applied":622,"records_discarded":0,"attempt":0},{"id":5738619,"status":"PROCESSED","start":1721585700000,"end":1721585999999,"processing_time":2118,"nodes_total":9,"nodes_processed":9,"records_processed":834,"records_applied":834,"records_discarded":0,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2604,6 +2871,7 @@ fn test_single_ocw_pallet_integration() {
         };
         let pending_request2 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host2, port),
             response: Some(br#"[{"id":5738616,"status":"PROCESSED","start":1721584800000,"end":1721585099999,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":5738617,"status":"PROCESSED","start":1721585100000,"end":1721585399999,"processing_time":1818,"nodes_total":9,"nodes_processed":9,"records_processed":16,"records_applied":16,"records_discarded":0,"attempt":0},{"id":5738618,"status":"PROCESSED","start":1721585400000,"end":1721585699999,"processing_time":1997,"nodes_total":9,"nodes_processed":9,"records_processed":622,"records_applied":622,"records_discarded":0,"attempt":0},{"id":5738619,"status":"PROCESSED","start":1721585700000,"end":1721585999999,"processing_time":2118,"nodes_total":9,"nodes_processed":9,"records_processed":834,"records_applied":834,"records_discarded":0,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2611,6 +2879,7 @@ fn test_single_ocw_pallet_integration() {
         };
         let pending_request3 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host3, port),
             response: Some(br#"[{"id":5738616,"status":"PROCESSED","start":1721584800000,"end":1721585099999,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":5738617,"status":"PROCESSED","start":1721585100000,"end":1721585399999,"processing_time":1818,"nodes_total":9,"nodes_processed":9,"records_processed":16,"records_applied":16,"records_discarded":0,"attempt":0},{"id":5738618,"status":"PROCESSED","start":1721585400000,"end":1721585699999,"processing_time":1997,"nodes_total":9,"nodes_processed":9,"records_processed":622,"records_applied":622,"records_discarded":0,"attempt":0},{"id":5738619,"status":"PROCESSED","start":1721585700000,"end":1721585999999,"processing_time":2118,"nodes_total":9,"nodes_processed":9,"records_processed":834,"records_applied":834,"records_discarded":0,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2618,6 +2887,7 @@ fn test_single_ocw_pallet_integration() {
         };
         let pending_request4 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host4, port),
             response: Some(br#"[{"id":5738616,"status":"PROCESSED","start":1721584800000,"end":1721585099999,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":5738617,"status":"PROCESSED","start":1721585100000,"end":1721585399999,"processing_time":1818,"nodes_total":9,"nodes_processed":9,"records_processed":16,"records_applied":16,"records_discarded":0,"attempt":0},{"id":5738618,"status":"PROCESSED","start":1721585400000,"end":1721585699999,"processing_time":1997,"nodes_total":9,"nodes_processed":9,"records_processed":622,"records_applied":622,"records_discarded":0,"attempt":0},{"id":5738619,"status":"PROCESSED","start":1721585700000,"end":1721585999999,"processing_time":2118,"nodes_total":9,"nodes_processed":9,"records_processed":834,"records_applied":834,"records_discarded":0,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2625,6 +2895,7 @@ fn test_single_ocw_pallet_integration() {
         };
         let pending_request5 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host5, port),
             response: Some(br#"[{"id":5738616,"status":"PROCESSED","start":1721584800000,"end":1721585099999,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":5738617,"status":"PROCESSED","start":1721585100000,"end":1721585399999,"processing_time":1818,"nodes_total":9,"nodes_processed":9,"records_processed":16,"records_applied":16,"records_discarded":0,"attempt":0},{"id":5738618,"status":"PROCESSED","start":1721585400000,"end":1721585699999,"processing_time":1997,"nodes_total":9,"nodes_processed":9,"records_processed":622,"records_applied":622,"records_discarded":0,"attempt":0},{"id":5738619,"status":"PROCESSED","start":1721585700000,"end":1721585999999,"processing_time":2118,"nodes_total":9,"nodes_processed":9,"records_processed":834,"records_applied":834,"records_discarded":0,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2632,6 +2903,7 @@ fn test_single_ocw_pallet_integration() {
         };
         let pending_request6 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host6, port),
             response: Some(br#"[{"id":5738616,"status":"PROCESSED","start":1721584800000,"end":1721585099999,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":5738617,"status":"PROCESSED","start":1721585100000,"end":1721585399999,"processing_time":1818,"nodes_total":9,"nodes_processed":9,"records_processed":16,"records_applied":16,"records_discarded":0,"attempt":0},{"id":5738618,"status":"PROCESSED","start":1721585400000,"end":1721585699999,"processing_time":1997,"nodes_total":9,"nodes_processed":9,"records_processed":622,"records_applied":622,"records_discarded":0,"attempt":0},{"id":5738619,"status":"PROCESSED","start":1721585700000,"end":1721585999999,"processing_time":2118,"nodes_total":9,"nodes_processed":9,"records_processed":834,"records_applied":834,"records_discarded":0,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2639,6 +2911,7 @@ fn test_single_ocw_pallet_integration() {
         };
         let pending_request7 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host7, port),
             response: Some(br#"[{"id":5738616,"status":"PROCESSED","start":1721584800000,"end":1721585099999,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":5738617,"status":"PROCESSED","start":1721585100000,"end":1721585399999,"processing_time":1818,"nodes_total":9,"nodes_processed":9,"records_processed":16,"records_applied":16,"records_discarded":0,"attempt":0},{"id":5738618,"status":"PROCESSED","start":1721585400000,"end":1721585699999,"processing_time":1997,"nodes_total":9,"nodes_processed":9,"records_processed":622,"records_applied":622,"records_discarded":0,"attempt":0},{"id":5738619,"status":"PROCESSED","start":1721585700000,"end":1721585999999,"processing_time":2118,"nodes_total":9,"nodes_processed":9,"records_processed":834,"records_applied":834,"records_discarded":0,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2646,6 +2919,7 @@ fn test_single_ocw_pallet_integration() {
         };
         let pending_request8 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host8, port),
             response: Some(br#"[{"id":5738616,"status":"PROCESSED","start":1721584800000,"end":1721585099999,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":5738617,"status":"PROCESSED","start":1721585100000,"end":1721585399999,"processing_time":1818,"nodes_total":9,"nodes_processed":9,"records_processed":16,"records_applied":16,"records_discarded":0,"attempt":0},{"id":5738618,"status":"PROCESSED","start":1721585400000,"end":1721585699999,"processing_time":1997,"nodes_total":9,"nodes_processed":9,"records_processed":622,"records_applied":622,"records_discarded":0,"attempt":0},{"id":5738619,"status":"PROCESSED","start":1721585700000,"end":1721585999999,"processing_time":2118,"nodes_total":9,"nodes_processed":9,"records_processed":834,"records_applied":834,"records_discarded":0,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2653,6 +2927,7 @@ fn test_single_ocw_pallet_integration() {
         };
         let pending_request9 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/eras", host9, port),
             response: Some(br#"[{"id":5738616,"status":"PROCESSED","start":1721584800000,"end":1721585099999,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0},{"id":5738617,"status":"PROCESSED","start":1721585100000,"end":1721585399999,"processing_time":1818,"nodes_total":9,"nodes_processed":9,"records_processed":16,"records_applied":16,"records_discarded":0,"attempt":0},{"id":5738618,"status":"PROCESSED","start":1721585400000,"end":1721585699999,"processing_time":1997,"nodes_total":9,"nodes_processed":9,"records_processed":622,"records_applied":622,"records_discarded":0,"attempt":0},{"id":5738619,"status":"PROCESSED","start":1721585700000,"end":1721585999999,"processing_time":2118,"nodes_total":9,"nodes_processed":9,"records_processed":834,"records_applied":834,"records_discarded":0,"attempt":0}]"#.to_vec()),
             sent: true,
@@ -2662,6 +2937,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let node_pending_request1 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=5738616&limit={}", host1, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0x48594f1fd4f05135914c42b03e63b61f6a3e4c537ccee3dbac555ef6df371b7e","stored_bytes": 675613289,"transferred_bytes": 1097091579,"number_of_puts": 889,"number_of_gets": 97},{"node_id": "0x9ef98ad9c3626ba725e78d76cfcfc4b4d07e84f0388465bc7eb992e3e117234a","stored_bytes": 0, "transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]"#.to_vec()),
 			sent: true,
@@ -2670,6 +2946,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let node_pending_request2 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=5738616&limit={}", host2, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0x48594f1fd4f05135914c42b03e63b61f6a3e4c537ccee3dbac555ef6df371b7e","stored_bytes": 675613289,"transferred_bytes": 1097091579,"number_of_puts": 889,"number_of_gets": 97},{"node_id": "0x9ef98ad9c3626ba725e78d76cfcfc4b4d07e84f0388465bc7eb992e3e117234a","stored_bytes": 0, "transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]"#.to_vec()),
 			sent: true,
@@ -2678,6 +2955,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let node_pending_request3 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=5738616&limit={}", host3, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0x48594f1fd4f05135914c42b03e63b61f6a3e4c537ccee3dbac555ef6df371b7e","stored_bytes": 675613289,"transferred_bytes": 1097091579,"number_of_puts": 889,"number_of_gets": 97},{"node_id": "0x9ef98ad9c3626ba725e78d76cfcfc4b4d07e84f0388465bc7eb992e3e117234a","stored_bytes": 0, "transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]"#.to_vec()),
 			sent: true,
@@ -2686,6 +2964,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let node_pending_request4 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=5738616&limit={}", host4, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0x48594f1fd4f05135914c42b03e63b61f6a3e4c537ccee3dbac555ef6df371b7e","stored_bytes": 675613289,"transferred_bytes": 1097091579,"number_of_puts": 889,"number_of_gets": 97},{"node_id": "0x9ef98ad9c3626ba725e78d76cfcfc4b4d07e84f0388465bc7eb992e3e117234a","stored_bytes": 0, "transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]"#.to_vec()),
 			sent: true,
@@ -2694,6 +2973,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let node_pending_request5 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=5738616&limit={}", host5, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0x48594f1fd4f05135914c42b03e63b61f6a3e4c537ccee3dbac555ef6df371b7e","stored_bytes": 675613289,"transferred_bytes": 1097091579,"number_of_puts": 889,"number_of_gets": 97},{"node_id": "0x9ef98ad9c3626ba725e78d76cfcfc4b4d07e84f0388465bc7eb992e3e117234a","stored_bytes": 0, "transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]"#.to_vec()),
 			sent: true,
@@ -2702,6 +2982,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let node_pending_request6 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=5738616&limit={}", host6, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0x48594f1fd4f05135914c42b03e63b61f6a3e4c537ccee3dbac555ef6df371b7e","stored_bytes": 675613289,"transferred_bytes": 1097091579,"number_of_puts": 889,"number_of_gets": 97},{"node_id": "0x9ef98ad9c3626ba725e78d76cfcfc4b4d07e84f0388465bc7eb992e3e117234a","stored_bytes": 0, "transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]"#.to_vec()),
 			sent: true,
@@ -2710,6 +2991,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let node_pending_request7 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=5738616&limit={}", host7, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0x48594f1fd4f05135914c42b03e63b61f6a3e4c537ccee3dbac555ef6df371b7e","stored_bytes": 675613289,"transferred_bytes": 1097091579,"number_of_puts": 889,"number_of_gets": 97},{"node_id": "0x9ef98ad9c3626ba725e78d76cfcfc4b4d07e84f0388465bc7eb992e3e117234a","stored_bytes": 0, "transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]"#.to_vec()),
 			sent: true,
@@ -2718,6 +3000,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let node_pending_request8 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=5738616&limit={}", host8, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0x48594f1fd4f05135914c42b03e63b61f6a3e4c537ccee3dbac555ef6df371b7e","stored_bytes": 675613289,"transferred_bytes": 1097091579,"number_of_puts": 889,"number_of_gets": 97},{"node_id": "0x9ef98ad9c3626ba725e78d76cfcfc4b4d07e84f0388465bc7eb992e3e117234a","stored_bytes": 0, "transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]"#.to_vec()),
 			sent: true,
@@ -2726,6 +3009,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let node_pending_request9 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/nodes?eraId=5738616&limit={}", host9, port, pallet::NODES_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"node_id": "0x48594f1fd4f05135914c42b03e63b61f6a3e4c537ccee3dbac555ef6df371b7e","stored_bytes": 675613289,"transferred_bytes": 1097091579,"number_of_puts": 889,"number_of_gets": 97},{"node_id": "0x9ef98ad9c3626ba725e78d76cfcfc4b4d07e84f0388465bc7eb992e3e117234a","stored_bytes": 0, "transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]"#.to_vec()),
 			sent: true,
@@ -2734,6 +3018,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let bucket_pending_request1 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=5738616&limit={}", host1, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"bucket_id": 90235,"stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1,"sub_aggregates": [{"NodeID": "0xbe26b2458fb0c9df4ec26ec5ba083051402b2a3b9d4a7fe6106fe9f8b5efde2c","stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]}]"#.to_vec()),
 			sent: true,
@@ -2742,6 +3027,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let bucket_pending_request2 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=5738616&limit={}", host2, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1,"bucket_id": 90235,"sub_aggregates": [{"NodeID": "0xbe26b2458fb0c9df4ec26ec5ba083051402b2a3b9d4a7fe6106fe9f8b5efde2c","stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]}]"#.to_vec()),
 			sent: true,
@@ -2750,6 +3036,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let bucket_pending_request3 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=5738616&limit={}", host3, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1,"bucket_id": 90235,"sub_aggregates": [{"NodeID": "0xbe26b2458fb0c9df4ec26ec5ba083051402b2a3b9d4a7fe6106fe9f8b5efde2c","stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]}]"#.to_vec()),
 			sent: true,
@@ -2758,6 +3045,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let bucket_pending_request4 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=5738616&limit={}", host4, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1,"bucket_id": 90235,"sub_aggregates": [{"NodeID": "0xbe26b2458fb0c9df4ec26ec5ba083051402b2a3b9d4a7fe6106fe9f8b5efde2c","stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]}]"#.to_vec()),
 			sent: true,
@@ -2766,6 +3054,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let bucket_pending_request5 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=5738616&limit={}", host5, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1,"bucket_id": 90235,"sub_aggregates": [{"NodeID": "0xbe26b2458fb0c9df4ec26ec5ba083051402b2a3b9d4a7fe6106fe9f8b5efde2c","stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]}]"#.to_vec()),
 			sent: true,
@@ -2774,6 +3063,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let bucket_pending_request6 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=5738616&limit={}", host6, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1,"bucket_id": 90235,"sub_aggregates": [{"NodeID": "0xbe26b2458fb0c9df4ec26ec5ba083051402b2a3b9d4a7fe6106fe9f8b5efde2c","stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]}]"#.to_vec()),
 			sent: true,
@@ -2782,6 +3072,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let bucket_pending_request7 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=5738616&limit={}", host7, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1,"bucket_id": 90235,"sub_aggregates": [{"NodeID": "0xbe26b2458fb0c9df4ec26ec5ba083051402b2a3b9d4a7fe6106fe9f8b5efde2c","stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]}]"#.to_vec()),
 			sent: true,
@@ -2790,6 +3081,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let bucket_pending_request8 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=5738616&limit={}", host8, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"bucket_id": 90235,"stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1,"sub_aggregates": [{"NodeID": "0xbe26b2458fb0c9df4ec26ec5ba083051402b2a3b9d4a7fe6106fe9f8b5efde2c","stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]}]"#.to_vec()),
 			sent: true,
@@ -2798,6 +3090,7 @@ fn test_single_ocw_pallet_integration() {
 
 		let bucket_pending_request9 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets?eraId=5738616&limit={}", host9, port, pallet::BUCKETS_AGGREGATES_FETCH_BATCH_SIZE),
 			response: Some(br#"[{"bucket_id": 90235,"stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1,"sub_aggregates": [{"NodeID": "0xbe26b2458fb0c9df4ec26ec5ba083051402b2a3b9d4a7fe6106fe9f8b5efde2c","stored_bytes": 0,"transferred_bytes": 38,"number_of_puts": 0,"number_of_gets": 1}]}]"#.to_vec()),
 			sent: true,
@@ -2949,6 +3242,7 @@ fn challenge_bucket_sub_aggregate_works() {
         //todo! put them in resource file
         let pending_request1 = PendingRequest {
             method: "GET".to_string(),
+            headers: accept_protobuf_or_json(),
             uri: format!("http://{}:{}/activity/buckets/123229/challenge?eraId=5757773&nodeId=0x1f50f1455f60f5774564233d321a116ca45ae3188b2200999445706d04839d72&merkleTreeNodeId=0,2,1,3", host1, port),
             response: Some(br#"{"proofs":[{"merkle_tree_node_id":3,"usage":{"stored_bytes":2097152,"transferred_bytes":1048576,"number_of_puts":1,"number_of_gets":1},"path":["hFnZfjnS5bAzgm5tHcWTxuJa5waDcaiU7OhBRofylhQ="],"leafs":[{"record":{"id":"17Z3vSjjRm6mWN3Swpw3Cw==","upstream":{"request":{"requestId":"e9920157-6c6a-485e-9f5a-1685ea6d4ef5","requestType":"REQUEST_TYPE_GET","contentType":"CONTENT_TYPE_PIECE","bucketId":"1","pieceCid":"AQIeIKLbs3OibO5qbLJ/PLCo1m02oFHWCl4s7S59GWgxDUbk","offset":"0","size":"0","timestamp":"1727346880632","signature":{"algorithm":"ED_25519","signer":"iNw0F9UFjsS0UD4MEuoaCom+IA/piSJCPUM0AU+msO4=","value":"KPDnQH5KZZQ2hksJ8F/w3GHwWloAm1QKoLt+SuUNYt3HxsGrh3r3q77COiu0jrwQ7mEsp/FFJp4pDp2Y1j2sDA=="}}},"downstream":[{"request":{"requestId":"a5bcaa37-97a4-45d2-beb9-c11cc955fb78","requestType":"REQUEST_TYPE_GET","contentType":"CONTENT_TYPE_MERKLE_TREE","bucketId":"0","pieceCid":"AQIeIKLbs3OibO5qbLJ/PLCo1m02oFHWCl4s7S59GWgxDUbk","offset":"0","size":"0","timestamp":"1727346880633","signature":{"algorithm":"ED_25519","signer":"CsfLnFNZTp9TjZlQxrzyjwwMe4OF3uouviQGK8ZA574=","value":"ulpjaksvopDDRRfYnrccUg5spkoRpfZlDARbjgfL4Y/X4HZNUp2cL5qQMHUosREB6PSMXr9rQvXYGA9kmrUBDg=="}}},{"request":{"requestId":"8af9ba14-4c49-438c-957d-d1a108a58b85","requestType":"REQUEST_TYPE_GET","contentType":"CONTENT_TYPE_SEGMENT","bucketId":"0","pieceCid":"AQIeIKLbs3OibO5qbLJ/PLCo1m02oFHWCl4s7S59GWgxDUbk","offset":"0","size":"524288","timestamp":"1727346880633","signature":{"algorithm":"ED_25519","signer":"CsfLnFNZTp9TjZlQxrzyjwwMe4OF3uouviQGK8ZA574=","value":"CLdw3HaQWVWdDHeog2SZjiEA4NZN6PD8vyw58JuQI7gMDpDXLFslMOcI7p/uNEyeDfNoKTAgNZpWbNR4vSZ/AA=="}}},{"request":{"requestId":"b3dc8833-d5aa-4e33-9afa-54584da29cda","requestType":"REQUEST_TYPE_GET","contentType":"CONTENT_TYPE_SEGMENT","bucketId":"0","pieceCid":"AQIeIKLbs3OibO5qbLJ/PLCo1m02oFHWCl4s7S59GWgxDUbk","offset":"0","size":"524288","timestamp":"1727346880633","signature":{"algorithm":"ED_25519","signer":"CsfLnFNZTp9TjZlQxrzyjwwMe4OF3uouviQGK8ZA574=","value":"5XTnDU/85DqWWpMy1kGRVK6ZHe/EYDeg2p07UbFnIr6xLX7n50k9MslwuF8jMl2/QoBrPnndHdCd5ssqV90kDg=="}}}],"timestamp":"1727346880633","signature":{"algorithm":"ED_25519","signer":"CsfLnFNZTp9TjZlQxrzyjwwMe4OF3uouviQGK8ZA574=","value":"8WWGHaL3n8+bkuYQhTua3l+i3W//XXhlnzCpQ7VJ/BmfXQPFGEjIZsXw0kKr4+VXh/kWAncF3VrvW9nEi6G2CQ=="}},"transferred_bytes":1048576,"stored_bytes":0},{"record":{"id":"8Rg6VlRrSE65NsCY02OnlA==","upstream":{"request":{"requestId":"aacf30c4-b2e9-4f37-826d-0016c280f39b","requestType":"REQUEST_TYPE_PUT","contentType":"CONTENT_TYPE_METADATA","bucketId":"0","pieceCid":"AAAAAAAAAAEBAh4gaLfPG3AA1QwNFQc3VvJYsMAINAN6mMkvo5vk5HP8g/0=","offset":"0","size":"385","timestamp":"1727346880673","signature":{"algorithm":"ED_25519","signer":"xHUfclv0KTLyCz1NjsLAdMrEBfKdlta130WiEBvB14s=","value":"yPZt7Fyfp1aiJL+hYOg5rRtPPTNDMZwgReX2RX4bWbP8+ivreh1cNvSwnM5ln0EFqxTn53iVQpZeMWXUSiJeCw=="}}},"downstream":[],"timestamp":"1727346880673","signature":{"algorithm":"ED_25519","signer":"CsfLnFNZTp9TjZlQxrzyjwwMe4OF3uouviQGK8ZA574=","value":"zX0aGW/FuhddMAtGvN4Gjf6P1JaFGasrwf5yCrQPFv4qUB1GyACynb1s1+Mv0zpMAGOtIOcwaemoPu4fnOByBA=="}},"transferred_bytes":1048576,"stored_bytes":1048576}]}]}"#.to_vec()),
             sent: true,
@@ -2957,6 +3251,7 @@ fn challenge_bucket_sub_aggregate_works() {
 
 		let pending_request2 = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("http://{}:{}/activity/buckets/123229/traverse?eraId=5757773&nodeId=0x1f50f1455f60f5774564233d321a116ca45ae3188b2200999445706d04839d72&merkleTreeNodeId=1&levels=1", host1, port),
 			response: Some(br#"{"merkle_tree_node_id":2,"hash":"hkujtYgWP21CrXdRP1rhRPrYR2ooIYCnP5zwCERTePI=","stored_bytes":20913291,"transferred_bytes":20913291,"number_of_puts":61,"number_of_gets":3}"#.to_vec()),
 			sent: true,
@@ -3007,10 +3302,19 @@ fn challenge_bucket_sub_aggregate_works() {
 }
 
 use crate::aggregator_client::{
-	json::{BucketAggregateResponse, SignedJsonResponse},
-	AggregatorClient,
+	json::{
+		BucketAggregateResponse, MerkleTreeNodeResponse, NodeAggregateResponse, SignedJsonResponse,
+	},
+	AggregatorClient, AggregatorClientError,
 };
 
+fn activity_buckets_signer() -> NodePubKey {
+	Pallet::<Test>::node_key_from_hex(
+		"c4751f725bf42932f20b3d4d8ec2c074cac405f29d96d6b5df45a2101bc1d78b".to_string(),
+	)
+	.unwrap()
+}
+
 #[test]
 fn aggregator_client_get_buckets_aggregates_works() {
 	let mut ext = TestExternalities::default();
@@ -3030,6 +3334,7 @@ fn aggregator_client_get_buckets_aggregates_works() {
 
 		let expected_request = PendingRequest {
 			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!("{}/activity/buckets?eraId={}&sign=true", base_url, era_id),
 			response: Some(activity_buckets_signed_resp.to_vec()),
 			sent: true,
@@ -3039,7 +3344,9 @@ fn aggregator_client_get_buckets_aggregates_works() {
 		offchain_state.expect_request(expected_request);
 		drop(offchain_state);
 
-		let client = AggregatorClient::new(base_url, Duration::from_millis(1_000), 1, true);
+		let signer = activity_buckets_signer();
+		let client =
+			AggregatorClient::new(base_url, Duration::from_millis(1_000), 1, &signer, true);
 
 		let expected_response: SignedJsonResponse<Vec<BucketAggregateResponse>> =
 			serde_json::from_slice(activity_buckets_signed_resp)
@@ -3051,7 +3358,7 @@ fn aggregator_client_get_buckets_aggregates_works() {
 }
 
 #[test]
-fn aggregator_client_challenge_bucket_sub_aggregate_works() {
+fn aggregator_client_rejects_response_signed_by_another_key() {
 	let mut ext = TestExternalities::default();
 	let (offchain, offchain_state) = TestOffchainExt::new();
 
@@ -3062,52 +3369,66 @@ fn aggregator_client_challenge_bucket_sub_aggregate_works() {
 		let mut offchain_state = offchain_state.write();
 		offchain_state.timestamp = Timestamp::from_unix_millis(0);
 
-		let base_url = "http://example.com";
-		let bucket_id = 1;
-		let era_id = 1;
-		let merkle_tree_node_id = "2,6";
-		let node_id = "0x0ac7cb9c53594e9f538d9950c6bcf28f0c0c7b8385deea2ebe24062bc640e7be";
+		let base_url = "http://example.com:8080";
+		let era_id = 346524624;
+		let activity_buckets_signed_resp =
+			include_bytes!("./test_data/activity_buckets_signed_resp.json").as_slice();
 
-		let expected_response = proto::ChallengeResponse {
-			proofs: vec![
-				proto::challenge_response::Proof {
-					merkle_tree_node_id: 2,
-					usage: Some(proto::Aggregate { stored: 4, delivered: 3, puts: 2, gets: 1 }),
-					..Default::default()
-				},
-				proto::challenge_response::Proof {
-					merkle_tree_node_id: 6,
-					usage: Some(proto::Aggregate { stored: 8, delivered: 7, puts: 6, gets: 5 }),
-					..Default::default()
-				},
-			],
-		};
-		let mut expected_response_serialized = Vec::new();
-		expected_response.encode(&mut expected_response_serialized).unwrap();
+		offchain_state.expect_request(PendingRequest {
+			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
+			uri: format!("{}/activity/buckets?eraId={}&sign=true", base_url, era_id),
+			response: Some(activity_buckets_signed_resp.to_vec()),
+			sent: true,
+			..Default::default()
+		});
+		drop(offchain_state);
 
-		let expected = PendingRequest {
-			method: "GET".into(),
-			headers: vec![("Accept".into(), "application/protobuf".into())],
-			uri: format!(
-				"{}/activity/buckets/{}/challenge?eraId={}&nodeId={}&merkleTreeNodeId={}",
-				base_url, bucket_id, era_id, node_id, merkle_tree_node_id
-			),
-			response: Some(expected_response_serialized),
+		// the response has a valid signature, but the signer is not the aggregator
+		let aggregator = NodePubKey::StoragePubKey(AccountId32::new([1; 32]));
+		let client =
+			AggregatorClient::new(base_url, Duration::from_millis(1_000), 1, &aggregator, true);
+
+		let result = client.buckets_aggregates(era_id, None, None);
+		assert_eq!(result, Err(AggregatorClientError::InvalidSignature));
+	})
+}
+
+#[test]
+fn aggregator_client_rejects_unsigned_response() {
+	let mut ext = TestExternalities::default();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(Box::new(offchain)));
+
+	ext.execute_with(|| {
+		let mut offchain_state = offchain_state.write();
+		offchain_state.timestamp = Timestamp::from_unix_millis(0);
+
+		let base_url = "http://example.com:8080";
+
+		offchain_state.expect_request(PendingRequest {
+			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
+			uri: format!("{}/activity/eras?sign=true", base_url),
+			response: Some(br#"[{"id":17,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0}]"#.to_vec()),
 			sent: true,
 			..Default::default()
-		};
-		offchain_state.expect_request(expected);
+		});
 		drop(offchain_state);
 
-		let client = AggregatorClient::new(base_url, Duration::from_millis(1_000), 1, false);
+		let signer = activity_buckets_signer();
+		let client =
+			AggregatorClient::new(base_url, Duration::from_millis(1_000), 1, &signer, true);
 
-		let result = client.challenge_bucket_sub_aggregate(era_id, bucket_id, node_id, vec![2, 6]);
-		assert_eq!(result, Ok(expected_response));
+		let result = client.eras();
+		assert_eq!(result, Err(AggregatorClientError::InvalidSignature));
 	})
 }
 
 #[test]
-fn aggregator_client_challenge_node_aggregate_works() {
+fn aggregator_client_negotiates_protobuf_responses() {
 	let mut ext = TestExternalities::default();
 	let (offchain, offchain_state) = TestOffchainExt::new();
 
@@ -3118,45 +3439,1849 @@ fn aggregator_client_challenge_node_aggregate_works() {
 		let mut offchain_state = offchain_state.write();
 		offchain_state.timestamp = Timestamp::from_unix_millis(0);
 
-		let base_url = "http://example.com";
-		let era_id = 1;
-		let merkle_tree_node_id = "2,6";
-		let node_id = "0x0ac7cb9c53594e9f538d9950c6bcf28f0c0c7b8385deea2ebe24062bc640e7be";
+		let base_url = "http://example.com:8080";
+		let era_id = 346524624;
+		let node_id = "0x48594f1fd4f05135914c42b03e63b61f6a3e4c537ccee3dbac555ef6df371b7e";
 
-		let expected_response = proto::ChallengeResponse {
-			proofs: vec![
-				proto::challenge_response::Proof {
-					merkle_tree_node_id: 2,
-					usage: Some(proto::Aggregate { stored: 4, delivered: 3, puts: 2, gets: 1 }),
-					..Default::default()
-				},
-				proto::challenge_response::Proof {
-					merkle_tree_node_id: 6,
-					usage: Some(proto::Aggregate { stored: 8, delivered: 7, puts: 6, gets: 5 }),
-					..Default::default()
-				},
-			],
+		let nodes_response = proto::NodeAggregatesResponse {
+			aggregates: vec![proto::NodeAggregate {
+				node_id: node_id.to_string(),
+				stored_bytes: -100,
+				transferred_bytes: 50,
+				number_of_puts: 10,
+				number_of_gets: 20,
+			}],
 		};
-		let mut expected_response_serialized = Vec::new();
-		expected_response.encode(&mut expected_response_serialized).unwrap();
+		offchain_state.expect_request(PendingRequest {
+			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
+			uri: format!("{}/activity/nodes?eraId={}", base_url, era_id),
+			response: Some(nodes_response.encode_to_vec()),
+			response_headers: vec![("content-type".into(), "application/protobuf".into())],
+			sent: true,
+			..Default::default()
+		});
 
-		let expected = PendingRequest {
-			method: "GET".into(),
-			headers: vec![("Accept".into(), "application/protobuf".into())],
+		let merkle_tree_node = proto::MerkleTreeNode {
+			merkle_tree_node_id: 2,
+			hash: vec![1, 2, 3],
+			stored_bytes: 100,
+			transferred_bytes: 50,
+			number_of_puts: 10,
+			number_of_gets: 20,
+		};
+		offchain_state.expect_request(PendingRequest {
+			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
 			uri: format!(
-				"{}/activity/nodes/{}/challenge?eraId={}&merkleTreeNodeId={}",
-				base_url, node_id, era_id, merkle_tree_node_id
+				"{}/activity/nodes/{}/traverse?eraId={}&merkleTreeNodeId=2&levels=1",
+				base_url, node_id, era_id
 			),
-			response: Some(expected_response_serialized),
+			response: Some(merkle_tree_node.encode_to_vec()),
+			response_headers: vec![(
+				"Content-Type".into(),
+				"application/x-protobuf; charset=binary".into(),
+			)],
 			sent: true,
 			..Default::default()
-		};
-		offchain_state.expect_request(expected);
+		});
+
+		// aggregators not serving protobuf yet respond with JSON
+		offchain_state.expect_request(PendingRequest {
+			method: "GET".to_string(),
+			headers: accept_protobuf_or_json(),
+			uri: format!("{}/activity/eras", base_url),
+			response: Some(br#"[{"id":17,"status":"PROCESSED","start":1,"end":2,"processing_time":15977,"nodes_total":9,"nodes_processed":9,"records_processed":0,"records_applied":0,"records_discarded":130755,"attempt":0}]"#.to_vec()),
+			response_headers: vec![("Content-Type".into(), "application/json".into())],
+			sent: true,
+			..Default::default()
+		});
 		drop(offchain_state);
 
-		let client = AggregatorClient::new(base_url, Duration::from_millis(1_000), 1, false);
+		let aggregator = NodePubKey::StoragePubKey(AccountId32::new([1; 32]));
+		let client =
+			AggregatorClient::new(base_url, Duration::from_millis(1_000), 1, &aggregator, false);
 
-		let result = client.challenge_node_aggregate(era_id, node_id, vec![2, 6]);
-		assert_eq!(result, Ok(expected_response));
+		assert_eq!(
+			client.nodes_aggregates(era_id, None, None),
+			Ok(vec![NodeAggregateResponse {
+				node_id: node_id.to_string(),
+				stored_bytes: -100,
+				transferred_bytes: 50,
+				number_of_puts: 10,
+				number_of_gets: 20,
+			}])
+		);
+		assert_eq!(
+			client.traverse_node_aggregate(era_id, node_id, 2, 1),
+			Ok(MerkleTreeNodeResponse {
+				merkle_tree_node_id: 2,
+				hash: "AQID".to_string(),
+				stored_bytes: 100,
+				transferred_bytes: 50,
+				number_of_puts: 10,
+				number_of_gets: 20,
+			})
+		);
+		assert_eq!(client.eras().map(|eras| eras.len()), Ok(1));
+	})
+}
+
+#[test]
+fn aggregator_client_verifies_signed_protobuf_responses() {
+	let mut ext = TestExternalities::default();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(Box::new(offchain)));
+
+	ext.execute_with(|| {
+		let mut offchain_state = offchain_state.write();
+		offchain_state.timestamp = Timestamp::from_unix_millis(0);
+
+		let base_url = "http://example.com:8080";
+		let aggregator_pair = sp_core::ed25519::Pair::from_seed(&[1; 32]);
+		let other_pair = sp_core::ed25519::Pair::from_seed(&[2; 32]);
+
+		let payload = proto::ErasResponse {
+			eras: vec![proto::Era {
+				id: 17,
+				status: "PROCESSED".to_string(),
+				..Default::default()
+			}],
+		}
+		.encode_to_vec();
+		let signed_response = |signer: &sp_core::ed25519::Pair| {
+			proto::SignedResponse {
+				payload: payload.clone(),
+				signature: Some(proto::Signature {
+					algorithm: proto::signature::Algorithm::Ed25519 as i32,
+					value: signer.sign(payload.as_slice()).0.to_vec(),
+					signer: signer.public().0.to_vec(),
+				}),
+			}
+			.encode_to_vec()
+		};
+
+		for signer in [&aggregator_pair, &other_pair] {
+			offchain_state.expect_request(PendingRequest {
+				method: "GET".to_string(),
+				headers: accept_protobuf_or_json(),
+				uri: format!("{}/activity/eras?sign=true", base_url),
+				response: Some(signed_response(signer)),
+				response_headers: vec![("Content-Type".into(), "application/protobuf".into())],
+				sent: true,
+				..Default::default()
+			});
+		}
+		drop(offchain_state);
+
+		let aggregator = NodePubKey::StoragePubKey(AccountId32::from(aggregator_pair.public().0));
+		let client =
+			AggregatorClient::new(base_url, Duration::from_millis(1_000), 1, &aggregator, true);
+
+		let eras = client.eras().expect("signed eras response is accepted");
+		assert_eq!(eras.len(), 1);
+		assert_eq!(eras[0].id, 17);
+		assert_eq!(eras[0].status, "PROCESSED");
+
+		// the response is correctly signed, but the signer is not the aggregator
+		assert_eq!(client.eras(), Err(AggregatorClientError::InvalidSignature));
+	})
+}
+
+#[test]
+fn aggregator_signature_errors_are_counted_per_aggregator() {
+	let mut ext = TestExternalities::default();
+	let (offchain, _offchain_state) = TestOffchainExt::new();
+
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(Box::new(offchain)));
+
+	ext.execute_with(|| {
+		let cluster_id = ClusterId::from([12; 20]);
+		let aggregator1 = NodePubKey::StoragePubKey(AccountId32::new([1; 32]));
+		let aggregator2 = NodePubKey::StoragePubKey(AccountId32::new([2; 32]));
+
+		Pallet::<Test>::report_aggregator_signature_error(&cluster_id, &aggregator1);
+		Pallet::<Test>::report_aggregator_signature_error(&cluster_id, &aggregator1);
+		Pallet::<Test>::report_aggregator_signature_error(&cluster_id, &aggregator2);
+
+		assert_eq!(Pallet::<Test>::get_aggregator_signature_failures(&aggregator1), 2);
+		assert_eq!(Pallet::<Test>::get_aggregator_signature_failures(&aggregator2), 1);
+
+		assert_eq!(
+			Pallet::<Test>::take_aggregator_signature_errors(&cluster_id),
+			vec![
+				OCWError::AggregatorResponseSignatureError {
+					cluster_id,
+					aggregator: aggregator1.clone(),
+					failures: 1,
+				},
+				OCWError::AggregatorResponseSignatureError {
+					cluster_id,
+					aggregator: aggregator1.clone(),
+					failures: 2,
+				},
+				OCWError::AggregatorResponseSignatureError {
+					cluster_id,
+					aggregator: aggregator2.clone(),
+					failures: 1,
+				},
+			]
+		);
+		// queued errors are taken once, counters are kept
+		assert!(Pallet::<Test>::take_aggregator_signature_errors(&cluster_id).is_empty());
+		assert_eq!(Pallet::<Test>::get_aggregator_signature_failures(&aggregator1), 2);
+	})
+}
+
+#[test]
+fn aggregator_client_challenge_bucket_sub_aggregate_works() {
+	let mut ext = TestExternalities::default();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(Box::new(offchain)));
+
+	ext.execute_with(|| {
+		let mut offchain_state = offchain_state.write();
+		offchain_state.timestamp = Timestamp::from_unix_millis(0);
+
+		let base_url = "http://example.com";
+		let bucket_id = 1;
+		let era_id = 1;
+		let merkle_tree_node_id = "2,6";
+		let node_id = "0x0ac7cb9c53594e9f538d9950c6bcf28f0c0c7b8385deea2ebe24062bc640e7be";
+
+		let expected_response = proto::ChallengeResponse {
+			proofs: vec![
+				proto::challenge_response::Proof {
+					merkle_tree_node_id: 2,
+					usage: Some(proto::Aggregate { stored: 4, delivered: 3, puts: 2, gets: 1 }),
+					..Default::default()
+				},
+				proto::challenge_response::Proof {
+					merkle_tree_node_id: 6,
+					usage: Some(proto::Aggregate { stored: 8, delivered: 7, puts: 6, gets: 5 }),
+					..Default::default()
+				},
+			],
+		};
+		let mut expected_response_serialized = Vec::new();
+		expected_response.encode(&mut expected_response_serialized).unwrap();
+
+		let expected = PendingRequest {
+			method: "GET".into(),
+			headers: vec![("Accept".into(), "application/protobuf".into())],
+			uri: format!(
+				"{}/activity/buckets/{}/challenge?eraId={}&nodeId={}&merkleTreeNodeId={}",
+				base_url, bucket_id, era_id, node_id, merkle_tree_node_id
+			),
+			response: Some(expected_response_serialized),
+			sent: true,
+			..Default::default()
+		};
+		offchain_state.expect_request(expected);
+		drop(offchain_state);
+
+		let aggregator = NodePubKey::StoragePubKey(AccountId32::new([1; 32]));
+		let client =
+			AggregatorClient::new(base_url, Duration::from_millis(1_000), 1, &aggregator, false);
+
+		let result = client.challenge_bucket_sub_aggregate(era_id, bucket_id, node_id, vec![2, 6]);
+		assert_eq!(result, Ok(expected_response));
+	})
+}
+
+#[test]
+fn aggregator_client_challenge_node_aggregate_works() {
+	let mut ext = TestExternalities::default();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(Box::new(offchain)));
+
+	ext.execute_with(|| {
+		let mut offchain_state = offchain_state.write();
+		offchain_state.timestamp = Timestamp::from_unix_millis(0);
+
+		let base_url = "http://example.com";
+		let era_id = 1;
+		let merkle_tree_node_id = "2,6";
+		let node_id = "0x0ac7cb9c53594e9f538d9950c6bcf28f0c0c7b8385deea2ebe24062bc640e7be";
+
+		let expected_response = proto::ChallengeResponse {
+			proofs: vec![
+				proto::challenge_response::Proof {
+					merkle_tree_node_id: 2,
+					usage: Some(proto::Aggregate { stored: 4, delivered: 3, puts: 2, gets: 1 }),
+					..Default::default()
+				},
+				proto::challenge_response::Proof {
+					merkle_tree_node_id: 6,
+					usage: Some(proto::Aggregate { stored: 8, delivered: 7, puts: 6, gets: 5 }),
+					..Default::default()
+				},
+			],
+		};
+		let mut expected_response_serialized = Vec::new();
+		expected_response.encode(&mut expected_response_serialized).unwrap();
+
+		let expected = PendingRequest {
+			method: "GET".into(),
+			headers: vec![("Accept".into(), "application/protobuf".into())],
+			uri: format!(
+				"{}/activity/nodes/{}/challenge?eraId={}&merkleTreeNodeId={}",
+				base_url, node_id, era_id, merkle_tree_node_id
+			),
+			response: Some(expected_response_serialized),
+			sent: true,
+			..Default::default()
+		};
+		offchain_state.expect_request(expected);
+		drop(offchain_state);
+
+		let aggregator = NodePubKey::StoragePubKey(AccountId32::new([1; 32]));
+		let client =
+			AggregatorClient::new(base_url, Duration::from_millis(1_000), 1, &aggregator, false);
+
+		let result = client.challenge_node_aggregate(era_id, node_id, vec![2, 6]);
+		assert_eq!(result, Ok(expected_response));
+	})
+}
+
+#[test]
+fn aggregator_client_accepts_unwrapped_challenge_responses_when_verifying_signatures() {
+	let mut ext = TestExternalities::default();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(Box::new(offchain)));
+
+	ext.execute_with(|| {
+		let mut offchain_state = offchain_state.write();
+		offchain_state.timestamp = Timestamp::from_unix_millis(0);
+
+		let base_url = "http://example.com";
+		let era_id = 1;
+		let node_id = "0x0ac7cb9c53594e9f538d9950c6bcf28f0c0c7b8385deea2ebe24062bc640e7be";
+		let aggregator_pair = sp_core::ed25519::Pair::from_seed(&[1; 32]);
+
+		// aggregators serve challenge responses unwrapped, their records are signed instead
+		let expected_response =
+			proto::ChallengeResponse::decode(fraud_proof_activity().as_slice()).unwrap();
+		offchain_state.expect_request(PendingRequest {
+			method: "GET".into(),
+			headers: vec![("Accept".into(), "application/protobuf".into())],
+			uri: format!(
+				"{}/activity/nodes/{}/challenge?eraId={}&merkleTreeNodeId=2",
+				base_url, node_id, era_id
+			),
+			response: Some(expected_response.encode_to_vec()),
+			sent: true,
+			..Default::default()
+		});
+		drop(offchain_state);
+
+		let aggregator = NodePubKey::StoragePubKey(AccountId32::from(aggregator_pair.public().0));
+		let client =
+			AggregatorClient::new(base_url, Duration::from_millis(1_000), 1, &aggregator, true);
+
+		let result = client.challenge_node_aggregate(era_id, node_id, vec![2]);
+		assert_eq!(result, Ok(expected_response.clone()));
+		assert!(crate::signature::Verify::verify(&expected_response));
+	})
+}
+
+fn get_dac_nodes_params(hosts: &[&str]) -> Vec<(NodePubKey, StorageNodeParams)> {
+	hosts
+		.iter()
+		.enumerate()
+		.map(|(i, host)| {
+			(
+				NodePubKey::StoragePubKey(AccountId32::new([i as u8 + 1; 32])),
+				StorageNodeParams {
+					ssl: false,
+					host: host.as_bytes().to_vec(),
+					http_port: 8080,
+					mode: StorageNodeMode::DAC,
+					p2p_port: 5555,
+					grpc_port: 4444,
+					domain: b"example.com".to_vec(),
+				},
+			)
+		})
+		.collect()
+}
+
+#[test]
+fn bucket_aggregators_assignment_is_deterministic() {
+	let cluster_id = ClusterId::from([12; 20]);
+	let dac_nodes = get_dac_nodes_params(&[
+		"178.251.228.236",
+		"95.217.8.119",
+		"178.251.228.42",
+		"37.27.30.47",
+		"178.251.228.49",
+	]);
+
+	let assigned = DdcVerification::get_bucket_aggregators(&cluster_id, 1, &dac_nodes, 3);
+	assert_eq!(assigned.len(), 3);
+	assert_eq!(assigned.iter().collect::<BTreeSet<_>>().len(), 3);
+	assert!(assigned.iter().all(|key| dac_nodes.iter().any(|(node, _)| node == key)));
+
+	// The assignment does not depend on the order the nodes are listed in
+	let mut reversed_dac_nodes = dac_nodes.clone();
+	reversed_dac_nodes.reverse();
+	assert_eq!(
+		DdcVerification::get_bucket_aggregators(&cluster_id, 1, &reversed_dac_nodes, 3),
+		assigned
+	);
+
+	// Buckets are spread across aggregators
+	let assignments: BTreeSet<Vec<NodePubKey>> = (0..20u64)
+		.map(|bucket_id| {
+			DdcVerification::get_bucket_aggregators(&cluster_id, bucket_id, &dac_nodes, 3)
+		})
+		.collect();
+	assert!(assignments.len() > 1);
+
+	// All nodes are assigned when there are fewer nodes than the redundancy factor
+	assert_eq!(
+		DdcVerification::get_bucket_aggregators(&cluster_id, 1, &dac_nodes[..2], 3).len(),
+		2
+	);
+}
+
+#[test]
+fn buckets_aggregates_from_not_assigned_aggregators_are_ignored() {
+	let cluster_id = ClusterId::from([12; 20]);
+	let era_id = 476817;
+	let dac_nodes =
+		get_dac_nodes_params(&["178.251.228.236", "95.217.8.119", "178.251.228.42", "37.27.30.47"]);
+
+	let bucket_aggregate = aggregator_client::json::BucketAggregateResponse {
+		bucket_id: 90235,
+		stored_bytes: 578,
+		transferred_bytes: 578,
+		number_of_puts: 2,
+		number_of_gets: 0,
+		sub_aggregates: vec![aggregator_client::json::BucketSubAggregateResponse {
+			NodeID: "0xb6186f80dce7190294665ab53860de2841383bb202c562bb8b81a624351fa318"
+				.to_string(),
+			stored_bytes: 578,
+			transferred_bytes: 578,
+			number_of_puts: 2,
+			number_of_gets: 0,
+		}],
+	};
+
+	let buckets_aggregates_by_aggregator = dac_nodes
+		.iter()
+		.map(|(node_pub_key, node_params)| {
+			(
+				AggregatorInfo {
+					node_pub_key: node_pub_key.clone(),
+					node_params: node_params.clone(),
+				},
+				vec![bucket_aggregate.clone()],
+			)
+		})
+		.collect::<Vec<_>>();
+
+	let assigned = DdcVerification::get_bucket_aggregators(
+		&cluster_id,
+		bucket_aggregate.bucket_id,
+		&dac_nodes,
+		3,
+	);
+
+	let filtered = DdcVerification::filter_buckets_aggregates_by_assignment(
+		&cluster_id,
+		era_id,
+		buckets_aggregates_by_aggregator,
+		&dac_nodes,
+		3,
+	);
+
+	assert_eq!(filtered.len(), dac_nodes.len());
+	for (aggregator, buckets_aggregates) in filtered {
+		if assigned.contains(&aggregator.node_pub_key) {
+			assert_eq!(buckets_aggregates, vec![bucket_aggregate.clone()]);
+		} else {
+			assert!(buckets_aggregates.is_empty());
+		}
+	}
+}
+
+fn setup_ocw_validators(validators: &[AccountId32]) {
+	ValidatorSet::<Test>::put(validators.to_vec());
+	for validator in validators {
+		ValidatorToStashKey::<Test>::insert(validator.clone(), validator.clone());
+	}
+}
+
+#[test]
+fn set_prepare_era_for_payout_requires_consensus_on_era_boundaries() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let validators: Vec<AccountId32> = get_validators().into_iter().take(3).collect();
+		setup_ocw_validators(&validators);
+
+		let payers_root: DeltaUsageHash = H256([1; 32]);
+		let payees_root: DeltaUsageHash = H256([2; 32]);
+		let era = EraActivity { id: 1, start: 1000, end: 2000 };
+		let shifted_era = EraActivity { id: 1, start: 1000, end: 2500 };
+
+		assert_ok!(DdcVerification::set_prepare_era_for_payout(
+			RuntimeOrigin::signed(validators[0].clone()),
+			cluster_id,
+			era,
+			payers_root,
+			payees_root,
+			vec![payers_root],
+			vec![payees_root],
+		));
+		System::assert_last_event(
+			Event::EraValidationNotReady { cluster_id, era_id: era.id }.into(),
+		);
+
+		// Same roots, but different era end do not count towards the quorum
+		assert_ok!(DdcVerification::set_prepare_era_for_payout(
+			RuntimeOrigin::signed(validators[1].clone()),
+			cluster_id,
+			shifted_era,
+			payers_root,
+			payees_root,
+			vec![payers_root],
+			vec![payees_root],
+		));
+		System::assert_last_event(
+			Event::EraValidationNotReady { cluster_id, era_id: era.id }.into(),
+		);
+
+		let era_validation = EraValidations::<Test>::get(cluster_id, era.id).unwrap();
+		assert_eq!(era_validation.status, EraValidationStatus::ValidatingData);
+		assert_eq!(era_validation.validators.len(), 2);
+		assert_eq!(
+			era_validation.validators.get(&(payers_root, payees_root, era.start, era.end)),
+			Some(&vec![validators[0].clone()])
+		);
+		assert_eq!(
+			era_validation.validators.get(&(
+				payers_root,
+				payees_root,
+				shifted_era.start,
+				shifted_era.end
+			)),
+			Some(&vec![validators[1].clone()])
+		);
+
+		assert_ok!(DdcVerification::set_prepare_era_for_payout(
+			RuntimeOrigin::signed(validators[2].clone()),
+			cluster_id,
+			era,
+			payers_root,
+			payees_root,
+			vec![payers_root],
+			vec![payees_root],
+		));
+		System::assert_last_event(Event::EraValidationReady { cluster_id, era_id: era.id }.into());
+
+		// Era boundaries are taken from the agreed vote
+		let era_validation = EraValidations::<Test>::get(cluster_id, era.id).unwrap();
+		assert_eq!(era_validation.status, EraValidationStatus::ReadyForPayout);
+		assert_eq!(era_validation.start_era, era.start);
+		assert_eq!(era_validation.end_era, era.end);
+		assert_eq!(era_validation.payers_merkle_root_hash, payers_root);
+		assert_eq!(era_validation.payees_merkle_root_hash, payees_root);
+	});
+}
+
+#[test]
+fn set_prepare_era_for_payout_allows_single_vote_per_validator() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let validators: Vec<AccountId32> = get_validators().into_iter().take(3).collect();
+		setup_ocw_validators(&validators);
+
+		let payers_root: DeltaUsageHash = H256([1; 32]);
+		let payees_root: DeltaUsageHash = H256([2; 32]);
+		let era = EraActivity { id: 1, start: 1000, end: 2000 };
+		let shifted_era = EraActivity { id: 1, start: 500, end: 2000 };
+
+		assert_ok!(DdcVerification::set_prepare_era_for_payout(
+			RuntimeOrigin::signed(validators[0].clone()),
+			cluster_id,
+			era,
+			payers_root,
+			payees_root,
+			vec![payers_root],
+			vec![payees_root],
+		));
+
+		assert_noop!(
+			DdcVerification::set_prepare_era_for_payout(
+				RuntimeOrigin::signed(validators[0].clone()),
+				cluster_id,
+				era,
+				payers_root,
+				payees_root,
+				vec![payers_root],
+				vec![payees_root],
+			),
+			Error::<Test>::AlreadySignedEra
+		);
+
+		// Voting for other era boundaries does not allow to sign the era twice
+		assert_noop!(
+			DdcVerification::set_prepare_era_for_payout(
+				RuntimeOrigin::signed(validators[0].clone()),
+				cluster_id,
+				shifted_era,
+				payers_root,
+				payees_root,
+				vec![payers_root],
+				vec![payees_root],
+			),
+			Error::<Test>::AlreadySignedEra
+		);
+	});
+}
+
+#[test]
+fn set_prepare_era_for_payout_accepts_validators_without_stash() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let validators: Vec<AccountId32> = get_validators().into_iter().take(3).collect();
+		ValidatorSet::<Test>::put(validators.clone());
+
+		let payers_root: DeltaUsageHash = H256([1; 32]);
+		let payees_root: DeltaUsageHash = H256([2; 32]);
+		let era = EraActivity { id: 1, start: 1000, end: 2000 };
+
+		for validator in &validators[..2] {
+			assert_ok!(DdcVerification::set_prepare_era_for_payout(
+				RuntimeOrigin::signed(validator.clone()),
+				cluster_id,
+				era,
+				payers_root,
+				payees_root,
+				vec![payers_root],
+				vec![payees_root],
+			));
+		}
+
+		let era_validation = EraValidations::<Test>::get(cluster_id, era.id).unwrap();
+		assert_eq!(
+			era_validation.validators[&(payers_root, payees_root, era.start, era.end)],
+			validators[..2].to_vec()
+		);
+	});
+}
+
+fn session_key(seed: u8) -> ddc_primitives::sr25519::AuthorityId {
+	sp_core::sr25519::Public::from_raw([seed; 32]).into()
+}
+
+#[test]
+fn session_keys_are_bound_to_validator_stashes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let stash1: AccountId32 = [101; 32].into();
+		let stash2: AccountId32 = [102; 32].into();
+		let key1: AccountId32 = [1; 32].into();
+		let key2: AccountId32 = [2; 32].into();
+		let rotated_key1: AccountId32 = [11; 32].into();
+
+		let validators = [(&stash1, session_key(1)), (&stash2, session_key(2))];
+		DdcVerification::on_new_session(
+			true,
+			validators.clone().into_iter(),
+			validators.into_iter(),
+		);
+
+		assert_eq!(ValidatorSet::<Test>::get(), vec![key1.clone(), key2.clone()]);
+		assert_eq!(ValidatorToStashKey::<Test>::get(&key1), Some(stash1.clone()));
+		assert_eq!(ValidatorToStashKey::<Test>::get(&key2), Some(stash2.clone()));
+		assert_eq!(StashToValidatorKey::<Test>::get(&stash1), Some(key1.clone()));
+		System::assert_has_event(Event::ValidatorKeySet { validator: key1.clone() }.into());
+		assert!(DdcVerification::is_ocw_validator(key1.clone()));
+
+		// Keys are rotated with `session.set_keys`
+		let validators = [(&stash1, session_key(11)), (&stash2, session_key(2))];
+		DdcVerification::on_new_session(
+			true,
+			validators.clone().into_iter(),
+			validators.into_iter(),
+		);
+
+		System::assert_has_event(
+			Event::ValidatorKeyRotated {
+				stash: stash1.clone(),
+				previous: key1.clone(),
+				validator: rotated_key1.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(ValidatorSet::<Test>::get(), vec![rotated_key1.clone(), key2.clone()]);
+		assert_eq!(StashToValidatorKey::<Test>::get(&stash1), Some(rotated_key1.clone()));
+		assert!(DdcVerification::is_ocw_validator(rotated_key1.clone()));
+		assert!(!DdcVerification::is_ocw_validator(key1.clone()));
+
+		// The replaced key stays bound to the stash
+		assert_eq!(
+			DdcVerification::verification_key_status(&key1),
+			VerificationKeyStatus {
+				in_validator_set: false,
+				stash: Some(stash1.clone()),
+				is_latest: false,
+			}
+		);
+		assert_eq!(
+			DdcVerification::verification_key_status(&rotated_key1),
+			VerificationKeyStatus { in_validator_set: true, stash: Some(stash1), is_latest: true }
+		);
+	});
+}
+
+#[test]
+fn set_prepare_era_for_payout_counts_rotated_keys_once() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let stash: AccountId32 = [101; 32].into();
+		let key: AccountId32 = [1; 32].into();
+		let rotated_key: AccountId32 = [11; 32].into();
+
+		let validators = [(&stash, session_key(1))];
+		DdcVerification::on_new_session(
+			true,
+			validators.clone().into_iter(),
+			validators.into_iter(),
+		);
+
+		let payers_root: DeltaUsageHash = H256([1; 32]);
+		let payees_root: DeltaUsageHash = H256([2; 32]);
+		let era = EraActivity { id: 1, start: 1000, end: 2000 };
+
+		assert_ok!(DdcVerification::set_prepare_era_for_payout(
+			RuntimeOrigin::signed(key),
+			cluster_id,
+			era,
+			payers_root,
+			payees_root,
+			vec![payers_root],
+			vec![payees_root],
+		));
+
+		let validators = [(&stash, session_key(11))];
+		DdcVerification::on_new_session(
+			true,
+			validators.clone().into_iter(),
+			validators.into_iter(),
+		);
+
+		assert_noop!(
+			DdcVerification::set_prepare_era_for_payout(
+				RuntimeOrigin::signed(rotated_key),
+				cluster_id,
+				era,
+				payers_root,
+				payees_root,
+				vec![payers_root],
+				vec![payees_root],
+			),
+			Error::<Test>::AlreadySignedEra
+		);
+	});
+}
+
+fn validator_pair(seed: u8) -> sp_core::sr25519::Pair {
+	sp_core::sr25519::Pair::from_seed(&[seed; 32])
+}
+
+fn validator_call(
+	pair: &sp_core::sr25519::Pair,
+	block_number: u64,
+	call: Call<Test>,
+) -> (ValidatorCallPayload<Test>, Signature) {
+	let payload = ValidatorCallPayload::<Test> {
+		public: pair.public().into(),
+		block_number,
+		call: Box::new(call),
+	};
+	let signature = pair.sign(&payload.encode()).into();
+	(payload, signature)
+}
+
+fn validate_validator_call(
+	(payload, signature): (ValidatorCallPayload<Test>, Signature),
+) -> TransactionValidity {
+	DdcVerification::validate_unsigned(
+		TransactionSource::External,
+		&Call::submit_validator_call { payload, signature },
+	)
+}
+
+fn prepare_era_call(cluster_id: ClusterId) -> Call<Test> {
+	let payers_root: DeltaUsageHash = H256([1; 32]);
+	let payees_root: DeltaUsageHash = H256([2; 32]);
+	Call::set_prepare_era_for_payout {
+		cluster_id,
+		era_activity: EraActivity { id: 1, start: 1000, end: 2000 },
+		payers_merkle_root_hash: payers_root,
+		payees_merkle_root_hash: payees_root,
+		payers_batch_merkle_root_hashes: vec![payers_root],
+		payees_batch_merkle_root_hashes: vec![payees_root],
+	}
+}
+
+fn charging_batch_call(cluster_id: ClusterId, batch_index: BatchIndex) -> Call<Test> {
+	Call::send_charging_customers_batch {
+		cluster_id,
+		era_id: 1,
+		batch_index,
+		payers: vec![],
+		batch_proof: MMRProof::default(),
+	}
+}
+
+#[test]
+fn validator_calls_are_submitted_as_unsigned_transactions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let validator = validator_pair(1);
+		setup_ocw_validators(&[
+			validator.public().into(),
+			validator_pair(3).public().into(),
+			validator_pair(4).public().into(),
+		]);
+
+		let validity =
+			validate_validator_call(validator_call(&validator, 10, prepare_era_call(cluster_id)))
+				.unwrap();
+		assert_eq!(validity.priority, UnsignedPriority::get());
+		assert_eq!(validity.provides.len(), 1);
+		assert_eq!(validity.longevity, UNSIGNED_TRANSACTIONS_LONGEVITY as u64);
+		assert!(validity.propagate);
+
+		let (payload, signature) = validator_call(&validator, 10, prepare_era_call(cluster_id));
+		assert_ok!(DdcVerification::submit_validator_call(
+			RuntimeOrigin::none(),
+			payload.clone(),
+			signature.clone(),
+		));
+
+		// the call is dispatched on behalf of the validator
+		let (payload, signature) = validator_call(&validator, 11, prepare_era_call(cluster_id));
+		assert_noop!(
+			DdcVerification::submit_validator_call(RuntimeOrigin::none(), payload, signature),
+			Error::<Test>::AlreadySignedEra
+		);
+	});
+}
+
+#[test]
+fn validator_calls_can_not_be_replayed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+
+		let validator = validator_pair(1);
+		setup_ocw_validators(&[validator.public().into()]);
+
+		let call = Call::emit_consensus_errors {
+			cluster_id: ClusterId::from([12; 20]),
+			errors: BoundedVec::truncate_from(vec![OCWError::FailedToFetchClusterNodes]),
+		};
+		let (payload, signature) = validator_call(&validator, 10, call);
+		assert_ok!(DdcVerification::submit_validator_call(
+			RuntimeOrigin::none(),
+			payload.clone(),
+			signature.clone(),
+		));
+
+		assert_eq!(
+			validate_validator_call((payload.clone(), signature.clone())),
+			InvalidTransaction::Stale.into()
+		);
+		assert_noop!(
+			DdcVerification::submit_validator_call(
+				RuntimeOrigin::none(),
+				payload.clone(),
+				signature.clone()
+			),
+			Error::<Test>::ValidatorCallAlreadyApplied
+		);
+
+		// applied calls are forgotten once their payloads expire
+		let call_hash = <Test as frame_system::Config>::Hashing::hash_of(&payload);
+		let expires_at = 10 + UNSIGNED_TRANSACTIONS_LONGEVITY as u64;
+		DdcVerification::on_initialize(expires_at);
+		assert!(AppliedValidatorCalls::<Test>::contains_key(10, call_hash));
+		DdcVerification::on_initialize(expires_at + 1);
+		assert!(!AppliedValidatorCalls::<Test>::contains_key(10, call_hash));
+	});
+}
+
+#[test]
+fn consensus_errors_are_reported_once_per_era() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let validator = validator_pair(1);
+		setup_ocw_validators(&[validator.public().into()]);
+		let call = || Call::emit_consensus_errors {
+			cluster_id,
+			errors: BoundedVec::truncate_from(vec![OCWError::FailedToFetchClusterNodes]),
+		};
+
+		let (payload, signature) = validator_call(&validator, 9, call());
+		assert_ok!(DdcVerification::submit_validator_call(
+			RuntimeOrigin::none(),
+			payload,
+			signature
+		));
+
+		// the errors are signed again at another block
+		let (payload, signature) = validator_call(&validator, 10, call());
+		assert_eq!(
+			validate_validator_call((payload.clone(), signature.clone())),
+			InvalidTransaction::Stale.into()
+		);
+		assert_noop!(
+			DdcVerification::submit_validator_call(RuntimeOrigin::none(), payload, signature),
+			Error::<Test>::ConsensusErrorsAlreadyReported
+		);
+
+		// the errors are reported again once the next era is paid
+		LastPaidEra::set(1);
+		let (payload, signature) = validator_call(&validator, 10, call());
+		assert!(validate_validator_call((payload.clone(), signature.clone())).is_ok());
+		assert_ok!(DdcVerification::submit_validator_call(
+			RuntimeOrigin::none(),
+			payload,
+			signature
+		));
+	});
+}
+
+#[test]
+fn validator_calls_are_rejected_unless_signed_by_validator() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(100);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let validator = validator_pair(1);
+		let stranger = validator_pair(2);
+		setup_ocw_validators(&[validator.public().into()]);
+
+		assert_eq!(
+			validate_validator_call(validator_call(&stranger, 100, prepare_era_call(cluster_id))),
+			InvalidTransaction::BadSigner.into()
+		);
+
+		let (mut payload, signature) = validator_call(&stranger, 100, prepare_era_call(cluster_id));
+		payload.public = validator.public().into();
+		assert_eq!(
+			validate_validator_call((payload, signature)),
+			InvalidTransaction::BadProof.into()
+		);
+
+		assert_eq!(
+			validate_validator_call(validator_call(&validator, 101, prepare_era_call(cluster_id))),
+			InvalidTransaction::Future.into()
+		);
+
+		let stale_at = 100 - UNSIGNED_TRANSACTIONS_LONGEVITY as u64 - 1;
+		assert_eq!(
+			validate_validator_call(validator_call(
+				&validator,
+				stale_at,
+				prepare_era_call(cluster_id)
+			)),
+			InvalidTransaction::Stale.into()
+		);
+
+		// root and permissionless calls can not be submitted by validators without fees
+		let call = Call::skip_dac_validation_to_era { cluster_id, era_id: 1 };
+		assert_eq!(
+			validate_validator_call(validator_call(&validator, 100, call.clone())),
+			InvalidTransaction::Call.into()
+		);
+		let (payload, signature) = validator_call(&validator, 100, call);
+		assert_noop!(
+			DdcVerification::submit_validator_call(RuntimeOrigin::none(), payload, signature),
+			Error::<Test>::NotValidatorCall
+		);
+	});
+}
+
+#[test]
+fn validator_calls_are_deduplicated_by_tags() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let validator1 = validator_pair(1);
+		let validator2 = validator_pair(2);
+		setup_ocw_validators(&[validator1.public().into(), validator2.public().into()]);
+
+		let provides = |pair: &sp_core::sr25519::Pair, block_number: u64, call: Call<Test>| {
+			validate_validator_call(validator_call(pair, block_number, call))
+				.unwrap()
+				.provides
+		};
+
+		// every validator votes for the era
+		assert_ne!(
+			provides(&validator1, 10, prepare_era_call(cluster_id)),
+			provides(&validator2, 10, prepare_era_call(cluster_id))
+		);
+		assert_eq!(
+			provides(&validator1, 9, prepare_era_call(cluster_id)),
+			provides(&validator1, 10, prepare_era_call(cluster_id))
+		);
+
+		// a batch is sent once by any validator
+		assert_eq!(
+			provides(&validator1, 9, charging_batch_call(cluster_id, 0)),
+			provides(&validator2, 10, charging_batch_call(cluster_id, 0))
+		);
+		assert_ne!(
+			provides(&validator1, 10, charging_batch_call(cluster_id, 0)),
+			provides(&validator1, 10, charging_batch_call(cluster_id, 1))
+		);
+	});
+}
+
+#[test]
+fn executed_payout_steps_are_rejected_by_transaction_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let validator = validator_pair(1);
+		setup_ocw_validators(&[validator.public().into()]);
+
+		BillingReportState::set(PayoutState::ChargingCustomers);
+		ProcessedBatches::set(vec![0]);
+
+		let check = |call: Call<Test>| {
+			let info = DispatchInfo::default();
+			let call = RuntimeCall::DdcVerification(call);
+			let signed = CheckPayoutStep::<Test>::new().validate(
+				&validator.public().into(),
+				&call,
+				&info,
+				0,
+			);
+			assert_eq!(signed, CheckPayoutStep::<Test>::validate_unsigned(&call, &info, 0));
+			signed.map(|_| ())
+		};
+		let stale = Err(InvalidTransaction::Stale.into());
+
+		assert_eq!(check(charging_batch_call(cluster_id, 0)), stale);
+		assert_eq!(check(charging_batch_call(cluster_id, 1)), Ok(()));
+		assert_eq!(
+			check(Call::begin_charging_customers { cluster_id, era_id: 1, max_batch_index: 1 }),
+			stale
+		);
+		assert_eq!(check(Call::end_charging_customers { cluster_id, era_id: 1 }), Ok(()));
+		assert_eq!(check(prepare_era_call(cluster_id)), Ok(()));
+
+		// payout steps submitted by validators as unsigned transactions are checked too
+		let (payload, signature) =
+			validator_call(&validator, 10, charging_batch_call(cluster_id, 0));
+		assert_eq!(check(Call::submit_validator_call { payload, signature }), stale);
+		let (payload, signature) =
+			validator_call(&validator, 10, charging_batch_call(cluster_id, 1));
+		assert_eq!(check(Call::submit_validator_call { payload, signature }), Ok(()));
+	});
+}
+
+#[test]
+fn payout_step_leader_is_elected_with_fallbacks() {
+	new_test_ext().execute_with(|| {
+		let cluster_id = ClusterId::from([12; 20]);
+		let validators: Vec<AccountId32> = get_validators().into_iter().take(3).collect();
+		setup_ocw_validators(&validators);
+
+		let step = DdcVerification::payout_step(&charging_batch_call(cluster_id, 0)).unwrap();
+		let leaders = |pending_for: u64| {
+			validators
+				.iter()
+				.filter(|validator| {
+					DdcVerification::is_payout_step_leader(&step, validator, pending_for)
+				})
+				.cloned()
+				.collect::<Vec<_>>()
+		};
+
+		let leader = leaders(0);
+		assert_eq!(leader.len(), 1);
+		assert_eq!(leaders(PAYOUT_STEP_LEADER_TIMEOUT as u64 - 1), leader);
+
+		let fallback = leaders(PAYOUT_STEP_LEADER_TIMEOUT as u64);
+		assert_eq!(fallback.len(), 2);
+		assert!(fallback.contains(&leader[0]));
+
+		assert_eq!(leaders(2 * PAYOUT_STEP_LEADER_TIMEOUT as u64).len(), 3);
+
+		// another batch may be sent by another validator, but the election is deterministic
+		let other_step = DdcVerification::payout_step(&charging_batch_call(cluster_id, 1)).unwrap();
+		assert_ne!(step, other_step);
+		assert_eq!(
+			validators
+				.iter()
+				.filter(|validator| {
+					DdcVerification::is_payout_step_leader(&other_step, validator, 0)
+				})
+				.count(),
+			1
+		);
+
+		// only validators of the current session are elected
+		let stranger: AccountId32 = [42; 32].into();
+		assert!(!DdcVerification::is_payout_step_leader(&step, &stranger, 1000));
+	});
+}
+
+fn fraud_proof_node() -> NodePubKey {
+	NodePubKey::StoragePubKey(AccountId32::new(array_bytes::hex_n_into_unchecked(
+		"0ac7cb9c53594e9f538d9950c6bcf28f0c0c7b8385deea2ebe24062bc640e7be",
+	)))
+}
+
+/// Client requests of the fixture are authorized by an auth token of this account.
+fn fraud_proof_bucket_owner() -> [u8; 32] {
+	array_bytes::hex2array_unchecked::<_, 32>(
+		"09cf069b8ef5d3f509eea39766d7c73a068c9e6de5c4995733a6962405499d45",
+	)
+}
+
+fn fraud_proof_activity() -> Vec<u8> {
+	include_bytes!("./test_data/challenge_response.pb").to_vec()
+}
+
+/// Billing fingerprint committing a single batch of payers and a single batch of payees.
+fn fraud_proof_fingerprint(
+	payers: &[(BucketId, BucketUsage)],
+	payees: &[(NodePubKey, NodeUsage)],
+) -> BillingFingerprintParams<AccountId> {
+	let cluster_id = ClusterId::from([12; 20]);
+	let era = 1;
+
+	let payers_leaves: Vec<H256> = payers
+		.iter()
+		.map(|(bucket_id, usage)| BucketPayableUsage(*bucket_id, usage.clone()).hash::<Test>())
+		.collect();
+	let payers_batch_root =
+		DdcVerification::create_merkle_root(&cluster_id, era, &payers_leaves).unwrap();
+
+	let payees_leaves: Vec<H256> = payees
+		.iter()
+		.map(|(node_key, usage)| NodePayableUsage(node_key.clone(), usage.clone()).hash::<Test>())
+		.collect();
+	let payees_batch_root =
+		DdcVerification::create_merkle_root(&cluster_id, era, &payees_leaves).unwrap();
+
+	BillingFingerprintParams {
+		cluster_id,
+		era,
+		// activity records of the fixture are created at 1730197103
+		start_era: 1730197000,
+		end_era: 1730198000,
+		payers_merkle_root: DdcVerification::create_merkle_root(
+			&cluster_id,
+			era,
+			&[payers_batch_root],
+		)
+		.unwrap(),
+		payees_merkle_root: DdcVerification::create_merkle_root(
+			&cluster_id,
+			era,
+			&[payees_batch_root],
+		)
+		.unwrap(),
+		..Default::default()
+	}
+}
+
+fn fraud_proof(usage: DisputedUsage, leaf_index: u32) -> FraudProof {
+	FraudProof {
+		usage,
+		leaf_index,
+		batch_index: 0,
+		max_batch_index: 0,
+		batch_proof: MMRProof::default(),
+		activity_proof: fraud_proof_activity(),
+		node_key: fraud_proof_node(),
+	}
+}
+
+#[test]
+fn fraud_proof_with_unaccounted_activity_is_accepted() {
+	new_test_ext().execute_with(|| {
+		BucketOwner::set(fraud_proof_bucket_owner());
+		let other_node = NodePubKey::StoragePubKey(AccountId32::new([1; 32]));
+		// the node got 1046528 + 385 + 2184 bytes acknowledged for 2 puts and 1 get
+		let payees = vec![
+			(other_node, NodeUsage::default()),
+			(
+				fraud_proof_node(),
+				NodeUsage {
+					transferred_bytes: 1046528,
+					stored_bytes: 2 * 1046528,
+					number_of_puts: 2,
+					number_of_gets: 1,
+				},
+			),
+		];
+		// the bucket was charged for nothing
+		let payers = vec![(1, BucketUsage::default())];
+		let fingerprint = fraud_proof_fingerprint(&payers, &payees);
+
+		assert_ok!(DdcVerification::verify_fraud_proof(
+			&fingerprint,
+			&fraud_proof(DisputedUsage::Payees(payees), 1),
+		));
+		assert_ok!(DdcVerification::verify_fraud_proof(
+			&fingerprint,
+			&fraud_proof(DisputedUsage::Payers(payers), 0),
+		));
+	});
+}
+
+#[test]
+fn fraud_proof_consistent_with_committed_usage_is_rejected() {
+	new_test_ext().execute_with(|| {
+		BucketOwner::set(fraud_proof_bucket_owner());
+		// the aggregator reports 3 pieces of 1046528 bytes delivered, but only the acknowledged
+		// bytes are signed
+		let payees = vec![(
+			fraud_proof_node(),
+			NodeUsage {
+				transferred_bytes: 1046528 + 385 + 2184,
+				stored_bytes: 2 * 1046528,
+				number_of_puts: 2,
+				number_of_gets: 1,
+			},
+		)];
+		let payers = vec![(2, BucketUsage::default())];
+		let fingerprint = fraud_proof_fingerprint(&payers, &payees);
+
+		assert_noop!(
+			DdcVerification::verify_fraud_proof(
+				&fingerprint,
+				&fraud_proof(DisputedUsage::Payees(payees.clone()), 0),
+			),
+			Error::<Test>::FraudProofDoesNotContradict
+		);
+		// there is no activity of another bucket
+		assert_noop!(
+			DdcVerification::verify_fraud_proof(
+				&fingerprint,
+				&fraud_proof(DisputedUsage::Payers(payers), 0),
+			),
+			Error::<Test>::FraudProofDoesNotContradict
+		);
+
+		// activity of another era is not taken into account
+		let payees = vec![(fraud_proof_node(), NodeUsage::default())];
+		let mut fingerprint = fraud_proof_fingerprint(&[], &payees);
+		fingerprint.start_era = 1730198000;
+		fingerprint.end_era = 1730199000;
+		assert_noop!(
+			DdcVerification::verify_fraud_proof(
+				&fingerprint,
+				&fraud_proof(DisputedUsage::Payees(payees), 0),
+			),
+			Error::<Test>::FraudProofDoesNotContradict
+		);
+	});
+}
+
+#[test]
+fn fraud_proof_must_prove_committed_batch_and_signed_activity() {
+	new_test_ext().execute_with(|| {
+		let payees = vec![(fraud_proof_node(), NodeUsage::default())];
+		let fingerprint = fraud_proof_fingerprint(&[], &payees);
+
+		// leaf is out of the batch
+		assert_noop!(
+			DdcVerification::verify_fraud_proof(
+				&fingerprint,
+				&fraud_proof(DisputedUsage::Payees(payees.clone()), 1),
+			),
+			Error::<Test>::BadFraudProof
+		);
+
+		// batch is not committed under the payees root
+		let other_payees =
+			vec![(fraud_proof_node(), NodeUsage { number_of_gets: 1, ..Default::default() })];
+		assert_noop!(
+			DdcVerification::verify_fraud_proof(
+				&fingerprint,
+				&fraud_proof(DisputedUsage::Payees(other_payees), 0),
+			),
+			Error::<Test>::FraudProofBatchIsNotCommitted
+		);
+
+		// activity proof is not a challenge response
+		let mut proof = fraud_proof(DisputedUsage::Payees(payees.clone()), 0);
+		proof.activity_proof = vec![0xff; 8];
+		assert_noop!(
+			DdcVerification::verify_fraud_proof(&fingerprint, &proof),
+			Error::<Test>::BadFraudProof
+		);
+
+		// activity record is tampered
+		let mut challenge_response =
+			proto::ChallengeResponse::decode(fraud_proof_activity().as_slice()).unwrap();
+		match &mut challenge_response.proofs[0].leaves[0].leaf_variant {
+			Some(proto::challenge_response::proof::leaf::LeafVariant::Record(record)) =>
+				record.record.as_mut().unwrap().timestamp += 1,
+			_ => panic!("fixture leaf is expected to be a record"),
+		}
+		let mut proof = fraud_proof(DisputedUsage::Payees(payees), 0);
+		proof.activity_proof = challenge_response.encode_to_vec();
+		assert_noop!(
+			DdcVerification::verify_fraud_proof(&fingerprint, &proof),
+			Error::<Test>::FraudProofSignatureInvalid
+		);
+	});
+}
+
+#[test]
+fn submit_fraud_proof_fails_when_dispute_window_is_closed() {
+	new_test_ext().execute_with(|| {
+		let challenger: AccountId32 = [7; 32].into();
+		let payees = vec![(fraud_proof_node(), NodeUsage::default())];
+
+		assert_noop!(
+			DdcVerification::submit_fraud_proof(
+				RuntimeOrigin::signed(challenger),
+				ClusterId::from([12; 20]),
+				1,
+				fraud_proof(DisputedUsage::Payees(payees), 0),
+			),
+			Error::<Test>::DisputeWindowIsClosed
+		);
+	});
+}
+
+#[test]
+fn fraud_proof_must_be_signed_by_cluster_nodes_or_bucket_owner() {
+	new_test_ext().execute_with(|| {
+		let client =
+			NodePubKey::StoragePubKey(AccountId32::new(array_bytes::hex_n_into_unchecked(
+				"88dc3417d5058ec4b4503e0c12ea1a0a89be200fe98922423d4334014fa6b0ee",
+			)));
+		let payees = vec![(fraud_proof_node(), NodeUsage::default())];
+		let fingerprint = fraud_proof_fingerprint(&[], &payees);
+		ClusterNodeKeys::set(Some(vec![fraud_proof_node(), client]));
+
+		// the client is neither the bucket owner nor authorized by the bucket owner, signing
+		// the requests as a node of the cluster does not authorize them
+		assert_noop!(
+			DdcVerification::verify_fraud_proof(
+				&fingerprint,
+				&fraud_proof(DisputedUsage::Payees(payees.clone()), 0),
+			),
+			Error::<Test>::FraudProofSignerIsNotAuthorized
+		);
+
+		BucketOwner::set(fraud_proof_bucket_owner());
+		assert_ok!(DdcVerification::verify_fraud_proof(
+			&fingerprint,
+			&fraud_proof(DisputedUsage::Payees(payees.clone()), 0),
+		));
+
+		// the records are signed by a node out of the cluster
+		ClusterNodeKeys::set(Some(vec![]));
+		assert_noop!(
+			DdcVerification::verify_fraud_proof(
+				&fingerprint,
+				&fraud_proof(DisputedUsage::Payees(payees.clone()), 0),
+			),
+			Error::<Test>::FraudProofSignerIsNotAuthorized
+		);
+	});
+}
+
+#[test]
+fn fraud_proof_must_be_signed_by_disputed_node() {
+	new_test_ext().execute_with(|| {
+		BucketOwner::set(fraud_proof_bucket_owner());
+		let other_node = NodePubKey::StoragePubKey(AccountId32::new([1; 32]));
+		ClusterNodeKeys::set(Some(vec![fraud_proof_node(), other_node.clone()]));
+		let payees = vec![(other_node.clone(), NodeUsage::default())];
+		let payers = vec![(1, BucketUsage::default())];
+		let fingerprint = fraud_proof_fingerprint(&payers, &payees);
+
+		// records of a node do not prove the usage of another node
+		let mut proof = fraud_proof(DisputedUsage::Payees(payees.clone()), 0);
+		assert_noop!(
+			DdcVerification::verify_fraud_proof(&fingerprint, &proof),
+			Error::<Test>::BadFraudProof
+		);
+		proof.node_key = other_node.clone();
+		assert_noop!(
+			DdcVerification::verify_fraud_proof(&fingerprint, &proof),
+			Error::<Test>::FraudProofDoesNotContradict
+		);
+
+		// records signed by another node of the cluster are not taken into account
+		let mut proof = fraud_proof(DisputedUsage::Payers(payers), 0);
+		proof.node_key = other_node;
+		assert_noop!(
+			DdcVerification::verify_fraud_proof(&fingerprint, &proof),
+			Error::<Test>::FraudProofDoesNotContradict
+		);
+		assert_noop!(
+			DdcVerification::verify_fraud_proof(
+				&fingerprint,
+				&fraud_proof(DisputedUsage::Payees(payees), 0),
+			),
+			Error::<Test>::FraudProofSignerIsNotAuthorized
+		);
+	});
+}
+
+#[test]
+fn submit_fraud_proof_disputes_billing_report() {
+	new_test_ext().execute_with(|| {
+		BucketOwner::set(fraud_proof_bucket_owner());
+		System::set_block_number(1);
+
+		let challenger: AccountId32 = [7; 32].into();
+		let validator: AccountId32 = [1; 32].into();
+		let cluster_id = ClusterId::from([12; 20]);
+		let era_id = 1;
+		let payees = vec![(fraud_proof_node(), NodeUsage::default())];
+		let fingerprint = fraud_proof_fingerprint(&[], &payees);
+
+		BillingFingerprint::set(Some((
+			fingerprint.start_era,
+			fingerprint.end_era,
+			fingerprint.payers_merkle_root,
+			fingerprint.payees_merkle_root,
+			vec![validator.clone()],
+		)));
+		BillingReportState::set(PayoutState::Initialized);
+		DisputeWindowOpen::set(true);
+		let pallet_account: AccountId = VerificationPalletId::get().into_account_truncating();
+		Balances::make_free_balance_be(&pallet_account, 1000);
+
+		assert_ok!(DdcVerification::submit_fraud_proof(
+			RuntimeOrigin::signed(challenger.clone()),
+			cluster_id,
+			era_id,
+			fraud_proof(DisputedUsage::Payees(payees.clone()), 0),
+		));
+		System::assert_last_event(
+			Event::BillingReportDisputed {
+				cluster_id,
+				era_id,
+				challenger: challenger.clone(),
+				validators: vec![validator.clone()],
+				reward: FraudProofReward::get(),
+			}
+			.into(),
+		);
+		assert_eq!(BillingReportState::get(), PayoutState::Disputed);
+		assert!(FlaggedValidators::<Test>::contains_key(&validator, (cluster_id, era_id)));
+		assert_eq!(Balances::free_balance(&challenger), FraudProofReward::get());
+
+		// the billing report is halted, so it can not be disputed again
+		assert_noop!(
+			DdcVerification::submit_fraud_proof(
+				RuntimeOrigin::signed(challenger),
+				cluster_id,
+				era_id,
+				fraud_proof(DisputedUsage::Payees(payees), 0),
+			),
+			Error::<Test>::DisputeWindowIsClosed
+		);
+	});
+}
+
+#[test]
+fn recovery_calls_require_root_origin() {
+	new_test_ext().execute_with(|| {
+		let caller: AccountId32 = [7; 32].into();
+		let cluster_id = ClusterId::from([12; 20]);
+
+		assert_noop!(
+			DdcVerification::rollback_billing_report(
+				RuntimeOrigin::signed(caller.clone()),
+				cluster_id,
+				1,
+				PayoutState::NotInitialized,
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			DdcVerification::void_billing_report_batch(
+				RuntimeOrigin::signed(caller.clone()),
+				cluster_id,
+				1,
+				0,
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			DdcVerification::force_advance_billing_report(
+				RuntimeOrigin::signed(caller.clone()),
+				cluster_id,
+				1,
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			DdcVerification::requeue_era_validation(RuntimeOrigin::signed(caller), cluster_id, 1),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn rolled_back_era_can_be_requeued_for_validation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let era_id = 1;
+		let validator: AccountId32 = [1; 32].into();
+		let mut validators = BTreeMap::new();
+		validators
+			.insert((H256::repeat_byte(1), H256::repeat_byte(2), 0, 0), vec![validator.clone()]);
+		EraValidations::<Test>::insert(
+			cluster_id,
+			era_id,
+			EraValidation::<Test> {
+				validators,
+				start_era: 0,
+				end_era: 0,
+				payers_merkle_root_hash: Default::default(),
+				payees_merkle_root_hash: Default::default(),
+				status: EraValidationStatus::PayoutInProgress,
+			},
+		);
+		BillingReportState::set(PayoutState::Initialized);
+
+		assert_noop!(
+			DdcVerification::requeue_era_validation(RuntimeOrigin::root(), cluster_id, era_id),
+			Error::<Test>::BillingReportNotRolledBack
+		);
+
+		assert_ok!(DdcVerification::rollback_billing_report(
+			RuntimeOrigin::root(),
+			cluster_id,
+			era_id,
+			PayoutState::NotInitialized,
+		));
+		assert_eq!(BillingReportState::get(), PayoutState::NotInitialized);
+		assert_eq!(
+			EraValidations::<Test>::get(cluster_id, era_id).unwrap().status,
+			EraValidationStatus::ReadyForPayout
+		);
+		assert!(!DdcVerification::is_era_pending_validation(&cluster_id, era_id, &validator));
+
+		assert_noop!(
+			DdcVerification::requeue_era_validation(RuntimeOrigin::root(), cluster_id, 2),
+			Error::<Test>::NoEraValidation
+		);
+		assert_ok!(DdcVerification::requeue_era_validation(
+			RuntimeOrigin::root(),
+			cluster_id,
+			era_id
+		));
+		System::assert_last_event(Event::EraValidationRequeued { cluster_id, era_id }.into());
+
+		let era_validation = EraValidations::<Test>::get(cluster_id, era_id).unwrap();
+		assert_eq!(era_validation.status, EraValidationStatus::ValidatingData);
+		assert!(era_validation.validators.is_empty());
+		assert!(DdcVerification::is_era_pending_validation(&cluster_id, era_id, &validator));
+	});
+}
+
+#[test]
+fn submit_node_validation_requires_quorum_on_outcome() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::new([7; 32]));
+		let validators = get_validators();
+		setup_ocw_validators(&validators);
+		NodeValidationStatus::set(ClusterNodeStatus::AwaitsValidation);
+
+		let succeeded =
+			NodeProbeResult { http_reachable: true, grpc_reachable: true, handshake_signed: true };
+		let failed = NodeProbeResult { handshake_signed: false, ..succeeded };
+
+		assert_noop!(
+			DdcVerification::submit_node_validation(
+				RuntimeOrigin::signed(AccountId32::new([9; 32])),
+				cluster_id,
+				node_pub_key.clone(),
+				succeeded,
+			),
+			Error::<Test>::Unauthorized
+		);
+
+		assert_ok!(DdcVerification::submit_node_validation(
+			RuntimeOrigin::signed(validators[0].clone()),
+			cluster_id,
+			node_pub_key.clone(),
+			failed,
+		));
+		System::assert_last_event(
+			Event::NodeProbeResultSubmitted {
+				cluster_id,
+				node_pub_key: node_pub_key.clone(),
+				validator: validators[0].clone(),
+				result: failed,
+			}
+			.into(),
+		);
+		assert_noop!(
+			DdcVerification::submit_node_validation(
+				RuntimeOrigin::signed(validators[0].clone()),
+				cluster_id,
+				node_pub_key.clone(),
+				succeeded,
+			),
+			Error::<Test>::AlreadySubmittedNodeProbeResult
+		);
+
+		// The failed probe does not count towards the quorum on the succeeded outcome
+		for validator in &validators[1..3] {
+			assert_ok!(DdcVerification::submit_node_validation(
+				RuntimeOrigin::signed(validator.clone()),
+				cluster_id,
+				node_pub_key.clone(),
+				succeeded,
+			));
+		}
+		assert_eq!(NodeValidationStatus::get(), ClusterNodeStatus::AwaitsValidation);
+		assert_eq!(
+			DdcVerification::node_validations(cluster_id, &node_pub_key).unwrap().succeeded,
+			None
+		);
+
+		assert_ok!(DdcVerification::submit_node_validation(
+			RuntimeOrigin::signed(validators[3].clone()),
+			cluster_id,
+			node_pub_key.clone(),
+			succeeded,
+		));
+		System::assert_last_event(
+			Event::NodeValidationCompleted {
+				cluster_id,
+				node_pub_key: node_pub_key.clone(),
+				succeeded: true,
+			}
+			.into(),
+		);
+		assert_eq!(NodeValidationStatus::get(), ClusterNodeStatus::ValidationSucceeded);
+
+		// Probe results are kept as an audit trail
+		let node_validation = DdcVerification::node_validations(cluster_id, &node_pub_key).unwrap();
+		assert_eq!(node_validation.added_at, 1);
+		assert_eq!(node_validation.succeeded, Some(true));
+		assert_eq!(
+			node_validation.results,
+			vec![
+				(validators[0].clone(), failed),
+				(validators[1].clone(), succeeded),
+				(validators[2].clone(), succeeded),
+				(validators[3].clone(), succeeded),
+			]
+		);
+
+		assert_noop!(
+			DdcVerification::submit_node_validation(
+				RuntimeOrigin::signed(validators[4].clone()),
+				cluster_id,
+				node_pub_key.clone(),
+				succeeded,
+			),
+			Error::<Test>::NodeIsNotAwaitingValidation
+		);
+
+		// The node rejoined the cluster is validated again from scratch
+		NodeValidationStatus::set(ClusterNodeStatus::AwaitsValidation);
+		NodeAddedAt::set(5);
+		assert_ok!(DdcVerification::submit_node_validation(
+			RuntimeOrigin::signed(validators[0].clone()),
+			cluster_id,
+			node_pub_key.clone(),
+			succeeded,
+		));
+		let node_validation = DdcVerification::node_validations(cluster_id, &node_pub_key).unwrap();
+		assert_eq!(node_validation.added_at, 5);
+		assert_eq!(node_validation.results, vec![(validators[0].clone(), succeeded)]);
+		assert_eq!(node_validation.succeeded, None);
+	});
+}
+
+#[test]
+fn submit_node_validation_matches_submitters_by_bound_stash() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let cluster_id = ClusterId::from([12; 20]);
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::new([7; 32]));
+		let validators = get_validators();
+		setup_ocw_validators(&validators);
+		NodeValidationStatus::set(ClusterNodeStatus::AwaitsValidation);
+
+		let failed =
+			NodeProbeResult { http_reachable: true, grpc_reachable: false, handshake_signed: true };
+
+		assert_ok!(DdcVerification::submit_node_validation(
+			RuntimeOrigin::signed(validators[0].clone()),
+			cluster_id,
+			node_pub_key.clone(),
+			failed,
+		));
+
+		// A submitter which is no longer bound to a stash does not match other submitters
+		ValidatorToStashKey::<Test>::remove(&validators[0]);
+		assert_ok!(DdcVerification::submit_node_validation(
+			RuntimeOrigin::signed(validators[1].clone()),
+			cluster_id,
+			node_pub_key.clone(),
+			failed,
+		));
+
+		// A rotated verification key of the same stash is the same submitter
+		let rotated_key = AccountId32::new([42; 32]);
+		ValidatorToStashKey::<Test>::insert(&rotated_key, &validators[1]);
+		ValidatorSet::<Test>::append(rotated_key.clone());
+		assert_noop!(
+			DdcVerification::submit_node_validation(
+				RuntimeOrigin::signed(rotated_key),
+				cluster_id,
+				node_pub_key.clone(),
+				failed,
+			),
+			Error::<Test>::AlreadySubmittedNodeProbeResult
+		);
+
+		let node_validation = DdcVerification::node_validations(cluster_id, &node_pub_key).unwrap();
+		assert_eq!(
+			node_validation.results,
+			vec![(validators[0].clone(), failed), (validators[1].clone(), failed)]
+		);
+	});
+}
+
+#[test]
+fn probe_node_requires_handshake_signed_by_node_key() {
+	let mut ext = TestExternalities::default();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(Box::new(offchain)));
+
+	ext.execute_with(|| {
+		let mut offchain_state = offchain_state.write();
+		offchain_state.timestamp = Timestamp::from_unix_millis(0);
+		offchain_state.seed = [3; 32];
+
+		let node_pair = sp_core::ed25519::Pair::from_seed(&[1; 32]);
+		let other_pair = sp_core::ed25519::Pair::from_seed(&[2; 32]);
+		let node_params = StorageNodeParams {
+			ssl: false,
+			host: b"example.com".to_vec(),
+			http_port: 8080,
+			mode: StorageNodeMode::Storage,
+			p2p_port: 5555,
+			grpc_port: 4444,
+			domain: b"example.com".to_vec(),
+		};
+
+		let nonce = [3u8; 32];
+		let handshake = |signer: &sp_core::ed25519::Pair| {
+			format!(
+				r#"{{"nonce":"{}","signer":"{}","signature":"{}"}}"#,
+				Base64::encode_string(&nonce),
+				Base64::encode_string(&signer.public().0),
+				Base64::encode_string(&signer.sign(&nonce).0),
+			)
+			.into_bytes()
+		};
+
+		// gRPC-Web frames of the health check request and of a `SERVING` response
+		let health_check = vec![0, 0, 0, 0, 0];
+		let serving = vec![0, 0, 0, 0, 2, 0x08, 0x01];
+
+		for (signer, grpc_response) in
+			[(&node_pair, serving.clone()), (&other_pair, serving), (&node_pair, vec![])]
+		{
+			offchain_state.expect_request(PendingRequest {
+				method: "GET".to_string(),
+				uri: "http://example.com:8080".to_string(),
+				response: Some(vec![]),
+				sent: true,
+				..Default::default()
+			});
+			offchain_state.expect_request(PendingRequest {
+				method: "POST".to_string(),
+				uri: "http://example.com:4444/grpc.health.v1.Health/Check".to_string(),
+				headers: vec![
+					("Content-Type".to_string(), "application/grpc-web+proto".to_string()),
+					("X-Grpc-Web".to_string(), "1".to_string()),
+				],
+				body: health_check.clone(),
+				response: Some(grpc_response),
+				sent: true,
+				..Default::default()
+			});
+			offchain_state.expect_request(PendingRequest {
+				method: "GET".to_string(),
+				uri: format!("http://example.com:8080/handshake?nonce={}", hex::encode(nonce)),
+				response: Some(handshake(signer)),
+				sent: true,
+				..Default::default()
+			});
+		}
+		drop(offchain_state);
+
+		let node_pub_key = NodePubKey::StoragePubKey(AccountId32::from(node_pair.public().0));
+		let result = DdcVerification::probe_node(&node_pub_key, &node_params);
+		assert_eq!(
+			result,
+			NodeProbeResult { http_reachable: true, grpc_reachable: true, handshake_signed: true }
+		);
+		assert!(result.succeeded());
+
+		// the nonce is correctly signed, but the signer is not the node
+		let result = DdcVerification::probe_node(&node_pub_key, &node_params);
+		assert!(result.http_reachable && result.grpc_reachable);
+		assert!(!result.handshake_signed);
+		assert!(!result.succeeded());
+
+		// the gRPC port responds, but not to the gRPC health check
+		let result = DdcVerification::probe_node(&node_pub_key, &node_params);
+		assert!(result.http_reachable && result.handshake_signed);
+		assert!(!result.grpc_reachable);
+		assert!(!result.succeeded());
 	})
 }
@@ -41,6 +41,12 @@ pub trait WeightInfo {
 	fn emit_consensus_errors(b: u32, ) -> Weight;
 	fn set_era_validations() -> Weight;
 	fn skip_dac_validation_to_era() -> Weight;
+	fn submit_fraud_proof(b: u32, ) -> Weight;
+	fn rollback_billing_report() -> Weight;
+	fn void_billing_report_batch() -> Weight;
+	fn force_advance_billing_report() -> Weight;
+	fn requeue_era_validation() -> Weight;
+	fn submit_node_validation() -> Weight;
 }
 
 /// Weights for pallet_ddc_verification using the Substrate node and recommended hardware.
@@ -202,10 +208,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: `DdcVerification::ValidatorSet` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	// Storage: `DdcPayouts::ActiveBillingReports` (r:1 w:1)
 	// Proof: `DdcPayouts::ActiveBillingReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::VoidedBatches` (r:1 w:0)
+	// Proof: `DdcPayouts::VoidedBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn end_rewarding_providers() -> Weight {
-		Weight::from_parts(34_094_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(98_517_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: `DdcVerification::ValidatorToStashKey` (r:1 w:0)
 	// Proof: `DdcVerification::ValidatorToStashKey` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -226,12 +236,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: `DdcVerification::ValidatorToStashKey` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcVerification::ValidatorSet` (r:1 w:0)
 	// Proof: `DdcVerification::ValidatorSet` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// The range of component `b` is `[1, 5]`.
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcVerification::ConsensusErrorsReports` (r:1 w:1)
+	// Proof: `DdcVerification::ConsensusErrorsReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[1, 64]`.
 	fn emit_consensus_errors(b: u32, ) -> Weight {
-		Weight::from_parts(17_280_993_u64, 0)
+		Weight::from_parts(27_134_116_u64, 0)
 			// Standard Error: 11_424
 			.saturating_add(Weight::from_parts(3_172_369_u64, 0).saturating_mul(b as u64))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: `DdcVerification::EraValidations` (r:1 w:1)
 	// Proof: `DdcVerification::EraValidations` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -248,6 +263,97 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+
+	// Storage: `DdcPayouts::DisputeWindows` (r:1 w:1)
+	// Proof: `DdcPayouts::DisputeWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::ActiveBillingReports` (r:1 w:1)
+	// Proof: `DdcPayouts::ActiveBillingReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::BillingFingerprints` (r:1 w:0)
+	// Proof: `DdcPayouts::BillingFingerprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcCustomers::Buckets` (r:1 w:0)
+	// Proof: `DdcCustomers::Buckets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `DdcVerification::FlaggedValidators` (r:0 w:1)
+	// Proof: `DdcVerification::FlaggedValidators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[4000, 400000]`.
+	fn submit_fraud_proof(b: u32, ) -> Weight {
+		Weight::from_parts(101_204_000_u64, 0)
+			// Standard Error: 1_108
+			.saturating_add(Weight::from_parts(315_562_u64, 0).saturating_mul(b as u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	// Storage: `DdcPayouts::ActiveBillingReports` (r:1 w:1)
+	// Proof: `DdcPayouts::ActiveBillingReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::VoidedBatches` (r:1 w:1)
+	// Proof: `DdcPayouts::VoidedBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcVerification::EraValidations` (r:1 w:1)
+	// Proof: `DdcVerification::EraValidations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::DisputeWindows` (r:0 w:1)
+	// Proof: `DdcPayouts::DisputeWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn rollback_billing_report() -> Weight {
+		Weight::from_parts(41_528_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: `DdcPayouts::ActiveBillingReports` (r:1 w:1)
+	// Proof: `DdcPayouts::ActiveBillingReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::VoidedBatches` (r:1 w:1)
+	// Proof: `DdcPayouts::VoidedBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn void_billing_report_batch() -> Weight {
+		Weight::from_parts(36_871_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcPayouts::ActiveBillingReports` (r:1 w:1)
+	// Proof: `DdcPayouts::ActiveBillingReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::VoidedBatches` (r:1 w:1)
+	// Proof: `DdcPayouts::VoidedBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersGovParams` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersGovParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:3 w:3)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Staking::Validators` (r:2 w:0)
+	// Proof: `Staking::Validators` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	// Storage: `Staking::Bonded` (r:1 w:0)
+	// Proof: `Staking::Bonded` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	// Storage: `Staking::Ledger` (r:1 w:0)
+	// Proof: `Staking::Ledger` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
+	// Storage: `Staking::Nominators` (r:1 w:0)
+	// Proof: `Staking::Nominators` (`max_values`: None, `max_size`: Some(558), added: 3033, mode: `MaxEncodedLen`)
+	fn force_advance_billing_report() -> Weight {
+		Weight::from_parts(1_402_715_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::ActiveBillingReports` (r:1 w:0)
+	// Proof: `DdcPayouts::ActiveBillingReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcVerification::EraValidations` (r:1 w:1)
+	// Proof: `DdcVerification::EraValidations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn requeue_era_validation() -> Weight {
+		Weight::from_parts(27_093_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	// Storage: `DdcVerification::ValidatorToStashKey` (r:2 w:0)
+	// Storage: `DdcVerification::ValidatorSet` (r:1 w:0)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:1)
+	// Storage: `DdcVerification::NodeValidations` (r:1 w:1)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:1)
+	fn submit_node_validation() -> Weight {
+		Weight::from_parts(60_000_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -408,10 +514,14 @@ impl WeightInfo for () {
 	// Proof: `DdcVerification::ValidatorSet` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	// Storage: `DdcPayouts::ActiveBillingReports` (r:1 w:1)
 	// Proof: `DdcPayouts::ActiveBillingReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::VoidedBatches` (r:1 w:0)
+	// Proof: `DdcPayouts::VoidedBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn end_rewarding_providers() -> Weight {
-		Weight::from_parts(34_094_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(98_517_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	// Storage: `DdcVerification::ValidatorToStashKey` (r:1 w:0)
 	// Proof: `DdcVerification::ValidatorToStashKey` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -432,12 +542,17 @@ impl WeightInfo for () {
 	// Proof: `DdcVerification::ValidatorToStashKey` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `DdcVerification::ValidatorSet` (r:1 w:0)
 	// Proof: `DdcVerification::ValidatorSet` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// The range of component `b` is `[1, 5]`.
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcVerification::ConsensusErrorsReports` (r:1 w:1)
+	// Proof: `DdcVerification::ConsensusErrorsReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[1, 64]`.
 	fn emit_consensus_errors(b: u32, ) -> Weight {
-		Weight::from_parts(17_280_993_u64, 0)
+		Weight::from_parts(27_134_116_u64, 0)
 			// Standard Error: 11_424
 			.saturating_add(Weight::from_parts(3_172_369_u64, 0).saturating_mul(b as u64))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	// Storage: `DdcVerification::EraValidations` (r:1 w:1)
 	// Proof: `DdcVerification::EraValidations` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -454,4 +569,95 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+
+	// Storage: `DdcPayouts::DisputeWindows` (r:1 w:1)
+	// Proof: `DdcPayouts::DisputeWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::ActiveBillingReports` (r:1 w:1)
+	// Proof: `DdcPayouts::ActiveBillingReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::BillingFingerprints` (r:1 w:0)
+	// Proof: `DdcPayouts::BillingFingerprints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcCustomers::Buckets` (r:1 w:0)
+	// Proof: `DdcCustomers::Buckets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `DdcVerification::FlaggedValidators` (r:0 w:1)
+	// Proof: `DdcVerification::FlaggedValidators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[4000, 400000]`.
+	fn submit_fraud_proof(b: u32, ) -> Weight {
+		Weight::from_parts(101_204_000_u64, 0)
+			// Standard Error: 1_108
+			.saturating_add(Weight::from_parts(315_562_u64, 0).saturating_mul(b as u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	// Storage: `DdcPayouts::ActiveBillingReports` (r:1 w:1)
+	// Proof: `DdcPayouts::ActiveBillingReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::VoidedBatches` (r:1 w:1)
+	// Proof: `DdcPayouts::VoidedBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcVerification::EraValidations` (r:1 w:1)
+	// Proof: `DdcVerification::EraValidations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::DisputeWindows` (r:0 w:1)
+	// Proof: `DdcPayouts::DisputeWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn rollback_billing_report() -> Weight {
+		Weight::from_parts(41_528_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: `DdcPayouts::ActiveBillingReports` (r:1 w:1)
+	// Proof: `DdcPayouts::ActiveBillingReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::VoidedBatches` (r:1 w:1)
+	// Proof: `DdcPayouts::VoidedBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn void_billing_report_batch() -> Weight {
+		Weight::from_parts(36_871_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: `DdcPayouts::ActiveBillingReports` (r:1 w:1)
+	// Proof: `DdcPayouts::ActiveBillingReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::VoidedBatches` (r:1 w:1)
+	// Proof: `DdcPayouts::VoidedBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcClusters::ClustersGovParams` (r:1 w:0)
+	// Proof: `DdcClusters::ClustersGovParams` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:3 w:3)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Staking::Validators` (r:2 w:0)
+	// Proof: `Staking::Validators` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	// Storage: `Staking::Bonded` (r:1 w:0)
+	// Proof: `Staking::Bonded` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	// Storage: `Staking::Ledger` (r:1 w:0)
+	// Proof: `Staking::Ledger` (`max_values`: None, `max_size`: Some(1091), added: 3566, mode: `MaxEncodedLen`)
+	// Storage: `Staking::Nominators` (r:1 w:0)
+	// Proof: `Staking::Nominators` (`max_values`: None, `max_size`: Some(558), added: 3033, mode: `MaxEncodedLen`)
+	fn force_advance_billing_report() -> Weight {
+		Weight::from_parts(1_402_715_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: `DdcClusters::Clusters` (r:1 w:0)
+	// Proof: `DdcClusters::Clusters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcPayouts::ActiveBillingReports` (r:1 w:0)
+	// Proof: `DdcPayouts::ActiveBillingReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `DdcVerification::EraValidations` (r:1 w:1)
+	// Proof: `DdcVerification::EraValidations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn requeue_era_validation() -> Weight {
+		Weight::from_parts(27_093_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	// Storage: `DdcVerification::ValidatorToStashKey` (r:2 w:0)
+	// Storage: `DdcVerification::ValidatorSet` (r:1 w:0)
+	// Storage: `DdcClusters::ClustersNodes` (r:1 w:1)
+	// Storage: `DdcVerification::NodeValidations` (r:1 w:1)
+	// Storage: `DdcClusters::ClustersNodesStats` (r:1 w:1)
+	fn submit_node_validation() -> Weight {
+		Weight::from_parts(60_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -11,7 +11,7 @@ use scale_info::{
 };
 use serde::{Deserialize, Serialize};
 use sp_core::{crypto::KeyTypeId, hash::H160, H256};
-use sp_runtime::{AccountId32, Perquintill, RuntimeDebug};
+use sp_runtime::{AccountId32, BoundedVec, Percent, Perquintill, RuntimeDebug};
 use sp_std::collections::btree_set::BTreeSet;
 
 pub mod traits;
@@ -19,6 +19,7 @@ pub mod traits;
 parameter_types! {
 	pub MaxHostLen: u8 = 255;
 	pub MaxDomainLen: u8 = 255;
+	pub MaxPricingTiers: u32 = 8;
 }
 
 pub const MAX_PAYOUT_BATCH_COUNT: u16 = 1000;
@@ -93,18 +94,113 @@ pub struct ClusterProtocolParams<Balance, BlockNumber> {
 	pub storage_bond_size: Balance,
 	pub storage_chill_delay: BlockNumber,
 	pub storage_unbonding_delay: BlockNumber,
+	/// Blocks a node stays in the cluster after its removal is requested, so that the data it
+	/// stores can be migrated to other nodes.
+	#[serde(default)]
+	pub node_removal_notice_period: BlockNumber,
 	pub unit_per_mb_stored: u128,
 	pub unit_per_mb_streamed: u128,
 	pub unit_per_put_request: u128,
 	pub unit_per_get_request: u128,
+	#[serde(default)]
+	pub pricing_tiers: ClusterPricingTiers,
 }
 
-#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq)]
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
 pub struct ClusterPricingParams {
 	pub unit_per_mb_stored: u128,
 	pub unit_per_mb_streamed: u128,
 	pub unit_per_put_request: u128,
 	pub unit_per_get_request: u128,
+	pub pricing_tiers: ClusterPricingTiers,
+}
+
+/// Price of the usage above the volume threshold of the tier.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PricingTier {
+	/// Volume from which the tier applies, in bytes for storage and streaming and in number of
+	/// requests for puts and gets. Streaming and requests volume is counted per billing month,
+	/// while the stored volume is the one stored in the charged era.
+	pub threshold: u64,
+	/// Price of a megabyte or a request within the tier.
+	pub unit_price: u128,
+}
+
+/// Pricing tiers in ascending order of their thresholds.
+pub type PricingTiers = BoundedVec<PricingTier, MaxPricingTiers>;
+
+/// Volume discounted prices of a cluster. The volume below the first tier threshold is charged by
+/// the flat unit price, and the volume above it by the price of the tier it falls into.
+#[derive(
+	Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+pub struct ClusterPricingTiers {
+	pub mb_stored: PricingTiers,
+	pub mb_streamed: PricingTiers,
+	pub put_requests: PricingTiers,
+	pub get_requests: PricingTiers,
+}
+
+impl ClusterPricingTiers {
+	/// Checks that the thresholds of the tiers of every kind of usage are strictly ascending.
+	pub fn is_valid(&self) -> bool {
+		[&self.mb_stored, &self.mb_streamed, &self.put_requests, &self.get_requests]
+			.iter()
+			.all(|tiers| tiers.windows(2).all(|pair| pair[0].threshold < pair[1].threshold))
+	}
+}
+
+/// Customer of a cluster a negotiated pricing is attached to.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub enum PricingOverrideTarget<AccountId> {
+	/// All buckets of the customer account in the cluster.
+	Customer(AccountId),
+	/// A single bucket, its pricing takes precedence over the pricing of the bucket owner.
+	Bucket(BucketId),
+}
+
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub enum CustomerPricing {
+	/// Prices charged instead of the cluster prices.
+	Params(ClusterPricingParams),
+	/// Discount on the charge calculated by the cluster prices.
+	Discount(Percent),
+}
+
+/// Pricing negotiated with a customer, it applies to the eras started within its validity
+/// period.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct PricingOverride {
+	pub pricing: CustomerPricing,
+	/// Timestamp in seconds from which the pricing applies.
+	pub valid_from: i64,
+	/// Timestamp in seconds from which the pricing no longer applies.
+	pub valid_till: i64,
+}
+
+impl PricingOverride {
+	pub fn is_valid(&self) -> bool {
+		let is_pricing_valid = match &self.pricing {
+			CustomerPricing::Params(params) => params.pricing_tiers.is_valid(),
+			CustomerPricing::Discount(_) => true,
+		};
+		is_pricing_valid && self.valid_from < self.valid_till
+	}
+
+	pub fn applies_at(&self, time: i64) -> bool {
+		self.valid_from <= time && time < self.valid_till
+	}
+}
+
+/// Price schedule a customer is charged by.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub enum PriceSchedule {
+	/// The pricing of the cluster.
+	Cluster,
+	/// The pricing negotiated with the bucket owner.
+	Customer(CustomerPricing),
+	/// The pricing negotiated for the bucket.
+	Bucket(CustomerPricing),
 }
 
 #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq)]
@@ -246,6 +342,14 @@ pub enum ClusterStatus {
 	Unbonding,
 }
 
+/// Registrar judgement on the identity of a node provider required by a cluster.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IdentityJudgement {
+	/// The identity is reasonable, a `KnownGood` judgement is accepted as well.
+	Reasonable,
+	KnownGood,
+}
+
 /// DDC node kind added to DDC cluster
 #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Serialize, Deserialize)]
 pub enum ClusterNodeKind {
@@ -365,6 +469,7 @@ pub enum PayoutState {
 	RewardingProviders = 5,
 	ProvidersRewarded = 6,
 	Finalized = 7,
+	Disputed = 8,
 }
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -374,6 +479,94 @@ pub struct BucketParams {
 
 pub const DAC_VERIFICATION_KEY_TYPE: KeyTypeId = KeyTypeId(*b"cer!");
 
+/// Key of the persistent offchain local storage entry holding [`OcwMetrics`].
+pub const OCW_METRICS_KEY: &[u8] = b"offchain::validator::metrics";
+
+/// Metrics of the DDC verification offchain worker, accumulated across its runs. The node reads
+/// them from the offchain local storage and exports them to Prometheus.
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Default)]
+pub struct OcwMetrics {
+	/// Number of offchain worker runs per cluster.
+	pub runs: BTreeMap<ClusterId, u64>,
+	/// Durations of the offchain worker phases per cluster and phase name.
+	pub phases: BTreeMap<(ClusterId, Vec<u8>), OcwPhaseMetrics>,
+	/// Requests to DAC aggregators per DAC node.
+	pub aggregator_requests: BTreeMap<NodePubKey, OcwRequestMetrics>,
+	/// Sizes of the last consistency groups per cluster and aggregate kind.
+	pub consistency_groups: BTreeMap<(ClusterId, Vec<u8>), OcwConsistencyGroupsMetrics>,
+	/// Challenges of inconsistent aggregates per cluster.
+	pub challenges: BTreeMap<ClusterId, OcwChallengeMetrics>,
+	/// Errors reported on-chain per error category.
+	pub submitted_errors: BTreeMap<Vec<u8>, u64>,
+}
+
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Default)]
+pub struct OcwPhaseMetrics {
+	pub runs: u64,
+	pub total_duration_ms: u64,
+	pub last_duration_ms: u64,
+}
+
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Default)]
+pub struct OcwRequestMetrics {
+	pub requests: u64,
+	pub errors: u64,
+	pub total_latency_ms: u64,
+}
+
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Default)]
+pub struct OcwConsistencyGroupsMetrics {
+	pub consensus: u32,
+	pub quorum: u32,
+	pub others: u32,
+}
+
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Default)]
+pub struct OcwChallengeMetrics {
+	pub runs: u64,
+	pub failures: u64,
+}
+
+/// Key of the persistent offchain local storage entry holding [`OcwSettings`].
+pub const OCW_SETTINGS_KEY: &[u8] = b"offchain::validator::settings";
+
+/// Offchain worker settings provided by the node operator at startup. Values which are not set
+/// fall back to the defaults of the verification pallet.
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Default)]
+pub struct OcwSettings {
+	/// Timeout of a single request to a DAC aggregator, in milliseconds.
+	pub response_timeout_ms: Option<u64>,
+	/// Number of attempts of a request to a DAC aggregator, the first one included.
+	pub retries: Option<u32>,
+	/// Page size used to fetch buckets aggregates.
+	pub buckets_aggregates_batch_size: Option<u32>,
+	/// Page size used to fetch nodes aggregates.
+	pub nodes_aggregates_batch_size: Option<u32>,
+	/// Clusters to validate, all activated clusters are validated if empty.
+	pub clusters: Vec<ClusterId>,
+	/// Clusters which are never validated by this node.
+	pub excluded_clusters: Vec<ClusterId>,
+	/// Base URLs (e.g. `http://dac-1.internal:8080`) used instead of the host and HTTP port a DAC
+	/// node has registered on-chain.
+	pub endpoint_overrides: Vec<(NodePubKey, Vec<u8>)>,
+}
+
+impl OcwSettings {
+	/// Whether the offchain worker should validate the cluster.
+	pub fn is_cluster_enabled(&self, cluster_id: &ClusterId) -> bool {
+		!self.excluded_clusters.contains(cluster_id) &&
+			(self.clusters.is_empty() || self.clusters.contains(cluster_id))
+	}
+
+	/// Base URL overridden by the node operator for a DAC node.
+	pub fn endpoint_override(&self, node_pub_key: &NodePubKey) -> Option<&[u8]> {
+		self.endpoint_overrides
+			.iter()
+			.find(|(key, _)| key == node_pub_key)
+			.map(|(_, url)| url.as_slice())
+	}
+}
+
 pub mod sr25519 {
 	mod app_sr25519 {
 		use sp_application_crypto::{app_crypto, sr25519};
@@ -431,7 +624,9 @@ pub enum EraValidationStatus {
 #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq)]
 #[scale_info(skip_type_params(T))]
 pub struct EraValidation<T: Config> {
-	pub validators: BTreeMap<(DeltaUsageHash, DeltaUsageHash), Vec<T::AccountId>>,
+	/// Validators voted for `(payers_merkle_root_hash, payees_merkle_root_hash, start_era,
+	/// end_era)`, era boundaries are the part of the agreement as they affect customers charging.
+	pub validators: BTreeMap<(DeltaUsageHash, DeltaUsageHash, i64, i64), Vec<T::AccountId>>,
 	pub start_era: i64,
 	pub end_era: i64,
 	pub payers_merkle_root_hash: DeltaUsageHash,
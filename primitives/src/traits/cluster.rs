@@ -1,16 +1,21 @@
+use frame_support::weights::Weight;
 use frame_system::{pallet_prelude::BlockNumberFor, Config};
 use sp_runtime::{DispatchError, DispatchResult};
 use sp_std::prelude::*;
 
 use crate::{
-	ClusterBondingParams, ClusterFeesParams, ClusterId, ClusterNodeKind, ClusterNodeState,
-	ClusterNodeStatus, ClusterNodesStats, ClusterParams, ClusterPricingParams,
-	ClusterProtocolParams, ClusterStatus, DdcEra, NodePubKey, NodeType,
+	BucketId, ClusterBondingParams, ClusterFeesParams, ClusterId, ClusterNodeKind,
+	ClusterNodeState, ClusterNodeStatus, ClusterNodesStats, ClusterParams, ClusterPricingParams,
+	ClusterProtocolParams, ClusterStatus, DdcEra, NodePubKey, NodeType, PriceSchedule,
+	PricingOverride, PricingOverrideTarget, StorageNodeMode,
 };
 
 pub trait ClusterQuery<T: Config> {
 	fn cluster_exists(cluster_id: &ClusterId) -> bool;
 	fn get_cluster_status(cluster_id: &ClusterId) -> Result<ClusterStatus, DispatchError>;
+	/// Whether the cluster is being decommissioned, such a cluster accepts neither nodes nor
+	/// buckets.
+	fn is_decommissioning(cluster_id: &ClusterId) -> bool;
 	fn get_manager_and_reserve_id(
 		cluster_id: &ClusterId,
 	) -> Result<(T::AccountId, T::AccountId), DispatchError>;
@@ -51,6 +56,16 @@ pub trait ClusterProtocol<T: Config, Balance>: ClusterQuery<T> {
 	fn start_unbond_cluster(cluster_id: &ClusterId) -> DispatchResult;
 
 	fn end_unbond_cluster(cluster_id: &ClusterId) -> DispatchResult;
+
+	/// Returns the price schedule the bucket is charged by for the era started at the given
+	/// time, the pricing negotiated for the bucket takes precedence over the pricing negotiated
+	/// with its owner.
+	fn get_price_schedule(
+		cluster_id: &ClusterId,
+		customer_id: &T::AccountId,
+		bucket_id: BucketId,
+		time: i64,
+	) -> PriceSchedule;
 }
 
 pub trait ClusterCreator<T: Config, Balance> {
@@ -80,8 +95,22 @@ pub trait ClusterManager<T: Config>: ClusterQuery<T> {
 		node_kind: &ClusterNodeKind,
 	) -> Result<(), DispatchError>;
 
+	/// Removes the node once the notice period configured for the cluster ends, the node stays in
+	/// the cluster until then so that its data can be migrated.
 	fn remove_node(cluster_id: &ClusterId, node_pub_key: &NodePubKey) -> Result<(), DispatchError>;
 
+	/// Removes the node on behalf of its provider, same as `remove_node`, but the removal can be
+	/// cancelled by the provider during the notice period.
+	fn start_node_leaving(
+		cluster_id: &ClusterId,
+		node_pub_key: &NodePubKey,
+	) -> Result<(), DispatchError>;
+
+	fn cancel_node_leaving(
+		cluster_id: &ClusterId,
+		node_pub_key: &NodePubKey,
+	) -> Result<(), DispatchError>;
+
 	fn get_node_state(
 		cluster_id: &ClusterId,
 		node_pub_key: &NodePubKey,
@@ -96,6 +125,41 @@ pub trait ClusterManager<T: Config>: ClusterQuery<T> {
 	) -> Result<(), DispatchError>;
 
 	fn get_clusters(status: ClusterStatus) -> Result<Vec<ClusterId>, DispatchError>;
+
+	/// Returns the dedicated set of DAC aggregators registered by the cluster manager. An empty
+	/// set means that any validated DAC node of the cluster may act as an aggregator.
+	fn get_aggregators(cluster_id: &ClusterId) -> Result<Vec<NodePubKey>, DispatchError>;
+
+	/// Returns the nodes waiting for a free slot of the mode in the full cluster, in the order
+	/// they are admitted in.
+	fn get_admission_queue(
+		cluster_id: &ClusterId,
+		mode: StorageNodeMode,
+	) -> Result<Vec<NodePubKey>, DispatchError>;
+
+	/// Proposes a new manager for the cluster regardless of the cluster status, the proposed
+	/// account takes over the cluster management once it accepts the transfer.
+	fn propose_manager_transfer(
+		cluster_id: &ClusterId,
+		new_manager_id: &T::AccountId,
+	) -> DispatchResult;
+
+	/// Cancels the pending manager transfer of the cluster on behalf of the cluster governance.
+	fn cancel_manager_transfer(cluster_id: &ClusterId) -> DispatchResult;
+
+	/// Sets the non-sensitive cluster parameters on behalf of the cluster governance.
+	fn update_cluster_params(
+		cluster_id: &ClusterId,
+		cluster_params: ClusterParams<T::AccountId>,
+	) -> DispatchResult;
+
+	/// Sets or removes the pricing negotiated with a customer on behalf of the cluster
+	/// governance.
+	fn set_pricing_override(
+		cluster_id: &ClusterId,
+		target: &PricingOverrideTarget<T::AccountId>,
+		pricing_override: Option<PricingOverride>,
+	) -> DispatchResult;
 }
 pub trait ClusterValidator<T: Config> {
 	/// Updates the `last_paid_era` for the given cluster and emits an event indicating the
@@ -129,3 +193,72 @@ pub trait ClusterValidator<T: Config> {
 	/// Returns `Ok(DdcEra)` identifier of the last validated era in cluster
 	fn get_last_paid_era(cluster_id: &ClusterId) -> Result<DdcEra, DispatchError>;
 }
+
+/// State kept by other pallets for a cluster which is removed when the cluster is decommissioned.
+pub trait ClusterDecommissionHandler {
+	/// Upper bound of the weight of `clean_up` called with `limit`.
+	fn clean_up_weight(limit: u32) -> Weight;
+
+	/// Removes or migrates up to `limit` items of the state kept for the cluster and returns the
+	/// number of processed items, the state is cleaned up once fewer than `limit` items are
+	/// processed. Buckets of the cluster are migrated to `buckets_migrate_to` if it is set.
+	///
+	/// Returns an error while the state can not be removed yet, e.g. when an era of the cluster
+	/// is not settled.
+	fn clean_up(
+		cluster_id: &ClusterId,
+		buckets_migrate_to: Option<ClusterId>,
+		limit: u32,
+	) -> Result<u32, DispatchError>;
+}
+
+impl ClusterDecommissionHandler for () {
+	fn clean_up_weight(_limit: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn clean_up(
+		_cluster_id: &ClusterId,
+		_buckets_migrate_to: Option<ClusterId>,
+		_limit: u32,
+	) -> Result<u32, DispatchError> {
+		Ok(0)
+	}
+}
+
+/// Handlers of a tuple clean up their state in order, the next handler is called once the
+/// previous one has cleaned up its state.
+macro_rules! impl_cluster_decommission_handler_for_tuple {
+	($($handler:ident),+) => {
+		impl<$($handler: ClusterDecommissionHandler),+> ClusterDecommissionHandler
+			for ($($handler,)+)
+		{
+			fn clean_up_weight(limit: u32) -> Weight {
+				Weight::zero()$(.saturating_add($handler::clean_up_weight(limit)))+
+			}
+
+			fn clean_up(
+				cluster_id: &ClusterId,
+				buckets_migrate_to: Option<ClusterId>,
+				limit: u32,
+			) -> Result<u32, DispatchError> {
+				let mut processed = 0;
+				$(
+					let remaining = limit.saturating_sub(processed);
+					let handler_processed =
+						$handler::clean_up(cluster_id, buckets_migrate_to, remaining)?;
+					if handler_processed >= remaining {
+						return Ok(limit);
+					}
+					processed = processed.saturating_add(handler_processed);
+				)+
+				Ok(processed)
+			}
+		}
+	};
+}
+
+impl_cluster_decommission_handler_for_tuple!(A);
+impl_cluster_decommission_handler_for_tuple!(A, B);
+impl_cluster_decommission_handler_for_tuple!(A, B, C);
+impl_cluster_decommission_handler_for_tuple!(A, B, C, D);
@@ -4,6 +4,9 @@
 /// vote exactly once, therefore also the number of votes for any given motion.
 pub type MemberCount = u32;
 
+/// Voting power of a member, the amount bonded by the member if the voting is stake-weighted.
+pub type VoteWeight = u128;
+
 /// Default voting strategy when a member is inactive.
 pub trait DefaultVote {
 	/// Get the default voting strategy, given:
@@ -22,4 +25,8 @@ pub trait DefaultVote {
 
 pub trait SeatsConsensus {
 	fn get_threshold(seats: MemberCount) -> MemberCount;
+
+	/// Get the approval threshold of the stake-weighted voting, given the total voting power of
+	/// the members.
+	fn get_weighted_threshold(total_weight: VoteWeight) -> VoteWeight;
 }
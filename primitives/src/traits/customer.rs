@@ -2,7 +2,7 @@ use core::u128;
 
 use sp_runtime::DispatchError;
 
-use crate::BucketId;
+use crate::{BucketId, ClusterId};
 
 pub trait CustomerCharger<T: frame_system::Config> {
 	fn charge_bucket_owner(
@@ -19,4 +19,5 @@ pub trait CustomerDepositor<T: frame_system::Config> {
 
 pub trait CustomerVisitor<T: frame_system::Config> {
 	fn get_bucket_owner(bucket_id: &BucketId) -> Result<T::AccountId, DispatchError>;
+	fn get_bucket_cluster_id(bucket_id: &BucketId) -> Result<ClusterId, DispatchError>;
 }
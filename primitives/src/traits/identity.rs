@@ -0,0 +1,12 @@
+use crate::IdentityJudgement;
+
+pub trait IdentityVerifier<AccountId> {
+	/// Whether a registrar has given the identity of `who` the `judgement`.
+	fn has_judgement(who: &AccountId, judgement: &IdentityJudgement) -> bool;
+}
+
+impl<AccountId> IdentityVerifier<AccountId> for () {
+	fn has_judgement(_who: &AccountId, _judgement: &IdentityJudgement) -> bool {
+		false
+	}
+}
@@ -2,6 +2,7 @@ pub mod bucket;
 pub mod cluster;
 pub mod cluster_gov;
 pub mod customer;
+pub mod identity;
 pub mod node;
 pub mod pallet;
 pub mod payout;
@@ -12,6 +13,7 @@ pub use bucket::*;
 pub use cluster::*;
 pub use cluster_gov::*;
 pub use customer::*;
+pub use identity::*;
 pub use node::*;
 pub use pallet::*;
 pub use payout::*;
@@ -1,4 +1,4 @@
-use sp_runtime::DispatchResult;
+use sp_runtime::{DispatchError, DispatchResult};
 use sp_std::boxed::Box;
 
 use crate::{
@@ -66,6 +66,18 @@ pub trait PayoutProcessor<T: frame_system::Config> {
 
 	fn all_provider_batches_processed(cluster_id: &ClusterId, era_id: DdcEra) -> bool;
 
+	fn is_customers_batch_processed(
+		cluster_id: &ClusterId,
+		era_id: DdcEra,
+		batch_index: BatchIndex,
+	) -> bool;
+
+	fn is_providers_batch_processed(
+		cluster_id: &ClusterId,
+		era_id: DdcEra,
+		batch_index: BatchIndex,
+	) -> bool;
+
 	fn get_next_customer_batch_for_payment(
 		cluster_id: &ClusterId,
 		era_id: DdcEra,
@@ -79,6 +91,40 @@ pub trait PayoutProcessor<T: frame_system::Config> {
 	fn create_billing_report(vault: T::AccountId, params: BillingReportParams);
 
 	fn create_billing_fingerprint(_params: BillingFingerprintParams<T::AccountId>) -> Fingerprint;
+
+	/// Billing fingerprint the billing report of a cluster in an era was initialized with.
+	fn get_billing_fingerprint(
+		cluster_id: &ClusterId,
+		era_id: DdcEra,
+	) -> Result<BillingFingerprintParams<T::AccountId>, DispatchError>;
+
+	/// Whether the billing report of a cluster in an era can still be disputed, i.e. it is
+	/// initialized and the customers charging is not allowed to begin yet.
+	fn is_dispute_window_open(cluster_id: &ClusterId, era_id: DdcEra) -> bool;
+
+	/// Halts the billing report of a cluster in an era disputed during its dispute window.
+	fn dispute_billing_report(cluster_id: ClusterId, era_id: DdcEra) -> DispatchResult;
+
+	/// Rolls the billing report of a cluster in an era back to an earlier state. The billing
+	/// report is removed when rolled back to `NotInitialized`. Only the stages which have not
+	/// moved funds yet can be rolled back.
+	fn rollback_billing_report(
+		cluster_id: ClusterId,
+		era_id: DdcEra,
+		state: PayoutState,
+	) -> DispatchResult;
+
+	/// Marks a batch of the current charging or rewarding stage of the billing report as
+	/// processed without charging its customers or rewarding its providers.
+	fn void_billing_report_batch(
+		cluster_id: ClusterId,
+		era_id: DdcEra,
+		batch_index: BatchIndex,
+	) -> DispatchResult;
+
+	/// Completes the current charging or rewarding stage of the billing report voiding its
+	/// unprocessed batches.
+	fn force_advance_billing_report(cluster_id: ClusterId, era_id: DdcEra) -> DispatchResult;
 }
 
 pub trait StorageUsageProvider<Key, Item> {
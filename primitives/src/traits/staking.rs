@@ -14,6 +14,12 @@ pub trait StakingVisitor<T: Config> {
 	fn has_chilling_attempt(node_pub_key: &NodePubKey) -> Result<bool, StakingVisitorError>;
 
 	fn stash_by_ctrl(controller: &T::AccountId) -> Result<T::AccountId, StakingVisitorError>;
+
+	/// Returns the amount bonded by the node provider that is at stake for the node.
+	fn get_node_active_stake(node_pub_key: &NodePubKey) -> Result<u128, StakingVisitorError>;
+
+	/// Returns the amount bonded for the cluster by its stash account.
+	fn get_cluster_active_stake(cluster_stash: &T::AccountId) -> Result<u128, StakingVisitorError>;
 }
 
 pub trait StakerCreator<T: Config, Balance> {
@@ -30,6 +36,13 @@ pub trait StakerCreator<T: Config, Balance> {
 		cluster_controller: T::AccountId,
 		cluster_id: ClusterId,
 	) -> DispatchResult;
+
+	/// Pairs the bonded cluster stash with a new controller, moving its staking ledger. Does
+	/// nothing if the cluster stash is not bonded.
+	fn set_cluster_controller(
+		cluster_stash: &T::AccountId,
+		cluster_controller: &T::AccountId,
+	) -> DispatchResult;
 }
 
 pub enum StakingVisitorError {
@@ -23,8 +23,11 @@
 #![recursion_limit = "256"]
 use codec::{Decode, Encode, MaxEncodedLen};
 use ddc_primitives::{
-	traits::pallet::{GetDdcOrigin, PalletVisitor},
-	MAX_PAYOUT_BATCH_COUNT, MAX_PAYOUT_BATCH_SIZE,
+	traits::{
+		identity::IdentityVerifier,
+		pallet::{GetDdcOrigin, PalletVisitor},
+	},
+	ClusterId, DdcEra, IdentityJudgement, MAX_PAYOUT_BATCH_COUNT, MAX_PAYOUT_BATCH_SIZE,
 };
 use frame_election_provider_support::{
 	bounds::ElectionBoundsBuilder, onchain, BalancingConfig, SequentialPhragmen, VoteWeight,
@@ -147,10 +150,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// and set impl_version to 0. If only runtime
 	// implementation changes and behavior does not, then leave spec_version as
 	// is and increment impl_version.
-	spec_version: 61010,
+	spec_version: 61011,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 24,
+	transaction_version: 25,
 	state_version: 0,
 };
 
@@ -986,6 +989,7 @@ where
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
 			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+			pallet_ddc_verification::CheckPayoutStep::<Runtime>::new(),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -1191,6 +1195,10 @@ impl pallet_ddc_customers::Config for Runtime {
 	type WeightInfo = pallet_ddc_customers::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const ClusterDecommissionNoticePeriod: BlockNumber = 7 * DAYS;
+}
+
 impl pallet_ddc_clusters::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type NodeRepository = pallet_ddc_nodes::Pallet<Runtime>;
@@ -1201,6 +1209,31 @@ impl pallet_ddc_clusters::Config for Runtime {
 	type MinErasureCodingRequiredLimit = ConstU32<4>;
 	type MinErasureCodingTotalLimit = ConstU32<6>;
 	type MinReplicationTotalLimit = ConstU32<3>;
+	type ManagerTransferRequiresGov = ConstBool<true>;
+	type MaxAllowlistLen = ConstU32<100>;
+	type IdentityVerifier = IdentityJudgements;
+	type DecommissionNoticePeriod = ClusterDecommissionNoticePeriod;
+	type DecommissionBatchSize = ConstU32<50>;
+	type ClusterDecommissionHandler = (DdcPayouts, DdcVerification, DdcCustomers, DdcClustersGov);
+	type MaxAdmissionQueueLen = ConstU32<100>;
+	type PricingOverrideRequiresGov = ConstBool<true>;
+	type CustomerVisitor = DdcCustomers;
+}
+
+pub struct IdentityJudgements;
+impl IdentityVerifier<AccountId> for IdentityJudgements {
+	fn has_judgement(who: &AccountId, judgement: &IdentityJudgement) -> bool {
+		Identity::identity(who).is_some_and(|registration| {
+			registration.judgements.iter().any(|(_, given)| match judgement {
+				IdentityJudgement::Reasonable => matches!(
+					given,
+					pallet_identity::Judgement::Reasonable | pallet_identity::Judgement::KnownGood
+				),
+				IdentityJudgement::KnownGood =>
+					matches!(given, pallet_identity::Judgement::KnownGood),
+			})
+		})
+	}
 }
 
 impl pallet_ddc_nodes::Config for Runtime {
@@ -1211,6 +1244,7 @@ impl pallet_ddc_nodes::Config for Runtime {
 
 parameter_types! {
 	pub const PayoutsPalletId: PalletId = PalletId(*b"payouts_");
+	pub const BillingReportDisputePeriod: BlockNumber = HOURS;
 }
 
 pub struct TreasuryWrapper;
@@ -1236,6 +1270,7 @@ impl pallet_ddc_payouts::Config for Runtime {
 	type Hasher = BlakeTwo256;
 	type ClusterValidator = pallet_ddc_clusters::Pallet<Runtime>;
 	type ValidatorsQuorum = MajorityOfValidators;
+	type DisputePeriod = BillingReportDisputePeriod;
 }
 
 parameter_types! {
@@ -1282,6 +1317,8 @@ parameter_types! {
 	pub ClusterProtocolActivatorTrackOrigin: RuntimeOrigin = pallet_origins::Origin::ClusterProtocolActivator.into();
 	pub ClusterProtocolUpdaterTrackOrigin: RuntimeOrigin = pallet_origins::Origin::ClusterProtocolUpdater.into();
 	pub const ReferendumEnactmentDuration: BlockNumber = 1;
+	pub const MaxClusterProposals: u32 = 10;
+	pub const MaxClusterNodes: u32 = 64;
 }
 
 impl pallet_ddc_clusters_gov::Config for Runtime {
@@ -1303,6 +1340,10 @@ impl pallet_ddc_clusters_gov::Config for Runtime {
 	type DefaultVote = pallet_ddc_clusters_gov::NayAsDefaultVote;
 	type MinValidatedNodesCount = MinValidatedNodesCount;
 	type ReferendumEnactmentDuration = ReferendumEnactmentDuration;
+	type MaxClusterProposals = MaxClusterProposals;
+	type MaxClusterNodes = MaxClusterNodes;
+	type StakeWeightedVoting = ConstBool<false>;
+	type StakingVisitor = pallet_ddc_staking::Pallet<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type StakerCreator = pallet_ddc_staking::Pallet<Runtime>;
 }
@@ -1327,6 +1368,10 @@ parameter_types! {
 	pub const VerificationPalletId: PalletId = PalletId(*b"verifypa");
 	pub const MajorityOfAggregators: Percent = Percent::from_percent(67);
 	pub const MajorityOfValidators: Percent = Percent::from_percent(67);
+	pub const FraudProofReward: Balance = 100 * DOLLARS;
+	/// DDC validators transactions are as important as staking ones.
+	pub const DdcVerificationUnsignedPriority: TransactionPriority =
+		TransactionPriority::max_value() / 2;
 }
 
 impl pallet_ddc_verification::Config for Runtime {
@@ -1352,6 +1397,8 @@ impl pallet_ddc_verification::Config for Runtime {
 	const MAX_MERKLE_NODE_IDENTIFIER: u16 = 3;
 	type Currency = Balances;
 	const VERIFY_AGGREGATOR_RESPONSE_SIGNATURE: bool = true;
+	type FraudProofReward = FraudProofReward;
+	type UnsignedPriority = DdcVerificationUnsignedPriority;
 	type BucketsStorageUsageProvider = DdcCustomers;
 	type NodesStorageUsageProvider = DdcNodes;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1360,6 +1407,8 @@ impl pallet_ddc_verification::Config for Runtime {
 	type ClusterCreator = DdcClusters;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BucketManager = DdcCustomers;
+	#[cfg(feature = "runtime-benchmarks")]
+	type StakerCreator = DdcStaking;
 }
 
 construct_runtime!(
@@ -1443,6 +1492,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_ddc_verification::CheckPayoutStep<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -1478,12 +1528,19 @@ pub mod migrations {
 	/// Unreleased migrations. Add new ones here:
 	pub type Unreleased = (
 		pallet_ddc_customers::migration::v2::MigrateToV2<Runtime>,
+		pallet_ddc_customers::migration::v3::MigrateToV3<Runtime>,
 		pallet_ddc_clusters::migrations::v3::MigrateToV3<Runtime>,
+		pallet_ddc_clusters::migrations::v4::MigrateToV4<Runtime>,
+		pallet_ddc_clusters::migrations::v5::MigrateToV5<Runtime>,
+		pallet_ddc_clusters::migrations::v6::MigrateToV6<Runtime>,
 		pallet_ddc_nodes::migrations::v1::MigrateToV1<Runtime>,
 		UpgradeSessionKeys,
 		pallet_ddc_verification::migrations::v1::MigrateToV1<Runtime>,
+		pallet_ddc_verification::migrations::v2::MigrateToV2<Runtime>,
 		pallet_ddc_payouts::migrations::v1::MigrateToV1<Runtime>,
 		pallet_ddc_payouts::migrations::v2::MigrateToV2<Runtime>,
+		pallet_ddc_clusters_gov::migrations::v1::MigrateToV1<Runtime>,
+		pallet_ddc_clusters_gov::migrations::v2::MigrateToV2<Runtime>,
 	);
 }
 
@@ -1822,6 +1879,24 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_ddc_verification::runtime_api::DdcVerificationApi<Block, AccountId> for Runtime {
+		fn dry_run_era(
+			cluster_id: ClusterId,
+			era_id: DdcEra,
+		) -> Result<
+			pallet_ddc_verification::ValidationDryRun,
+			Vec<pallet_ddc_verification::OCWError>,
+		> {
+			DdcVerification::dry_run_era(&cluster_id, era_id)
+		}
+
+		fn verification_key_status(
+			key: AccountId,
+		) -> pallet_ddc_verification::VerificationKeyStatus<AccountId> {
+			DdcVerification::verification_key_status(&key)
+		}
+	}
+
 	impl pallet_nomination_pools_runtime_api::NominationPoolsApi<
 		Block,
 		AccountId,
@@ -0,0 +1,524 @@
+//! Deterministic generation of signed synthetic activity records and their aggregates.
+//!
+//! Every record is derived from a hash of the era, the storage node, the bucket and the record
+//! index, and ed25519 signatures are deterministic, so all aggregators of a scenario serve the
+//! same records byte by byte unless they are configured with faults.
+
+use std::collections::BTreeMap;
+
+use base64ct::{Base64, Encoding};
+use codec::Encode;
+use ddc_primitives::{BucketId, MergeMMRHash};
+use pallet_ddc_verification::{aggregator_client::json, proto};
+use polkadot_ckb_merkle_mountain_range::{
+	util::{MemMMR, MemStore},
+	MMR,
+};
+use prost::Message;
+use sp_core::{ed25519, hashing::blake2_256, Pair, H256};
+
+use crate::scenario::{pair_from_uri, EraSpec, Faults, Scenario};
+
+/// Activity record served by a storage node for a bucket.
+#[derive(Debug, Clone)]
+pub struct GeneratedRecord {
+	/// Index of the storage node in the scenario.
+	pub node: usize,
+	pub bucket_id: BucketId,
+	pub record: proto::ActivityRecord,
+	pub stored_bytes: i64,
+	pub transferred_bytes: u64,
+}
+
+impl GeneratedRecord {
+	fn request(&self) -> &proto::ActivityRequest {
+		self.record
+			.upstream
+			.as_ref()
+			.and_then(|upstream| upstream.request.as_ref())
+			.expect("generated records always have an upstream request")
+	}
+
+	fn request_type(&self) -> proto::activity_request::RequestType {
+		self.request().request_type()
+	}
+}
+
+/// Usage of a bucket, a node or a sub aggregate of a bucket per node.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+	pub stored_bytes: i64,
+	pub transferred_bytes: u64,
+	pub number_of_puts: u64,
+	pub number_of_gets: u64,
+}
+
+impl Usage {
+	fn add(&mut self, record: &GeneratedRecord) {
+		self.stored_bytes += record.stored_bytes;
+		self.transferred_bytes += record.transferred_bytes;
+		match record.request_type() {
+			proto::activity_request::RequestType::Put => self.number_of_puts += 1,
+			proto::activity_request::RequestType::Get => self.number_of_gets += 1,
+			proto::activity_request::RequestType::Delete => {},
+		}
+	}
+
+	fn of<'a>(records: impl IntoIterator<Item = &'a GeneratedRecord>) -> Self {
+		let mut usage = Self::default();
+		records.into_iter().for_each(|record| usage.add(record));
+		usage
+	}
+
+	/// Usage reported by a faulty aggregator.
+	fn inflated(self) -> Self {
+		Self { transferred_bytes: self.transferred_bytes * 2 + 1, ..self }
+	}
+}
+
+pub struct ActivityGenerator {
+	scenario: Scenario,
+	nodes: Vec<ed25519::Pair>,
+	clients: Vec<ed25519::Pair>,
+}
+
+impl ActivityGenerator {
+	pub fn new(scenario: Scenario) -> Result<Self, String> {
+		let nodes =
+			scenario.nodes.iter().map(|uri| pair_from_uri(uri)).collect::<Result<_, _>>()?;
+		let clients = scenario
+			.clients
+			.iter()
+			.map(|uri| pair_from_uri(uri))
+			.collect::<Result<_, _>>()?;
+
+		Ok(Self { scenario, nodes, clients })
+	}
+
+	pub fn scenario(&self) -> &Scenario {
+		&self.scenario
+	}
+
+	/// Node id as reported by aggregators, a `0x` prefixed hex of the node key.
+	pub fn node_id(&self, node: usize) -> String {
+		format!("0x{}", hex::encode(self.nodes[node].public().0))
+	}
+
+	pub fn node_index(&self, node_id: &str) -> Option<usize> {
+		(0..self.nodes.len()).find(|&node| self.node_id(node).eq_ignore_ascii_case(node_id))
+	}
+
+	/// Records of all storage nodes and buckets in the era.
+	pub fn era_records(&self, era: &EraSpec) -> Vec<GeneratedRecord> {
+		let mut records = Vec::new();
+		for node in 0..self.nodes.len() {
+			for &bucket_id in &self.scenario.buckets {
+				for index in 0..self.scenario.activity.records_per_node_bucket {
+					records.push(self.record(era, node, bucket_id, index));
+				}
+			}
+		}
+
+		records
+	}
+
+	fn record(
+		&self,
+		era: &EraSpec,
+		node: usize,
+		bucket_id: BucketId,
+		index: u32,
+	) -> GeneratedRecord {
+		let seed = blake2_256(&(era.id, node as u32, bucket_id, index).encode());
+		let id = seed[..16].to_vec();
+		let client = &self.clients[seed[16] as usize % self.clients.len()];
+		let request_type = if seed[17] % 2 == 0 {
+			proto::activity_request::RequestType::Put
+		} else {
+			proto::activity_request::RequestType::Get
+		};
+
+		// leave the last second of the era to the record timestamp
+		let era_duration_ms = ((era.end - era.start).max(1) as u64) * 1000 - 1;
+		let offset_ms =
+			u64::from_le_bytes(seed[18..26].try_into().expect("8 bytes")) % era_duration_ms.max(1);
+		let timestamp = era.start as u64 * 1000 + offset_ms;
+
+		let piece_size = self.scenario.activity.piece_size;
+		let mut request = proto::ActivityRequest {
+			parent_request: None,
+			request_id: hex::encode(&id),
+			request_type: request_type as i32,
+			content_type: proto::activity_request::ContentType::Piece as i32,
+			bucket_id,
+			piece_cid: blake2_256(&seed).to_vec(),
+			offset: 0,
+			size: piece_size,
+			timestamp,
+			signature: None,
+		};
+		request.signature = Some(sign(client, &request.encode_to_vec()));
+
+		let mut record = proto::ActivityRecord {
+			id,
+			upstream: Some(proto::ActivityFulfillment {
+				request: Some(request),
+				ack: None,
+				response: None,
+			}),
+			downstream: vec![],
+			timestamp: timestamp + 1,
+			signature: None,
+			auth_token: None,
+		};
+		record.signature = Some(sign(&self.nodes[node], &record.encode_to_vec()));
+
+		let stored_bytes = match request_type {
+			proto::activity_request::RequestType::Put => piece_size as i64,
+			_ => 0,
+		};
+
+		GeneratedRecord { node, bucket_id, record, stored_bytes, transferred_bytes: piece_size }
+	}
+
+	pub fn buckets_aggregates(
+		&self,
+		records: &[GeneratedRecord],
+		faults: &Faults,
+	) -> Vec<json::BucketAggregateResponse> {
+		let mut by_bucket: BTreeMap<BucketId, BTreeMap<usize, Vec<&GeneratedRecord>>> =
+			BTreeMap::new();
+		for record in records {
+			by_bucket
+				.entry(record.bucket_id)
+				.or_default()
+				.entry(record.node)
+				.or_default()
+				.push(record);
+		}
+
+		by_bucket
+			.into_iter()
+			.map(|(bucket_id, by_node)| {
+				let is_inconsistent = faults.inconsistent_buckets.contains(&bucket_id);
+				let sub_aggregates: Vec<_> = by_node
+					.into_iter()
+					.map(|(node, records)| {
+						let mut usage = Usage::of(records);
+						if is_inconsistent {
+							usage = usage.inflated();
+						}
+						(node, usage)
+					})
+					.collect();
+
+				let mut total = Usage::default();
+				for (_, usage) in &sub_aggregates {
+					total.stored_bytes += usage.stored_bytes;
+					total.transferred_bytes += usage.transferred_bytes;
+					total.number_of_puts += usage.number_of_puts;
+					total.number_of_gets += usage.number_of_gets;
+				}
+
+				json::BucketAggregateResponse {
+					bucket_id,
+					stored_bytes: total.stored_bytes,
+					transferred_bytes: total.transferred_bytes,
+					number_of_puts: total.number_of_puts,
+					number_of_gets: total.number_of_gets,
+					sub_aggregates: sub_aggregates
+						.into_iter()
+						.map(|(node, usage)| json::BucketSubAggregateResponse {
+							NodeID: self.node_id(node),
+							stored_bytes: usage.stored_bytes,
+							transferred_bytes: usage.transferred_bytes,
+							number_of_puts: usage.number_of_puts,
+							number_of_gets: usage.number_of_gets,
+						})
+						.collect(),
+				}
+			})
+			.collect()
+	}
+
+	/// Nodes aggregates sorted by node id, the order aggregators paginate them in.
+	pub fn nodes_aggregates(
+		&self,
+		records: &[GeneratedRecord],
+		faults: &Faults,
+	) -> Vec<json::NodeAggregateResponse> {
+		let mut aggregates: Vec<_> = (0..self.nodes.len())
+			.map(|node| {
+				let mut usage = Usage::of(records.iter().filter(|r| r.node == node));
+				if faults.inconsistent_nodes.contains(&self.scenario.nodes[node]) {
+					usage = usage.inflated();
+				}
+
+				json::NodeAggregateResponse {
+					node_id: self.node_id(node),
+					stored_bytes: usage.stored_bytes,
+					transferred_bytes: usage.transferred_bytes,
+					number_of_puts: usage.number_of_puts,
+					number_of_gets: usage.number_of_gets,
+				}
+			})
+			.collect();
+		aggregates.sort_by(|a, b| a.node_id.cmp(&b.node_id));
+
+		aggregates
+	}
+}
+
+/// Merkle tree of records of an aggregate.
+///
+/// The mock keeps all records of an aggregate under a single merkle tree node, so every
+/// challenged merkle tree node is proven by all the records with an empty path.
+pub struct AggregateTree<'a> {
+	pub records: Vec<&'a GeneratedRecord>,
+	pub kind: AggregateKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateKind {
+	BucketSubAggregate,
+	NodeAggregate,
+}
+
+impl<'a> AggregateTree<'a> {
+	pub fn usage(&self) -> Usage {
+		Usage::of(self.records.iter().copied())
+	}
+
+	pub fn challenge_response(
+		&self,
+		merkle_tree_node_ids: &[u32],
+		corrupt_signatures: bool,
+	) -> proto::ChallengeResponse {
+		let usage = self.usage();
+		let leaves: Vec<_> = self
+			.records
+			.iter()
+			.map(|r| {
+				let mut record = r.record.clone();
+				if corrupt_signatures {
+					if let Some(signature) = record.signature.as_mut() {
+						signature.value[0] ^= 0xff;
+					}
+				}
+
+				proto::challenge_response::proof::Leaf {
+					leaf_variant: Some(
+						proto::challenge_response::proof::leaf::LeafVariant::Record(
+							proto::challenge_response::proof::Record {
+								record: Some(record),
+								stored: r.stored_bytes,
+								delivered: r.transferred_bytes,
+							},
+						),
+					),
+				}
+			})
+			.collect();
+
+		proto::ChallengeResponse {
+			proofs: merkle_tree_node_ids
+				.iter()
+				.map(|&merkle_tree_node_id| proto::challenge_response::Proof {
+					merkle_tree_node_id,
+					usage: Some(proto::Aggregate {
+						stored: usage.stored_bytes,
+						delivered: usage.transferred_bytes,
+						puts: usage.number_of_puts,
+						gets: usage.number_of_gets,
+					}),
+					path: vec![],
+					leaves: leaves.clone(),
+				})
+				.collect(),
+		}
+	}
+
+	pub fn challenge_response_json(
+		&self,
+		merkle_tree_node_ids: &[u32],
+	) -> json::ChallengeAggregateResponse {
+		let usage = self.usage();
+
+		json::ChallengeAggregateResponse {
+			proofs: merkle_tree_node_ids
+				.iter()
+				.map(|&merkle_tree_node_id| json::Proof {
+					merkle_tree_node_id,
+					usage: json::Usage {
+						stored_bytes: usage.stored_bytes,
+						transferred_bytes: usage.transferred_bytes,
+						number_of_puts: usage.number_of_puts,
+						number_of_gets: usage.number_of_gets,
+					},
+					path: vec![],
+					leafs: self.json_leaves(),
+				})
+				.collect(),
+		}
+	}
+
+	pub fn traverse_response(&self, merkle_tree_node_id: u32) -> json::MerkleTreeNodeResponse {
+		let usage = self.usage();
+
+		json::MerkleTreeNodeResponse {
+			merkle_tree_node_id,
+			hash: Base64::encode_string(self.root().as_bytes()),
+			stored_bytes: usage.stored_bytes,
+			transferred_bytes: usage.transferred_bytes,
+			number_of_puts: usage.number_of_puts,
+			number_of_gets: usage.number_of_gets,
+		}
+	}
+
+	/// Root of the records tree hashed the same way validators hash challenged leaves.
+	fn root(&self) -> H256 {
+		let hashes: Vec<H256> = self
+			.json_leaves()
+			.iter()
+			.map(|leaf| {
+				let mut data = match self.kind {
+					AggregateKind::BucketSubAggregate => {
+						let mut data = leaf.record.id.encode();
+						data.extend_from_slice(&leaf.record.upstream.request.requestType.encode());
+						data
+					},
+					AggregateKind::NodeAggregate => {
+						let mut data = leaf.record.upstream.request.bucketId.encode();
+						data.extend_from_slice(&leaf.record.encode());
+						data.extend_from_slice(&leaf.record.upstream.request.requestType.encode());
+						data
+					},
+				};
+				data.extend_from_slice(&leaf.stored_bytes.encode());
+				data.extend_from_slice(&leaf.transferred_bytes.encode());
+				H256(blake2_256(&data))
+			})
+			.collect();
+
+		if hashes.is_empty() {
+			return H256::default();
+		}
+
+		let store = MemStore::default();
+		let mut mmr: MMR<H256, MergeMMRHash, &MemStore<H256>> =
+			MemMMR::<_, MergeMMRHash>::new(0, &store);
+		for hash in hashes {
+			mmr.push(hash).expect("in-memory MMR push can't fail");
+		}
+
+		mmr.get_root().expect("MMR with leaves has a root")
+	}
+
+	fn json_leaves(&self) -> Vec<json::Leaf> {
+		self.records
+			.iter()
+			.map(|r| {
+				let request = r.request();
+				json::Leaf {
+					record: json::Record {
+						id: hex::encode(&r.record.id),
+						upstream: json::Upstream { request: json_request(request) },
+						downstream: vec![],
+						timestamp: r.record.timestamp.to_string(),
+						signature: json_signature(r.record.signature.as_ref()),
+					},
+					transferred_bytes: r.transferred_bytes,
+					stored_bytes: r.stored_bytes,
+				}
+			})
+			.collect()
+	}
+}
+
+fn json_request(request: &proto::ActivityRequest) -> json::Request {
+	json::Request {
+		requestId: request.request_id.clone(),
+		requestType: request.request_type().as_str_name().into(),
+		contentType: request.content_type().as_str_name().into(),
+		bucketId: request.bucket_id.to_string(),
+		pieceCid: hex::encode(&request.piece_cid),
+		offset: request.offset.to_string(),
+		size: request.size.to_string(),
+		timestamp: request.timestamp.to_string(),
+		signature: json_signature(request.signature.as_ref()),
+	}
+}
+
+fn json_signature(signature: Option<&proto::Signature>) -> json::Signature {
+	let signature = signature.cloned().unwrap_or_default();
+	json::Signature {
+		algorithm: signature.algorithm().as_str_name().into(),
+		signer: hex::encode(signature.signer),
+		value: hex::encode(signature.value),
+	}
+}
+
+fn sign(pair: &ed25519::Pair, message: &[u8]) -> proto::Signature {
+	proto::Signature {
+		algorithm: proto::signature::Algorithm::Ed25519 as i32,
+		signer: pair.public().0.to_vec(),
+		value: pair.sign(message).0.to_vec(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::scenario::ActivitySpec;
+
+	fn scenario() -> Scenario {
+		Scenario {
+			aggregators: vec![],
+			nodes: vec!["//Node1".into(), "//Node2".into()],
+			clients: vec!["//Client1".into()],
+			buckets: vec![1, 2],
+			eras: vec![EraSpec { id: 1, start: 1_000, end: 1_600 }],
+			activity: ActivitySpec { records_per_node_bucket: 3, piece_size: 100 },
+		}
+	}
+
+	#[test]
+	fn activity_is_deterministic() {
+		let era = EraSpec { id: 1, start: 1_000, end: 1_600 };
+		let first = ActivityGenerator::new(scenario()).unwrap().era_records(&era);
+		let second = ActivityGenerator::new(scenario()).unwrap().era_records(&era);
+
+		assert_eq!(first.len(), 12);
+		assert_eq!(
+			first.iter().map(|r| r.record.encode_to_vec()).collect::<Vec<_>>(),
+			second.iter().map(|r| r.record.encode_to_vec()).collect::<Vec<_>>(),
+		);
+		assert!(first.iter().all(|r| (1_000_000..1_600_000).contains(&r.record.timestamp)));
+	}
+
+	#[test]
+	fn aggregates_match_records() {
+		let generator = ActivityGenerator::new(scenario()).unwrap();
+		let records = generator.era_records(&generator.scenario().eras[0].clone());
+		let total = Usage::of(records.iter());
+
+		let buckets = generator.buckets_aggregates(&records, &Faults::default());
+		assert_eq!(buckets.len(), 2);
+		assert_eq!(
+			buckets.iter().map(|b| b.transferred_bytes).sum::<u64>(),
+			total.transferred_bytes
+		);
+		assert!(buckets.iter().all(|b| b.sub_aggregates.len() == 2));
+
+		let nodes = generator.nodes_aggregates(&records, &Faults::default());
+		assert_eq!(
+			nodes.iter().map(|n| n.number_of_puts + n.number_of_gets).sum::<u64>(),
+			records.len() as u64
+		);
+
+		let faults = Faults { inconsistent_buckets: [1].into(), ..Default::default() };
+		let inconsistent = generator.buckets_aggregates(&records, &faults);
+		assert_ne!(inconsistent[0], buckets[0]);
+		assert_eq!(inconsistent[1], buckets[1]);
+	}
+}
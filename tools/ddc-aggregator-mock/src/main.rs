@@ -0,0 +1,88 @@
+//! Mock of DAC aggregators for end-to-end tests of DDC validation and payouts.
+//!
+//! The mock serves the endpoints `pallet-ddc-verification` fetches from aggregators: processed
+//! eras, buckets and nodes aggregates, challenges (JSON and protobuf) and merkle tree traversal.
+//! The activity is synthetic, deterministic and correctly signed, it is generated from a
+//! scenario file which can also configure aggregators with faults.
+
+mod activity;
+mod scenario;
+mod server;
+
+use std::{collections::BTreeMap, path::PathBuf, sync::Arc};
+
+use clap::Parser;
+
+use crate::{activity::ActivityGenerator, scenario::Scenario, server::Aggregator};
+
+#[derive(Debug, Parser)]
+#[command(about = "Serves deterministic signed DDC activity as a set of DAC aggregators")]
+struct Cli {
+	/// Path to the scenario file.
+	#[arg(long, short)]
+	scenario: PathBuf,
+
+	/// Print keys of the aggregators and storage nodes of the scenario and exit.
+	#[arg(long)]
+	print_keys: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+	let cli = Cli::parse();
+	let scenario = Scenario::load(&cli.scenario)?;
+	let generator = Arc::new(ActivityGenerator::new(scenario.clone())?);
+
+	let records: BTreeMap<_, _> =
+		scenario.eras.iter().map(|era| (era.id, generator.era_records(era))).collect();
+	let records = Arc::new(records);
+
+	let aggregators = scenario
+		.aggregators
+		.iter()
+		.map(|spec| Aggregator::new(spec, generator.clone(), records.clone()).map(Arc::new))
+		.collect::<Result<Vec<_>, _>>()?;
+
+	if cli.print_keys {
+		for (spec, aggregator) in scenario.aggregators.iter().zip(&aggregators) {
+			println!(
+				"aggregator {} {}:{} {}",
+				spec.key,
+				spec.host,
+				spec.port,
+				aggregator.public_key()
+			);
+		}
+		for (index, uri) in scenario.nodes.iter().enumerate() {
+			println!("node {} {}", uri, generator.node_id(index));
+		}
+		return Ok(());
+	}
+
+	let handles: Vec<_> = scenario
+		.aggregators
+		.iter()
+		.zip(aggregators)
+		.map(|(spec, aggregator)| {
+			log::info!(
+				"Aggregator {} serves on {}:{} with faults {:?}",
+				aggregator.public_key(),
+				spec.host,
+				spec.port,
+				spec.faults
+			);
+			tokio::spawn(aggregator.serve())
+		})
+		.collect();
+
+	for handle in handles {
+		handle
+			.await
+			.map_err(|e| format!("Aggregator task failed: {}", e))?
+			.map_err(|e| format!("Aggregator server failed: {}", e))?;
+	}
+
+	Ok(())
+}
@@ -0,0 +1,145 @@
+//! Scenario file describing the synthetic DDC activity served by the mock aggregators.
+
+use std::{collections::BTreeSet, fs, path::Path};
+
+use ddc_primitives::{BucketId, DdcEra};
+use serde::Deserialize;
+use sp_core::{ed25519, Pair};
+
+/// Synthetic activity of a cluster and the aggregators serving it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+	/// Aggregators (DAC nodes) serving the activity, each one on its own port.
+	pub aggregators: Vec<AggregatorSpec>,
+	/// Secret URIs of storage nodes that served the activity, e.g. `//Node1`.
+	pub nodes: Vec<String>,
+	/// Secret URIs of clients that issued requests to the storage nodes.
+	pub clients: Vec<String>,
+	/// Buckets the activity belongs to.
+	pub buckets: Vec<BucketId>,
+	/// Processed eras, boundaries are in seconds.
+	pub eras: Vec<EraSpec>,
+	/// Amount of generated activity.
+	#[serde(default)]
+	pub activity: ActivitySpec,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AggregatorSpec {
+	/// Secret URI of the aggregator node key, e.g. `//Aggregator1`. The public key must match the
+	/// key the DAC node is registered with on chain.
+	pub key: String,
+	/// Host to listen on.
+	#[serde(default = "default_host")]
+	pub host: String,
+	/// HTTP port to listen on, it must match the port the DAC node is registered with on chain.
+	pub port: u16,
+	/// Misbehaviour of the aggregator.
+	#[serde(default)]
+	pub faults: Faults,
+}
+
+/// Faults an aggregator can be configured with to exercise validation failures.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Faults {
+	/// Eras the aggregator has not processed. They are not listed and their activity is not
+	/// served.
+	#[serde(default)]
+	pub missing_eras: BTreeSet<DdcEra>,
+	/// Buckets reported with inflated usage, inconsistent with other aggregators.
+	#[serde(default)]
+	pub inconsistent_buckets: BTreeSet<BucketId>,
+	/// Secret URIs of storage nodes reported with inflated usage, inconsistent with other
+	/// aggregators.
+	#[serde(default)]
+	pub inconsistent_nodes: BTreeSet<String>,
+	/// Sign JSON responses with a key other than the aggregator key.
+	#[serde(default)]
+	pub bad_response_signatures: bool,
+	/// Do not sign JSON responses even if a signed response is requested.
+	#[serde(default)]
+	pub unsigned_responses: bool,
+	/// Serve activity records with corrupted node signatures in challenge responses.
+	#[serde(default)]
+	pub bad_record_signatures: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EraSpec {
+	pub id: DdcEra,
+	pub start: i64,
+	pub end: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ActivitySpec {
+	/// Number of activity records per each pair of a storage node and a bucket in an era.
+	pub records_per_node_bucket: u32,
+	/// Size of a piece stored or delivered by each request.
+	pub piece_size: u64,
+}
+
+impl Default for ActivitySpec {
+	fn default() -> Self {
+		Self { records_per_node_bucket: 4, piece_size: 1_048_576 }
+	}
+}
+
+fn default_host() -> String {
+	"127.0.0.1".into()
+}
+
+impl Scenario {
+	pub fn load(path: &Path) -> Result<Self, String> {
+		let content = fs::read_to_string(path)
+			.map_err(|e| format!("Failed to read scenario {}: {}", path.display(), e))?;
+		let scenario: Scenario = serde_json::from_str(&content)
+			.map_err(|e| format!("Failed to parse scenario {}: {}", path.display(), e))?;
+		scenario.validate()?;
+
+		Ok(scenario)
+	}
+
+	fn validate(&self) -> Result<(), String> {
+		if self.aggregators.is_empty() {
+			return Err("Scenario has no aggregators".into());
+		}
+		if self.nodes.is_empty() || self.clients.is_empty() {
+			return Err("Scenario has no storage nodes or clients".into());
+		}
+
+		for uri in self
+			.aggregators
+			.iter()
+			.map(|a| &a.key)
+			.chain(self.nodes.iter())
+			.chain(self.clients.iter())
+		{
+			pair_from_uri(uri)?;
+		}
+
+		for aggregator in &self.aggregators {
+			if let Some(node) =
+				aggregator.faults.inconsistent_nodes.iter().find(|n| !self.nodes.contains(n))
+			{
+				return Err(format!("Inconsistent node {} is not in the scenario nodes", node));
+			}
+		}
+
+		if let Some(era) = self.eras.iter().find(|era| era.start >= era.end) {
+			return Err(format!("Era {} ends before it starts", era.id));
+		}
+
+		Ok(())
+	}
+}
+
+/// Key pair from a secret URI such as `//Alice` or a hex seed.
+pub fn pair_from_uri(uri: &str) -> Result<ed25519::Pair, String> {
+	ed25519::Pair::from_string(uri, None).map_err(|e| format!("Bad secret URI {}: {:?}", uri, e))
+}
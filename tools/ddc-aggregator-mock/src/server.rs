@@ -0,0 +1,289 @@
+//! HTTP endpoints of a DAC aggregator as they are called by `AggregatorClient`.
+
+use std::{
+	collections::{BTreeMap, HashMap},
+	convert::Infallible,
+	net::SocketAddr,
+	sync::Arc,
+};
+
+use ddc_primitives::{BucketId, DdcEra};
+use hyper::{
+	header::{ACCEPT, CONTENT_TYPE},
+	service::{make_service_fn, service_fn},
+	Body, Method, Request, Response, Server, StatusCode,
+};
+use pallet_ddc_verification::aggregator_client::json;
+use prost::Message;
+use serde::Serialize;
+use sp_core::{ed25519, Pair};
+
+use crate::{
+	activity::{ActivityGenerator, AggregateKind, AggregateTree, GeneratedRecord},
+	scenario::{pair_from_uri, AggregatorSpec, Faults},
+};
+
+const PROTOBUF_CONTENT_TYPE: &str = "application/protobuf";
+
+/// Mock of a single DAC aggregator.
+pub struct Aggregator {
+	generator: Arc<ActivityGenerator>,
+	records: Arc<BTreeMap<DdcEra, Vec<GeneratedRecord>>>,
+	key: ed25519::Pair,
+	/// Key used to sign responses when the aggregator is configured with bad signatures.
+	foreign_key: ed25519::Pair,
+	faults: Faults,
+	addr: SocketAddr,
+}
+
+impl Aggregator {
+	pub fn new(
+		spec: &AggregatorSpec,
+		generator: Arc<ActivityGenerator>,
+		records: Arc<BTreeMap<DdcEra, Vec<GeneratedRecord>>>,
+	) -> Result<Self, String> {
+		let addr = format!("{}:{}", spec.host, spec.port)
+			.parse()
+			.map_err(|e| format!("Bad address of aggregator {}: {}", spec.key, e))?;
+
+		Ok(Self {
+			generator,
+			records,
+			key: pair_from_uri(&spec.key)?,
+			foreign_key: pair_from_uri(&format!("{}//foreign", spec.key))?,
+			faults: spec.faults.clone(),
+			addr,
+		})
+	}
+
+	pub fn public_key(&self) -> String {
+		format!("0x{}", hex::encode(self.key.public().0))
+	}
+
+	pub async fn serve(self: Arc<Self>) -> Result<(), hyper::Error> {
+		let addr = self.addr;
+		let make_service = make_service_fn(move |_| {
+			let aggregator = self.clone();
+			async move {
+				Ok::<_, Infallible>(service_fn(move |request| {
+					let aggregator = aggregator.clone();
+					async move { Ok::<_, Infallible>(aggregator.handle(request)) }
+				}))
+			}
+		});
+
+		Server::bind(&addr).serve(make_service).await
+	}
+
+	fn handle(&self, request: Request<Body>) -> Response<Body> {
+		let response = self.route(&request).unwrap_or_else(|status| {
+			Response::builder().status(status).body(Body::empty()).expect("valid response")
+		});
+		log::debug!(
+			"{} {} {} -> {}",
+			self.addr,
+			request.method(),
+			request.uri(),
+			response.status()
+		);
+
+		response
+	}
+
+	fn route(&self, request: &Request<Body>) -> Result<Response<Body>, StatusCode> {
+		if request.method() != Method::GET {
+			return Err(StatusCode::METHOD_NOT_ALLOWED);
+		}
+
+		let query = Query::parse(request.uri().query());
+		let accepts_protobuf = request
+			.headers()
+			.get(ACCEPT)
+			.and_then(|accept| accept.to_str().ok())
+			.is_some_and(|accept| accept.contains(PROTOBUF_CONTENT_TYPE));
+
+		let segments: Vec<&str> = request
+			.uri()
+			.path()
+			.trim_matches('/')
+			.split('/')
+			.filter(|s| !s.is_empty())
+			.collect();
+
+		match segments.as_slice() {
+			["activity", "eras"] => self.json(self.eras(), &query),
+			["activity", "buckets"] => {
+				let records = self.era_records(&query)?;
+				let prev_token: Option<BucketId> = query.parse_opt("prevToken")?;
+				let aggregates = self
+					.generator
+					.buckets_aggregates(records, &self.faults)
+					.into_iter()
+					.filter(|a| prev_token.map_or(true, |prev| a.bucket_id > prev));
+				self.json(query.limit(aggregates)?, &query)
+			},
+			["activity", "nodes"] => {
+				let records = self.era_records(&query)?;
+				let prev_token = query.get("prevToken").map(str::to_lowercase);
+				let aggregates = self
+					.generator
+					.nodes_aggregates(records, &self.faults)
+					.into_iter()
+					.filter(|a| prev_token.as_ref().map_or(true, |prev| &a.node_id > prev));
+				self.json(query.limit(aggregates)?, &query)
+			},
+			["activity", "buckets", bucket_id, action] => {
+				let bucket_id: BucketId = bucket_id.parse().map_err(|_| StatusCode::BAD_REQUEST)?;
+				let node = self.node(query.get("nodeId").ok_or(StatusCode::BAD_REQUEST)?)?;
+				let tree = AggregateTree {
+					records: self
+						.era_records(&query)?
+						.iter()
+						.filter(|r| r.bucket_id == bucket_id && r.node == node)
+						.collect(),
+					kind: AggregateKind::BucketSubAggregate,
+				};
+				self.aggregate_action(action, &tree, &query, accepts_protobuf)
+			},
+			["activity", "nodes", node_id, action] => {
+				let node = self.node(node_id)?;
+				let tree = AggregateTree {
+					records: self.era_records(&query)?.iter().filter(|r| r.node == node).collect(),
+					kind: AggregateKind::NodeAggregate,
+				};
+				self.aggregate_action(action, &tree, &query, accepts_protobuf)
+			},
+			_ => Err(StatusCode::NOT_FOUND),
+		}
+	}
+
+	fn aggregate_action(
+		&self,
+		action: &str,
+		tree: &AggregateTree,
+		query: &Query,
+		accepts_protobuf: bool,
+	) -> Result<Response<Body>, StatusCode> {
+		match action {
+			"challenge" => {
+				let ids = query.merkle_tree_node_ids()?;
+				if accepts_protobuf {
+					let response = tree.challenge_response(&ids, self.faults.bad_record_signatures);
+					Ok(Response::builder()
+						.header(CONTENT_TYPE, PROTOBUF_CONTENT_TYPE)
+						.body(Body::from(response.encode_to_vec()))
+						.expect("valid response"))
+				} else {
+					self.json(tree.challenge_response_json(&ids), query)
+				}
+			},
+			"traverse" => {
+				let id = query.parse_opt("merkleTreeNodeId")?.ok_or(StatusCode::BAD_REQUEST)?;
+				self.json(tree.traverse_response(id), query)
+			},
+			_ => Err(StatusCode::NOT_FOUND),
+		}
+	}
+
+	fn eras(&self) -> Vec<json::AggregationEraResponse> {
+		let nodes_total = self.generator.scenario().nodes.len() as u32;
+
+		self.generator
+			.scenario()
+			.eras
+			.iter()
+			.filter(|era| !self.faults.missing_eras.contains(&era.id))
+			.map(|era| {
+				let records = self.records.get(&era.id).map_or(0, |r| r.len()) as u32;
+				json::AggregationEraResponse {
+					id: era.id,
+					status: "PROCESSED".into(),
+					start: era.start,
+					end: era.end,
+					processing_time: 0,
+					nodes_total,
+					nodes_processed: nodes_total,
+					records_processed: records,
+					records_applied: records,
+					records_discarded: 0,
+					attempt: 0,
+				}
+			})
+			.collect()
+	}
+
+	fn era_records(&self, query: &Query) -> Result<&[GeneratedRecord], StatusCode> {
+		let era_id: DdcEra = query.parse_opt("eraId")?.ok_or(StatusCode::BAD_REQUEST)?;
+		if self.faults.missing_eras.contains(&era_id) {
+			return Err(StatusCode::NOT_FOUND);
+		}
+
+		self.records.get(&era_id).map(Vec::as_slice).ok_or(StatusCode::NOT_FOUND)
+	}
+
+	fn node(&self, node_id: &str) -> Result<usize, StatusCode> {
+		self.generator.node_index(node_id).ok_or(StatusCode::NOT_FOUND)
+	}
+
+	/// JSON response, wrapped with the aggregator signature when a signed response is requested.
+	fn json<T: Serialize>(&self, payload: T, query: &Query) -> Result<Response<Body>, StatusCode> {
+		let sign = query.get("sign") == Some("true") && !self.faults.unsigned_responses;
+
+		let body = if sign {
+			let message =
+				serde_json::to_vec(&payload).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+			let signing_key =
+				if self.faults.bad_response_signatures { &self.foreign_key } else { &self.key };
+			serde_json::to_vec(&json::SignedJsonResponse {
+				payload,
+				signer: self.key.public().0.to_vec(),
+				signature: signing_key.sign(&message).0.to_vec(),
+			})
+		} else {
+			serde_json::to_vec(&payload)
+		}
+		.map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+		Ok(Response::builder()
+			.header(CONTENT_TYPE, "application/json")
+			.body(Body::from(body))
+			.expect("valid response"))
+	}
+}
+
+struct Query(HashMap<String, String>);
+
+impl Query {
+	fn parse(query: Option<&str>) -> Self {
+		Self(
+			url::form_urlencoded::parse(query.unwrap_or_default().as_bytes())
+				.into_owned()
+				.collect(),
+		)
+	}
+
+	fn get(&self, key: &str) -> Option<&str> {
+		self.0.get(key).map(String::as_str)
+	}
+
+	fn parse_opt<V: std::str::FromStr>(&self, key: &str) -> Result<Option<V>, StatusCode> {
+		self.get(key)
+			.map(|v| v.parse().map_err(|_| StatusCode::BAD_REQUEST))
+			.transpose()
+	}
+
+	fn limit<I: Iterator>(&self, items: I) -> Result<Vec<I::Item>, StatusCode> {
+		Ok(match self.parse_opt::<usize>("limit")? {
+			Some(limit) => items.take(limit).collect(),
+			None => items.collect(),
+		})
+	}
+
+	fn merkle_tree_node_ids(&self) -> Result<Vec<u32>, StatusCode> {
+		self.get("merkleTreeNodeId")
+			.ok_or(StatusCode::BAD_REQUEST)?
+			.split(',')
+			.map(|id| id.parse().map_err(|_| StatusCode::BAD_REQUEST))
+			.collect()
+	}
+}